@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use bininfo::{FileFormat, Recovery};
+use bininfo::formats::ar::Ar;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut ar) = Ar::parse(data) {
+        let _ = ar.parse_members(data, &mut Recovery::strict());
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use bininfo::Binary;
+
+// Whole pipeline: magic detection followed by the matching parser.
+fuzz_target!(|data: &[u8]| {
+    let _ = Binary::parse(data);
+});
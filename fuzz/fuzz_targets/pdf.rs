@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use bininfo::FileFormat;
+use bininfo::formats::pdf::Pdf;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Pdf::parse(data);
+});
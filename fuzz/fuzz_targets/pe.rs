@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use bininfo::FileFormat;
+use bininfo::formats::pe::Pe;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Pe::parse(data);
+});
@@ -1,12 +1,17 @@
-use crate::Opt;
 use crate::magic::{self, Format};
 use crate::formats::*;
+use crate::Problem;
 
 use failure::{
     Error,
 };
 
-// #[derive(Debug)]
+/// Result of parsing a file of any supported format.
+///
+/// Serializes as `{"format": "elf", "data": {...}}`.
+#[derive(Serialize)]
+#[allow(clippy::large_enum_variant)]
+#[serde(tag = "format", content = "data", rename_all = "lowercase")]
 pub enum Binary {
     Png(png::Png),
     Bmp(bmp::Bmp),
@@ -20,6 +25,7 @@ pub enum Binary {
     Lua(lua::Lua),
 
     Zip(zip::Zip),
+    Ar(ar::Ar),
 
     Pdf(pdf::Pdf),
 
@@ -28,25 +34,37 @@ pub enum Binary {
 
 impl Binary {
 
-    pub fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    /// Detect the format of `buf` and parse it.
+    pub fn parse(buf: &[u8]) -> Result<Self, Error> {
+        Binary::parse_with(buf, &mut Recovery::strict())
+    }
+
+    /// Detect the format of `buf` and parse it, recovering from damaged
+    /// parts of the file as `recovery` allows.
+    pub fn parse_with(buf: &[u8], recovery: &mut Recovery) -> Result<Self, Error> {
+        Binary::parse_as(buf, magic::detect(buf), recovery)
+    }
 
-        use std::io::Cursor;
+    /// Parse `buf` as `format`, whatever its magic says.
+    pub fn parse_as(buf: &[u8], format: Format, recovery: &mut Recovery) -> Result<Self, Error> {
 
-        match magic::parse(&mut Cursor::new(&buf))? {
-            Format::Png => Ok(Binary::Png(png::Png::parse(opt, buf)?)),
-            Format::Bmp => Ok(Binary::Bmp(bmp::Bmp::parse(opt, buf)?)),
-            Format::Gif => Ok(Binary::Gif(gif::Gif::parse(opt, buf)?)),
-            Format::Jpg => Ok(Binary::Jpg(jpg::Jpg::parse(opt, buf)?)),
+        match format {
+            Format::Png => Ok(Binary::Png(png::Png::parse_with(buf, recovery)?)),
+            Format::Bmp => Ok(Binary::Bmp(bmp::Bmp::parse_with(buf, recovery)?)),
+            Format::Gif => Ok(Binary::Gif(gif::Gif::parse_with(buf, recovery)?)),
+            Format::Jpg => Ok(Binary::Jpg(jpg::Jpg::parse_with(buf, recovery)?)),
 
-            Format::Pe => Ok(Binary::Pe(pe::Pe::parse(opt, buf)?)),
-            Format::Elf => Ok(Binary::Elf(elf::Elf::parse(opt, buf)?)),
-            Format::JavaClass => Ok(Binary::JavaClass(javaclass::JavaClass::parse(opt, buf)?)),
-            Format::MachO => Ok(Binary::MachO(macho::MachO::parse(opt, buf)?)),
-            Format::Lua => Ok(Binary::Lua(lua::Lua::parse(opt, buf)?)),
+            Format::Pe => Ok(Binary::Pe(pe::Pe::parse_with(buf, recovery)?)),
+            Format::Elf => Ok(Binary::Elf(elf::Elf::parse_with(buf, recovery)?)),
+            Format::JavaClass => Ok(Binary::JavaClass(javaclass::JavaClass::parse_with(buf, recovery)?)),
+            Format::MachO => Ok(Binary::MachO(macho::MachO::parse_with(buf, recovery)?)),
+            Format::MachOFat => Err(Error::from(Problem::msg("Mach-O universal binaries are not supported"))),
+            Format::Lua => Ok(Binary::Lua(lua::Lua::parse_with(buf, recovery)?)),
 
-            Format::Zip => Ok(Binary::Zip(zip::Zip::parse(opt, buf)?)),
+            Format::Zip => Ok(Binary::Zip(zip::Zip::parse_with(buf, recovery)?)),
+            Format::Ar => Ok(Binary::Ar(ar::Ar::parse_with(buf, recovery)?)),
 
-            Format::Pdf => Ok(Binary::Pdf(pdf::Pdf::parse(opt, buf)?)),
+            Format::Pdf => Ok(Binary::Pdf(pdf::Pdf::parse_with(buf, recovery)?)),
 
             Format::Unknown => Ok(Binary::Unknown),
         }
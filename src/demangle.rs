@@ -0,0 +1,461 @@
+//! Readable names of symbols mangled by C++ and Rust compilers: Itanium C++
+//! names (`_Z`), Rust legacy (`_ZN..17h<hash>E`) and v0 (`_R`) names, and
+//! MSVC C++ names (`?`).
+//!
+//! Names are printed the way `c++filt` prints them, without the return
+//! type, access and calling convention MSVC names also carry.
+
+/// Demangled form of `name`, or None when it is not a mangled name.
+pub fn demangle(name: &str) -> Option<String> {
+    // Mach-O puts an underscore before every symbol
+    let name = if name.starts_with("__Z") || name.starts_with("__R") { &name[1..] } else { name };
+
+    if name.starts_with("_R") || name.starts_with("_ZN") {
+        if let Ok(demangled) = rustc_demangle::try_demangle(name) {
+            return Some(format!("{:#}", demangled));
+        }
+    }
+    if name.starts_with("_Z") {
+        return cpp_demangle::Symbol::new(name).ok()
+            .and_then(|symbol| symbol.demangle(&cpp_demangle::DemangleOptions::default()).ok());
+    }
+    if name.starts_with('?') {
+        return Msvc::new(name).symbol();
+    }
+    None
+}
+
+/// Names with a special encoding after `??`, like constructors and operators.
+enum Special {
+    Constructor,
+    Destructor,
+    Name(&'static str),
+}
+
+fn special_name(code: &str) -> Option<Special> {
+    Some(match code {
+        "0" => Special::Constructor,
+        "1" => Special::Destructor,
+        "2" => Special::Name("operator new"),
+        "3" => Special::Name("operator delete"),
+        "4" => Special::Name("operator="),
+        "5" => Special::Name("operator>>"),
+        "6" => Special::Name("operator<<"),
+        "7" => Special::Name("operator!"),
+        "8" => Special::Name("operator=="),
+        "9" => Special::Name("operator!="),
+        "A" => Special::Name("operator[]"),
+        "B" => Special::Name("operator cast"),
+        "C" => Special::Name("operator->"),
+        "D" => Special::Name("operator*"),
+        "E" => Special::Name("operator++"),
+        "F" => Special::Name("operator--"),
+        "G" => Special::Name("operator-"),
+        "H" => Special::Name("operator+"),
+        "I" => Special::Name("operator&"),
+        "J" => Special::Name("operator->*"),
+        "K" => Special::Name("operator/"),
+        "L" => Special::Name("operator%"),
+        "M" => Special::Name("operator<"),
+        "N" => Special::Name("operator<="),
+        "O" => Special::Name("operator>"),
+        "P" => Special::Name("operator>="),
+        "Q" => Special::Name("operator,"),
+        "R" => Special::Name("operator()"),
+        "S" => Special::Name("operator~"),
+        "T" => Special::Name("operator^"),
+        "U" => Special::Name("operator|"),
+        "V" => Special::Name("operator&&"),
+        "W" => Special::Name("operator||"),
+        "X" => Special::Name("operator*="),
+        "Y" => Special::Name("operator+="),
+        "Z" => Special::Name("operator-="),
+        "_0" => Special::Name("operator/="),
+        "_1" => Special::Name("operator%="),
+        "_2" => Special::Name("operator>>="),
+        "_3" => Special::Name("operator<<="),
+        "_4" => Special::Name("operator&="),
+        "_5" => Special::Name("operator|="),
+        "_6" => Special::Name("operator^="),
+        "_7" => Special::Name("`vftable'"),
+        "_8" => Special::Name("`vbtable'"),
+        "_E" => Special::Name("`vector deleting destructor'"),
+        "_G" => Special::Name("`scalar deleting destructor'"),
+        "_U" => Special::Name("operator new[]"),
+        "_V" => Special::Name("operator delete[]"),
+        _ => return None,
+    })
+}
+
+/// Deepest nesting of types decoded, so crafted names cannot exhaust the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Decoder of MSVC names. Names and function parameter types seen so far
+/// can be referred back to by a digit, up to 10 of each.
+struct Msvc<'a> {
+    input: &'a [u8],
+    pos:   usize,
+    names: Vec<String>,
+    types: Vec<String>,
+    depth: usize,
+}
+
+impl<'a> Msvc<'a> {
+
+    fn new(input: &'a str) -> Msvc<'a> {
+        Msvc { input: input.as_bytes(), pos: 0, names: Vec::new(), types: Vec::new(), depth: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Text up to the next `@`, which is skipped.
+    fn until_at(&mut self) -> Option<&'a str> {
+        let input = self.input;
+        let len = input[self.pos..].iter().position(|&c| c == b'@')?;
+        let text = std::str::from_utf8(&input[self.pos..self.pos + len]).ok()?;
+        self.pos += len + 1;
+        Some(text)
+    }
+
+    fn remember(table: &mut Vec<String>, entry: &str) {
+        if table.len() < 10 {
+            table.push(entry.to_string());
+        }
+    }
+
+    fn symbol(&mut self) -> Option<String> {
+        if !self.eat(b'?') {
+            return None;
+        }
+
+        let special = if self.peek() == Some(b'?') && self.input.get(self.pos + 1) != Some(&b'$') {
+            self.pos += 1;
+            let code = if self.eat(b'_') { format!("_{}", self.next()? as char) } else { (self.next()? as char).to_string() };
+            Some(special_name(&code)?)
+        } else {
+            None
+        };
+        let first = match special {
+            Some(_) => None,
+            None => Some(self.fragment()?),
+        };
+        let mut scopes = self.scopes()?;
+        scopes.reverse();
+
+        let last = match special {
+            Some(Special::Constructor) => scopes.last()?.clone(),
+            Some(Special::Destructor) => format!("~{}", scopes.last()?),
+            Some(Special::Name(name)) => name.to_string(),
+            None => first?,
+        };
+        scopes.push(last);
+        let name = scopes.join("::");
+
+        self.encoding(name)
+    }
+
+    /// Type of the symbol after its name: data, or a function and its parameters.
+    fn encoding(&mut self, name: String) -> Option<String> {
+        let kind = match self.next() {
+            Some(kind) => kind,
+            // Names of vftables and the like may end there
+            None => return Some(name),
+        };
+        match kind {
+            b'0'..=b'4' => {
+                self.type_()?;
+                Some(name)
+            },
+            b'5'..=b'9' | b'$' => Some(name),
+            b'A'..=b'Z' => {
+                // Thunks give the adjustment of `this` first
+                if b"GHOPWX".contains(&kind) {
+                    self.number()?;
+                }
+                let member = b"ABEFGHIJMNOPQRUVWX".contains(&kind);
+                let mut constant = false;
+                if member {
+                    while self.peek().is_some_and(|c| b"EFI".contains(&c)) {
+                        self.pos += 1;
+                    }
+                    constant = matches!(self.next()?, b'B' | b'D');
+                }
+                // Calling convention
+                self.next()?;
+                // Constructors and destructors return nothing
+                if !self.eat(b'@') {
+                    if self.eat(b'?') {
+                        self.next()?;
+                    }
+                    self.type_()?;
+                }
+                let params = self.params()?;
+                Some(format!("{}({}){}", name, params, if constant { " const" } else { "" }))
+            },
+            _ => None,
+        }
+    }
+
+    /// Parameters of a function, from `X` for none to a list ended by `@`
+    /// or by `Z` for a variadic function.
+    fn params(&mut self) -> Option<String> {
+        if self.eat(b'X') {
+            return Some(String::new());
+        }
+        let mut params = Vec::new();
+        loop {
+            if self.eat(b'@') {
+                break;
+            }
+            if self.eat(b'Z') {
+                params.push("...".to_string());
+                break;
+            }
+            let start = self.pos;
+            let param = self.type_()?;
+            if self.pos - start > 1 {
+                Msvc::remember(&mut self.types, &param);
+            }
+            params.push(param);
+        }
+        Some(params.join(", "))
+    }
+
+    /// Enclosing namespaces and classes, innermost first, up to a `@`.
+    fn scopes(&mut self) -> Option<Vec<String>> {
+        let mut scopes = Vec::new();
+        while !self.eat(b'@') {
+            scopes.push(self.fragment()?);
+        }
+        Some(scopes)
+    }
+
+    /// One part of a qualified name.
+    fn fragment(&mut self) -> Option<String> {
+        match self.peek()? {
+            c @ b'0'..=b'9' => {
+                self.pos += 1;
+                self.names.get((c - b'0') as usize).cloned()
+            },
+            b'?' => {
+                self.pos += 1;
+                match self.next()? {
+                    b'$' => {
+                        // Templates have their own back references
+                        let name = self.until_at()?;
+                        let names = std::mem::take(&mut self.names);
+                        let types = std::mem::take(&mut self.types);
+                        Msvc::remember(&mut self.names, name);
+                        let args = self.template_args();
+                        self.names = names;
+                        self.types = types;
+                        let template = format!("{}<{}>", name, args?);
+                        Msvc::remember(&mut self.names, &template);
+                        Some(template)
+                    },
+                    b'A' => {
+                        self.until_at()?;
+                        Some("`anonymous namespace'".to_string())
+                    },
+                    _ => None,
+                }
+            },
+            _ => {
+                let name = self.until_at()?;
+                Msvc::remember(&mut self.names, name);
+                Some(name.to_string())
+            },
+        }
+    }
+
+    fn template_args(&mut self) -> Option<String> {
+        let mut args = Vec::new();
+        while !self.eat(b'@') {
+            let start = self.pos;
+            let arg = if self.input[self.pos..].starts_with(b"$0") {
+                self.pos += 2;
+                self.number()?.to_string()
+            } else {
+                self.type_()?
+            };
+            if self.pos - start > 1 {
+                Msvc::remember(&mut self.types, &arg);
+            }
+            args.push(arg);
+        }
+        Some(args.join(","))
+    }
+
+    /// Qualified name of a class, struct, union or enum.
+    fn class_name(&mut self) -> Option<String> {
+        let first = self.fragment()?;
+        let mut scopes = self.scopes()?;
+        scopes.reverse();
+        scopes.push(first);
+        Some(scopes.join("::"))
+    }
+
+    fn type_(&mut self) -> Option<String> {
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let decoded = self.nested_type();
+        self.depth -= 1;
+        decoded
+    }
+
+    fn nested_type(&mut self) -> Option<String> {
+        let c = self.next()?;
+        let basic = match c {
+            b'C' => "signed char",
+            b'D' => "char",
+            b'E' => "unsigned char",
+            b'F' => "short",
+            b'G' => "unsigned short",
+            b'H' => "int",
+            b'I' => "unsigned int",
+            b'J' => "long",
+            b'K' => "unsigned long",
+            b'M' => "float",
+            b'N' => "double",
+            b'O' => "long double",
+            b'X' => "void",
+            b'_' => match self.next()? {
+                b'N' => "bool",
+                b'J' => "__int64",
+                b'K' => "unsigned __int64",
+                b'W' => "wchar_t",
+                b'S' => "char16_t",
+                b'U' => "char32_t",
+                b'Q' => "char8_t",
+                _ => return None,
+            },
+            b'P' | b'Q' | b'R' | b'S' => return self.pointer(if c == b'Q' { " *const" } else { " *" }),
+            b'A' | b'B' => return self.pointer(" &"),
+            b'T' | b'U' | b'V' => return self.class_name(),
+            b'W' => {
+                self.next()?;
+                return self.class_name();
+            },
+            b'0'..=b'9' => return self.types.get((c - b'0') as usize).cloned(),
+            b'?' => {
+                let constant = matches!(self.next()?, b'B' | b'D');
+                let inner = self.type_()?;
+                return Some(if constant { format!("const {}", inner) } else { inner });
+            },
+            b'$' => {
+                if !self.eat(b'$') {
+                    return None;
+                }
+                return match self.next()? {
+                    b'Q' => self.pointer(" &&"),
+                    b'T' => Some("std::nullptr_t".to_string()),
+                    b'C' => {
+                        let constant = matches!(self.next()?, b'B' | b'D');
+                        let inner = self.type_()?;
+                        Some(if constant { format!("const {}", inner) } else { inner })
+                    },
+                    _ => None,
+                };
+            },
+            _ => return None,
+        };
+        Some(basic.to_string())
+    }
+
+    /// Pointer or reference to a type, or to a function.
+    fn pointer(&mut self, suffix: &str) -> Option<String> {
+        while self.peek().is_some_and(|c| b"EFI".contains(&c)) {
+            self.pos += 1;
+        }
+        match self.next()? {
+            b'6' => {
+                // Calling convention, return type, parameters and exceptions
+                self.next()?;
+                let ret = self.type_()?;
+                let params = self.params()?;
+                self.eat(b'Z');
+                Some(format!("{} ({})({})", ret, suffix.trim(), params))
+            },
+            cv => {
+                let pointee = self.type_()?;
+                let constant = if matches!(cv, b'B' | b'D') { "const " } else { "" };
+                Some(format!("{}{}{}", constant, pointee, suffix))
+            },
+        }
+    }
+
+    /// Number in MSVC encoding: a digit for 1 to 10, else hexadecimal
+    /// written with the letters A to P up to a `@`, negated by a leading `?`.
+    fn number(&mut self) -> Option<i64> {
+        let negative = self.eat(b'?');
+        let value = match self.next()? {
+            c @ b'0'..=b'9' => (c - b'0') as i64 + 1,
+            c @ b'A'..=b'P' => {
+                let mut value = (c - b'A') as i64;
+                loop {
+                    match self.next()? {
+                        b'@' => break,
+                        c @ b'A'..=b'P' => value = value.checked_mul(16)? + (c - b'A') as i64,
+                        _ => return None,
+                    }
+                }
+                value
+            },
+            b'@' => 0,
+            _ => return None,
+        };
+        Some(if negative { -value } else { value })
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::demangle;
+
+    #[test]
+    fn itanium_and_rust() {
+        assert_eq!(demangle("_ZN3foo3barEv").unwrap(), "foo::bar()");
+        assert_eq!(demangle("__ZNSt6vectorIiSaIiEE9push_backERKi").unwrap(), "std::vector<int, std::allocator<int> >::push_back(int const&)");
+        assert_eq!(demangle("_ZN4core3fmt5write17h0123456789abcdefE").unwrap(), "core::fmt::write");
+        assert_eq!(demangle("_RNvCs1234_7mycrate4main").unwrap(), "mycrate::main");
+        assert_eq!(demangle("main"), None);
+        assert_eq!(demangle("_Z3fo"), None);
+    }
+
+    #[test]
+    fn msvc() {
+        assert_eq!(demangle("?foo@@YAHH@Z").unwrap(), "foo(int)");
+        assert_eq!(demangle("?bar@Foo@@QEBAXXZ").unwrap(), "Foo::bar() const");
+        assert_eq!(demangle("??0Foo@@QEAA@XZ").unwrap(), "Foo::Foo()");
+        assert_eq!(demangle("??1Foo@ns@@UEAA@XZ").unwrap(), "ns::Foo::~Foo()");
+        assert_eq!(demangle("??_7Foo@@6B@").unwrap(), "Foo::`vftable'");
+        assert_eq!(demangle("?x@@3HA").unwrap(), "x");
+        assert_eq!(demangle("?f@@YAXPEAD0@Z").unwrap(), "f(char *, char *)");
+        assert_eq!(demangle("?push_back@?$vector@HV?$allocator@H@std@@@std@@QEAAXAEBH@Z").unwrap(),
+                   "std::vector<int,std::allocator<int>>::push_back(const int &)");
+        assert_eq!(demangle("?printf@@YAHPEBDZZ").unwrap(), "printf(const char *, ...)");
+        assert_eq!(demangle("?broken@"), None);
+        assert_eq!(demangle(&format!("?f@@YAX{}H@Z", "PEA".repeat(1000))), None);
+    }
+
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use bininfo::Problem;
+use failure::{Error, Fail};
+
+/// Bytes shown per hexdump line.
+const WIDTH: usize = 16;
+/// Lines shown before and after the one holding the failing byte.
+const CONTEXT_LINES: usize = 2;
+
+/// Parse error together with a hexdump of the bytes it happened at.
+#[derive(Debug)]
+pub struct Diagnostic {
+    error: Error,
+    excerpt: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}\n\n{}", self.error, self.excerpt)
+    }
+}
+
+impl Fail for Diagnostic {
+    fn cause(&self) -> Option<&dyn Fail> {
+        Some(self.error.as_fail())
+    }
+}
+
+/// Attaches a hexdump excerpt of `buf` to `error` if it knows the offset it happened at.
+pub fn locate(error: Error, buf: &[u8]) -> Error {
+    let offset = match error.downcast_ref::<Problem>().and_then(|problem| problem.offset) {
+        Some(offset) => offset,
+        None => return error,
+    };
+
+    Error::from(Diagnostic {
+        error,
+        excerpt: excerpt(buf, offset),
+    })
+}
+
+/// Hexdump of the lines of `buf` around `offset`, with the byte at `offset`
+/// marked on the line below it.
+pub fn excerpt(buf: &[u8], offset: u64) -> String {
+    let mut out = String::new();
+
+    if buf.is_empty() {
+        out.push_str("File is empty");
+        return out;
+    }
+
+    let past_end = offset >= buf.len() as u64;
+    let line = if past_end { (buf.len() - 1) / WIDTH } else { offset as usize / WIDTH };
+    let first = line.saturating_sub(CONTEXT_LINES);
+    let last = std::cmp::min(line + CONTEXT_LINES, (buf.len() - 1) / WIDTH);
+
+    for l in first..=last {
+        let start = l * WIDTH;
+        let bytes = &buf[start..std::cmp::min(start + WIDTH, buf.len())];
+        out.push_str(&hexdump_line(start, bytes));
+        out.push('\n');
+
+        if !past_end && l == line {
+            let col = offset as usize % WIDTH;
+            // Offset column, two spaces, three chars per byte and a gap after the eighth
+            let indent = 8 + 2 + col * 3 + if col >= 8 { 1 } else { 0 };
+            out.push_str(&" ".repeat(indent));
+            out.push_str("^^\n");
+        }
+    }
+
+    if past_end {
+        out.push_str(&format!("{:#X} is past the end of the file of size {:#X}\n", offset, buf.len()));
+    }
+
+    out.pop();
+    out
+}
+
+fn hexdump_line(start: usize, bytes: &[u8]) -> String {
+    let mut hex = String::new();
+    for i in 0..WIDTH {
+        if i == 8 {
+            hex.push(' ');
+        }
+        match bytes.get(i) {
+            Some(b) => hex.push_str(&format!("{:02x} ", b)),
+            None => hex.push_str("   "),
+        }
+    }
+
+    let ascii: String = bytes.iter()
+        .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+        .collect();
+
+    format!("{:08x}  {} |{}|", start, hex, ascii)
+}
@@ -0,0 +1,165 @@
+//! Errors reported by the parsers.
+//!
+//! Every parser fails with a [`Problem`] wrapped in a `failure::Error`. Besides
+//! saying what went wrong, a problem records where: the file offset of the
+//! failing read and the structures that were being parsed at the time, e.g.
+//! `ELF symbol #3` in `section .dynsym`. Callers get at it with
+//! `error.downcast_ref::<Problem>()`.
+
+use std::fmt;
+
+use failure::{Error, Fail};
+
+/// What went wrong, without the where.
+#[derive(Debug)]
+pub enum Cause {
+    /// A value could not be read, usually because it runs past the end of the file.
+    Read(scroll::Error),
+    /// The file holds something invalid or unsupported.
+    Msg(String),
+}
+
+/// Error raised while parsing a file.
+#[derive(Debug)]
+pub struct Problem {
+    pub cause: Cause,
+    /// File offset the failure happened at, when known.
+    pub offset: Option<u64>,
+    /// Structures that were being parsed, innermost first.
+    pub context: Vec<String>,
+}
+
+impl Problem {
+    pub fn msg<S: Into<String>>(msg: S) -> Problem {
+        Problem {
+            cause: Cause::Msg(msg.into()),
+            offset: None,
+            context: Vec::new(),
+        }
+    }
+
+    pub fn read(err: scroll::Error) -> Problem {
+        Problem {
+            cause: Cause::Read(err),
+            offset: None,
+            context: Vec::new(),
+        }
+    }
+
+    /// Records the file offset of the failure, unless a more precise one is already known.
+    pub fn at(mut self, offset: u64) -> Problem {
+        self.offset = self.offset.or(Some(offset));
+        self
+    }
+
+    /// Records a structure enclosing the ones already recorded.
+    pub fn within<S: Into<String>>(mut self, what: S) -> Problem {
+        self.context.push(what.into());
+        self
+    }
+}
+
+impl fmt::Display for Cause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cause::Read(e) => write!(f, "{}", e),
+            Cause::Msg(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.context.is_empty() {
+            write!(f, "Could not read {}", self.context.join(" in "))?;
+            if let Some(offset) = self.offset {
+                write!(f, " at {:#X}", offset)?;
+            }
+            write!(f, ": {}", self.cause)
+        }
+        else if let Some(offset) = self.offset {
+            write!(f, "{} at {:#X}", self.cause, offset)
+        }
+        else {
+            write!(f, "{}", self.cause)
+        }
+    }
+}
+
+impl Fail for Problem {
+    fn cause(&self) -> Option<&dyn Fail> {
+        match &self.cause {
+            Cause::Read(e) => Some(e),
+            Cause::Msg(_) => None,
+        }
+    }
+}
+
+impl From<scroll::Error> for Problem {
+    fn from(err: scroll::Error) -> Problem {
+        Problem::read(err)
+    }
+}
+
+impl From<std::str::Utf8Error> for Problem {
+    fn from(err: std::str::Utf8Error) -> Problem {
+        Problem::msg(err.to_string())
+    }
+}
+
+impl From<Error> for Problem {
+    fn from(err: Error) -> Problem {
+        match err.downcast::<Problem>() {
+            Ok(problem) => problem,
+            Err(err) => match err.downcast::<scroll::Error>() {
+                Ok(err) => Problem::read(err),
+                Err(err) => Problem::msg(err.to_string()),
+            },
+        }
+    }
+}
+
+/// Adds location to the errors of parsing results.
+pub trait ResultExt<T> {
+    /// Records that the error happened reading `what` at file offset `offset`.
+    fn at<D: fmt::Display>(self, offset: u64, what: D) -> Result<T, Error>;
+
+    /// Records that the error happened somewhere inside `what`.
+    fn within<D: fmt::Display>(self, what: D) -> Result<T, Error>;
+}
+
+impl<T, E: Into<Problem>> ResultExt<T> for Result<T, E> {
+    fn at<D: fmt::Display>(self, offset: u64, what: D) -> Result<T, Error> {
+        self.map_err(|e| Error::from(e.into().at(offset).within(what.to_string())))
+    }
+
+    fn within<D: fmt::Display>(self, what: D) -> Result<T, Error> {
+        self.map_err(|e| Error::from(e.into().within(what.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn innermost_offset_and_context_come_first() {
+        let res: Result<(), scroll::Error> = Err(scroll::Error::BadOffset(0x40));
+        let err = res.at(0x40, "ELF symbol #3")
+            .within("section .dynsym")
+            .at(0x30, "ELF file")
+            .unwrap_err();
+        let problem = err.downcast_ref::<Problem>().unwrap();
+
+        assert_eq!(problem.offset, Some(0x40));
+        assert_eq!(problem.context, ["ELF symbol #3", "section .dynsym", "ELF file"]);
+        assert!(err.to_string().starts_with("Could not read ELF symbol #3 in section .dynsym in ELF file at 0x40: "));
+    }
+
+    #[test]
+    fn message_without_context() {
+        assert_eq!(Problem::msg("Invalid EI_CLASS").to_string(), "Invalid EI_CLASS");
+        assert_eq!(Problem::msg("Invalid load command size: 3").at(0x20).to_string(),
+                   "Invalid load command size: 3 at 0x20");
+    }
+}
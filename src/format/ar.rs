@@ -0,0 +1,115 @@
+use ansi_term::Color;
+use bininfo::Binary;
+use bininfo::formats::ar::*;
+use failure::Error;
+
+use crate::{Opt, Part};
+use crate::render::{Document, Section, Table, Text, paint};
+use super::{Describe, symbol_name};
+
+impl Describe for Ar {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+
+        let mut doc = Document::new(ar_title(self));
+
+        //
+        // ARCHIVE
+        //
+        if opt.show(Part::Headers) {
+            let mut section = Section::new("Archive");
+            section
+                .field("Variant", paint(Color::Blue, match self.variant { ArVariant::Gnu => "GNU", ArVariant::Bsd => "BSD" }))
+                .field("Thin", self.thin)
+                .field("Members", self.members.len())
+                .field("Symbol index", match self.symbol_index {
+                    Some(index) => text![paint(Color::Yellow, index.name()), format!(" ({} symbols)", self.symbols.len())],
+                    None => text!["none"],
+                });
+            if let Some(version) = &self.deb_version {
+                section.field("Debian format", paint(Color::Blue, version.as_str()));
+            }
+            doc.section(section);
+        }
+
+        //
+        // MEMBERS
+        //
+        if opt.show(Part::Sections) && !self.members.is_empty() {
+            let mut section = Section::with_count("Members", self.members.len());
+            let mut table = Table::new(&["Idx", "Name", "Offset", "Size", "Mode", "UID", "GID", "Date"])
+                .align_right(&[0, 5, 6, 7]);
+
+            for (i, member) in self.members.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    paint(Color::Blue, member.name.as_str()),
+                    // Members of thin archives are files of their own
+                    if self.thin { text!["-"] } else { text![paint(Color::Yellow, format!("{:#X}", member.data_offset))] },
+                    paint(Color::Green, format!("{:#X}", member.size)),
+                    format!("{:o}", member.mode),
+                    member.uid,
+                    member.gid,
+                    member.date,
+                ]);
+            }
+            section.table(table);
+            if self.members.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // SYMBOL INDEX
+        //
+        if opt.show(Part::Symbols) && !self.symbols.is_empty() {
+            let mut section = Section::with_count("SymbolIndex", self.symbols.len());
+            let mut table = Table::new(&["Idx", "Name", "Member"]).align_right(&[0]);
+
+            for (i, symbol) in self.symbols.iter().enumerate().take(opt.trim_lines) {
+                let member = match symbol.member.and_then(|member| self.members.get(member)) {
+                    Some(member) => paint(Color::Blue, member.name.as_str()),
+                    None => paint(Color::Red, format!("{:#X}", symbol.offset)),
+                };
+                table.row(cells![
+                    i,
+                    paint(Color::Yellow, symbol_name(&symbol.name, opt.demangle)),
+                    member,
+                ]);
+            }
+            section.table(table);
+            if self.symbols.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // PARSED MEMBERS
+        //
+        for member in &self.members {
+            if let Some(binary) = member.binary.as_ref().filter(|binary| !matches!(binary, Binary::Unknown)) {
+                let parsed = binary.describe(opt)?;
+                let mut section = Section::new(text![paint(Color::Blue, member.name.as_str()), ": ", parsed.title]);
+                section.children = parsed.children;
+                doc.section(section);
+            }
+        }
+
+        Ok(doc)
+    }
+
+}
+
+fn ar_title(ar: &Ar) -> Text {
+    if let Some(version) = &ar.deb_version {
+        return text!["Debian package ", paint(Color::Blue, version.as_str())];
+    }
+    let mut title = text!["ar archive "];
+    title.push(paint(Color::Blue, match ar.variant { ArVariant::Gnu => "GNU", ArVariant::Bsd => "BSD" }));
+    if ar.thin {
+        title.push(" ").push(paint(Color::Yellow, "thin"));
+    }
+    title
+}
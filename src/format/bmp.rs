@@ -0,0 +1,97 @@
+use bininfo::Problem;
+use bininfo::formats::bmp::{Bmp, INFO_V1, INFO_V2, INFO_V3, INFO_V4, INFO_V5};
+use failure::Error;
+
+use crate::Opt;
+use crate::render::{Document, Section, paint};
+use super::Describe;
+
+impl Describe for Bmp {
+
+    fn describe(&self, _opt: &Opt) -> Result<Document, Error> {
+        use ansi_term::Color;
+
+        let mut doc = Document::new(format!("BMP width: {}px height: {}px",
+                                            self.dib_header.width,
+                                            self.dib_header.height));
+
+        let mut file_header = Section::new("BITMAPFILEHEADER");
+        file_header
+            .field("File size", self.bmp_header.file_size)
+            .field("Reserved1", self.bmp_header.reserved1)
+            .field("Reserved2", self.bmp_header.reserved2)
+            .field("Offset to pixels", self.bmp_header.offset);
+        doc.section(file_header);
+
+        let version = match self.dib_header.size as usize {
+            INFO_V1 => "",
+            INFO_V2 => "V2",
+            INFO_V3 => "V3",
+            INFO_V4 => "V4",
+            INFO_V5 => "V5",
+            _ => return Err(Error::from(Problem::msg("Invalid/Unsupported header".to_string()))),
+        };
+
+        //
+        // BITMAPINFOHEADER
+        //
+        let mut info = Section::new(text!["BITMAP", paint(Color::Yellow, version), "INFOHEADER"]);
+        info
+            .field("Bitmap width", self.dib_header.width)
+            .field("Bitmap height", self.dib_header.height)
+            .field("Color planes", self.dib_header.planes)
+            .field("Bits per pixel", self.dib_header.bpp)
+            .field("Compression method", self.dib_header.compression)
+            .field("Image size", self.dib_header.image_size)
+            .field("Print resolution", format!("{}x{}",
+                                               self.dib_header.h_resolution,
+                                               self.dib_header.v_resolution))
+            .field("Number of colors in palette", self.dib_header.palette)
+            .field("Important colors", self.dib_header.imp_colors);
+
+        //
+        // RGB bitmask
+        //
+        if let Some(rgb_bitmask) = &self.rgb_bitmask {
+            info
+                .field("Red channel bitmask", paint(Color::Red, format!("{:#010X}", rgb_bitmask.red_bitmask)))
+                .field("Green channel bitmask", paint(Color::Green, format!("{:#010X}", rgb_bitmask.green_bitmask)))
+                .field("Blue channel bitmask", paint(Color::Blue, format!("{:#010X}", rgb_bitmask.blue_bitmask)));
+        }
+
+        //
+        // Alpha bitmask
+        //
+        if let Some(alpha_bitmask) = &self.alpha_bitmask {
+            info.field("Alpha channel bitmask", paint(Color::White, format!("{:#010X}", alpha_bitmask.alpha_bitmask)));
+        }
+
+        //
+        // Color space gamma
+        //
+        if let Some(csg) = &self.color_space_gamma {
+            info
+                .line("Color space:")
+                .hex(&csg.color_space)
+                .field("Red channel gamma", paint(Color::Red, format!("{:#010X}", csg.gamma_red)))
+                .field("Green channel gamma", paint(Color::Green, format!("{:#010X}", csg.gamma_green)))
+                .field("Blue channel gamma", paint(Color::Blue, format!("{:#010X}", csg.gamma_blue)));
+        }
+
+        //
+        // ICC
+        //
+        if let Some(icc) = &self.icc_color {
+            info
+                .field("Intent", icc.intent)
+                .field("ICC data", icc.icc_data)
+                .field("ICC size", icc.icc_size)
+                .field("Reserved", icc.reserved);
+        }
+        doc.section(info);
+
+        Ok(doc)
+
+    }
+
+}
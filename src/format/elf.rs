@@ -0,0 +1,1230 @@
+use bininfo::Problem;
+use bininfo::formats::elf::*;
+use bininfo::formats::elf::compress::compress_to_str;
+use bininfo::formats::elf::coredump::*;
+use bininfo::formats::elf::dwarf::{Dwarf, lang_to_str};
+use bininfo::formats::elf::hardening::{Hardening, Pie, Relro};
+use bininfo::formats::elf::hash::{Elf_gnu_hash, Elf_hash, Miss, Unreachable};
+use bininfo::formats::elf::layout::{Region, RegionKind};
+use bininfo::formats::elf::module::{Elf_module, PKEY_ID_PKCS7, pkey_id_to_str};
+use bininfo::formats::elf::note::{NoteDesc, Property};
+use failure::Error;
+use scroll::Pread;
+use textwrap::fill;
+
+use crate::{Opt, Part};
+use crate::render::{Document, Section, Table, Text, paint};
+use super::{Describe, symbol_name};
+
+impl Describe for Elf {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+        use ansi_term::Color;
+
+        //
+        // ELF file
+        //
+        let mut doc = Document::new(elf_title(&self.header));
+        let dynsym_names = versioned_names(self, opt.demangle);
+        if opt.show(Part::Headers) {
+            doc.section(elf_header(&self.header));
+        }
+
+        //
+        // Program Headers
+        //
+        if opt.show(Part::Headers) {
+            let mut section = Section::with_count("ProgramHeaders", self.program_headers.len());
+            let mut table = Table::new(&["Idx", "Type", "Flags", "Offset", "Vaddr", "Paddr", "Filesz", "Memsz", "Align"]);
+            for (i, header) in self.program_headers.iter().enumerate() {
+                let flags = text![
+                    if header.p_flags & PF_R != 0 { paint(Color::Fixed(83), "R") } else { paint(Color::Fixed(138), "-") },
+                    if header.p_flags & PF_W != 0 { paint(Color::Fixed(10), "W") } else { paint(Color::Fixed(138), "-") },
+                    if header.p_flags & PF_X != 0 { paint(Color::Red, "X") } else { paint(Color::Fixed(138), "-") },
+                ];
+                table.row(cells![
+                    i,
+                    pt_to_str(header.p_type),
+                    flags,
+                    paint(Color::Yellow, format!("{:#X}", header.p_offset)),
+                    paint(Color::Red, format!("{:#X}", header.p_vaddr)),
+                    paint(Color::Red, format!("{:#X}", header.p_paddr)),
+                    paint(Color::Green, format!("{:#X}", header.p_filesz)),
+                    paint(Color::Green, format!("{:#X}", header.p_memsz)),
+                    format!("{:#X}", header.p_align),
+                ]);
+            }
+            section.table(table);
+            doc.section(section);
+        }
+
+        //
+        // Sections of each segment
+        //
+        if opt.show(Part::Headers) && !self.program_headers.is_empty() && !self.section_headers.is_empty() {
+            doc.section(elf_segment_mapping(self, opt.wrap_chars));
+        }
+
+        //
+        // Notes
+        //
+        if opt.show(Part::Headers) && !self.notes.is_empty() {
+            let mut section = Section::with_count("Notes", self.notes.len());
+            let mut table = Table::new(&["Idx", "Name", "Type", "Desc"]);
+            for (i, note) in self.notes.iter().enumerate() {
+                table.row(cells![
+                    i,
+                    paint(Color::Blue, note.name.as_str()),
+                    paint(Color::Yellow, note.type_to_str()),
+                    fill(&note_desc(self, note), opt.wrap_chars),
+                ]);
+            }
+            section.table(table);
+            doc.section(section);
+        }
+
+        //
+        // Core dump
+        //
+        if let (true, Some(core)) = (opt.show(Part::Headers), &self.core) {
+            for section in elf_core(core, self.header.e_machine, opt.wrap_chars) {
+                doc.section(section);
+            }
+        }
+
+        //
+        // Kernel module
+        //
+        if let (true, Some(module)) = (opt.show(Part::Headers), &self.module) {
+            for section in elf_module(module, opt.wrap_chars) {
+                doc.section(section);
+            }
+        }
+        if let (true, Some(module)) = (opt.show(Part::Imports), &self.module) {
+            if !module.versions.is_empty() {
+                doc.section(elf_module_versions(module, opt.trim_lines));
+            }
+        }
+
+        //
+        // Section headers
+        //
+        if opt.show(Part::Sections) {
+            let mut section = Section::with_count("SectionHeaders", self.section_headers.len());
+            let mut table = Table::new(&["Idx", "Name", "Type", "Flags", "Addr", "Offset", "Size", "Link", "Entsize", "Align"]);
+            for (i, header) in self.section_headers.iter().enumerate() {
+
+                let flags_cell = {
+                    let shflags = header.sh_flags as u32;
+                    let mut flags = String::new();
+                    for flag in &SHF_FLAGS {
+                        let flag = *flag;
+                        if shflags & flag == flag {
+                            flags += &shf_to_str(flag).to_string().split_off(4);
+                            flags += " ";
+                        }
+                    }
+                    flags
+                };
+
+                table.row(cells![
+                    i,
+                    string_at(&self.sh_strtab, header.sh_name),
+                    sht_to_str(header.sh_type),
+                    flags_cell,
+                    paint(Color::Red, format!("{:#X}", header.sh_addr)),
+                    paint(Color::Yellow, format!("{:#X}", header.sh_offset)),
+                    paint(Color::Green, format!("{:#X}", header.sh_size)),
+                    self.section_headers.get(header.sh_link as usize)
+                        .map_or(INVALID, |link| string_at(&self.sh_strtab, link.sh_name)),
+                    format!("{:#X}", header.sh_entsize),
+                    format!("{:#X}", header.sh_addralign),
+                ]);
+            }
+            section.table(table);
+            doc.section(section);
+        }
+
+        //
+        // Compressed sections
+        //
+        if opt.show(Part::Sections) && !self.compressed_sections.is_empty() {
+            doc.section(elf_compressed_sections(self));
+        }
+
+        //
+        // File layout
+        //
+        if opt.show(Part::Sections) {
+            doc.section(elf_layout(self));
+        }
+
+        //
+        // Symbol table
+        //
+        if opt.show(Part::Symbols) {
+            let mut section = Section::with_count("SymbolTable", self.symtab.len());
+            if !self.symtab.is_empty() {
+                let names: Vec<_> = self.symtab.iter()
+                    .map(|sym| symbol_name(string_at(&self.symstr, sym.st_name), opt.demangle))
+                    .collect();
+                let symbols = self.symtab.iter().zip(names.iter().map(|name| name.as_ref()));
+                section.table(elf_sym_table(symbols, &self.section_headers, &self.sh_strtab, opt.wrap_chars)
+                              .map_err(|e| Problem::msg(format!("Could not print Symbol table: {}", e)))?);
+            }
+            doc.section(section);
+        }
+
+        //
+        // DynSym table
+        //
+        if opt.show(Part::Symbols) {
+            let mut section = Section::with_count("DynSymTable", self.dynsym.len());
+            if !self.dynsym.is_empty() {
+                let symbols = self.dynsym.iter().zip(dynsym_names.iter().map(String::as_str));
+                section.table(elf_sym_table(symbols, &self.section_headers, &self.sh_strtab, opt.wrap_chars)
+                              .map_err(|e| Problem::msg(format!("Could not print DynSym table: {}", e)))?);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Hash tables
+        //
+        if opt.show(Part::Symbols) && (self.hash.is_some() || self.gnu_hash.is_some()) {
+            let unreachable = self.unreachable_symbols();
+            if let Some(hash) = &self.hash {
+                doc.section(elf_hash(hash, &unreachable, &dynsym_names));
+            }
+            if let Some(gnu_hash) = &self.gnu_hash {
+                doc.section(elf_gnu_hash(gnu_hash, &unreachable, &dynsym_names));
+            }
+        }
+
+        //
+        // Relocations
+        //
+        if opt.show(Part::Relocs) {
+            let total = self.relocations.iter().map(Elf_relocations::len).sum();
+            let mut section = Section::with_count("Relocations", total);
+            // Object files relocate against .symtab, where sections stand for their start
+            let symtab_names: Vec<String> = if self.relocations.iter().any(|relocs| relocs.uses_symtab(&self.section_headers)) {
+                self.symtab.iter().map(|sym| match (sym.st_name, sym.st_info & 0xF) {
+                    (0, STT_SECTION) => section_name(self, sym.st_shndx as usize).to_string(),
+                    _ => symbol_name(string_at(&self.symstr, sym.st_name), opt.demangle).into_owned(),
+                }).collect()
+            } else {
+                Vec::new()
+            };
+            for relocs in &self.relocations {
+                let names = if relocs.uses_symtab(&self.section_headers) { &symtab_names } else { &dynsym_names };
+                section.section(elf_relocations(self, relocs, names, opt.wrap_chars)
+                                .map_err(|e| Problem::msg(format!("Could not print relocations of {}: {}", relocs.name, e)))?);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Dynamic
+        //
+        if opt.show(Part::Headers) && !self.dynamic.is_empty() {
+            let mut section = Section::with_count("Dynamic", self.dynamic.len());
+            section.table(elf_dynamic(self, opt.demangle)
+                          .map_err(|e| Problem::msg(format!("Could not print dynamic table: {}", e)))?);
+            doc.section(section);
+        }
+
+        //
+        // Init arrays
+        //
+        if opt.show(Part::Headers) && !self.init_arrays.is_empty() {
+            doc.section(elf_init_arrays(self, opt.demangle));
+        }
+
+        //
+        // Hardening
+        //
+        if opt.show(Part::Headers) && (self.header.e_type == ET_EXEC || self.header.e_type == ET_DYN) {
+            doc.section(elf_hardening(&self.hardening(), opt.wrap_chars));
+        }
+
+        //
+        // Libraries
+        //
+        if opt.show(Part::Imports) && !self.dynamic.is_empty() {
+            let mut section = Section::new("Libraries");
+            for entry in self.dynamic.iter() {
+                if entry.d_tag == DT_NEEDED {
+                    section.line(paint(Color::Blue, string_at(&self.dynstr, entry.d_ptr)));
+                }
+            }
+            doc.section(section);
+        }
+
+        //
+        // Needed versions
+        //
+        if opt.show(Part::Imports) && !self.verneed.is_empty() {
+            let mut section = Section::with_count("VersionsNeeded", self.verneed.len());
+            for need in &self.verneed {
+                let mut line = text![paint(Color::Blue, string_at(&self.dynstr, need.vn_file)), ":"];
+                for aux in &need.aux {
+                    line.push(" ");
+                    line.push(paint(Color::Yellow, string_at(&self.dynstr, aux.vna_name)));
+                    if aux.vna_flags & VER_FLG_WEAK != 0 {
+                        line.push(" (weak)");
+                    }
+                }
+                section.line(line);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Defined versions
+        //
+        if opt.show(Part::Exports) && !self.verdef.is_empty() {
+            let mut section = Section::with_count("VersionDefinitions", self.verdef.len());
+            let mut table = Table::new(&["Ndx", "Flags", "Version", "Parents"]);
+            for def in &self.verdef {
+                let mut names = def.names.iter().map(|&name| string_at(&self.dynstr, name));
+                let flags = match (def.vd_flags & VER_FLG_BASE != 0, def.vd_flags & VER_FLG_WEAK != 0) {
+                    (true, true) => "BASE WEAK",
+                    (true, false) => "BASE",
+                    (false, true) => "WEAK",
+                    (false, false) => "",
+                };
+                table.row(cells![
+                    def.vd_ndx,
+                    flags,
+                    paint(Color::Yellow, names.next().unwrap_or(INVALID)),
+                    names.collect::<Vec<_>>().join(" "),
+                ]);
+            }
+            section.table(table);
+            doc.section(section);
+        }
+
+        //
+        // Imported symbols
+        //
+        if opt.selected(Part::Imports) {
+            let imports: Vec<(&Elf_symbol_header, &str)> = self.dynsym.iter()
+                .zip(dynsym_names.iter().map(String::as_str))
+                .filter(|(sym, _)| sym.st_shndx as u32 == SHN_UNDEF && sym.st_name != 0)
+                .collect();
+            let mut section = Section::with_count("ImportedSymbols", imports.len());
+            if !imports.is_empty() {
+                section.table(elf_sym_table(imports, &self.section_headers, &self.sh_strtab, opt.wrap_chars)
+                              .map_err(|e| Problem::msg(format!("Could not print imported symbols: {}", e)))?);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Exported symbols
+        //
+        if opt.selected(Part::Exports) {
+            let exports: Vec<(&Elf_symbol_header, &str)> = self.dynsym.iter()
+                .zip(dynsym_names.iter().map(String::as_str))
+                .filter(|(sym, _)| sym.st_shndx as u32 != SHN_UNDEF && sym.st_info >> 4 != STB_LOCAL)
+                .collect();
+            let mut section = Section::with_count("ExportedSymbols", exports.len());
+            if !exports.is_empty() {
+                section.table(elf_sym_table(exports, &self.section_headers, &self.sh_strtab, opt.wrap_chars)
+                              .map_err(|e| Problem::msg(format!("Could not print exported symbols: {}", e)))?);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Debug information
+        //
+        if let (true, Some(dwarf)) = (opt.show(Part::DebugInfo), &self.dwarf) {
+            doc.section(elf_compilation_units(dwarf, opt.trim_lines, opt.wrap_chars));
+        }
+
+        //
+        // Source line
+        //
+        if let Some(address) = opt.line {
+            let mut section = Section::new("SourceLine");
+            section.field("Address", paint(Color::Red, format!("{:#X}", address)));
+            match self.dwarf.as_ref().map(|dwarf| dwarf.find_line(address)) {
+                Some(Some(location)) => {
+                    section
+                        .field("File", paint(Color::Blue, location.file.unwrap_or(INVALID)))
+                        .field("Line", paint(Color::Green, location.line.to_string()))
+                        .field("Column", location.column)
+                        .field("Unit", location.unit.name.as_ref().map_or("", String::as_str));
+                },
+                Some(None) => { section.line(paint(Color::Yellow, "No line information for this address")); },
+                None => { section.line(paint(Color::Yellow, "No DWARF debug information")); },
+            }
+            doc.section(section);
+        }
+
+        Ok(doc)
+
+    }
+
+}
+
+pub fn elf_title(header: &Elf_header) -> Text {
+    use ansi_term::Color;
+
+    let str_type = et_to_str(header.e_type);
+    let mut title = text!["ELF "];
+
+    match header.e_type {
+        ET_REL => {
+            title.push(paint(Color::Yellow, str_type));
+        },
+        ET_EXEC => {
+            title.push(paint(Color::Red, str_type));
+        },
+        ET_DYN => {
+            title.push(paint(Color::Blue, str_type));
+        },
+        ET_CORE => {
+            title.push(paint(Color::Black, str_type));
+        },
+        _ => {},
+    }
+
+    title
+        .push(" ")
+        .push(paint(Color::White, machine_to_str(header.e_machine)))
+        .push(" ")
+        .push(paint(Color::Blue, if header.e_ident.ei_data == ELFDATA2LSB { "little-endian" }
+                    else { "big-endian" }))
+        .push(" @ ")
+        .push(paint(Color::Red, format!("{:#X}", header.e_entry)));
+    title
+}
+
+pub fn elf_header(header: &Elf_header) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::new("Header");
+    section
+        .field("e_phoff", paint(Color::Yellow, format!("{:#X}", header.e_phoff)))
+        .field("e_shoff", paint(Color::Yellow, format!("{:#X}", header.e_shoff)))
+        .field("e_flags", format!("{:#X}", header.e_flags))
+        .field("e_ehsize", header.e_ehsize)
+        .field("e_phentsize", header.e_phentsize)
+        .field("e_phnum", header.e_phnum)
+        .field("e_shentsize", header.e_shentsize)
+        .field("e_shnum", header.e_shnum)
+        .field("e_shstrndx", header.e_shstrndx);
+    section
+}
+
+pub fn elf_hardening(hardening: &Hardening, wrap: usize) -> Section {
+    use ansi_term::Color;
+
+    let good = |yes: bool, text: &'static str, otherwise: &'static str| if yes {
+        paint(Color::Green, text)
+    } else {
+        paint(Color::Red, otherwise)
+    };
+
+    let mut section = Section::new("Hardening");
+    section
+        .field("RELRO", match hardening.relro {
+            Relro::Full => paint(Color::Green, "Full"),
+            Relro::Partial => paint(Color::Yellow, "Partial"),
+            Relro::None => paint(Color::Red, "None"),
+        })
+        .field("Stack canary", good(hardening.canary, "Found", "Not found"))
+        .field("NX", good(hardening.nx, "Enabled", "Disabled"))
+        .field("PIE", match hardening.pie {
+            Pie::Pie => paint(Color::Green, "PIE"),
+            Pie::Dso => paint(Color::Blue, "DSO"),
+            Pie::Rel => paint(Color::Blue, "REL"),
+            Pie::No => paint(Color::Red, "No PIE"),
+        })
+        .field("FORTIFY", if hardening.fortified.is_empty() {
+            text![paint(Color::Red, "No")]
+        } else {
+            text![paint(Color::Green, "Yes"), " ", fill(&hardening.fortified.join(" "), wrap)]
+        })
+        .field("IBT", good(hardening.ibt, "Enabled", "Disabled"))
+        .field("SHSTK", good(hardening.shstk, "Enabled", "Disabled"));
+    section
+}
+
+/// `.modinfo` tags, `struct module` and signature of a kernel module.
+pub fn elf_module(module: &Elf_module, wrap: usize) -> Vec<Section> {
+    use ansi_term::Color;
+
+    let mut sections = Vec::new();
+
+    let mut section = Section::new("KernelModule");
+    if let Some(name) = &module.name {
+        section.field("Name", paint(Color::Blue, name.as_str()));
+    }
+    if let Some(init) = &module.init {
+        section.field("Init", paint(Color::Yellow, init.as_str()));
+    }
+    if let Some(exit) = &module.exit {
+        section.field("Exit", paint(Color::Yellow, exit.as_str()));
+    }
+    for info in &module.modinfo {
+        section.field(&info.key, fill(&info.value, wrap));
+    }
+    sections.push(section);
+
+    if let Some(signature) = &module.signature {
+        let mut section = Section::new("ModuleSignature");
+        section
+            .field("Type", paint(Color::Blue, pkey_id_to_str(signature.id_type)))
+            .field("Offset", paint(Color::Yellow, format!("{:#X}", signature.offset)))
+            .field("Size", paint(Color::Green, format!("{:#X}", signature.sig_len)));
+        match &signature.pkcs7 {
+            Some(signer) => {
+                if let Some(issuer) = &signer.issuer {
+                    section.field("Signer", paint(Color::Blue, fill(issuer, wrap)));
+                }
+                section
+                    .field(if signer.issuer.is_some() { "Serial" } else { "Key ID" }, hex(&signer.key_id))
+                    .field("Digest", paint(Color::Yellow, signer.digest.as_str()))
+                    .field("Algorithm", paint(Color::Yellow, signer.algorithm.as_str()));
+            },
+            None if signature.id_type != PKEY_ID_PKCS7 => {
+                section
+                    .field("Signer", paint(Color::Blue, String::from_utf8_lossy(&signature.signer).into_owned()))
+                    .field("Key ID", hex(&signature.key_id));
+            },
+            None => {
+                section.line(paint(Color::Red, "PKCS#7 message could not be decoded"));
+            },
+        }
+        sections.push(section);
+    }
+
+    sections
+}
+
+/// CRCs of the symbols a kernel module imports.
+fn elf_module_versions(module: &Elf_module, trim_lines: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("ModuleVersions", module.versions.len());
+    let mut table = Table::new(&["Idx", "CRC", "Symbol"]).align_right(&[0]);
+    for (i, version) in module.versions.iter().enumerate().take(trim_lines) {
+        table.row(cells![
+            i,
+            paint(Color::Purple, format!("{:#010x}", version.crc)),
+            paint(Color::Yellow, version.name.as_str()),
+        ]);
+    }
+    section.table(table);
+    if module.versions.len() > trim_lines {
+        section.trimmed();
+    }
+    section
+}
+
+/// Process, signal, threads, mapped files and auxiliary vector of a core dump.
+pub fn elf_core(core: &Elf_core, machine: u16, wrap: usize) -> Vec<Section> {
+    use ansi_term::Color;
+
+    let mut sections = Vec::new();
+
+    if let Some(process) = &core.process {
+        let mut section = Section::new("Process");
+        section
+            .field("Command", paint(Color::Yellow, fill(&process.pr_psargs, wrap)))
+            .field("Name", paint(Color::Blue, process.pr_fname.as_str()))
+            .field("PID", process.pr_pid)
+            .field("PPID", process.pr_ppid)
+            .field("PGRP", process.pr_pgrp)
+            .field("SID", process.pr_sid)
+            .field("UID", process.pr_uid)
+            .field("GID", process.pr_gid)
+            .field("State", process.pr_sname.to_string());
+        sections.push(section);
+    }
+
+    // NT_SIGINFO knows the faulting address, the threads only the signal
+    let signal = core.signal.as_ref()
+        .map(|info| (info.si_signo, info.si_code, info.si_addr))
+        .or_else(|| core.threads.first().map(|thread| (thread.pr_cursig as i32, thread.si_code, None)));
+    if let Some((signo, code, addr)) = signal {
+        let mut section = Section::new("Signal");
+        section
+            .field("Signal", text![paint(Color::Red, signal_to_str(signo)), format!(" ({})", signo)])
+            .field("Code", code);
+        if let Some(addr) = addr {
+            section.field("Address", paint(Color::Red, format!("{:#X}", addr)));
+        }
+        sections.push(section);
+    }
+
+    if !core.threads.is_empty() {
+        let mut section = Section::with_count("Threads", core.threads.len());
+        for thread in &core.threads {
+            let mut thread_section = Section::new(format!("Thread {}", thread.pr_pid));
+            thread_section.field("Signal", text![signal_to_str(thread.pr_cursig as i32), format!(" ({})", thread.pr_cursig)]);
+            let names = register_names(machine).filter(|names| names.len() == thread.registers.len());
+            let mut table = Table::new(&["Register", "Value"]).align_right(&[0]);
+            for (i, value) in thread.registers.iter().enumerate() {
+                let name = names.map_or_else(|| format!("r{}", i), |names| names[i].to_string());
+                table.row(cells![name, paint(Color::Red, format!("{:#X}", value))]);
+            }
+            thread_section.table(table);
+            section.section(thread_section);
+        }
+        sections.push(section);
+    }
+
+    if !core.files.is_empty() {
+        let mut section = Section::with_count("MappedFiles", core.files.len());
+        let mut table = Table::new(&["Start", "End", "Offset", "File"]).align_right(&[0, 1]);
+        for file in &core.files {
+            table.row(cells![
+                paint(Color::Red, format!("{:#X}", file.start)),
+                paint(Color::Red, format!("{:#X}", file.end)),
+                paint(Color::Yellow, format!("{:#X}", file.offset)),
+                paint(Color::Blue, fill(&file.name, wrap)),
+            ]);
+        }
+        section.table(table);
+        sections.push(section);
+    }
+
+    if !core.auxv.is_empty() {
+        let mut section = Section::with_count("AuxVector", core.auxv.len());
+        let mut table = Table::new(&["Type", "Value"]).align_right(&[0]);
+        for entry in &core.auxv {
+            let value = match entry.a_type {
+                AT_PHENT | AT_PHNUM | AT_PAGESZ | AT_CLKTCK | AT_UID | AT_EUID | AT_GID | AT_EGID | AT_SECURE
+                | AT_RSEQ_FEATURE_SIZE | AT_RSEQ_ALIGN => paint(Color::Green, entry.a_val.to_string()),
+                _ => paint(Color::Red, format!("{:#X}", entry.a_val)),
+            };
+            table.row(cells![at_to_str(entry.a_type), value]);
+        }
+        section.table(table);
+        sections.push(section);
+    }
+
+    sections
+}
+
+/// Sections each segment holds, like readelf -l.
+pub fn elf_segment_mapping(elf: &Elf, wrap: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::new("SegmentMapping");
+    let mut table = Table::new(&["Idx", "Type", "Sections"]);
+    for (i, (header, sections)) in elf.program_headers.iter().zip(elf.segment_sections()).enumerate() {
+        let names: Vec<&str> = sections.iter().map(|&index| section_name(elf, index)).collect();
+        table.row(cells![
+            i,
+            pt_to_str(header.p_type),
+            paint(Color::Blue, fill(&names.join(" "), wrap)),
+        ]);
+    }
+    section.table(table);
+    section
+}
+
+/// Compressed sections with their algorithm and how much they shrank.
+pub fn elf_compressed_sections(elf: &Elf) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("CompressedSections", elf.compressed_sections.len());
+    let mut table = Table::new(&["Idx", "Name", "Algorithm", "Compressed", "Uncompressed", "Ratio"]).align_right(&[0, 3, 4, 5]);
+    for compression in &elf.compressed_sections {
+        let compressed = elf.section_headers[compression.section].sh_size;
+        let ratio = match compression.ch_size {
+            0 => String::new(),
+            size => format!("{:.1}%", compressed as f64 * 100.0 / size as f64),
+        };
+        table.row(cells![
+            compression.section,
+            paint(Color::Blue, section_name(elf, compression.section)),
+            compress_to_str(compression.ch_type),
+            paint(Color::Green, format!("{:#X}", compressed)),
+            paint(Color::Green, format!("{:#X}", compression.ch_size)),
+            ratio,
+        ]);
+    }
+    section.table(table);
+    section
+}
+
+/// Width of the file map drawn for each region.
+const LAYOUT_WIDTH: u64 = 32;
+
+/// Regions of the file in order, with the segments holding them, a map of
+/// where they are in the file, and the holes and overlaps between them.
+pub fn elf_layout(elf: &Elf) -> Section {
+    use ansi_term::Color;
+
+    let region_name = |kind: RegionKind| match kind {
+        RegionKind::Header => "ELF header",
+        RegionKind::ProgramHeaders => "Program headers",
+        RegionKind::SectionHeaders => "Section headers",
+        RegionKind::Section(index) => section_name(elf, index),
+        RegionKind::Gap => "(padding)",
+        RegionKind::SegmentData => "(segment data)",
+        RegionKind::Trailing => "(trailing data)",
+    };
+    // Segments whose file bytes hold the whole region
+    let segments = |region: &Region| -> String {
+        elf.program_headers.iter().enumerate()
+            .filter(|(_, header)| header.p_filesz != 0 && region.offset >= header.p_offset
+                    && region.offset.saturating_add(region.size) <= header.p_offset.saturating_add(header.p_filesz))
+            .map(|(i, _)| i.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let layout = elf.layout();
+    let mut section = Section::with_count("Layout", layout.len());
+    let mut table = Table::new(&["Offset", "Size", "Region", "Segments", "Map", "Note"]).align_right(&[0, 1]);
+    for region in &layout {
+        let name = region_name(region.kind);
+        let (name, note) = match (region.kind, region.overlaps) {
+            (RegionKind::Gap, _) | (RegionKind::SegmentData, _) => (paint(Color::Fixed(138), name), Text::default()),
+            (RegionKind::Trailing, _) => (paint(Color::Fixed(138), name), text![paint(Color::Yellow, "past the last section")]),
+            (_, Some(other)) => (paint(Color::Blue, name), text![paint(Color::Red, format!("overlaps {}", region_name(other)))]),
+            (_, None) => (paint(Color::Blue, name), Text::default()),
+        };
+        table.row(cells![
+            paint(Color::Yellow, format!("{:#X}", region.offset)),
+            paint(Color::Green, format!("{:#X}", region.size)),
+            name,
+            segments(region),
+            layout_map(region, elf.file_size),
+            note,
+        ]);
+    }
+    section.table(table);
+    section
+}
+
+/// Where `region` lies in a file of `file_size` bytes, as a bar LAYOUT_WIDTH wide.
+fn layout_map(region: &Region, file_size: u64) -> String {
+    let mark = match region.kind {
+        RegionKind::Gap | RegionKind::SegmentData | RegionKind::Trailing => '.',
+        RegionKind::Section(_) => '=',
+        _ => '#',
+    };
+    let scale = |offset: u64| (offset.min(file_size) as u128 * LAYOUT_WIDTH as u128 / file_size.max(1) as u128) as u64;
+    let start = scale(region.offset).min(LAYOUT_WIDTH - 1);
+    let end = scale(region.offset.saturating_add(region.size)).max(start + 1);
+    let bar: String = (0..LAYOUT_WIDTH).map(|column| if column >= start && column < end { mark } else { ' ' }).collect();
+    format!("|{}|", bar)
+}
+
+/// Buckets, chain lengths and exported symbols missing from a DT_HASH table.
+pub fn elf_hash(hash: &Elf_hash, unreachable: &[Unreachable], names: &[String]) -> Section {
+    let lengths = hash.chain_lengths();
+    let mut section = Section::new("HashTable");
+    section
+        .field("Buckets", hash.buckets.len())
+        .field("Symbols", hash.chains.len());
+    hash_chains(&mut section, &lengths);
+    hash_unreachable(&mut section, DT_HASH, unreachable, names);
+    section
+}
+
+/// Buckets, bloom filter, chain lengths and exported symbols missing from a
+/// DT_GNU_HASH table.
+pub fn elf_gnu_hash(hash: &Elf_gnu_hash, unreachable: &[Unreachable], names: &[String]) -> Section {
+    let lengths = hash.chain_lengths();
+    let bloom_bits = hash.bloom.len() as u64 * hash.bloom_bits as u64;
+    let mut section = Section::new("GnuHashTable");
+    section
+        .field("Buckets", hash.buckets.len())
+        .field("Symbol offset", hash.symoffset)
+        .field("Hashed symbols", hash.chains.len())
+        .field("Bloom words", format!("{} of {} bits", hash.bloom.len(), hash.bloom_bits))
+        .field("Bloom shift", hash.bloom_shift)
+        .field("Bloom bits set", format!("{} of {} ({})", hash.bloom_set(), bloom_bits, percent(hash.bloom_set() as u64, bloom_bits)));
+    hash_chains(&mut section, &lengths);
+    hash_unreachable(&mut section, DT_GNU_HASH, unreachable, names);
+    section
+}
+
+fn percent(part: u64, total: u64) -> String {
+    if total == 0 {
+        return "-".to_string();
+    }
+    format!("{:.1}%", part as f64 * 100.0 / total as f64)
+}
+
+/// Histogram of chain lengths, with the share of symbols found in chains
+/// at most that long, like readelf --histogram.
+fn hash_chains(section: &mut Section, lengths: &[usize]) {
+    use ansi_term::Color;
+
+    let longest = lengths.iter().copied().max().unwrap_or(0);
+    let symbols: usize = lengths.iter().sum();
+    section
+        .field("Empty buckets", lengths.iter().filter(|&&length| length == 0).count())
+        .field("Longest chain", longest);
+    if lengths.is_empty() {
+        return;
+    }
+
+    let mut table = Table::new(&["Length", "Buckets", "Coverage"]).align_right(&[0, 1, 2]);
+    let mut covered = 0;
+    for length in 0..=longest {
+        let buckets = lengths.iter().filter(|&&l| l == length).count();
+        covered += length * buckets;
+        table.row(cells![
+            length,
+            paint(Color::Green, buckets.to_string()),
+            percent(covered as u64, symbols as u64),
+        ]);
+    }
+    section.table(table);
+}
+
+fn hash_unreachable(section: &mut Section, tag: u64, unreachable: &[Unreachable], names: &[String]) {
+    use ansi_term::Color;
+
+    let missing: Vec<&Unreachable> = unreachable.iter().filter(|symbol| symbol.table == tag).collect();
+    if missing.is_empty() {
+        return;
+    }
+    let mut missing_section = Section::with_count("Unreachable", missing.len());
+    let mut table = Table::new(&["Idx", "Name", "Reason"]).align_right(&[0]);
+    for symbol in missing {
+        table.row(cells![
+            symbol.index,
+            paint(Color::Yellow, names.get(symbol.index).map_or(INVALID, String::as_str)),
+            paint(Color::Red, match symbol.miss {
+                Miss::NotHashed => "before the first hashed symbol",
+                Miss::Bloom => "rejected by the bloom filter",
+                Miss::Chain => "not in the chain of its bucket",
+            }),
+        ]);
+    }
+    missing_section.table(table);
+    section.section(missing_section);
+}
+
+/// Compilation units of DWARF debug information, with their source files.
+pub fn elf_compilation_units(dwarf: &Dwarf, trim: usize, wrap: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("CompilationUnits", dwarf.units.len());
+    for unit in &dwarf.units {
+        let mut unit_section = Section::new(paint(Color::Blue, unit.name.as_ref().map_or("", String::as_str)));
+        if let Some(producer) = &unit.producer {
+            unit_section.field("Producer", paint(Color::Yellow, fill(producer, wrap)));
+        }
+        if let Some(language) = unit.language {
+            unit_section.field("Language", lang_to_str(language));
+        }
+        unit_section.field("Version", unit.version);
+        if let Some(comp_dir) = &unit.comp_dir {
+            unit_section.field("Directory", comp_dir.as_str());
+        }
+        if let (Some(low_pc), Some(high_pc)) = (unit.low_pc, unit.high_pc) {
+            unit_section.field("Addresses", paint(Color::Red, format!("{:#X}-{:#X}", low_pc, high_pc)));
+        }
+        if let Some(dwo_name) = &unit.dwo_name {
+            unit_section.field("Split DWARF", dwo_name.as_str());
+        }
+
+        // Version 5 tables list the primary file twice
+        let mut files: Vec<&str> = Vec::new();
+        for file in &unit.files {
+            if !files.contains(&file.as_str()) {
+                files.push(file);
+            }
+        }
+        if !files.is_empty() {
+            let mut files_section = Section::with_count("Files", files.len());
+            for file in files.iter().take(trim) {
+                files_section.line(*file);
+            }
+            if files.len() > trim {
+                files_section.trimmed();
+            }
+            unit_section.section(files_section);
+        }
+        section.section(unit_section);
+    }
+    section
+}
+
+pub fn elf_sym_table<'a, I>(symtab: I, section_headers: &[Elf_section_header], sh_strtab: &[u8], wrap: usize) -> Result<Table, Error>
+    where I: IntoIterator<Item = (&'a Elf_symbol_header, &'a str)> {
+    use ansi_term::Color;
+
+    let mut table = Table::new(&["Addr", "Bind", "Type", "Symbol", "Section", "Size", "Other"]).align_right(&[0]);
+    for (header, symbol) in symtab {
+
+        let bind = format!("{:<8}", bind_to_str(header.st_info >> 4));
+        let bind_cell = match header.st_info >> 4 {
+            STB_LOCAL  => paint(Color::Black.on(Color::Cyan).bold(), bind),
+            STB_GLOBAL => paint(Color::Black.on(Color::Red).bold(), bind),
+            STB_WEAK   => paint(Color::Black.on(Color::Purple).bold(), bind),
+            _ => bind.into(),
+        };
+        let typ = format!("{:<9}", type_to_str(header.st_info & 0xF));
+        let typ_cell = match header.st_info & 0xF {
+            STT_OBJECT    => paint(Color::Yellow.bold(), typ),
+            STT_FUNC      => paint(Color::Red.bold(), typ),
+            STT_GNU_IFUNC => paint(Color::Cyan.bold(), typ),
+            STT_FILE      => paint(Color::Blue.bold(), typ),
+            STT_SECTION   => paint(Color::White.bold(), typ),
+            _ => typ.into(),
+        };
+
+        table.row(cells![
+            paint(Color::Red, format!("{:>#16X}", header.st_value)),
+            bind_cell,
+            typ_cell,
+            paint(Color::Yellow, fill(symbol, wrap)),
+            if (header.st_shndx as usize) < (section_headers.len()) {
+                string_at(sh_strtab, section_headers[header.st_shndx as usize].sh_name)
+            }
+            else if header.st_shndx as u32 == SHN_UNDEF {
+                "UNDEF"
+            }
+            else {
+                "ABS"
+            },
+            paint(Color::Green, format!("{:#X}", header.st_size)),
+            format!("{:#X}", header.st_other),
+        ]);
+    }
+
+    Ok(table)
+
+}
+
+/// Bytes in lowercase hex, like build IDs are usually shown.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Bytes of a note description shown before cutting it.
+const NOTE_DESC_MAX: usize = 64;
+
+/// Description of `note`, decoded when its owner and type are known and in
+/// hex otherwise. Each program property goes on its own line.
+fn note_desc(elf: &Elf, note: &Elf_note) -> String {
+    let endianness = if elf.header.e_ident.ei_data == ELFDATA2MSB { scroll::BE } else { scroll::LE };
+    match elf.note_desc(note) {
+        NoteDesc::AbiTag { os, version } => format!("{} {}.{}.{}", abi_os_to_str(os), version[0], version[1], version[2]),
+        NoteDesc::BuildId(id) => hex(&id),
+        NoteDesc::Properties(properties) => properties.iter()
+            .map(|property| format!("{}: {}", gnu_property_to_str(property.pr_type, elf.header.e_machine).trim_start_matches("GNU_PROPERTY_"),
+                                    property_value(elf, property, endianness)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        NoteDesc::Text(text) => text,
+        NoteDesc::Version(version) => version.to_string(),
+        NoteDesc::AndroidIdent { api_level, ndk_version, ndk_build } => match (ndk_version, ndk_build) {
+            (Some(version), Some(build)) => format!("API level {}, NDK {} ({})", api_level, version, build),
+            _ => format!("API level {}", api_level),
+        },
+        NoteDesc::FeatureCtl(features) => {
+            let known = NT_FREEBSD_FCTL_FLAGS.map(u64::from);
+            flags(features as u64, &known, |flag| freebsd_fctl_to_str(flag as u32), "NT_FREEBSD_FCTL_")
+        },
+        NoteDesc::Probe(probe) => {
+            let mut desc = format!("{}:{} at {:#X}, base {:#X}", probe.provider, probe.name, probe.pc, probe.base);
+            if probe.semaphore != 0 {
+                desc += &format!(", semaphore {:#X}", probe.semaphore);
+            }
+            if !probe.args.is_empty() {
+                desc += &format!(", arguments {}", probe.args);
+            }
+            desc
+        },
+        NoteDesc::Empty => String::new(),
+        NoteDesc::Bytes => {
+            // Register sets of core dumps run to kilobytes
+            let mut desc = hex(&note.desc[..note.desc.len().min(NOTE_DESC_MAX)]);
+            if note.desc.len() > NOTE_DESC_MAX {
+                desc += &format!("... ({:#X} bytes)", note.desc.len());
+            }
+            desc
+        },
+    }
+}
+
+/// Value of a program property: the names of the bits of bit masks, sizes
+/// and versions in hex, and nothing for properties telling all by their presence.
+fn property_value(elf: &Elf, property: &Property, endianness: scroll::Endian) -> String {
+    let value = property.value(endianness);
+    let x86 = elf.header.e_machine == EM_386 || elf.header.e_machine == EM_X86_64;
+    let aarch64 = elf.header.e_machine == EM_AARCH64;
+    let word = |offset: usize| if property.data.len() >= offset + 8 {
+        property.data.pread_with::<u64>(offset, endianness).unwrap_or(0)
+    } else {
+        property.data.pread_with::<u32>(offset, endianness).map_or(0, u64::from)
+    };
+    match property.pr_type {
+        GNU_PROPERTY_STACK_SIZE => format!("{:#X}", word(0)),
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED | GNU_PROPERTY_MEMORY_SEAL => String::new(),
+        GNU_PROPERTY_1_NEEDED => flags(value as u64, &[GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS as u64], |_| "INDIRECT_EXTERN_ACCESS", ""),
+        GNU_PROPERTY_X86_FEATURE_1_AND if x86 => {
+            flags(value as u64, &GNU_PROPERTY_X86_FEATURE_1_FLAGS.map(u64::from), |flag| x86_feature_1_to_str(flag as u32), "GNU_PROPERTY_X86_FEATURE_1_")
+        },
+        GNU_PROPERTY_X86_FEATURE_2_USED | GNU_PROPERTY_X86_FEATURE_2_NEEDED if x86 => {
+            flags(value as u64, &GNU_PROPERTY_X86_FEATURE_2_FLAGS.map(u64::from), |flag| x86_feature_2_to_str(flag as u32), "GNU_PROPERTY_X86_FEATURE_2_")
+        },
+        GNU_PROPERTY_X86_ISA_1_USED | GNU_PROPERTY_X86_ISA_1_NEEDED if x86 => {
+            flags(value as u64, &GNU_PROPERTY_X86_ISA_1_FLAGS.map(u64::from), |flag| x86_isa_1_to_str(flag as u32), "GNU_PROPERTY_X86_ISA_1_")
+        },
+        GNU_PROPERTY_AARCH64_FEATURE_1_AND if aarch64 => {
+            flags(value as u64, &GNU_PROPERTY_AARCH64_FEATURE_1_FLAGS.map(u64::from), |flag| aarch64_feature_1_to_str(flag as u32), "GNU_PROPERTY_AARCH64_FEATURE_1_")
+        },
+        GNU_PROPERTY_AARCH64_FEATURE_PAUTH if aarch64 => format!("platform {:#X}, version {:#X}", word(0), word(8)),
+        _ => hex(&property.data),
+    }
+}
+
+/// Placeholder for names the parser reported as damaged, which only get
+/// this far when parsing leniently.
+const INVALID: &str = "<invalid>";
+
+fn string_at<N: Into<u64>>(strtab: &[u8], offset: N) -> &str {
+    strtab.pread::<&str>(offset.into() as usize).unwrap_or(INVALID)
+}
+
+/// Names of the dynamic symbols with the version they bind to,
+/// `name@@VERSION` for the default version of a definition, `name@VERSION`
+/// for the others and for versions needed from libraries.
+fn versioned_names(elf: &Elf, demangle: bool) -> Vec<String> {
+    elf.dynsym.iter().enumerate().map(|(i, sym)| {
+        let name = symbol_name(string_at(&elf.dynstr, sym.st_name), demangle);
+        match elf.symbol_version(i) {
+            Some(version) => {
+                let at = if version.defined && !version.hidden { "@@" } else { "@" };
+                format!("{}{}{}", name, at, string_at(&elf.dynstr, version.name))
+            },
+            None => name.to_string(),
+        }
+    }).collect()
+}
+
+/// Name of section `index`, or INVALID.
+fn section_name(elf: &Elf, index: usize) -> &str {
+    elf.section_headers.get(index).map_or(INVALID, |head| string_at(&elf.sh_strtab, head.sh_name))
+}
+
+fn reloc_symbol(names: &[String], info: usize) -> &str {
+    names.get(info).map_or(INVALID, String::as_str)
+}
+
+/// Relocations of one table, with the symbol names of the table they refer to.
+pub fn elf_relocations(elf: &Elf, relocs: &Elf_relocations, names: &[String], wrap: usize) -> Result<Section, Error> {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count(paint(Color::Fixed(75), relocs.name.as_str()), relocs.len());
+    if relocs.sh_info != 0 {
+        section.field("Section", paint(Color::Blue, section_name(elf, relocs.sh_info as usize)));
+    }
+    if relocs.sh_link != 0 {
+        section.field("Symbols", section_name(elf, relocs.sh_link as usize));
+    }
+    if relocs.is_empty() {
+        return Ok(section);
+    }
+    match &relocs.entries {
+        Elf_reloc_entries::Rel(rels) => section.table(elf_rel_table(rels, names, elf, wrap)?),
+        Elf_reloc_entries::Rela(relas) => section.table(elf_rela_table(relas, names, elf, wrap)?),
+        Elf_reloc_entries::Relr(addresses) => section.table(elf_relr_table(addresses)),
+    };
+    Ok(section)
+}
+
+pub fn elf_rel_table(rel: &[Elf_rel], names: &[String], elf: &Elf, wrap: usize) -> Result<Table, Error> {
+    use ansi_term::Color;
+
+    let mut table = Table::new(&["Offset", "Type", "Name"]).align_right(&[0]);
+    for header in rel {
+
+        let info = elf.r_sym(header.r_info);
+
+        table.row(cells![
+            paint(Color::Red, format!("{:>#16X}", header.r_offset)),
+            elf.r_type_name(header.r_info),
+            paint(Color::Yellow, fill(reloc_symbol(names, info), wrap)),
+        ]);
+    }
+
+    Ok(table)
+
+}
+
+pub fn elf_rela_table(rela: &[Elf_rela], names: &[String], elf: &Elf, wrap: usize) -> Result<Table, Error> {
+    use ansi_term::Color;
+
+    let mut table = Table::new(&["Offset", "Type", "Name+addend"]).align_right(&[0]);
+    for header in rela {
+
+        let info = elf.r_sym(header.r_info);
+        let name = reloc_symbol(names, info);
+        let name = if name.is_empty() {
+            paint(Color::White, "ABS")
+        }
+        else {
+            paint(Color::Yellow, fill(name, wrap))
+        };
+
+        table.row(cells![
+            paint(Color::Red, format!("{:>#16X}", header.r_offset)),
+            elf.r_type_name(header.r_info),
+            text![name, "+", paint(Color::Red, header.r_addend.to_string())],
+        ]);
+    }
+
+    Ok(table)
+
+}
+
+/// Addresses of packed relative relocations.
+pub fn elf_relr_table(addresses: &[u64]) -> Table {
+    use ansi_term::Color;
+
+    let mut table = Table::new(&["Offset"]).align_right(&[0]);
+    for address in addresses {
+        table.row(cells![paint(Color::Red, format!("{:>#16X}", address))]);
+    }
+    table
+}
+
+pub fn elf_dynamic(elf: &Elf, demangle: bool) -> Result<Table, Error> {
+    use ansi_term::Color;
+
+    // Addresses, with the section they point into
+    let address = |value: u64| {
+        let mut text = text![paint(Color::Red, format!("{:#X}", value))];
+        if let Some(section) = section_at(elf, value) {
+            text.push(" (").push(paint(Color::Blue, section)).push(")");
+        }
+        text
+    };
+
+    let mut table = Table::new(&["Tag", "Value"]).align_right(&[0]);
+    for header in &elf.dynamic {
+
+        let value: Text = match header.d_tag {
+            DT_NEEDED       => text![paint(Color::Blue, string_at(&elf.dynstr, header.d_ptr))],
+            DT_SONAME       => text![paint(Color::Blue, string_at(&elf.dynstr, header.d_ptr))],
+            DT_RPATH | DT_RUNPATH
+                            => text![paint(Color::Red, string_at(&elf.dynstr, header.d_ptr))],
+            tag if DT_STRING_TAGS.contains(&tag)
+                            => text![paint(Color::Yellow, string_at(&elf.dynstr, header.d_ptr))],
+            DT_FLAGS        => text![flags(header.d_ptr, &DF_FLAGS, df_to_str, "DF_")],
+            DT_FLAGS_1      => text![flags(header.d_ptr, &DF_1_FLAGS, df_1_to_str, "DF_1_")],
+            DT_POSFLAG_1    => text![flags(header.d_ptr, &[DF_P1_LAZYLOAD, DF_P1_GROUPPERM], |flag| match flag {
+                DF_P1_LAZYLOAD => "LAZYLOAD",
+                _ => "GROUPPERM",
+            }, "")],
+            DT_FEATURE_1    => text![flags(header.d_ptr, &[DTF_1_PARINIT, DTF_1_CONFEXP], |flag| match flag {
+                DTF_1_PARINIT => "PARINIT",
+                _ => "CONFEXP",
+            }, "")],
+            DT_PLTREL       => text![tag_to_str(header.d_ptr)],
+            DT_INIT | DT_FINI => {
+                let mut text = address(header.d_ptr);
+                if let Some(symbol) = symbol_at(elf, header.d_ptr, demangle) {
+                    text.push(" ").push(paint(Color::Yellow, symbol));
+                }
+                text
+            },
+            DT_PLTGOT | DT_HASH | DT_STRTAB | DT_SYMTAB | DT_RELA | DT_REL | DT_JMPREL | DT_DEBUG
+            | DT_INIT_ARRAY | DT_FINI_ARRAY | DT_PREINIT_ARRAY | DT_SYMTAB_SHNDX | DT_RELR
+            | DT_VERSYM | DT_VERDEF | DT_VERNEED => address(header.d_ptr),
+            DT_ADDRRNGLO..=DT_ADDRRNGHI => address(header.d_ptr),
+            DT_PLTRELSZ | DT_RELASZ | DT_RELAENT | DT_STRSZ | DT_SYMENT | DT_RELSZ | DT_RELENT
+            | DT_INIT_ARRAYSZ | DT_FINI_ARRAYSZ | DT_PREINIT_ARRAYSZ | DT_RELRSZ | DT_RELRENT
+            | DT_GNU_CONFLICTSZ | DT_GNU_LIBLISTSZ | DT_PLTPADSZ | DT_MOVEENT | DT_MOVESZ | DT_SYMINSZ | DT_SYMINENT
+                            => text![paint(Color::Green, format!("{:#X}", header.d_ptr))],
+            DT_RELACOUNT | DT_RELCOUNT | DT_VERDEFNUM | DT_VERNEEDNUM
+                            => text![paint(Color::Green, header.d_ptr.to_string())],
+            // Presence is what counts
+            DT_NULL | DT_SYMBOLIC | DT_TEXTREL | DT_BIND_NOW => text![""],
+            _ => text![format!("{:#x}", header.d_ptr)],
+        };
+        table.row(cells![tag_to_str(header.d_tag), value]);
+
+    }
+
+    Ok(table)
+
+}
+
+/// Names of the bits set in `value`, without `prefix`, and the unknown ones in hex.
+fn flags(value: u64, known: &[u64], to_str: fn(u64) -> &'static str, prefix: &str) -> String {
+    let mut names: Vec<String> = known.iter()
+        .filter(|&&flag| value & flag != 0)
+        .map(|&flag| to_str(flag).trim_start_matches(prefix).to_string())
+        .collect();
+    let unknown = known.iter().fold(value, |rest, flag| rest & !flag);
+    if unknown != 0 {
+        names.push(format!("{:#x}", unknown));
+    }
+    names.join(" ")
+}
+
+/// Allocated section holding `address`, preferring one starting there over
+/// one ending there, as empty sections share addresses with the next. .tbss
+/// takes no room outside of threads, so overlaps what follows.
+fn section_at(elf: &Elf, address: u64) -> Option<&str> {
+    let mut sections = elf.section_headers.iter()
+        .filter(|head| head.sh_flags & SHF_ALLOC as u64 != 0 && head.sh_addr != 0)
+        .filter(|head| !(head.sh_type == SHT_NOBITS && head.sh_flags & SHF_TLS as u64 != 0));
+    sections.clone().find(|head| head.sh_addr == address && head.sh_size != 0)
+        .or_else(|| sections.find(|head| address > head.sh_addr && address - head.sh_addr < head.sh_size))
+        .map(|head| string_at(&elf.sh_strtab, head.sh_name))
+}
+
+/// Function or object symbol at `address`, as `name+offset` when inside it.
+fn symbol_at(elf: &Elf, address: u64, demangle: bool) -> Option<String> {
+    let tables = [(&elf.symtab, &elf.symstr), (&elf.dynsym, &elf.dynstr)];
+    let mut candidates = tables.iter().flat_map(|(symbols, strtab)| symbols.iter().map(move |sym| (sym, *strtab)))
+        .filter(|(sym, _)| sym.st_name != 0 && sym.st_shndx as u32 != SHN_UNDEF
+                && matches!(sym.st_info & 0xF, STT_NOTYPE | STT_FUNC | STT_OBJECT | STT_GNU_IFUNC));
+    let exact = candidates.clone().find(|(sym, _)| sym.st_value == address);
+    exact.or_else(|| candidates.find(|(sym, _)| address > sym.st_value && address - sym.st_value < sym.st_size))
+        .map(|(sym, strtab)| {
+            let name = symbol_name(string_at(strtab, sym.st_name), demangle);
+            match address - sym.st_value {
+                0 => name.into_owned(),
+                offset => format!("{}+{:#x}", name, offset),
+            }
+        })
+}
+
+/// Functions the loader calls before and after the program, with their symbols.
+pub fn elf_init_arrays(elf: &Elf, demangle: bool) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("InitArrays", elf.init_arrays.len());
+    for array in &elf.init_arrays {
+        let mut array_section = Section::with_count(paint(Color::Fixed(75), tag_to_str(array.tag)), array.functions.len());
+        let mut address = text![paint(Color::Red, format!("{:#X}", array.address))];
+        if let Some(name) = section_at(elf, array.address) {
+            address.push(" (").push(paint(Color::Blue, name)).push(")");
+        }
+        array_section.field("Address", address);
+        if !array.functions.is_empty() {
+            let mut table = Table::new(&["Idx", "Function", "Symbol"]).align_right(&[0, 1]);
+            for (i, &function) in array.functions.iter().enumerate() {
+                table.row(cells![
+                    i,
+                    paint(Color::Red, format!("{:#X}", function)),
+                    paint(Color::Yellow, symbol_at(elf, function, demangle).unwrap_or_default()),
+                ]);
+            }
+            array_section.table(table);
+        }
+        section.section(array_section);
+    }
+    section
+}
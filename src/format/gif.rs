@@ -0,0 +1,154 @@
+use bininfo::formats::gif::{Gif, RGB};
+use failure::Error;
+
+use crate::Opt;
+use crate::render::{Document, Section, Table, paint};
+use super::Describe;
+
+impl Describe for Gif {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+        use ansi_term::Color;
+
+        //
+        // GIF file
+        //
+        let mut doc = Document::new(format!("GIF{} width: {}px height: {}px",
+                                            std::str::from_utf8(&self.header.version)?,
+                                            self.lsd.logic_width,
+                                            self.lsd.logic_height));
+
+        // Global Color Table Flag
+        let gctf      = &self.lsd.packed_fields >> 7;
+        let color_res = &self.lsd.packed_fields << 1 >> 5;
+        let sort_flag = &self.lsd.packed_fields << 4 >> 7;
+        // Size of Global Color Table
+        let sz_gct    = &self.lsd.packed_fields << 5 >> 5;
+
+        //
+        // Logical Screen Descriptor
+        //
+        let mut lsd = Section::new("Logical Screen Descriptor");
+        lsd
+            .field("Logical screen width", format!("{}px", self.lsd.logic_width))
+            .field("Logical screen height", format!("{}px", self.lsd.logic_height))
+            .field("Global color table flag", gctf)
+            .field("Color resolution", color_res)
+            .field("Sort flag", sort_flag)
+            .field("Size of global color table", sz_gct);
+        doc.section(lsd);
+
+        //
+        // Global Color Table
+        //
+        if let Some(gct) = &self.gct {
+            doc.section(color_table("Global Color Table", &gct.table, opt.trim_lines));
+        }
+
+        //
+        // Graphic Control Extension
+        //
+        if let Some(gc_ext) = &self.gc_ext {
+            let mut section = Section::new("Graphic Control Extension");
+            section
+                .field("Block size", gc_ext.block_size)
+                .field("Reserved", gc_ext.packet_fields >> 5)
+                .field("Disposal method", gc_ext.packet_fields << 3 >> 5)
+                .field("User input flag", gc_ext.packet_fields & 0b00000010)
+                .field("Transparent color flag", gc_ext.packet_fields & 0b00000001)
+                .field("Transparent color idx", gc_ext.transp_color_idx);
+            doc.section(section);
+        }
+
+        //
+        // Comment Extension
+        //
+        if let Some(comment_ext) = &self.comment_ext {
+            let mut section = Section::new("Comment Extension");
+            for com in comment_ext.comment_data.iter() {
+                section.line(com);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Plain Text Extension
+        //
+        if let Some(pt_ext) = &self.pt_ext {
+            let mut section = Section::new("Plain Text Extension");
+            section
+                .field("Block size", pt_ext.header.block_size)
+                .field("Text grid left position", format!("{}px", pt_ext.header.tg_left_pos))
+                .field("Text grid top position", format!("{}px", pt_ext.header.tg_top_pos))
+                .field("Text grid width", format!("{}px", pt_ext.header.tg_width))
+                .field("Text grid height", format!("{}px", pt_ext.header.tg_height))
+                .field("Character cell width", format!("{}px", pt_ext.header.char_cell_width))
+                .field("Character cell height", format!("{}px", pt_ext.header.char_cell_height))
+                .field("Text foreground color idx", pt_ext.header.tf_color_idx)
+                .field("Text background color idx", pt_ext.header.tb_color_idx);
+            for text in pt_ext.plain_text.iter() {
+                section.line(text);
+            }
+            doc.section(section);
+        }
+
+        //
+        // Application Extension
+        //
+        if let Some(app_ext) = &self.app_ext {
+            let mut section = Section::new("Application Extension");
+            section
+                .field("Block size", app_ext.block_size)
+                .field("Application identifier",
+                       paint(Color::Blue, std::str::from_utf8(&app_ext.app_identifier)?))
+                .field("Application auth code",
+                       paint(Color::Blue, std::str::from_utf8(&app_ext.app_auth_code)?));
+            doc.section(section);
+        }
+
+        //
+        // Image Descriptor
+        //
+        let mut img_desc = Section::new("Image Descriptor");
+        img_desc
+            .field("Image left position", format!("{}px", self.img_desc.left_pos))
+            .field("Image top position", format!("{}px", self.img_desc.top_pos))
+            .field("Image width", format!("{}px", self.img_desc.width))
+            .field("Image height", format!("{}px", self.img_desc.height))
+            .field("Local color table flag", self.img_desc.packed_fields >> 7)
+            .field("Interlace flag", self.img_desc.packed_fields << 1 >> 7)
+            .field("Sort flag", self.img_desc.packed_fields << 2 >> 7)
+            .field("Reserved", self.img_desc.packed_fields << 3 >> 6)
+            .field("Size of local color table", self.img_desc.packed_fields & 0b00000111);
+        doc.section(img_desc);
+
+        //
+        // Local Color Table
+        //
+        if let Some(lct) = &self.lct {
+            doc.section(color_table("Local Color Table", &lct.table, opt.trim_lines));
+        }
+
+        Ok(doc)
+
+    }
+
+}
+
+fn color_table(title: &str, colors: &[RGB], trim_lines: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::new(title);
+    let mut table = Table::new(&["Idx", "Red", "Green", "Blue"]).align_right(&[0, 1, 2, 3]);
+    for (i, rgb) in colors.iter().enumerate().take(trim_lines) {
+        table.row(cells![i,
+                         paint(Color::Red, format!("{:#04X}", rgb.red)),
+                         paint(Color::Green, format!("{:#04X}", rgb.green)),
+                         paint(Color::Blue, format!("{:#04X}", rgb.blue))]);
+    }
+    section.table(table);
+    if colors.len() > trim_lines {
+        section.trimmed();
+    }
+    section
+}
@@ -0,0 +1,671 @@
+use bininfo::formats::javaclass::*;
+use failure::Error;
+
+use crate::{Opt, Part};
+use crate::render::{Document, Section, Table, Text, paint};
+use super::Describe;
+
+impl Describe for JavaClass {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+        use ansi_term::Color;
+
+        let const_tab = &self.class_header.const_pool_tab;
+
+        //
+        // JAVA CLASS FILE
+        //
+        let mut doc = Document::new(text!["JAVA_CLASS ",
+                                          paint(Color::Blue, java_version_to_str(
+                                              self.class_header.minor_ver,
+                                              self.class_header.major_ver))]);
+        doc.line(access_flags_to_str(self.class_header.access_flags));
+        doc.field("Class", const_value(const_tab, self.class_header.this_class));
+        if self.class_header.super_class > 0 {
+            doc.field("Super Class", const_value(const_tab, self.class_header.super_class));
+        }
+
+        //
+        // CONSTANTS
+        //
+        if opt.show(Part::Headers) && !const_tab.is_empty() {
+            let mut section = Section::new("Constants");
+            let mut table = Table::new(&["Idx", "Type", "Value"]);
+
+            for (i, entry) in const_tab.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    entry.as_ref(),
+                    entry.values_to_text(const_tab).wrap(opt.wrap_chars),
+                ]);
+            }
+            section.table(table);
+            if const_tab.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // INTERFACES
+        //
+        if opt.show(Part::Headers) && !self.class_header.interface_tab.is_empty() {
+            let mut section = Section::new("Interfaces");
+            let mut table = Table::new(&["Idx", "Name"]);
+
+            for (i, entry) in self.class_header.interface_tab.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    const_value(const_tab, *entry),
+                ]);
+            }
+            section.table(table);
+            if self.class_header.interface_tab.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // FIELDS
+        //
+        if opt.show(Part::Symbols) && !self.class_header.field_tab.is_empty() {
+            let mut section = Section::new("Fields");
+            let mut table = Table::new(&["Idx", "Name", "Flags", "Descriptor", "Attributes"]);
+
+            for (i, entry) in self.class_header.field_tab.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    const_value(const_tab, entry.name_idx),
+                    access_flags_to_str(entry.access_flags),
+                    short_desc(const_tab, entry.desc_idx, opt.wrap_chars / 4),
+                    attributes(const_tab, &entry.attributes).wrap(opt.wrap_chars),
+                ]);
+            }
+            section.table(table);
+            if self.class_header.field_tab.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // METHODS
+        //
+        if opt.show(Part::Symbols) && !self.class_header.method_tab.is_empty() {
+            let mut section = Section::new("Methods");
+            let mut table = Table::new(&["Idx", "Name", "Flags", "Desc", "Attributes"]);
+
+            for (i, entry) in self.class_header.method_tab.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    const_value(const_tab, entry.name_idx),
+                    access_flags_to_str(entry.access_flags),
+                    short_desc(const_tab, entry.desc_idx, opt.wrap_chars / 4),
+                    attributes(const_tab, &entry.attributes).wrap(opt.wrap_chars),
+                ]);
+            }
+            section.table(table);
+            if self.class_header.method_tab.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // ATTRIBUTES
+        //
+        if opt.show(Part::Headers) && !self.class_header.attribute_tab.is_empty() {
+            let mut section = Section::new("Attributes");
+            let mut table = Table::new(&["Idx", "Name", "Info"]);
+
+            for (i, entry) in self.class_header.attribute_tab.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    entry.as_ref(),
+                    entry.values_to_text(const_tab).wrap(opt.wrap_chars + 30),
+                ]);
+            }
+            section.table(table);
+            if self.class_header.attribute_tab.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // IMPORTED CLASSES
+        //
+        if opt.selected(Part::Imports) {
+            let classes: Vec<(usize, &Constants)> = const_tab.iter().enumerate()
+                .filter(|(i, entry)| match entry {
+                    Constants::ClassRef{..} => *i != self.class_header.this_class as usize,
+                    _                       => false,
+                })
+                .collect();
+            let mut section = Section::with_count("ImportedClasses", classes.len());
+            let mut table = Table::new(&["Idx", "Name"]);
+
+            for (i, entry) in classes.iter().take(opt.trim_lines) {
+                table.row(cells![
+                    *i,
+                    entry.values_to_text(const_tab),
+                ]);
+            }
+            section.table(table);
+            if classes.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        Ok(doc)
+
+    }
+
+}
+
+/// Constant pool entry at `idx`, resolved to its value.
+fn const_value<I: Into<usize>>(const_tab: &[Constants], idx: I) -> Text {
+    nested_const_value(const_tab, idx.into(), 0)
+}
+
+/// How many constant references are followed before giving up, so entries
+/// referring to each other cannot recurse forever.
+const MAX_CONST_DEPTH: usize = 8;
+
+fn nested_const_value(const_tab: &[Constants], idx: usize, depth: usize) -> Text {
+    use ansi_term::Color;
+
+    if depth > MAX_CONST_DEPTH {
+        return text![paint(Color::Red, "...")];
+    }
+    match const_tab.get(idx) {
+        Some(constant) => constant_to_text(constant, const_tab, depth),
+        None           => text![paint(Color::Red, format!("<invalid constant {}>", idx))],
+    }
+}
+
+/// Descriptor at `idx`, keeping only its last `len` characters.
+fn short_desc(const_tab: &[Constants], idx: u16, len: usize) -> String {
+    let desc = const_value(const_tab, idx).plain();
+    let chars = desc.chars().count();
+    if chars > len {
+        format!("...{}", desc.chars().skip(chars - len).collect::<String>())
+    }
+    else {
+        desc
+    }
+}
+
+fn attributes(const_tab: &[Constants], attrs: &[Attributes]) -> Text {
+    let mut values = Text::default();
+    for attr in attrs {
+        values.append(attr.values_to_text(const_tab)).push("\n");
+    }
+    values
+}
+
+/// Colored description of class file structures, resolving their indices
+/// through the constant pool.
+trait ValuesToText {
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text;
+}
+
+impl ValuesToText for Constants {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        constant_to_text(self, const_tab, 0)
+    }
+
+}
+
+fn constant_to_text(constant: &Constants, const_tab: &[Constants], depth: usize) -> Text {
+    use Constants::*;
+    use ansi_term::Color;
+
+    let idx = |idx: u16| paint(Color::Red, idx.to_string());
+    let value = |idx: u16| nested_const_value(const_tab, idx as usize, depth + 1);
+
+    match constant {
+        UTF8{len: _len, text}                                  => text!(text),
+        Integer(int)                                           => text!(paint(Color::Fixed(120), int.to_string())),
+        Float(float)                                           => text!(paint(Color::Fixed(120), float.to_string())),
+        Long(long)                                             => text!(paint(Color::Fixed(120), long.to_string())),
+        Double(double)                                         => text!(paint(Color::Fixed(120), double.to_string())),
+        ClassRef{name_idx}                                     => text![value(*name_idx).styled(Color::Yellow),
+                                                                        "(", idx(*name_idx), ")"],
+        StringRef{string_idx}                                  => text!["\"",
+                                                                        value(*string_idx).styled(Color::Purple),
+                                                                        "\"(", idx(*string_idx), ")"],
+        FieldRef{class_idx, nametype_idx}                      => text![value(*class_idx),
+                                                                        ", ",
+                                                                        value(*nametype_idx)],
+        MethodRef{class_idx, nametype_idx}                     => text![value(*class_idx),
+                                                                        ", ",
+                                                                        value(*nametype_idx)],
+        InterfaceMethodRef{class_idx, nametype_idx}            => text![value(*class_idx),
+                                                                        ", ",
+                                                                        value(*nametype_idx)],
+        NameAndType{name_idx, desc_idx}                        => text![value(*name_idx).styled(Color::Blue),
+                                                                        "(", idx(*name_idx), "), ",
+                                                                        value(*desc_idx).styled(Color::Green),
+                                                                        "(", idx(*desc_idx), ")"],
+        MethodHandle{kind, idx: ref_idx}                       => text![ref_kind_to_str(*kind),
+                                                                        ", ",
+                                                                        value(*ref_idx),
+                                                                        "(", idx(*ref_idx), ")"],
+        MethodType{desc_idx}                                   => text![value(*desc_idx),
+                                                                        "(", idx(*desc_idx), ")"],
+        Dynamic{bootstrap_method_attr_idx, nametype_idx}       => text![format!("Bootstrap: {} ", bootstrap_method_attr_idx),
+                                                                        value(*nametype_idx)],
+        InvokeDynamic{bootstrap_method_attr_idx, nametype_idx} => text![format!("Bootstrap: {} ", bootstrap_method_attr_idx),
+                                                                        value(*nametype_idx)],
+        Module{name_idx}                                       => text![value(*name_idx),
+                                                                        "(", idx(*name_idx), ")"],
+        Package{name_idx}                                      => text![value(*name_idx),
+                                                                        "(", idx(*name_idx), ")"],
+        Ghost                                                  => Text::default(),
+    }
+
+}
+
+impl ValuesToText for Attributes {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        use Attributes::*;
+        use ansi_term::Color;
+
+        match self {
+
+            ConstantValue{name_idx, attr_len: _, const_idx} => text![const_value(const_tab, *name_idx),
+                                                                    ": ",
+                                                                    const_value(const_tab, *const_idx)],
+            Code{name_idx, attr_len: _, max_stack, max_locals, code_length,
+                 code: _, ex_tab_len: _, ex_tab, attr_count: _, attributes} => {
+                let mut text = text![const_value(const_tab, *name_idx).styled(Color::White.underline()),
+                                     ": Max stack: ",
+                                     paint(Color::Purple, max_stack.to_string()),
+                                     ", Max locals: ",
+                                     paint(Color::Cyan, max_locals.to_string()),
+                                     ", Code_length: ",
+                                     paint(Color::Green, code_length.to_string()),
+                                     "\n"];
+                for ex in ex_tab {
+                    text.push(format!("  Start: {}, End: {}, Handler: {}, Type: ",
+                                      ex.start_pc,
+                                      ex.end_pc,
+                                      ex.handler_pc))
+                        .append(const_value(const_tab, ex.catch_type))
+                        .push("\n");
+                }
+                for at in attributes {
+                    text.append(at.values_to_text(const_tab)).push("\n");
+                }
+                text
+            },
+            StackMapTable{name_idx, attr_len: _, n_entries: _, entries} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for entry in entries {
+                    text.push("  ").append(entry.values_to_text(const_tab)).push("\n");
+                }
+                text
+            },
+            Exceptions{name_idx, attr_len: _, n_of_ex: _, ex_idx_tab} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for ex in ex_idx_tab {
+                    text.append(const_value(const_tab, *ex));
+                }
+                text
+            },
+            InnerClasses{name_idx: _, attr_len: _, n_of_classes: _, classes} => {
+                let mut text = Text::default();
+                for class in classes {
+                    text.push("Inner: ")
+                        .append(const_value(const_tab, class.inner_class_info_idx))
+                        .push(", Outer: ")
+                        .append(const_value(const_tab, class.outer_class_info_idx))
+                        .push(", Inner name: ")
+                        .append(const_value(const_tab, class.inner_name_idx).styled(Color::Yellow))
+                        .push(format!(", Flags: {}\n", access_flags_to_str(class.inner_class_access_flags)));
+                }
+                text
+            },
+            EnclosingMethod{name_idx, attr_len: _, class_idx, method_idx} => {
+                text![const_value(const_tab, *name_idx),
+                      ": ",
+                      const_value(const_tab, *class_idx),
+                      " ",
+                      const_value(const_tab, *method_idx)]
+            },
+            Synthetic{name_idx, attr_len: _, } => {
+                const_value(const_tab, *name_idx)
+            },
+            Signature{name_idx: _, attr_len: _, sig_idx} => {
+                text!["Sig: ", const_value(const_tab, *sig_idx).styled(Color::Yellow)]
+            },
+            SourceFile{name_idx: _, attr_len: _, source_idx} => {
+                const_value(const_tab, *source_idx).styled(Color::Yellow)
+            },
+            SourceDebugExtension{name_idx: _, attr_len: _, debug_ext} => {
+                text!(String::from_utf8_lossy(debug_ext).into_owned())
+            },
+            LineNumberTable{name_idx, attr_len: _, line_num_tab_len: _, line_num_tab} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for line in line_num_tab {
+                    text.push("  pc: ")
+                        .push(paint(Color::Red, line.start_pc.to_string()))
+                        .push(", line: ")
+                        .push(paint(Color::Blue, line.line_num.to_string()))
+                        .push("\n");
+                }
+                text
+            },
+            LocalVariableTable{name_idx, attr_len: _, local_var_tab_len: _, local_var_tab} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for local in local_var_tab {
+                    text.push("  ")
+                        .append(const_value(const_tab, local.name_idx).styled(Color::Blue))
+                        .push(": ")
+                        .append(const_value(const_tab, local.desc_idx).styled(Color::Yellow))
+                        .push(format!(", Start: {}, Idx: {}\n", local.start_pc, local.idx));
+                }
+                text
+            },
+            LocalVariableTypeTable{name_idx, attr_len: _, local_var_type_tab_len: _, local_var_type_tab} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for local in local_var_type_tab {
+                    text.push("  ")
+                        .append(const_value(const_tab, local.name_idx).styled(Color::Blue))
+                        .push(": ")
+                        .append(const_value(const_tab, local.sig_idx).styled(Color::Yellow))
+                        .push(format!(", Start: {}, Idx: {}\n", local.start_pc, local.idx));
+                }
+                text
+            },
+            Deprecated{name_idx, attr_len: _, } => {
+                const_value(const_tab, *name_idx)
+            },
+            RuntimeVisibleAnnotations{name_idx, attr_len: _, num_anno: _, anno} |
+            RuntimeInvisibleAnnotations{name_idx, attr_len: _, num_anno: _, anno} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for ann in anno {
+                    text.push("  ").append(ann.values_to_text(const_tab)).push("\n");
+                }
+                text
+            },
+            RuntimeVisibleParameterAnnotations{name_idx, attr_len: _, num_params: _, param_anno} |
+            RuntimeInvisibleParameterAnnotations{name_idx, attr_len: _, num_params: _, param_anno} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for param in param_anno {
+                    for ann in &param.anno {
+                        text.push("  ").append(ann.values_to_text(const_tab)).push("\n");
+                    }
+                }
+                text
+            },
+            RuntimeVisibleTypeAnnotations{name_idx, attr_len: _, num_anno: _, anno} |
+            RuntimeInvisibleTypeAnnotations{name_idx, attr_len: _, num_anno: _, anno} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for ann in anno {
+                    text.push("  ").append(ann.values_to_text(const_tab)).push("\n");
+                }
+                text
+            },
+            AnnotationDefault{name_idx, attr_len: _, default_value} => {
+                text![const_value(const_tab, *name_idx),
+                      ": ",
+                      default_value.value.values_to_text(const_tab)]
+            },
+            BootstrapMethods{name_idx: _, attr_len: _, n_bootstrap_methods: _, bootstrap_methods} => {
+                let mut text = Text::default();
+                for boot in bootstrap_methods {
+                    text.append(const_value(const_tab, boot.bootstrap_method_ref)).push(":\n");
+                    for arg in &boot.bootstrap_args {
+                        text.push("  ").append(const_value(const_tab, *arg)).push("\n");
+                    }
+                    text.push("\n");
+                }
+                text
+            },
+            MethodParameters{name_idx, attr_len: _, params_count: _, params} => {
+                let mut text = text![const_value(const_tab, *name_idx), ":\n"];
+                for param in params {
+                    text.push("  ")
+                        .append(const_value(const_tab, param.name_idx))
+                        .push(format!(", {}\n", access_flags_to_str(param.access_flags)));
+                }
+                text
+            },
+            Module{name_idx: _, attr_len: _, mod_name_idx, mod_flags, mod_ver_idx, requires_cnt: _, requires,
+                   exports_cnt: _, exports, opens_cnt: _, opens, uses_cnt: _, uses_idx, provides_cnt: _, provides} => {
+                let mut text = text![const_value(const_tab, *mod_name_idx),
+                                     format!(": {} ", access_flags_to_str(*mod_flags)),
+                                     const_value(const_tab, *mod_ver_idx),
+                                     "\nRequire:\n"];
+                for req in requires {
+                    text.push("  ")
+                        .append(const_value(const_tab, req.idx))
+                        .push(format!(" {} ", access_flags_to_str(req.flags)))
+                        .append(const_value(const_tab, req.ver_idx).styled(Color::Blue))
+                        .push("\n");
+                }
+                text.push("Exports:\n");
+                for exp in exports {
+                    text.push("  ")
+                        .append(const_value(const_tab, exp.idx))
+                        .push(format!(" {} ", access_flags_to_str(exp.flags)));
+                    if !exp.to_idx.is_empty() {
+                        text.push(paint(Color::Green, "Exports:"));
+                    }
+                    text.push(" ");
+                    for id in &exp.to_idx {
+                        text.append(const_value(const_tab, *id)).push(", ");
+                    }
+                    text.push("\n");
+                }
+                text.push("Opens:\n");
+                for op in opens {
+                    text.push("  ")
+                        .append(const_value(const_tab, op.idx))
+                        .push(format!(" {} ", access_flags_to_str(op.flags)));
+                    if !op.to_idx.is_empty() {
+                        text.push(paint(Color::Green, "Opens:"));
+                    }
+                    text.push(" ");
+                    for id in &op.to_idx {
+                        text.append(const_value(const_tab, *id));
+                    }
+                    text.push("\n");
+                }
+                text.push("Uses:\n");
+                for us in uses_idx {
+                    text.push("  ").append(const_value(const_tab, *us)).push("\n");
+                }
+                text.push("Provides:\n");
+                for prov in provides {
+                    text.push("  ")
+                        .append(const_value(const_tab, prov.idx))
+                        .push(" ");
+                    if !prov.with_idx.is_empty() {
+                        text.push(paint(Color::Green, "Provides:"));
+                    }
+                    text.push(" ");
+                    for id in &prov.with_idx {
+                        text.append(const_value(const_tab, *id));
+                    }
+                    text.push("\n");
+                }
+                text
+            },
+            ModulePackages{name_idx: _, attr_len: _, package_cnt: _, package_idx} => {
+                let mut text = Text::default();
+                for package in package_idx {
+                    text.append(const_value(const_tab, *package)).push("\n");
+                }
+                text
+            },
+            ModuleMainClass{name_idx: _, attr_len: _, main_class_idx} => {
+                const_value(const_tab, *main_class_idx)
+            },
+            NestHost{name_idx: _, attr_len: _, host_class_idx} => {
+                const_value(const_tab, *host_class_idx)
+            },
+            NestMembers{name_idx: _, attr_len: _, n_of_classes: _, classes} => {
+                let mut text = Text::default();
+                for class in classes {
+                    text.append(const_value(const_tab, *class)).push("\n");
+                }
+                text
+            },
+
+        }
+
+    }
+
+}
+
+impl ValuesToText for StackMapFrame {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        use StackMapFrame::*;
+        use ansi_term::Color;
+
+        let frame = |name: &str, frame_type: &u8| text![name, "(", paint(Color::Red, frame_type.to_string()), ")"];
+
+        match self {
+            SameFrame(frame_type) => frame("SAME", frame_type),
+            SameLocals1StackItemFrame(frame_type, stack) => {
+                let mut text = frame("SAME_LOCALS_1_STACK_ITEM", frame_type);
+                text.push(": Stack: ").append(stack.values_to_text(const_tab));
+                text
+            },
+            SameLocals1StackItemFrameExt(frame_type, offset_delta, stack) => {
+                let mut text = frame("SAME_LOCALS_1_STACK_ITEM_EXTENDED", frame_type);
+                text.push(": Offset: ")
+                    .push(paint(Color::Blue, offset_delta.to_string()))
+                    .push(", Stack: ")
+                    .append(stack.values_to_text(const_tab));
+                text
+            },
+            ChopFrame(frame_type, offset_delta) => {
+                let mut text = frame("CHOP", frame_type);
+                text.push(": Offset: ").push(paint(Color::Blue, offset_delta.to_string()));
+                text
+            },
+            SameFrameExt(frame_type, offset_delta) => {
+                let mut text = frame("SAME_FRAME_EXTENDED", frame_type);
+                text.push(": Offset: ").push(paint(Color::Blue, offset_delta.to_string()));
+                text
+            },
+            AppendFrame(frame_type, offset_delta, locals) => {
+                let mut text = frame("APPEND", frame_type);
+                text.push(": Offset: ")
+                    .push(paint(Color::Blue, offset_delta.to_string()))
+                    .push("\n  Locals: ");
+                for local in locals {
+                    text.push("\n    ").append(local.values_to_text(const_tab));
+                }
+                text
+            },
+            FullFrame(frame_type, offset_delta, _, locals, _, items) => {
+                let mut text = frame("FULL_FRAME", frame_type);
+                text.push(": Offset: ")
+                    .push(paint(Color::Blue, offset_delta.to_string()))
+                    .push("\n  Locals: ");
+                for local in locals {
+                    text.push("\n    ").append(local.values_to_text(const_tab));
+                }
+                text.push("\n  Items: ");
+                for item in items {
+                    text.push("\n    ").append(item.values_to_text(const_tab));
+                }
+                text
+            }
+        }
+    }
+
+}
+
+impl ValuesToText for VerificationTypeInfo {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        use VerificationTypeInfo::*;
+
+        match self {
+            ObjectVariable(_, idx)           => text![self.as_ref(), ": ", const_value(const_tab, *idx)],
+            UninitializedVariable(_, offset) => text![self.as_ref(), ": ", *offset],
+            _                                => text!(self.as_ref()),
+        }
+
+    }
+
+}
+
+impl ValuesToText for Annotation {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        use ansi_term::Color;
+
+        let mut text = text![const_value(const_tab, self.type_idx).styled(Color::Yellow), "\n"];
+        for val in &self.ele_val_pairs {
+            text.push("    ").append(val.value.value.values_to_text(const_tab)).push("\n");
+        }
+        text
+    }
+
+}
+
+impl ValuesToText for Value {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        use Value::*;
+        use ansi_term::Color;
+
+        match self {
+            ConstValueIdx(idx) => text![self.as_ref(), ": ", const_value(const_tab, *idx)],
+            EnumConstValue(type_idx, name_idx) => text![self.as_ref(),
+                                                        ": ",
+                                                        const_value(const_tab, *type_idx).styled(Color::Blue),
+                                                        " ",
+                                                        const_value(const_tab, *name_idx).styled(Color::Green)],
+            ClassInfoIdx(idx) => text![self.as_ref(), ": ", const_value(const_tab, *idx)],
+            AnnotationValue(anno) => {
+                let mut text = text![self.as_ref(),
+                                     ": ",
+                                     const_value(const_tab, anno.type_idx).styled(Color::Blue),
+                                     ":\n"];
+                for ann in &anno.ele_val_pairs {
+                    text.append(ann.value.value.values_to_text(const_tab));
+                }
+                text
+            },
+            ArrayValue(_, values) => {
+                let mut text = text![self.as_ref(), "\n"];
+                for value in values {
+                    text.push("      ").append(value.value.values_to_text(const_tab)).push("\n");
+                }
+                text
+            },
+        }
+
+    }
+
+}
+
+impl ValuesToText for Type_annotation {
+
+    fn values_to_text(&self, const_tab: &[Constants]) -> Text {
+        let mut text = text![format!("{}, {:?} ", self.target_info.value_to_string(), self.target_path),
+                             const_value(const_tab, self.type_idx),
+                             ": "];
+        for val in &self.ele_val_pairs {
+            text.append(val.value.value.values_to_text(const_tab))
+                .push(": ")
+                .append(const_value(const_tab, val.name_idx))
+                .push("\n");
+        }
+        text
+    }
+
+}
@@ -0,0 +1,15 @@
+use bininfo::formats::jpg::Jpg;
+use failure::Error;
+
+use crate::Opt;
+use crate::render::Document;
+use super::Describe;
+
+impl Describe for Jpg {
+
+    fn describe(&self, _opt: &Opt) -> Result<Document, Error> {
+
+        Ok(Document::new("JPG"))
+    }
+
+}
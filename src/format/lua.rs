@@ -0,0 +1,217 @@
+use bininfo::formats::lua::{Lua, Info};
+use bininfo::formats::lua::lua51::{Lua51_info, Inner_function_block, Const, Constant, Local, vararg_to_str};
+use failure::Error;
+
+use crate::{Opt, Part};
+use crate::render::{Document, Section, Table, paint};
+use super::Describe;
+
+impl Describe for Lua {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+
+        let mut doc = Document::new(format!("Lua Bytecode {}.{}",
+                                            self.lua_header.ver >> 4,
+                                            self.lua_header.ver << 4 >> 4));
+
+        match &self.info {
+            Info::Lua51(info) => describe_lua51(&mut doc, info, opt),
+        }
+
+        Ok(doc)
+    }
+
+}
+
+fn describe_lua51(doc: &mut Document, info: &Lua51_info, opt: &Opt) {
+    let trim_lines = opt.trim_lines;
+
+    if opt.show(Part::Headers) {
+        describe_lua51_header(doc, info);
+    }
+
+    //
+    // CONSTANTS
+    //
+    if opt.show(Part::Symbols) && info.main_func.consts_sz >= 1 {
+        doc.section(constants(info.main_func.consts_sz as usize, &info.main_func.consts, trim_lines));
+    }
+
+    //
+    // FUNCTIONS
+    //
+    if opt.show(Part::Sections) && info.main_func.funcs_sz >= 1 {
+        let mut section = Section::with_count("Functions", info.main_func.funcs_sz as usize);
+        for func in &info.main_func.funcs {
+            section.section(inner_function(func, trim_lines));
+        }
+        doc.section(section);
+    }
+
+    //
+    // SOURCE LINES
+    //
+    if opt.show(Part::Sections) && info.main_func.source.source_sz >= 1 {
+        doc.section(Section::with_count("SourceLines", info.main_func.source.source_sz as usize));
+    }
+
+    //
+    // LOCALS
+    //
+    if opt.show(Part::Symbols) && info.main_func.locals.locals_sz >= 1 {
+        doc.section(locals(info.main_func.locals.locals_sz as usize, &info.main_func.locals.locals, trim_lines));
+    }
+
+    //
+    // UPVALUES
+    //
+    if opt.show(Part::Symbols) && info.main_func.upvalues.upvalues_sz >= 1 {
+        doc.section(upvalues(info.main_func.upvalues.upvalues_sz as usize, &info.main_func.upvalues.upvalues, trim_lines));
+    }
+
+}
+
+fn describe_lua51_header(doc: &mut Document, info: &Lua51_info) {
+    use ansi_term::Color;
+
+    let mut header = Section::new("Header");
+    header
+        .field("Format", info.header.format)
+        .field("Endian", if info.header.endianness == 0 { "Big-endian" } else { "Little-endian" })
+        .field("Size of integer", paint(Color::Green, info.header.int_sz.to_string()))
+        .field("Size of size_t", paint(Color::Green, info.header.size_t_sz.to_string()))
+        .field("Size of instruction", paint(Color::Green, info.header.intr_sz.to_string()))
+        .field("Size of lua Number", paint(Color::Green, info.header.lua_number_sz.to_string()))
+        .field("Integral flag", info.header.integral_flag);
+    doc.section(header);
+
+    let mut main = Section::new("Main function");
+    main
+        .field("Name", paint(Color::Yellow, info.main_func.name.as_str()))
+        .field("Number of upvalues", paint(Color::Purple, info.main_func.n_upvalues.to_string()))
+        .field("Number of params", paint(Color::Purple, info.main_func.n_params.to_string()))
+        .field("Varag", vararg_to_str(info.main_func.is_vararg))
+        .field("Max stack size", paint(Color::Green, info.main_func.max_stack_sz.to_string()))
+        .field("Code size", paint(Color::Green, info.main_func.code_sz.to_string()));
+    doc.section(main);
+
+}
+
+fn inner_function(block: &Inner_function_block, trim_lines: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::new(paint(Color::White, "---Inner function---"));
+    section
+        .field("Number of upvalues", paint(Color::Purple, block.n_upvalues.to_string()))
+        .field("Number of params", paint(Color::Purple, block.n_params.to_string()))
+        .field("Vararg", vararg_to_str(block.is_vararg))
+        .field("Max stack size", paint(Color::Green, block.max_stack_sz.to_string()))
+        .field("Code size", paint(Color::Green, block.code_sz.to_string()));
+
+    //
+    // CONSTANTS
+    //
+    if block.consts_sz >= 1 {
+        section.section(constants(block.consts_sz as usize, &block.consts, trim_lines));
+    }
+
+    //
+    // FUNCTIONS
+    //
+    if block.funcs_sz >= 1 {
+        let mut funcs = Section::with_count("Functions", block.funcs_sz as usize);
+        for func in &block.funcs {
+            funcs.section(inner_function(func, trim_lines));
+        }
+        section.section(funcs);
+    }
+
+    //
+    // SOURCE LINES
+    //
+    if block.source.source_sz >= 1 {
+        section.section(Section::with_count("SourceLines", block.source.source_sz as usize));
+    }
+
+    //
+    // LOCALS
+    //
+    if block.locals.locals_sz >= 1 {
+        section.section(locals(block.locals.locals_sz as usize, &block.locals.locals, trim_lines));
+    }
+
+    //
+    // UPVALUES
+    //
+    if block.upvalues.upvalues_sz >= 1 {
+        section.section(upvalues(block.upvalues.upvalues_sz as usize, &block.upvalues.upvalues, trim_lines));
+    }
+
+    section
+
+}
+
+fn constants(count: usize, consts: &[Constant], trim_lines: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("Constants", count);
+    let mut table = Table::new(&["Idx", "Type", "Value"]);
+
+    for (i, entry) in consts.iter().enumerate().take(trim_lines) {
+        table.row(cells![
+            i,
+            entry.constant.as_ref(),
+            match &entry.constant {
+                Const::Nil        => paint(Color::Cyan, "Nil"),
+                Const::Boolean(b) => if *b { paint(Color::Green, b.to_string()) } else { paint(Color::Red, b.to_string()) },
+                Const::Number(n)  => paint(Color::Blue, n.to_string()),
+                Const::String(s)  => paint(Color::Yellow, s.as_str()),
+            },
+        ]);
+    }
+    section.table(table);
+    if consts.len() > trim_lines {
+        section.trimmed();
+    }
+    section
+}
+
+fn locals(count: usize, locals: &[Local], trim_lines: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("Locals", count);
+    let mut table = Table::new(&["Idx", "Name", "Start_pc", "End_pc"]);
+
+    for (i, entry) in locals.iter().enumerate().take(trim_lines) {
+        table.row(cells![
+            i,
+            paint(Color::Yellow, entry.var_name.as_str()),
+            paint(Color::Green, entry.start_pc.to_string()),
+            paint(Color::Red, entry.end_pc.to_string()),
+        ]);
+    }
+    section.table(table);
+    if locals.len() > trim_lines {
+        section.trimmed();
+    }
+    section
+}
+
+fn upvalues(count: usize, upvalues: &[String], trim_lines: usize) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count("Upvalues", count);
+    let mut table = Table::new(&["Idx", "Name"]);
+
+    for (i, entry) in upvalues.iter().enumerate().take(trim_lines) {
+        table.row(cells![
+            i,
+            paint(Color::Yellow, entry.as_str()),
+        ]);
+    }
+    section.table(table);
+    if upvalues.len() > trim_lines {
+        section.trimmed();
+    }
+    section
+}
@@ -0,0 +1,308 @@
+use bininfo::formats::macho::{self, *};
+use bininfo::Problem;
+use failure::Error;
+use scroll::Pread;
+
+use crate::{Opt, Part};
+use crate::render::{Document, Section, Table, Text, paint};
+use super::{Describe, symbol_name};
+
+impl Describe for MachO {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+        use ansi_term::Color;
+
+        //
+        // MACH-O FILE
+        //
+        let mut doc = Document::new(macho_title(&self.header));
+
+        //
+        // COMMANDS
+        //
+        if opt.show(Part::Headers) && !self.commands.is_empty() {
+            let mut section = Section::with_count("LoadCommands", self.commands.len());
+            let mut table = Table::new(&["Idx", "Name"]);
+
+            for (i, entry) in self.commands.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![
+                    i,
+                    segment_to_str(entry.get_cmd_type()),
+                ]);
+            }
+            section.table(table);
+            if self.commands.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // SEGMENTS
+        //
+        if opt.show(Part::Sections) && !self.segments.is_empty() {
+            let mut section = Section::with_count("Segments", self.segments.len());
+
+            for entry in &self.segments {
+
+                let mut segment = Section::new(text![paint(Color::Fixed(75), std::str::from_utf8(&entry.header.seg_name)?),
+                                                     format!("({})", entry.sects.len())]);
+
+                if !entry.sects.is_empty() {
+
+                    //
+                    // SECTIONS
+                    //
+                    let mut table = Table::new(&["Idx", "Name", "Addr", "Size", "Offset", "Align", "RelOff", "Nreloc", "Flags"])
+                        .align_right(&[7]);
+
+                    for (i, entry) in entry.sects.iter().enumerate().take(opt.trim_lines) {
+                        table.row(cells![
+                            i,
+                            std::str::from_utf8(&entry.sect_name)?,
+                            paint(Color::Red, format!("{:#X}", entry.addr)),
+                            paint(Color::Green, format!("{:#X}", entry.size)),
+                            paint(Color::Yellow, format!("{:#X}", entry.offset)),
+                            format!("{:#X}", entry.align),
+                            format!("{:#X}", entry.reloff),
+                            paint(Color::Purple, entry.n_reloc.to_string()),
+                            text![paint(Color::Blue, section_type_to_str(entry.flags)),
+                                  " ",
+                                  section_attr_to_str(entry.flags)],
+                        ]);
+                    }
+                    segment.table(table);
+                    if entry.sects.len() > opt.trim_lines {
+                        segment.trimmed();
+                    }
+                }
+                section.section(segment);
+            }
+            doc.section(section);
+        }
+
+        //
+        // SYMBOLS
+        //
+        if let (true, Some(symtab)) = (opt.show(Part::Symbols), &self.symtab) {
+            let mut section = Section::with_count("Symbols", symtab.syms.len());
+
+            if let Some(dysymtab) = &self.dysymtab {
+                //
+                // LOCAL SYMBOLS
+                //
+                section.section(macho_syms("LocalSymbols",
+                                           dysym_range(&symtab.syms, dysymtab.local_sym_idx, dysymtab.local_sym_n)?,
+                                           &symtab.strs,
+                                           &self.sections,
+                                           opt.trim_lines,
+                                           opt.demangle)?);
+
+                //
+                // EXTERNAL SYMBOLS
+                //
+                section.section(macho_syms("ExternalSymbols",
+                                           dysym_range(&symtab.syms, dysymtab.ext_def_sym_idx, dysymtab.ext_def_sym_n)?,
+                                           &symtab.strs,
+                                           &self.sections,
+                                           opt.trim_lines,
+                                           opt.demangle)?);
+
+                //
+                // UNDEFINED SYMBOLS
+                //
+                section.section(macho_syms("UndefinedSymbols",
+                                           dysym_range(&symtab.syms, dysymtab.undef_sym_idx, dysymtab.undef_sym_n)?,
+                                           &symtab.strs,
+                                           &self.sections,
+                                           opt.trim_lines,
+                                           opt.demangle)?);
+
+            }
+
+            else  {
+                let syms = macho_syms("", &symtab.syms, &symtab.strs, &self.sections, opt.trim_lines, opt.demangle)?;
+                section.children.extend(syms.children);
+            }
+            doc.section(section);
+        }
+
+        //
+        // RELOCATIONS
+        //
+        if opt.show(Part::Relocs) && !self.relocs.is_empty() {
+            let mut section = Section::with_count("Relocations", self.relocs.len());
+
+            if let Some(symtab) = &self.symtab {
+                for reloc in &self.relocs {
+                    section.section(macho_reloc(reloc, symtab, &self.sections, opt.trim_lines, opt.demangle)?);
+                }
+            }
+            doc.section(section);
+        }
+
+        //
+        // LIBRARIES
+        //
+        if opt.show(Part::Imports) && !self.libs.is_empty() {
+            let mut section = Section::with_count("Libraries", self.libs.len());
+            for lib in &self.libs {
+                section.line(paint(Color::Blue, lib.as_str()));
+            }
+            doc.section(section);
+        }
+
+        //
+        // IMPORTS AND EXPORTS
+        //
+        if let (Some(symtab), Some(dysymtab)) = (&self.symtab, &self.dysymtab) {
+            if opt.selected(Part::Imports) {
+                doc.section(macho_syms("ImportedSymbols",
+                                       dysym_range(&symtab.syms, dysymtab.undef_sym_idx, dysymtab.undef_sym_n)?,
+                                       &symtab.strs,
+                                       &self.sections,
+                                       opt.trim_lines,
+                                       opt.demangle)?);
+            }
+            if opt.selected(Part::Exports) {
+                doc.section(macho_syms("ExportedSymbols",
+                                       dysym_range(&symtab.syms, dysymtab.ext_def_sym_idx, dysymtab.ext_def_sym_n)?,
+                                       &symtab.strs,
+                                       &self.sections,
+                                       opt.trim_lines,
+                                       opt.demangle)?);
+            }
+        }
+
+        Ok(doc)
+
+    }
+
+}
+
+pub fn macho_title(header: &Mach_header) -> Text {
+    use ansi_term::Color;
+
+    let mut title = text!["Mach-O "];
+
+    if mach_is_lib(header.filetype) {
+        title.push(paint(Color::Blue, "LIB")).push(" ");
+    }
+    if mach_is_exe(header.filetype) {
+        title.push(paint(Color::Red, "EXECUTE")).push(" ");
+    }
+    title
+
+}
+
+/// Symbols `idx..idx + n` of a range described by the dynamic symbol table.
+fn dysym_range(syms: &[Nlist], idx: u32, n: u32) -> Result<&[Nlist], Error> {
+    let start = idx as usize;
+    syms.get(start..start + n as usize)
+        .ok_or_else(|| Error::from(Problem::msg(format!("Invalid symbol range: {} symbols at {}", n, idx))))
+}
+
+/// Placeholder for names the parser reported as damaged, which only get
+/// this far when parsing leniently.
+const INVALID: &str = "<invalid>";
+
+/// `segment.section` name of the 1-based section number `n_sect`.
+fn section_name(secs: &[macho::Section], n_sect: usize) -> Result<String, Error> {
+    match n_sect.checked_sub(1).and_then(|idx| secs.get(idx)) {
+        Some(sec) => Ok(format!("{}.{}", std::str::from_utf8(&sec.seg_name)?, std::str::from_utf8(&sec.sect_name)?)),
+        None => Ok(INVALID.to_string()),
+    }
+}
+
+fn string_at(strs: &[u8], offset: u32) -> &str {
+    strs.pread::<&str>(offset as usize).unwrap_or(INVALID)
+}
+
+pub fn macho_syms(title: &str, syms: &[Nlist], strs: &[u8], secs: &[macho::Section], trim_lines: usize, demangle: bool) -> Result<Section, Error> {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count(paint(Color::Fixed(75), title), syms.len());
+    let mut table = Table::new(&["Idx", "Name", "Section"]);
+
+    for (i, entry) in syms.iter().enumerate().take(trim_lines) {
+
+        let mut sect = paint(Color::Cyan, "NONE");
+        if entry.n_sect > 0 {
+            sect = paint(Color::Blue, section_name(secs, entry.n_sect as usize)?);
+        }
+
+        table.row(cells![
+            i,
+            paint(Color::Yellow, symbol_name(string_at(strs, entry.n_un), demangle)),
+            sect,
+        ]);
+    }
+    section.table(table);
+    if syms.len() > trim_lines {
+        section.trimmed();
+    }
+
+    Ok(section)
+
+}
+
+//TODO This needs fixing for 32bit object files
+// https://opensource.apple.com/source/xnu/xnu-4903.221.2/EXTERNAL_HEADERS/mach-o/reloc.h.auto.html
+pub fn macho_reloc(reloc: &Relocation, syms: &Symtab, secs: &[macho::Section], trim_lines: usize, demangle: bool) -> Result<Section, Error> {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count(text![paint(Color::Fixed(75), std::str::from_utf8(&reloc.sec.seg_name)?),
+                                                ".",
+                                                paint(Color::Fixed(75), std::str::from_utf8(&reloc.sec.sect_name)?)],
+                                          reloc.info.len());
+    let mut table = Table::new(&["Idx", "Offset", "Type", "Extern", "Length", "PIC", "SymbolNum", "Symbol"])
+        .align_right(&[4, 6]);
+
+    for (i, entry) in reloc.info.iter().enumerate().take(trim_lines) {
+
+        let r_type   = entry.sym >> 28;
+        let r_extern = entry.sym << 4 >> 31 == 1;
+        let r_length = entry.sym << 5 >> 30;
+        let r_pcrel  = entry.sym << 7 >> 31 == 1;
+        let r_sym    = entry.sym << 8 >> 8;
+
+        let mut sym = String::new();
+        if r_extern {
+            sym = syms.syms.get(r_sym as usize)
+                .map_or(INVALID.into(), |nlist| symbol_name(string_at(&syms.strs, nlist.n_un), demangle))
+                .into_owned()
+        }
+        else {
+            if r_sym >= 1 {
+                sym.push_str(&section_name(secs, r_sym as usize)?);
+            }
+            else {
+                sym.push_str("NONE");
+            }
+        }
+
+        let bool_cell = |b: bool| if b {
+            paint(Color::Green, "true")
+        } else {
+            paint(Color::Red, "false")
+        };
+
+        table.row(cells![
+            i,
+            paint(Color::Red, format!("{:#X}", entry.addr)),
+            r_type,
+            bool_cell(r_extern),
+            paint(Color::Green, r_length.to_string()),
+            bool_cell(r_pcrel),
+            paint(Color::Purple, r_sym.to_string()),
+            paint(Color::Yellow, sym),
+        ]);
+    }
+    section.table(table);
+    if reloc.info.len() > trim_lines {
+        section.trimmed();
+    }
+
+    Ok(section)
+
+}
@@ -0,0 +1,99 @@
+mod png;
+mod bmp;
+mod gif;
+mod jpg;
+
+mod pe;
+mod elf;
+mod javaclass;
+mod macho;
+mod lua;
+
+mod zip;
+mod ar;
+
+mod pdf;
+
+use std::borrow::Cow;
+
+use bininfo::{Binary, Problem};
+use bininfo::demangle::demangle;
+use bininfo::magic::{self, Candidate};
+use failure::Error;
+
+use crate::Opt;
+use crate::render::{Document, Section, paint};
+
+/// `name` demangled when `demangle` is set and it is a mangled name.
+pub fn symbol_name(name: &str, demangle_names: bool) -> Cow<'_, str> {
+    if demangle_names {
+        demangle(name).map_or(Cow::Borrowed(name), Cow::Owned)
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Human readable description of a parsed file, rendered by any
+/// [`Renderer`](crate::render::Renderer).
+pub trait Describe {
+    fn describe(&self, opt: &Opt) -> Result<Document, Error>;
+}
+
+impl Describe for Binary {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+
+        match self {
+            Binary::Bmp(bmp)              => bmp.describe(opt),
+            Binary::Png(png)              => png.describe(opt),
+            Binary::Gif(gif)              => gif.describe(opt),
+            Binary::Jpg(jpg)              => jpg.describe(opt),
+
+            Binary::Pe(pe)                => pe.describe(opt),
+            Binary::Elf(elf)              => elf.describe(opt),
+            Binary::JavaClass(java_class) => java_class.describe(opt),
+            Binary::MachO(macho)          => macho.describe(opt),
+            Binary::Lua(lua)              => lua.describe(opt),
+
+            Binary::Pdf(pdf)              => pdf.describe(opt),
+
+            Binary::Zip(zip)              => zip.describe(opt),
+            Binary::Ar(ar)                => ar.describe(opt),
+
+            Binary::Unknown => {
+                use ansi_term::Color;
+
+                let mut doc = Document::new(paint(Color::Black.on(Color::Red), "Unknown/unsupported file format"));
+                doc.line(format!("Check for newest version: {}", "https://github.com/Forlos/bininfo"));
+                Ok(doc)
+            },
+        }
+
+    }
+
+}
+
+/// Problems a lenient parse recovered from, listed after everything else.
+pub fn warnings(warnings: &[Problem]) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count(paint(Color::Yellow, "Warnings"), warnings.len());
+    for warning in warnings {
+        section.line(paint(Color::Yellow, warning.to_string()));
+    }
+    section
+}
+
+/// Formats the file could be besides the one it was parsed as.
+pub fn candidates(candidates: &[Candidate]) -> Section {
+    use ansi_term::Color;
+
+    let mut section = Section::with_count(paint(Color::Yellow, "Candidates"), candidates.len());
+    for candidate in candidates {
+        if let Some(detector) = magic::detector(candidate.format) {
+            section.line(text![paint(Color::Yellow, detector.name),
+                               format!(" (score {}): {}", candidate.score, detector.description)]);
+        }
+    }
+    section
+}
@@ -0,0 +1,15 @@
+use bininfo::formats::pdf::Pdf;
+use failure::Error;
+
+use crate::Opt;
+use crate::render::Document;
+use super::Describe;
+
+impl Describe for Pdf {
+
+    fn describe(&self, _opt: &Opt) -> Result<Document, Error> {
+
+        Ok(Document::new(format!("PDF{}", self.version)))
+    }
+
+}
@@ -0,0 +1,219 @@
+use ansi_term::Color;
+use bininfo::formats::pe::*;
+use failure::Error;
+
+use crate::{Opt, Part};
+use crate::render::{Document, Section, Table, Text, paint};
+use super::{Describe, symbol_name};
+
+impl Describe for Pe {
+
+    fn describe(&self, options: &Opt) -> Result<Document, Error> {
+
+        //
+        // PE HEADER
+        //
+        let mut doc = Document::new(pe_title(&self.coff));
+        doc.line(characteristics_to_str(self.coff.characteristics));
+        if options.show(Part::Headers) {
+            doc.section(pe_header(&self.coff));
+        }
+
+        if let (true, Some(opt)) = (options.show(Part::Headers), &self.coff_optional_header) {
+            //
+            // OPTIONAL HEADER
+            //
+            let mut section = Section::new("Optional Header");
+            section
+                .field("Major linker version", opt.std_coff.major_link_ver)
+                .field("Minor linker version", opt.std_coff.minor_link_ver)
+                .field("Size of code", paint(Color::Green, format!("{:#X}", opt.std_coff.sz_of_code)))
+                .field("Size of initialized", paint(Color::Green, format!("{:#X}", opt.std_coff.sz_of_init)))
+                .field("Size of uninitialized", paint(Color::Green, format!("{:#X}", opt.std_coff.sz_of_uninit)))
+                .field("Entry point", paint(Color::Red, format!("{:#X}", opt.std_coff.addr_of_entry)))
+                .field("Base of code", paint(Color::Red, format!("{:#X}", opt.std_coff.base_of_code)));
+            if opt.std_coff.magic == PE32_MAGIC {
+                section.field("Base of data", paint(Color::Red, format!("{:#X}", opt.std_coff.base_of_data)));
+            }
+            doc.section(section);
+
+            //
+            // WINDOWS FIELDS
+            //
+            let mut section = Section::new("Windows Fields");
+            section
+                .field("Image base", paint(Color::Red, format!("{:#X}", opt.win_fields.image_base)))
+                .field("Section alignment", format!("{:#X}", opt.win_fields.section_align))
+                .field("File alignment", format!("{:#X}", opt.win_fields.file_align))
+                .field("Major OS version", opt.win_fields.major_os_ver)
+                .field("Minor OS version", opt.win_fields.minor_os_ver)
+                .field("Major image version", opt.win_fields.major_img_ver)
+                .field("Minor image version", opt.win_fields.minor_img_ver)
+                .field("Major subsys version", opt.win_fields.major_sub_ver)
+                .field("Minor subsys version", opt.win_fields.minor_sub_ver)
+                .field("Size of image", paint(Color::Green, format!("{:#X}", opt.win_fields.sz_of_img)))
+                .field("Size of headers", paint(Color::Green, format!("{:#X}", opt.win_fields.sz_of_headers)))
+                .field("Checksum", format!("{:#X}", opt.win_fields.checksum))
+                .field("Subsystem", paint(Color::White, subsys_to_str(opt.win_fields.subsys)))
+                .field("DLL Characteristics", paint(Color::White, dllchara_to_str(opt.win_fields.dll_chara)))
+                .field("Size of stack commit", paint(Color::Green, format!("{:#X}", opt.win_fields.sz_stack_commit)))
+                .field("Size of stack reserve", paint(Color::Green, format!("{:#X}", opt.win_fields.sz_stack_reserve)))
+                .field("Size of heap commit", paint(Color::Green, format!("{:#X}", opt.win_fields.sz_heap_commit)))
+                .field("Size of heap reserve", paint(Color::Green, format!("{:#X}", opt.win_fields.sz_heap_reserve)))
+                .field("Loader flags", format!("{:#X}", opt.win_fields.loader_flags))
+                .field("Number of Rva and Sizes", paint(Color::Purple, format!("{:#X}", opt.win_fields.n_of_rva)));
+            doc.section(section);
+
+            //
+            // DATA DIRECTORIES
+            //
+            let mut section = Section::new("Data Directories");
+            let mut table = Table::new(&["Idx", "Name", "VirtAddr", "Size"]).align_right(&[0]);
+            for (i, data) in opt.data_dirs.dirs.iter().enumerate().take(options.trim_lines) {
+                table.row(cells![
+                    i,
+                    DATA_DIRS[i],
+                    paint(Color::Red, format!("{:#X}", data.rva)),
+                    paint(Color::Green, format!("{:#X}", data.sz)),
+                ]);
+            }
+            section.table(table);
+            if opt.data_dirs.dirs.len() > options.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // SECTIONS
+        //
+        if options.show(Part::Sections) && !self.sections.is_empty() {
+            let mut section = Section::with_count("Sections", self.sections.len());
+            let mut table = Table::new(&["Idx", "Name", "VirtSz", "VirtAddr", "SzRawData",
+                                         "PtrRawData", "PtrRelocs", "PtrLineNum", "nRelocs", "nLinenum", "Characteristics"])
+                .align_right(&[0]);
+
+            for (i, sec) in self.sections.iter().enumerate().take(options.trim_lines) {
+                table.row(cells![
+                    i,
+                    std::str::from_utf8(&sec.name)?,
+                    paint(Color::Green, format!("{:#X}", sec.virt_sz)),
+                    paint(Color::Red, format!("{:#X}", sec.virt_addr)),
+                    paint(Color::Green, format!("{:#X}", sec.sz_raw_data)),
+                    paint(Color::Red, format!("{:#X}", sec.ptr_raw_data)),
+                    paint(Color::Red, format!("{:#X}", sec.ptr_relocs)),
+                    paint(Color::Red, format!("{:#X}", sec.ptr_linenum)),
+                    paint(Color::Purple, format!("{:#X}", sec.n_relocs)),
+                    paint(Color::Purple, format!("{:#X}", sec.n_linenum)),
+                    section_chara_to_str(sec.characteristics),
+                ]);
+            }
+            section.table(table);
+            if self.sections.len() > options.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // IMPORTS
+        //
+        if options.show(Part::Imports) && !self.imports.is_empty() {
+            let sum = self.imports.iter().fold(0, |sum, i| {
+                sum + i.entries.len() + i.ordinals.len()
+            });
+            let mut section = Section::with_count("Imports", sum);
+
+            for imp in &self.imports {
+                let mut library = Section::new(text![paint(Color::Fixed(75), imp.name.as_str()),
+                                                     format!("({})({})", imp.entries.len(), imp.ordinals.len())]);
+
+                if !imp.entries.is_empty() {
+                    let mut table = Table::new(&["Idx", "Name"]).align_right(&[0]);
+                    for (i, entry) in imp.entries.iter().enumerate().take(options.trim_lines) {
+                        table.row(cells![i, paint(Color::Yellow, symbol_name(entry, options.demangle))]);
+                    }
+                    library.table(table);
+                    if imp.entries.len() > options.trim_lines {
+                        library.trimmed();
+                    }
+                }
+                if !imp.ordinals.is_empty() {
+                    let mut table = Table::new(&["Idx", "Ordinal"]).align_right(&[0]);
+                    for (i, entry) in imp.ordinals.iter().enumerate().take(options.trim_lines) {
+                        table.row(cells![i, paint(Color::Blue, entry.to_string())]);
+                    }
+                    library.table(table);
+                    if imp.ordinals.len() > options.trim_lines {
+                        library.trimmed();
+                    }
+                }
+                section.section(library);
+            }
+            doc.section(section);
+        }
+
+        //
+        // EXPORTS
+        //
+        if let (true, Some(exports)) = (options.show(Part::Exports), &self.exports) {
+            let mut section = Section::with_count("Exports", exports.func_addr.len());
+            let mut table = Table::new(&["Idx", "Addr", "Name", "Ordinal"]);
+
+            for (i, entry) in exports.funcs.iter().enumerate().take(options.trim_lines) {
+                table.row(cells![
+                    i,
+                    paint(Color::Red, format!("{:#X}", entry.addr)),
+                    paint(Color::Yellow, symbol_name(&entry.name, options.demangle)),
+                    paint(Color::Blue, entry.ordinal.to_string()),
+                ]);
+            }
+            section.table(table);
+            if exports.funcs.len() > options.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // LIBRARIES
+        //
+        if options.show(Part::Imports) && !self.imports.is_empty() {
+            let mut section = Section::with_count("Libraries", self.imports.len());
+            for lib in &self.imports {
+                section.line(paint(Color::Blue, lib.name.as_str()));
+            }
+            doc.section(section);
+        }
+
+        Ok(doc)
+    }
+
+}
+
+pub fn pe_title(header: &COFF_header) -> Text {
+
+    let mut title = text!["PE "];
+
+    if is_dll(header.characteristics) {
+        title.push(paint(Color::Blue, "DLL")).push(" ");
+    }
+    if is_exe(header.characteristics) {
+        title.push(paint(Color::Red, "EXE")).push(" ");
+    }
+
+    title.push(paint(Color::White, machine_to_str(header.machine)));
+    title
+}
+
+pub fn pe_header(header: &COFF_header) -> Section {
+
+    let mut section = Section::new("Header");
+    section
+        .field("Number of sections", paint(Color::Purple, header.n_of_sections.to_string()))
+        .field("Timedate stamp", format!("{:#X}", header.timedate_stamp))
+        .field("Pointer to symbol table", format!("{:#X}", header.pointer_to_symtab))
+        .field("Number of symbols", paint(Color::Purple, header.n_of_symtab.to_string()))
+        .field("Size of optional header", paint(Color::Green, header.sz_of_opt_header.to_string()));
+    section
+}
@@ -0,0 +1,489 @@
+use bininfo::Problem;
+use bininfo::formats::png::*;
+use failure::Error;
+
+use crate::Opt;
+use crate::render::{Document, Section, Table, paint};
+use super::Describe;
+
+impl Describe for Png {
+
+    fn describe(&self, opt: &Opt) -> Result<Document, Error> {
+        use ansi_term::Color;
+
+        //
+        // PNG file
+        //
+        let mut doc = Document::new(format!("PNG width: {}px height: {}px",
+                                            self.ihdr.width,
+                                            self.ihdr.height));
+
+        //
+        // IHDR
+        //
+        let mut section = png_chunk("IHDR", &self.ihdr.prefix, &self.ihdr.postfix);
+        section
+            .field("Image width", self.ihdr.width)
+            .field("Image height", self.ihdr.height)
+            .field("Bits per pixel", self.ihdr.bpp)
+            .field("Color type", self.ihdr.color)
+            .field("Compression method", self.ihdr.compression)
+            .field("Filter method", self.ihdr.filter)
+            .field("Interlace method", self.ihdr.interlace);
+        doc.section(section);
+
+        //
+        // PLTE
+        //
+        if let Some(plte) = &self.plte {
+            let mut section = png_chunk("PLTE", &plte.prefix, &plte.postfix);
+            let mut table = Table::new(&["Idx", "Red", "Green", "Blue"]).align_right(&[0, 1, 2, 3]);
+            for (i, rgb) in plte.rgb.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![i,
+                                 paint(Color::Red, format!("{:#04X}", rgb.red)),
+                                 paint(Color::Green, format!("{:#04X}", rgb.green)),
+                                 paint(Color::Blue, format!("{:#04X}", rgb.blue))]);
+            }
+            section.table(table);
+            if plte.rgb.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // bKGD
+        //
+        if let Some(background) = &self.bkgd {
+            let mut section = png_chunk("bKGD", &background.prefix, &background.postfix);
+            match &background.color {
+                bKGD::bKGD3(bkgd)  => {
+                    section.field("Palette index", bkgd.index);
+                },
+                bKGD::bKGD04(bkgd) => {
+                    section.field("Gray", format!("{:#06X}", bkgd.gray));
+                },
+                bKGD::bKGD26(bkgd) => {
+                    section
+                        .field("Red", paint(Color::Red, format!("{:#06X}", bkgd.red)))
+                        .field("Green", paint(Color::Green, format!("{:#06X}", bkgd.green)))
+                        .field("Blue", paint(Color::Blue, format!("{:#06X}", bkgd.blue)));
+                },
+            }
+            doc.section(section);
+        }
+
+        //
+        // cHRM
+        //
+        if let Some(chrm) = &self.chrm {
+            let mut section = png_chunk("cHRM", &chrm.prefix, &chrm.postfix);
+            let mut table = Table::new(&["Color", "X", "Y", "Point x", "Point y"]).align_right(&[0, 1, 2, 3, 4]);
+            let points = [
+                (paint(Color::White, "White"), chrm.white_x, chrm.white_y),
+                (paint(Color::Red, "Red"), chrm.red_x, chrm.red_y),
+                (paint(Color::Green, "Green"), chrm.green_x, chrm.green_y),
+                (paint(Color::Blue, "Blue"), chrm.blue_x, chrm.blue_y),
+            ];
+            for (color, x, y) in points.iter() {
+                table.row(cells![color.clone(),
+                                 *x, *y,
+                                 format!("{:.5}", *x as f32 / 100000.0),
+                                 format!("{:.5}", *y as f32 / 100000.0)]);
+            }
+            section.table(table);
+            doc.section(section);
+        }
+
+        //
+        // gAMA
+        //
+        if let Some(gama) = &self.gama {
+            let mut section = png_chunk("gAMA", &gama.prefix, &gama.postfix);
+            section.field("Gamma", format!("{} ({:.5})",
+                                           gama.gamma,
+                                           gama.gamma as f32 / 100000.0));
+            doc.section(section);
+        }
+
+        //
+        // hIST
+        //
+        if let Some(hist) = &self.hist {
+            let mut section = png_chunk("hIST", &hist.prefix, &hist.postfix);
+            let mut table = Table::new(&["Idx", "Frequency"]).align_right(&[0, 1]);
+            for (i, entry) in hist.entries.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![i,
+                                 paint(Color::Blue, format!("{:.8}", (*entry as f64 / hist.sum as f64)))]);
+            }
+            section.table(table);
+            if hist.entries.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // pHYs
+        //
+        if let Some(phys) = &self.phys {
+            let mut section = png_chunk("pHYs", &phys.prefix, &phys.postfix);
+            let unit = {
+                if phys.unit == 0 {
+                    "unknown unit"
+                }
+                else if phys.unit == 1{
+                    "meter"
+                }
+                else {
+                    return Err(Error::from(Problem::msg("pHYs: Invalid unit specifier".to_string())));
+                }
+            };
+            section
+                .field(&format!("Pixels per {}, X axis", unit), phys.ppu_x)
+                .field(&format!("Pixels per {}, Y axis", unit), phys.ppu_y);
+            doc.section(section);
+        }
+
+        //
+        // sBIT
+        //
+        if let Some(sbit) = &self.sbit {
+            let mut section = png_chunk("sBIT", &sbit.prefix, &sbit.postfix);
+            match &sbit.sbit {
+                sBIT::sBIT0(inner_sbit) => {
+                    section.field("Significant bits", inner_sbit.sig);
+                },
+                sBIT::sBIT2(inner_sbit) => {
+                    section
+                        .field("Significant bits red", paint(Color::Red, inner_sbit.rgb.red.to_string()))
+                        .field("Significant bits green", paint(Color::Green, inner_sbit.rgb.green.to_string()))
+                        .field("Significant bits blue", paint(Color::Blue, inner_sbit.rgb.blue.to_string()));
+                },
+                sBIT::sBIT3(inner_sbit) => {
+                    section
+                        .field("Significant bits red", paint(Color::Red, inner_sbit.rgb.red.to_string()))
+                        .field("Significant bits green", paint(Color::Green, inner_sbit.rgb.green.to_string()))
+                        .field("Significant bits blue", paint(Color::Blue, inner_sbit.rgb.blue.to_string()));
+                },
+                sBIT::sBIT4(inner_sbit) => {
+                    section
+                        .field("Significant bits", inner_sbit.sig)
+                        .field("Significant bits alpha", paint(Color::White, inner_sbit.alpha.to_string()));
+                },
+                sBIT::sBIT6(inner_sbit) => {
+                    section
+                        .field("Significant bits red", paint(Color::Red, inner_sbit.rgb.red.to_string()))
+                        .field("Significant bits green", paint(Color::Green, inner_sbit.rgb.green.to_string()))
+                        .field("Significant bits blue", paint(Color::Blue, inner_sbit.rgb.blue.to_string()))
+                        .field("Significant bits alpha", paint(Color::White, inner_sbit.alpha.to_string()));
+                },
+            }
+            doc.section(section);
+        }
+
+        //
+        // tEXt
+        //
+        for chunk in &self.text {
+            let mut section = png_chunk("tEXt", &chunk.prefix, &chunk.postfix);
+            section.line(text![paint(Color::Blue, chunk.keyword.as_str()), ": ", chunk.text.as_str()]);
+            doc.section(section);
+        }
+
+        //
+        // tIME
+        //
+        if let Some(time) = &self.time {
+            let mut section = png_chunk("tIME", &time.prefix, &time.postfix);
+            section.field("Date", format!("{}.{}.{} {}:{}:{}",
+                                          time.day,
+                                          time.month,
+                                          time.year,
+                                          time.hour,
+                                          time.minute,
+                                          time.second));
+            doc.section(section);
+        }
+
+        //
+        // tRNS
+        //
+        if let Some(trns) = &self.trns {
+            let mut section = png_chunk("tRNS", &trns.prefix, &trns.postfix);
+            match &trns.data {
+                tRNS::tRNS0(data) => {
+                    section.field("Gray", format!("{:#06X}", data.gray));
+                },
+                tRNS::tRNS2(data) => {
+                    section
+                        .field("Red", paint(Color::Red, format!("{:#06X}", data.rgb.red)))
+                        .field("Green", paint(Color::Green, format!("{:#06X}", data.rgb.green)))
+                        .field("Blue", paint(Color::Blue, format!("{:#06X}", data.rgb.blue)));
+                },
+                tRNS::tRNS3(data) => {
+                    let mut table = Table::new(&["Idx", "Transparency"]).align_right(&[0, 1]);
+                    for (i, entry) in data.alpha.iter().enumerate().take(opt.trim_lines) {
+                        table.row(cells![i, paint(Color::White, format!("{:#04X}", entry))]);
+                    }
+                    section.table(table);
+                    if data.alpha.len() > opt.trim_lines {
+                        section.trimmed();
+                    }
+                },
+            }
+            doc.section(section);
+        }
+
+        //
+        // zTXt
+        //
+        for chunk in &self.ztxt {
+            let mut section = png_chunk("zTXt", &chunk.prefix, &chunk.postfix);
+            section
+                .field("Compression method", chunk.comp_method)
+                .line(text![paint(Color::Blue, chunk.keyword.as_str()), ":"])
+                .hex(&chunk.comp_text);
+            doc.section(section);
+        }
+
+        //
+        // sRGB
+        //
+        if let Some(srgb) = &self.srgb {
+            let mut section = png_chunk("sRGB", &srgb.prefix, &srgb.postfix);
+            let intent = match srgb.rendering {
+                0 => "Perceptual",
+                1 => "Relative colorimetric",
+                2 => "Saturation",
+                3 => "Absolute colorimetric",
+                _ => return Err(Error::from(Problem::msg("Invalid rendering intent".to_string()))),
+            };
+            section.field("Rendering intent", format!("{} {}", srgb.rendering, intent));
+            doc.section(section);
+        }
+
+        //
+        // iCCP
+        //
+        if let Some(iccp) = &self.iccp {
+            let mut section = png_chunk("iCCP", &iccp.prefix, &iccp.postfix);
+            section
+                .field("Compression method", iccp.comp_method)
+                .line(format!("{}:", iccp.profile))
+                .hex(&iccp.comp_profile);
+            doc.section(section);
+        }
+
+        //
+        // iTXt
+        //
+        for chunk in &self.itxt {
+            let mut section = png_chunk("iTXt", &chunk.prefix, &chunk.postfix);
+            section
+                .field("Compression flag", chunk.comp_flag)
+                .field("Lang", paint(Color::Green, chunk.lang_tag.as_str()))
+                .field("Keyword", paint(Color::Blue, chunk.keyword.as_str()))
+                .field("Translated keyword", paint(Color::Purple, chunk.trans_keyword.as_str()));
+            if chunk.comp_flag == 1 {
+                section
+                    .field("Compression method", chunk.comp_method)
+                    .line(text![paint(Color::Blue, chunk.keyword.as_str()), ":"])
+                    .hex(&chunk.comp_text);
+            }
+            else {
+                section.field("Text", std::str::from_utf8(&chunk.comp_text)?);
+            }
+            doc.section(section);
+        }
+
+        //
+        // sPLT
+        //
+        if !self.splt.is_empty() {
+            let mut splts = Section::with_count("sPLT", self.splt.len());
+            for chunk in self.splt.iter().take(opt.trim_lines) {
+                let mut section = png_chunk("sPLT", &chunk.prefix, &chunk.postfix);
+                section.field("Name", paint(Color::Blue, chunk.name.as_str()));
+
+                let mut table = Table::new(&["Idx", "Depth", "Red", "Green", "Blue", "Alpha", "Freq"])
+                    .align_right(&[0, 1, 2, 3, 4, 5, 6]);
+                for (j, palette) in chunk.plt.iter().enumerate().take(opt.trim_lines) {
+                    let (red, green, blue, alpha, freq) = match palette {
+                        sPLT::sPLT8(plt)  => (plt.rgb.red as u16, plt.rgb.green as u16, plt.rgb.blue as u16,
+                                              plt.alpha as u16, plt.freq),
+                        sPLT::sPLT16(plt) => (plt.rgb.red, plt.rgb.green, plt.rgb.blue,
+                                              plt.alpha, plt.freq),
+                    };
+                    table.row(cells![j,
+                                     chunk.depth,
+                                     paint(Color::Red, red.to_string()),
+                                     paint(Color::Green, green.to_string()),
+                                     paint(Color::Blue, blue.to_string()),
+                                     paint(Color::White, alpha.to_string()),
+                                     freq]);
+                }
+                section.table(table);
+                if chunk.plt.len() > opt.trim_lines {
+                    section.trimmed();
+                }
+                splts.section(section);
+            }
+            if self.splt.len() > opt.trim_lines {
+                splts.trimmed();
+            }
+            doc.section(splts);
+        }
+
+        //
+        // oFFs
+        //
+        if let Some(offs) = &self.offs {
+            let mut section = png_chunk("oFFs", &offs.prefix, &offs.postfix);
+            section
+                .field("X", offs.x)
+                .field("Y", offs.y)
+                .field("Unit specifier", offs.unit);
+            doc.section(section);
+        }
+
+        //
+        // pCAL
+        //
+        if let Some(pcal) = &self.pcal {
+            let mut section = png_chunk("pCAL", &pcal.prefix, &pcal.postfix);
+            section
+                .field("Name", pcal.name.as_str())
+                .field("Original zero", pcal.org_zero)
+                .field("Original max", pcal.org_max)
+                .field("Equation type", pcal.equation)
+                .field("Unit name", pcal.unit_name.as_str())
+                .field("Number of parameters", pcal.parameters_count);
+            for param in &pcal.parameters {
+                section.line(param.as_str());
+            }
+            doc.section(section);
+        }
+
+        //
+        // sCAL
+        //
+        if let Some(scal) = &self.scal {
+            let mut section = png_chunk("sCAL", &scal.prefix, &scal.postfix);
+            section
+                .field("Unit specifier", scal.unit)
+                .field("Pixel width", scal.pixel_width.as_str())
+                .field("Pixel height", scal.pixel_height.as_str());
+            doc.section(section);
+        }
+
+        //
+        // gIFg
+        //
+        if !self.gifg.is_empty() {
+            let mut gifgs = Section::with_count("gIFg", self.gifg.len());
+            for chunk in &self.gifg {
+                let mut section = png_chunk("gIFg", &chunk.prefix, &chunk.postfix);
+                section
+                    .field("Disposal method", chunk.disposal)
+                    .field("User input flag", chunk.user_input)
+                    .field("Delay time", chunk.delay);
+                gifgs.section(section);
+            }
+            doc.section(gifgs);
+        }
+
+        //
+        // gIFx
+        //
+        if !self.gifx.is_empty() {
+            let mut gifxs = Section::with_count("gIFx", self.gifx.len());
+            for chunk in &self.gifx {
+                let mut section = png_chunk("gIFx", &chunk.prefix, &chunk.postfix);
+                section
+                    .field("Application identifier", String::from_utf8_lossy(&chunk.app_id).into_owned())
+                    .field("Authentication code", format!("{:02X?}", chunk.app_code))
+                    .line("Application data:")
+                    .hex(&chunk.app_data);
+                gifxs.section(section);
+            }
+            doc.section(gifxs);
+        }
+
+        //
+        // sTER
+        //
+        if let Some(ster) = &self.ster {
+            let mut section = png_chunk("sTER", &ster.prefix, &ster.postfix);
+            let layout = match ster.mode {
+                0 => "cross-fuse layout",
+                1 => "diverging-fuse layout",
+                _ => return Err(Error::from(Problem::msg(format!("Invalid mode {} for sTER chunk", ster.mode)))),
+            };
+            section.field("Mode", format!("{}: {}", ster.mode, layout));
+            doc.section(section);
+        }
+
+        //
+        // IDAT
+        //
+        {
+            let mut section = Section::with_count("IDAT", self.idat.len());
+            section
+                .field("Compression method", paint(Color::Purple, (self.zlib.cmf << 4 >> 4).to_string()))
+                .field("Compression info", paint(Color::Cyan, (self.zlib.cmf >> 4).to_string()))
+                .field("Checksum", paint(Color::Green, format!("{:#07b}", self.zlib.flg << 3 >> 3)))
+                .field("Dict", paint(Color::Red, format!("{:#03b}", self.zlib.flg << 2 >> 7)))
+                .field("Compression level", paint(Color::Yellow, format!("{:#04b}", self.zlib.flg >> 6)))
+                .field("Adler", paint(Color::Fixed(221), format!("{:#010X}", self.zlib.adler)));
+
+            let mut table = Table::new(&["Idx", "Size", "Checksum"]).align_right(&[0, 1, 2]);
+            for (i, idat) in self.idat.iter().enumerate().take(opt.trim_lines) {
+                table.row(cells![i,
+                                 paint(Color::Yellow, idat.prefix.size.to_string()),
+                                 paint(Color::Green, format!("{:#010X}", idat.postfix.checksum))]);
+            }
+            section.table(table);
+            if self.idat.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // Chunks not decoded
+        //
+        if !self.unknown_chunks.is_empty() {
+            let mut section = Section::with_count("Unsupported chunks", self.unknown_chunks.len());
+            for chunk in self.unknown_chunks.iter().take(opt.trim_lines) {
+                section.line(paint(Color::Red, chunk.as_str()));
+            }
+            if self.unknown_chunks.len() > opt.trim_lines {
+                section.trimmed();
+            }
+            doc.section(section);
+        }
+
+        //
+        // IEND
+        //
+        if let Some(iend) = &self.iend {
+            doc.section(png_chunk("IEND", &iend.prefix, &iend.postfix));
+        }
+
+        Ok(doc)
+
+    }
+
+}
+
+/// Section of one chunk, titled with its size and checksum.
+pub fn png_chunk(name: &'static str, prefix: &Prefix, postfix: &Postfix) -> Section {
+    use ansi_term::Color;
+
+    Section::new(text![name,
+                       " ",
+                       paint(Color::Yellow, format!("Size: {}", prefix.size)),
+                       " ",
+                       paint(Color::Green, format!("Checksum: {:#010X}", postfix.checksum))])
+}
@@ -0,0 +1,442 @@
+//! Unix `ar` archives: static libraries and Debian packages.
+//!
+//! Members start with a 60 byte header of space padded text fields. GNU ar
+//! keeps names longer than 15 bytes in a `//` member and refers to them as
+//! `/offset`, BSD ar stores them right after the header as `#1/length`.
+//! The symbol index, `/` or `/SYM64/` for GNU ar and `__.SYMDEF` for BSD ar,
+//! maps the symbols defined by the members to their header offsets.
+#![allow(non_camel_case_types)]
+use failure::{Error};
+use scroll::{self, Pread};
+
+use crate::{Binary, Problem, ResultExt};
+use crate::magic;
+use super::{Recovery, bytes};
+
+pub const AR_MAGIC:      &[u8; AR_MAGIC_SIZE] = b"!<arch>\n";
+/// Archive whose members are files of their own, only named in it
+pub const AR_THIN_MAGIC: &[u8; AR_MAGIC_SIZE] = b"!<thin>\n";
+pub const AR_MAGIC_SIZE: usize = 8;
+
+pub const AR_HEADER_SIZE: u64 = 60;
+/// Ends every member header
+pub const AR_FMAG: &[u8; 2] = b"`\n";
+
+/// First member of a Debian package, holding its format version
+pub const DEB_VERSION_MEMBER: &str = "debian-binary";
+
+/// Flavour of the archive, from how it names members.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum ArVariant {
+    /// GNU and System V ar, also used for Windows import libraries
+    Gnu,
+    /// BSD and macOS ar
+    Bsd,
+}
+
+/// Member the symbol index was read from.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum SymbolIndex {
+    /// `/`, with 32-bit big endian offsets
+    Gnu,
+    /// `/SYM64/`, with 64-bit big endian offsets
+    Gnu64,
+    /// `__.SYMDEF`, with 32-bit little endian offsets
+    Bsd,
+    /// `__.SYMDEF_64`, with 64-bit little endian offsets
+    Bsd64,
+}
+
+impl SymbolIndex {
+    pub fn name(self) -> &'static str {
+        match self {
+            SymbolIndex::Gnu   => "/",
+            SymbolIndex::Gnu64 => "/SYM64/",
+            SymbolIndex::Bsd   => "__.SYMDEF",
+            SymbolIndex::Bsd64 => "__.SYMDEF_64",
+        }
+    }
+}
+
+/// Member of the archive, without the symbol index and long name table.
+#[derive(Serialize)]
+pub struct Ar_member {
+    /// File offset of the header
+    pub offset:      u64,
+    pub name:        String,
+    /// Modification time in seconds since the epoch, 0 for deterministic archives
+    pub date:        u64,
+    pub uid:         u32,
+    pub gid:         u32,
+    pub mode:        u32,
+    /// File offset of the contents, after the name of BSD members
+    pub data_offset: u64,
+    /// Size of the contents. Members of thin archives are not stored in it.
+    pub size:        u64,
+    /// Contents parsed by [`Ar::parse_members`], when they were
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary:      Option<Binary>,
+}
+
+/// Entry of the symbol index.
+#[derive(Serialize, Debug)]
+pub struct Ar_symbol {
+    pub name:   String,
+    /// File offset of the header of the member defining the symbol
+    pub offset: u64,
+    /// Index of that member in [`Ar::members`], if there is one there
+    pub member: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct Ar {
+    pub variant:      ArVariant,
+    pub thin:         bool,
+    pub members:      Vec<Ar_member>,
+    pub symbol_index: Option<SymbolIndex>,
+    pub symbols:      Vec<Ar_symbol>,
+    /// Format version of a Debian package, like "2.0"
+    pub deb_version:  Option<String>,
+}
+
+/// Member header with its name not yet resolved.
+struct Header<'a> {
+    name: &'a str,
+    date: u64,
+    uid:  u32,
+    gid:  u32,
+    mode: u32,
+    size: u64,
+}
+
+/// Number in a space padded header field, 0 when blank.
+fn field(buf: &[u8], offset: u64, start: usize, len: usize, radix: u32, what: &str) -> Result<u64, Error> {
+    let text = std::str::from_utf8(&buf[offset as usize + start..offset as usize + start + len])
+        .at(offset + start as u64, what)?
+        .trim_end_matches(' ');
+    if text.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(text, radix)
+        .map_err(|_| Error::from(Problem::msg(format!("Invalid {} {:?}", what, text)).at(offset + start as u64)))
+}
+
+fn read_header(buf: &[u8], offset: u64) -> Result<Header<'_>, Error> {
+    let header = bytes(buf, offset, AR_HEADER_SIZE, "member header")?;
+    if &header[58..] != AR_FMAG {
+        return Err(Error::from(Problem::msg(format!("Invalid member header end {:?}", String::from_utf8_lossy(&header[58..])))
+                               .at(offset + 58)));
+    }
+    Ok(Header {
+        name: std::str::from_utf8(&header[..16]).at(offset, "member name")?.trim_end_matches(' '),
+        date: field(buf, offset, 16, 12, 10, "member date")?,
+        uid:  field(buf, offset, 28, 6, 10, "member uid")? as u32,
+        gid:  field(buf, offset, 34, 6, 10, "member gid")? as u32,
+        mode: field(buf, offset, 40, 8, 8, "member mode")? as u32,
+        size: field(buf, offset, 48, 10, 10, "member size")?,
+    })
+}
+
+/// Name at `offset` of the GNU long name table, ended by "/\n".
+fn long_name(table: &[u8], offset: usize) -> Option<String> {
+    let name = table.get(offset..)?.split(|b| *b == b'\n').next()?;
+    Some(String::from_utf8_lossy(name.strip_suffix(b"/").unwrap_or(name)).into_owned())
+}
+
+/// Symbols of the index at `offset`, `size` bytes long, still to be matched
+/// to members.
+fn read_symbol_index(buf: &[u8], rec: &mut Recovery, kind: SymbolIndex, offset: u64, size: u64) -> Result<Vec<Ar_symbol>, Error> {
+    let data = bytes(buf, offset, size, "symbol index")?;
+    // Entries are read at file offsets, but may not run past the index
+    let buf = &buf[..(offset + size) as usize];
+    let (offsets, strings) = match kind {
+        SymbolIndex::Gnu | SymbolIndex::Gnu64 => {
+            // Count, member offsets, then null terminated names in the same order
+            let width = if kind == SymbolIndex::Gnu { 4 } else { 8 };
+            let count = if width == 4 {
+                data.pread_with::<u32>(0, scroll::BE).at(offset, "symbol count")? as u64
+            } else {
+                data.pread_with::<u64>(0, scroll::BE).at(offset, "symbol count")?
+            };
+            let offsets = rec.table(buf, offset + width, count.saturating_mul(width), width, "symbol index entry", |i| if width == 4 {
+                buf.pread_with::<u32>(i, scroll::BE).map(u64::from)
+            } else {
+                buf.pread_with::<u64>(i, scroll::BE)
+            })?;
+            let start = std::cmp::min(width + offsets.len() as u64 * width, size) as usize;
+            let mut names = data[start..].split(|b| *b == 0);
+            return Ok(offsets.into_iter()
+                .map(|offset| Ar_symbol { name: String::from_utf8_lossy(names.next().unwrap_or(&[])).into_owned(), offset, member: None })
+                .collect());
+        },
+        SymbolIndex::Bsd => {
+            // Size of the entries, (name, member offset) entries, size of the names, names
+            let entries = data.pread_with::<u32>(0, scroll::LE).at(offset, "symbol index size")? as u64;
+            let offsets = rec.table(buf, offset + 4, entries, 8, "symbol index entry",
+                                    |i| Ok((buf.pread_with::<u32>(i, scroll::LE)? as u64, buf.pread_with::<u32>(i + 4, scroll::LE)? as u64)))?;
+            let strings = 4 + entries + 4;
+            (offsets, strings)
+        },
+        SymbolIndex::Bsd64 => {
+            let entries = data.pread_with::<u64>(0, scroll::LE).at(offset, "symbol index size")?;
+            let offsets = rec.table(buf, offset + 8, entries, 16, "symbol index entry",
+                                    |i| Ok((buf.pread_with::<u64>(i, scroll::LE)?, buf.pread_with::<u64>(i + 8, scroll::LE)?)))?;
+            let strings = 8u64.saturating_add(entries).saturating_add(8);
+            (offsets, strings)
+        },
+    };
+    let strings = data.get(std::cmp::min(strings, size) as usize..).unwrap_or(&[]);
+    Ok(offsets.into_iter()
+        .map(|(strx, offset)| {
+            let name = strings.get(strx as usize..).and_then(|name| name.split(|b| *b == 0).next()).unwrap_or(&[]);
+            Ar_symbol { name: String::from_utf8_lossy(name).into_owned(), offset, member: None }
+        })
+        .collect())
+}
+
+impl Ar {
+
+    /// Whether the archive is a Debian package.
+    pub fn is_deb(&self) -> bool {
+        self.deb_version.is_some()
+    }
+
+    /// Contents of `member` in the file in `buf`, None for members of thin archives.
+    pub fn member_data<'a>(&self, buf: &'a [u8], member: &Ar_member) -> Option<&'a [u8]> {
+        if self.thin {
+            return None;
+        }
+        bytes(buf, member.data_offset, member.size, "member contents").ok()
+    }
+
+    /// Parses the contents of every member as its format, like
+    /// [`Binary::parse_with`], keeping the result in [`Ar_member::binary`].
+    /// Offsets of errors and warnings are made relative to the archive.
+    /// Members of thin archives are elsewhere, and only get a warning.
+    pub fn parse_members(&mut self, buf: &[u8], rec: &mut Recovery) -> Result<(), Error> {
+        if self.thin {
+            return rec.warn(Error::from(Problem::msg("Members of a thin archive are files of their own, and were not parsed")
+                                        .at(0)));
+        }
+        for member in &mut self.members {
+            let data = bytes(buf, member.data_offset, member.size, "member contents")?;
+            let format = magic::detect(data);
+            let first = rec.warnings.len();
+            let shift = member.data_offset;
+            let binary = rec.within(format!("archive member {}", member.name), |rec| Binary::parse_as(data, format, rec))
+                .map_err(|err| {
+                    let mut problem = Problem::from(err);
+                    problem.offset = problem.offset.map(|offset| offset + shift);
+                    Error::from(problem)
+                });
+            for warning in &mut rec.warnings[first..] {
+                warning.offset = warning.offset.map(|offset| offset + shift);
+            }
+            member.binary = rec.recover(binary.map(Some), None)?;
+        }
+        Ok(())
+    }
+
+}
+
+impl super::FileFormat for Ar {
+    type Item = Self;
+
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
+        Ar::parse_with(buf, &mut Recovery::strict())
+    }
+
+    fn parse_with(buf: &[u8], rec: &mut Recovery) -> Result<Self, Error> {
+
+        let thin = match buf.get(..AR_MAGIC_SIZE) {
+            Some(magic) if magic == AR_MAGIC => false,
+            Some(magic) if magic == AR_THIN_MAGIC => true,
+            _ => return Err(Error::from(Problem::msg("Invalid ar magic").at(0))),
+        };
+
+        let mut variant = ArVariant::Gnu;
+        let mut members = Vec::new();
+        let mut long_names: &[u8] = &[];
+        let mut index = None;
+        let mut offset = AR_MAGIC_SIZE as u64;
+
+        while offset < buf.len() as u64 {
+            let start = offset;
+            let what = format!("archive member at {:#X}", offset);
+            let header = match rec.recover(read_header(buf, offset).within(&what).map(Some), None)? {
+                Some(header) => header,
+                None => break,
+            };
+            let special = header.name.starts_with('/') && !header.name[1..].starts_with(|c: char| c.is_ascii_digit());
+            // Thin archives only hold the symbol index and long names
+            let stored = if thin && !special { 0 } else { header.size };
+            let mut data_offset = offset + AR_HEADER_SIZE;
+            let mut size = header.size;
+            if let Err(err) = bytes(buf, data_offset, stored, "member contents").within(&what) {
+                rec.warn(err)?;
+                break;
+            }
+
+            let name = match header.name {
+                "/" if index.is_none() => {
+                    index = Some((SymbolIndex::Gnu, data_offset, size));
+                    None
+                },
+                "/SYM64/" => {
+                    index = Some((SymbolIndex::Gnu64, data_offset, size));
+                    None
+                },
+                "//" => {
+                    long_names = &buf[data_offset as usize..(data_offset + size) as usize];
+                    None
+                },
+                // Second linker member of Windows import libraries, and the like
+                _ if special => None,
+                name if name.starts_with('/') => {
+                    let resolved = name[1..].parse().ok().and_then(|strx| long_name(long_names, strx));
+                    if resolved.is_none() {
+                        rec.warn(Error::from(Problem::msg(format!("Invalid long name {:?}", name)).at(offset).within(&what)))?;
+                    }
+                    Some(resolved.unwrap_or_else(|| name.to_string()))
+                },
+                name if name.starts_with("#1/") => {
+                    variant = ArVariant::Bsd;
+                    let len = name[3..].parse::<u64>().ok().filter(|len| *len <= stored);
+                    match len {
+                        Some(len) => {
+                            let bsd_name = &buf[data_offset as usize..(data_offset + len) as usize];
+                            data_offset += len;
+                            size -= len;
+                            Some(String::from_utf8_lossy(bsd_name.split(|b| *b == 0).next().unwrap_or(&[])).into_owned())
+                        },
+                        None => {
+                            rec.warn(Error::from(Problem::msg(format!("Invalid long name {:?}", name)).at(offset).within(&what)))?;
+                            Some(name.to_string())
+                        },
+                    }
+                },
+                name => Some(name.strip_suffix('/').unwrap_or(name).to_string()),
+            };
+
+            let next = offset + AR_HEADER_SIZE + stored;
+            // Members are aligned to 2 bytes
+            offset = next + (next & 1);
+
+            let name = match name {
+                Some(name) => name,
+                None => continue,
+            };
+            match name.as_str() {
+                "__.SYMDEF" | "__.SYMDEF SORTED" => {
+                    variant = ArVariant::Bsd;
+                    index = Some((SymbolIndex::Bsd, data_offset, size));
+                    continue;
+                },
+                "__.SYMDEF_64" | "__.SYMDEF_64 SORTED" => {
+                    variant = ArVariant::Bsd;
+                    index = Some((SymbolIndex::Bsd64, data_offset, size));
+                    continue;
+                },
+                _ => {},
+            }
+            members.push(Ar_member {
+                offset: start,
+                name,
+                date: header.date,
+                uid: header.uid,
+                gid: header.gid,
+                mode: header.mode,
+                data_offset,
+                size,
+                binary: None,
+            });
+        }
+
+        let mut symbols = match index {
+            Some((kind, offset, size)) => {
+                let read = rec.within(kind.name(), |rec| read_symbol_index(buf, rec, kind, offset, size));
+                rec.recover(read, Vec::new())?
+            },
+            None => Vec::new(),
+        };
+        // Members are in file order
+        for symbol in &mut symbols {
+            symbol.member = members.binary_search_by_key(&symbol.offset, |member: &Ar_member| member.offset).ok();
+        }
+        let orphans = symbols.iter().filter(|symbol| symbol.member.is_none()).count();
+        if let (Some((kind, offset, _)), Some(first)) = (index, symbols.iter().find(|symbol| symbol.member.is_none())) {
+            rec.warn(Error::from(Problem::msg(format!("{} symbols point to no member, first is {} at {:#X}", orphans, first.name, first.offset))
+                                 .at(offset)
+                                 .within(kind.name())))?;
+        }
+
+        let deb_version = match members.first() {
+            Some(member) if !thin && member.name == DEB_VERSION_MEMBER => {
+                let data = &buf[member.data_offset as usize..(member.data_offset + member.size) as usize];
+                Some(String::from_utf8_lossy(data).trim_end().to_string())
+            },
+            _ => None,
+        };
+
+        Ok(Ar {
+            variant,
+            thin,
+            members,
+            symbol_index: index.map(|(kind, _, _)| kind),
+            symbols,
+            deb_version,
+        })
+
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FileFormat;
+
+    fn member(archive: &mut Vec<u8>, name: &str, data: &[u8]) {
+        archive.extend_from_slice(format!("{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", name, 0, 0, 0, 644, data.len()).as_bytes());
+        archive.extend_from_slice(data);
+        if archive.len() % 2 == 1 {
+            archive.push(b'\n');
+        }
+    }
+
+    #[test]
+    fn sym64_index_and_long_names() {
+        let long_names = b"a_rather_long_object_name.o/\n";
+        // Index, long names and the first member take 0x5E, 0x5A and 0x3E bytes with their headers
+        let first = (AR_MAGIC_SIZE + 0x5E + 0x5A) as u64;
+        let second = first + 0x3E;
+        let mut index = Vec::new();
+        index.extend_from_slice(&2u64.to_be_bytes());
+        index.extend_from_slice(&first.to_be_bytes());
+        index.extend_from_slice(&second.to_be_bytes());
+        index.extend_from_slice(b"one\0two\0\0\0");
+
+        let mut buf = AR_MAGIC.to_vec();
+        member(&mut buf, "/SYM64/", &index);
+        member(&mut buf, "//", long_names);
+        member(&mut buf, "short.o/", b"1");
+        member(&mut buf, "/0", b"22");
+        assert!(buf[first as usize..].starts_with(b"short.o/") && buf[second as usize..].starts_with(b"/0 "));
+
+        let ar = Ar::parse(&buf).unwrap();
+        assert_eq!((ar.variant, ar.symbol_index), (ArVariant::Gnu, Some(SymbolIndex::Gnu64)));
+        let names: Vec<&str> = ar.members.iter().map(|member| member.name.as_str()).collect();
+        assert_eq!(names, ["short.o", "a_rather_long_object_name.o"]);
+        assert_eq!(ar.member_data(&buf, &ar.members[1]), Some(&b"22"[..]));
+        let symbols: Vec<(&str, Option<usize>)> = ar.symbols.iter().map(|symbol| (symbol.name.as_str(), symbol.member)).collect();
+        assert_eq!(symbols, [("one", Some(0)), ("two", Some(1))]);
+
+        // A long name out of the table only fails strict parsing
+        let mut buf = AR_MAGIC.to_vec();
+        member(&mut buf, "/40", b"");
+        assert!(Ar::parse(&buf).is_err());
+        let mut rec = Recovery::lenient();
+        assert_eq!(Ar::parse_with(&buf, &mut rec).unwrap().members[0].name, "/40");
+        assert_eq!(rec.warnings.len(), 1);
+    }
+}
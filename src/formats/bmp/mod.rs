@@ -1,14 +1,9 @@
 #![allow(non_camel_case_types, dead_code)]
-use crate::Problem;
-use crate::Opt;
+use crate::ResultExt;
 
 use failure::{Error};
 use scroll::{self, Pread};
 
-use crate::format::{
-    fmt_indent, fmt_indentln,
-};
-
 // BMP in windows
 pub const BMP_MAGIC: &'static [u8; BMP_MAGIC_SIZE] = b"BM";
 pub const BMP_MAGIC_SIZE: usize = 2;
@@ -27,71 +22,83 @@ pub const OS2_BMP_MAGIC_POINTER:       &'static [u8; BMP_MAGIC_SIZE] = b"PT";
 
 const BMP_HEADER_SIZE: usize = 14;
 
-const INFO_V1: usize = 40;
-const INFO_V2: usize = 52;
-const INFO_V3: usize = 56;
-const INFO_V4: usize = 108;
-const INFO_V5: usize = 124;
+pub const INFO_V1: usize = 40;
+pub const INFO_V2: usize = 52;
+pub const INFO_V3: usize = 56;
+pub const INFO_V4: usize = 108;
+pub const INFO_V5: usize = 124;
 
-#[derive(Debug, Pread)]
+/// BITMAPFILEHEADER, the 14 byte header at the start of the file.
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Bmp_header {
-    magic:     u16,
-    file_size: u32,
-    reserved1: u16,
-    reserved2: u16,
-    offset:    u32,
+pub struct Bmp_header {
+    pub magic:     u16,
+    pub file_size: u32,
+    pub reserved1: u16,
+    pub reserved2: u16,
+    pub offset:    u32,
 }
 
-#[derive(Debug, Pread)]
+/// BITMAPINFOHEADER, common to every supported DIB header version.
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Bitmap_info_header {
-    size:         u32,
-    width:        i32,
-    height:       i32,
-    planes:       u16,
-    bpp:          u16,
-    compression:  u32,
-    image_size:   u32,
-    h_resolution: i32,
-    v_resolution: i32,
-    palette:      u32,
-    imp_colors:   u32,
+pub struct Bitmap_info_header {
+    pub size:         u32,
+    pub width:        i32,
+    pub height:       i32,
+    pub planes:       u16,
+    pub bpp:          u16,
+    pub compression:  u32,
+    pub image_size:   u32,
+    pub h_resolution: i32,
+    pub v_resolution: i32,
+    pub palette:      u32,
+    pub imp_colors:   u32,
 }
 
-#[derive(Debug, Pread)]
+/// Channel bitmasks added by BITMAPV2INFOHEADER.
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct RGB_bitmask {
-    red_bitmask:   u32,
-    green_bitmask: u32,
-    blue_bitmask:  u32,
+pub struct RGB_bitmask {
+    pub red_bitmask:   u32,
+    pub green_bitmask: u32,
+    pub blue_bitmask:  u32,
 }
 
-#[derive(Debug, Pread)]
+/// Alpha bitmask added by BITMAPV3INFOHEADER.
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Alpha_bitmask {
-    alpha_bitmask: u32
+pub struct Alpha_bitmask {
+    pub alpha_bitmask: u32
 }
 
-#[derive(Pread)]
+/// Color space endpoints and gamma added by BITMAPV4INFOHEADER.
+#[derive(Serialize, Pread)]
 #[repr(C)]
-struct Color_space_gamma {
-    color_space:   [u8; 36],
-    gamma_red:     u32,
-    gamma_green:   u32,
-    gamma_blue:    u32,
+pub struct Color_space_gamma {
+    #[serde(serialize_with = "serialize_color_space")]
+    pub color_space:   [u8; 36],
+    pub gamma_red:     u32,
+    pub gamma_green:   u32,
+    pub gamma_blue:    u32,
+}
+
+// serde only implements Serialize for arrays up to 32 elements
+fn serialize_color_space<S: serde::Serializer>(color_space: &[u8; 36], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(color_space.iter())
 }
 
-#[derive(Pread)]
+/// ICC profile information added by BITMAPV5INFOHEADER.
+#[derive(Serialize, Pread)]
 #[repr(C)]
-struct ICC_color_prof {
-    intent:        u32,
-    icc_data:      u32,
-    icc_size:      u32,
-    reserved:      u32,
+pub struct ICC_color_prof {
+    pub intent:        u32,
+    pub icc_data:      u32,
+    pub icc_size:      u32,
+    pub reserved:      u32,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(u32)]
 enum Compression_method {
     BI_RGB            = 0,
@@ -106,29 +113,30 @@ enum Compression_method {
     BI_CMYKRLE4       = 13,
 }
 
+/// Parsed BMP image: file header, DIB header and the optional
+/// V2-V5 header extensions.
 #[repr(C)]
+#[derive(Serialize)]
 pub struct Bmp {
-    opt:               Opt,
-
-    bmp_header:        Bmp_header,
-    dib_header:        Bitmap_info_header,
-    rgb_bitmask:       Option<RGB_bitmask>,
-    alpha_bitmask:     Option<Alpha_bitmask>,
-    color_space_gamma: Option<Color_space_gamma>,
-    icc_color:         Option<ICC_color_prof>,
+    pub bmp_header:        Bmp_header,
+    pub dib_header:        Bitmap_info_header,
+    pub rgb_bitmask:       Option<RGB_bitmask>,
+    pub alpha_bitmask:     Option<Alpha_bitmask>,
+    pub color_space_gamma: Option<Color_space_gamma>,
+    pub icc_color:         Option<ICC_color_prof>,
 }
 
 impl super::FileFormat for Bmp {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
 
         let bmp_header = buf.pread_with(0, scroll::LE)
-            .map_err(|e| Problem::Msg(format!("Could not read bmp header: {}", e)))?;
+            .at(0, "BMP header")?;
         let size       = buf.pread_with::<u32>(BMP_HEADER_SIZE, scroll::LE)
-            .map_err(|e| Problem::Msg(format!("Could not read bmp info header size: {}", e)))? as usize;
+            .at(BMP_HEADER_SIZE as u64, "info header size")? as usize;
         let dib_header = buf.pread_with(BMP_HEADER_SIZE, scroll::LE)
-            .map_err(|e| Problem::Msg(format!("Could not read BMPINFOHEADER: {}", e)))?;
+            .at(BMP_HEADER_SIZE as u64, "BITMAPINFOHEADER")?;
 
         let mut rgb_bitmask       = None;
         let mut alpha_bitmask     = None;
@@ -138,27 +146,25 @@ impl super::FileFormat for Bmp {
         if size >= INFO_V2 {
             rgb_bitmask = Some(buf
                 .pread_with::<RGB_bitmask>(BMP_HEADER_SIZE + INFO_V1, scroll::BE)
-                    .map_err(|e| Problem::Msg(format!("Could not read BITMAPV2INFOHEADER: {}", e)))?);
+                    .at((BMP_HEADER_SIZE + INFO_V1) as u64, "BITMAPV2INFOHEADER")?);
         }
         if size >= INFO_V3 {
             alpha_bitmask = Some(buf
                 .pread_with::<Alpha_bitmask>(BMP_HEADER_SIZE + INFO_V2, scroll::BE)
-                    .map_err(|e| Problem::Msg(format!("Could not read BITMAPV3INFOHEADER: {}", e)))?);
+                    .at((BMP_HEADER_SIZE + INFO_V2) as u64, "BITMAPV3INFOHEADER")?);
         }
         if size >= INFO_V4 {
             color_space_gamma = Some(buf
                 .pread_with::<Color_space_gamma>(BMP_HEADER_SIZE + INFO_V3, scroll::LE)
-                    .map_err(|e| Problem::Msg(format!("Could not read BITMAPV4INFOHEADER: {}", e)))?);
+                    .at((BMP_HEADER_SIZE + INFO_V3) as u64, "BITMAPV4INFOHEADER")?);
         }
         if size == INFO_V5 {
             icc_color = Some(buf
                 .pread_with::<ICC_color_prof>(BMP_HEADER_SIZE + INFO_V4, scroll::LE)
-                    .map_err(|e| Problem::Msg(format!("Could not read BITMAPV5INFOHEADER: {}", e)))?);
+                    .at((BMP_HEADER_SIZE + INFO_V4) as u64, "BITMAPV5INFOHEADER")?);
         }
 
         Ok(Bmp {
-            opt,
-
             bmp_header,
             dib_header,
             rgb_bitmask,
@@ -169,133 +175,4 @@ impl super::FileFormat for Bmp {
 
     }
 
-    fn print(&self) -> Result<(), Error> {
-        use ansi_term::Color;
-
-        println!("BMP width: {}px height: {}px",
-                 self.dib_header.width,
-                 self.dib_header.height);
-
-        println!();
-
-        println!("{}:", Color::White.underline().paint("BITMAPFILEHEADER"));
-        fmt_indentln(format!
-                     ("File size: {:}\n  Reserved1: {:}\n  Reserved2: {:}\n  Offset to pixels: {:}",
-                 self.bmp_header.file_size,
-                 self.bmp_header.reserved1,
-                 self.bmp_header.reserved2,
-                 self.bmp_header.offset));
-        println!();
-
-        match self.dib_header.size as usize {
-
-            INFO_V1 => {
-                println!("{}:", Color::White.underline().paint("BITMAPINFOHEADER"));
-            },
-            INFO_V2 => {
-                println!("{}{}{}:",
-                         Color::White.underline().paint("BITMAP"),
-                         Color::Yellow.underline().paint("V2"),
-                         Color::White.underline().paint("INFOHEADER"))
-            },
-            INFO_V3 => {
-                println!("{}{}{}:",
-                         Color::White.underline().paint("BITMAP"),
-                         Color::Yellow.underline().paint("V3"),
-                         Color::White.underline().paint("INFOHEADER"))
-            },
-            INFO_V4 => {
-                println!("{}{}{}:",
-                         Color::White.underline().paint("BITMAP"),
-                         Color::Yellow.underline().paint("V4"),
-                         Color::White.underline().paint("INFOHEADER"))
-            },
-            INFO_V5 => {
-                println!("{}{}{}:",
-                         Color::White.underline().paint("BITMAP"),
-                         Color::Yellow.underline().paint("V5"),
-                         Color::White.underline().paint("INFOHEADER"))
-            },
-            _ => panic!("Invalid/Unsupported header"),
-
-        }
-
-        //
-        // BITMAPINFOHEADER
-        //
-        fmt_indentln(format!("Bitmap width: {} height: {}",
-                             self.dib_header.width,
-                             self.dib_header.height));
-        fmt_indentln(format!("Color planes: {}", self.dib_header.planes));
-        fmt_indentln(format!("Bits per pixel: {}", self.dib_header.bpp));
-        fmt_indentln(format!("Compression method: {}", self.dib_header.compression));
-        fmt_indentln(format!("Image size: {}", self.dib_header.image_size));
-        fmt_indentln(format!("Print resolution: {}x{}",
-                             self.dib_header.h_resolution,
-                             self.dib_header.v_resolution));
-
-        fmt_indentln(format!("Number of colors in palette: {}", self.dib_header.palette));
-        fmt_indentln(format!("Important colors: {}", self.dib_header.imp_colors));
-
-        //
-        // RGB bitmask
-        //
-        if let Some(rgb_bitmask) = &self.rgb_bitmask {
-            println!();
-            fmt_indentln(format!("Red channel bitmask: {:}",
-                         Color::Red.paint(format!("  {:#010X}",rgb_bitmask.red_bitmask))));
-            fmt_indentln(format!("Green channel bitmask: {}",
-                         Color::Green.paint(format!("{:#010X}",rgb_bitmask.green_bitmask))));
-            fmt_indentln(format!("Blue channel bitmask: {}",
-                         Color::Blue.paint(format!(" {:#010X}",rgb_bitmask.blue_bitmask))));
-        }
-
-        //
-        // Alpha bitmask
-        //
-        if let Some(alpha_bitmask) = &self.alpha_bitmask {
-            fmt_indentln(format!("Alpha channel bitmask: {}",
-                         Color::White.paint(format!("{:#010X}", alpha_bitmask.alpha_bitmask))));
-        }
-
-        //
-        // Color space gamma
-        //
-        if let Some(csg) = &self.color_space_gamma {
-            println!();
-            fmt_indent(format!("Color space: "));
-            for (i, b) in csg.color_space.iter().enumerate() {
-                if i % 16 == 0 {
-                    println!("");
-                    fmt_indent(format!("{:02X} ", b));
-                }
-                else {
-                    print!("{:02X} ", b);
-                }
-            }
-            println!();
-            fmt_indentln(format!("Red channel gamma: {}",
-                                 Color::Red.paint(format!("  {:#010X}", csg.gamma_red))));
-            fmt_indentln(format!("Green channel gamma: {}",
-                                 Color::Green.paint(format!("{:#010X}", csg.gamma_green))));
-            fmt_indentln(format!("Blue channel gamma: {}",
-                                 Color::Blue.paint(format!(" {:#010X}", csg.gamma_blue))));
-        }
-
-        //
-        // ICC
-        //
-        if let Some(icc) = &self.icc_color {
-            println!();
-            fmt_indentln(format!("Intent:   {}",format!("{}", icc.intent)));
-            fmt_indentln(format!("ICC data: {}",format!("{}", icc.icc_data)));
-            fmt_indentln(format!("ICC size: {}",format!("{}", icc.icc_size)));
-            fmt_indentln(format!("Reserved: {}",format!("{}", icc.reserved)));
-        }
-
-
-        Ok(())
-
-    }
-
 }
@@ -0,0 +1,174 @@
+//! Compressed sections: SHF_COMPRESSED ones, starting with an Elf_Chdr
+//! header, and the older GNU `.zdebug` ones, starting with "ZLIB" and the
+//! big endian size of their contents.
+
+use std::borrow::Cow;
+use std::io::Read;
+
+use scroll::Pread;
+
+use super::*;
+
+/// zlib
+pub const ELFCOMPRESS_ZLIB: u32 = 1;
+/// Zstandard
+pub const ELFCOMPRESS_ZSTD: u32 = 2;
+/// Start of OS-specific.
+pub const ELFCOMPRESS_LOOS: u32 = 0x60000000;
+/// End of OS-specific.
+pub const ELFCOMPRESS_HIOS: u32 = 0x6fffffff;
+/// Start of processor-specific.
+pub const ELFCOMPRESS_LOPROC: u32 = 0x70000000;
+/// End of processor-specific.
+pub const ELFCOMPRESS_HIPROC: u32 = 0x7fffffff;
+
+#[inline]
+pub fn compress_to_str(ch_type: u32) -> &'static str {
+    match ch_type {
+        ELFCOMPRESS_ZLIB => "ZLIB",
+        ELFCOMPRESS_ZSTD => "ZSTD",
+        ELFCOMPRESS_LOOS..=ELFCOMPRESS_HIOS => "OS_SPECIFIC",
+        ELFCOMPRESS_LOPROC..=ELFCOMPRESS_HIPROC => "PROC_SPECIFIC",
+        _ => "UNKNOWN_ELFCOMPRESS",
+    }
+}
+
+/// Compression header of a section.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_compression {
+    /// Index of the section
+    pub section:      usize,
+    /// ELFCOMPRESS_ZLIB for `.zdebug` sections
+    pub ch_type:      u32,
+    /// Size of the uncompressed contents
+    pub ch_size:      u64,
+    pub ch_addralign: u64,
+    /// Bytes before the compressed data
+    pub header_size:  u64,
+}
+
+/// Reads the compression header of section `index`, if it has one.
+pub fn read_compression(buf: &[u8], index: usize, head: &Elf_section_header, name: &str, is_32: bool, endianness: scroll::Endian) -> Result<Option<Elf_compression>, Error> {
+    if head.sh_type == SHT_NOBITS {
+        return Ok(None);
+    }
+    if head.sh_flags & SHF_COMPRESSED as u64 != 0 {
+        let (header_size, (ch_type, ch_size, ch_addralign)) = if is_32 {
+            let data = bytes(buf, head.sh_offset, 12, "compression header")?;
+            (12, (data.pread_with::<u32>(0, endianness).at(head.sh_offset, "ch_type")?,
+                  data.pread_with::<u32>(4, endianness).at(head.sh_offset + 4, "ch_size")? as u64,
+                  data.pread_with::<u32>(8, endianness).at(head.sh_offset + 8, "ch_addralign")? as u64))
+        } else {
+            let data = bytes(buf, head.sh_offset, 24, "compression header")?;
+            (24, (data.pread_with::<u32>(0, endianness).at(head.sh_offset, "ch_type")?,
+                  data.pread_with::<u64>(8, endianness).at(head.sh_offset + 8, "ch_size")?,
+                  data.pread_with::<u64>(16, endianness).at(head.sh_offset + 16, "ch_addralign")?))
+        };
+        if header_size > head.sh_size {
+            return Err(Error::from(Problem::msg(format!("Section of size {:#X} too small for its compression header", head.sh_size))
+                                   .at(head.sh_offset)));
+        }
+        return Ok(Some(Elf_compression { section: index, ch_type, ch_size, ch_addralign, header_size }));
+    }
+    if name.starts_with(".zdebug") && head.sh_size >= 12 {
+        let data = bytes(buf, head.sh_offset, 12, "compression header")?;
+        if &data[..4] == b"ZLIB" {
+            let ch_size = data.pread_with::<u64>(4, scroll::BE).at(head.sh_offset + 4, "uncompressed size")?;
+            return Ok(Some(Elf_compression { section: index, ch_type: ELFCOMPRESS_ZLIB, ch_size, ch_addralign: head.sh_addralign, header_size: 12 }));
+        }
+    }
+    Ok(None)
+}
+
+/// Contents of a section, decompressed if `compression` says it is compressed.
+pub fn section_data<'a>(buf: &'a [u8], head: &Elf_section_header, compression: Option<&Elf_compression>) -> Result<Cow<'a, [u8]>, Error> {
+    let data = bytes(buf, head.sh_offset, head.sh_size, "section contents")?;
+    let compression = match compression {
+        Some(compression) => compression,
+        None => return Ok(Cow::Borrowed(data)),
+    };
+    let offset = head.sh_offset + compression.header_size;
+    let compressed = &data[compression.header_size as usize..];
+    let size = compression.ch_size as usize;
+    let data = match compression.ch_type {
+        ELFCOMPRESS_ZLIB => miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(compressed, size)
+            .map_err(|err| Problem::msg(format!("Invalid zlib data: {}", err)).at(offset))?,
+        ELFCOMPRESS_ZSTD => {
+            // Compressors may split the contents in several frames
+            let mut data = Vec::new();
+            let mut input = compressed;
+            while !input.is_empty() && data.len() <= size {
+                let decoder = ruzstd::decoding::StreamingDecoder::new(&mut input)
+                    .map_err(|err| Problem::msg(format!("Invalid zstd frame: {}", err)).at(offset))?;
+                decoder.take((size - data.len()) as u64 + 1).read_to_end(&mut data)
+                    .map_err(|err| Problem::msg(format!("Invalid zstd data: {}", err)).at(offset))?;
+            }
+            data
+        },
+        other => return Err(Error::from(Problem::msg(format!("Unsupported compression {} ({:#X})", compress_to_str(other), other)).at(head.sh_offset))),
+    };
+    if data.len() != size {
+        return Err(Error::from(Problem::msg(format!("Decompressed to {:#X} bytes instead of {:#X}", data.len(), size)).at(head.sh_offset)));
+    }
+    Ok(Cow::Owned(data))
+}
+
+impl Elf {
+
+    /// Compression header of section `index`, if it is compressed.
+    pub fn compression(&self, index: usize) -> Option<&Elf_compression> {
+        self.compressed_sections.iter().find(|compression| compression.section == index)
+    }
+
+    /// Contents of section `index` of the file in `buf`, decompressed.
+    pub fn section_data<'a>(&self, buf: &'a [u8], index: usize) -> Result<Cow<'a, [u8]>, Error> {
+        match self.section_headers.get(index) {
+            Some(head) => section_data(buf, head, self.compression(index)),
+            None => Err(Error::from(Problem::msg(format!("No section #{}", index)))),
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(sh_flags: u32, sh_size: u64) -> Elf_section_header {
+        Elf_section_header {
+            sh_name: 0, sh_type: SHT_PROGBITS, sh_flags: sh_flags as u64, sh_addr: 0, sh_offset: 0, sh_size,
+            sh_link: 0, sh_info: 0, sh_addralign: 1, sh_entsize: 0,
+        }
+    }
+
+    #[test]
+    fn zdebug_sections() {
+        let contents = b"hello, hello, hello";
+        let mut buf = b"ZLIB".to_vec();
+        buf.extend_from_slice(&(contents.len() as u64).to_be_bytes());
+        buf.extend(miniz_oxide::deflate::compress_to_vec_zlib(contents, 6));
+        let head = section(0, buf.len() as u64);
+
+        assert!(read_compression(&buf, 1, &head, ".debug_str", false, scroll::LE).unwrap().is_none());
+        let compression = read_compression(&buf, 1, &head, ".zdebug_str", false, scroll::LE).unwrap().unwrap();
+        assert_eq!((compression.ch_type, compression.ch_size, compression.header_size), (ELFCOMPRESS_ZLIB, contents.len() as u64, 12));
+        assert_eq!(&section_data(&buf, &head, Some(&compression)).unwrap()[..], contents);
+
+        // A wrong size is caught rather than truncating the contents
+        let compression = Elf_compression { ch_size: 4, ..compression };
+        assert!(section_data(&buf, &head, Some(&compression)).is_err());
+    }
+
+    #[test]
+    fn compression_headers() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&ELFCOMPRESS_ZSTD.to_le_bytes());
+        buf.extend_from_slice(&0x1234u32.to_le_bytes());
+        buf.extend_from_slice(&8u32.to_le_bytes());
+        let head = section(SHF_COMPRESSED, 12);
+        let compression = read_compression(&buf, 1, &head, ".debug_info", true, scroll::LE).unwrap().unwrap();
+        assert_eq!((compression.ch_type, compression.ch_size, compression.ch_addralign), (ELFCOMPRESS_ZSTD, 0x1234, 8));
+        assert!(read_compression(&buf, 1, &head, ".debug_info", false, scroll::LE).is_err());
+    }
+}
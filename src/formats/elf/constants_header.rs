@@ -370,6 +370,8 @@ pub const EM_RISCV: u16 = 243;
 
 /// Linux BPF -- in-kernel virtual machine
 pub const EM_BPF: u16 = 247;
+/// LoongArch
+pub const EM_LOONGARCH: u16 = 258;
 
 pub const EM_NUM: u16 = 248;
 
@@ -553,6 +555,7 @@ pub fn machine_to_str (machine: u16) -> &'static str {
         EM_AMDGPU => "AMDGPU",
         EM_RISCV => "RISCV",
         EM_BPF => "BPF",
+        EM_LOONGARCH => "LOONGARCH",
         _val => "EM_UNKNOWN",
     }
 }
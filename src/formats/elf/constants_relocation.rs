@@ -790,9 +790,901 @@ pub const R_MIPS_TLS_TPREL_HI16: u32 = 49;
 /// TP-relative offset, low 16 bits
 pub const R_MIPS_TLS_TPREL_LO16: u32 = 50;
 pub const R_MIPS_GLOB_DAT: u32 = 51;
+/// PC relative 21 bit, shifted by 2
+pub const R_MIPS_PC21_S2: u32 = 60;
+/// PC relative 26 bit, shifted by 2
+pub const R_MIPS_PC26_S2: u32 = 61;
+/// PC relative 18 bit, shifted by 3
+pub const R_MIPS_PC18_S3: u32 = 62;
+/// PC relative 19 bit, shifted by 2
+pub const R_MIPS_PC19_S2: u32 = 63;
+/// PC relative high 16 bit
+pub const R_MIPS_PCHI16: u32 = 64;
+/// PC relative low 16 bit
+pub const R_MIPS_PCLO16: u32 = 65;
 pub const R_MIPS_COPY: u32 = 126;
 pub const R_MIPS_JUMP_SLOT: u32 = 127;
 pub const R_MIPS_NUM: u32 = 128;
+/// PC relative 32 bit
+pub const R_MIPS_PC32: u32 = 248;
+/// Offset of an exception handling table entry from the GP
+pub const R_MIPS_EH: u32 = 249;
+pub const R_MIPS_GNU_REL16_S2: u32 = 250;
+pub const R_MIPS_GNU_VTINHERIT: u32 = 253;
+pub const R_MIPS_GNU_VTENTRY: u32 = 254;
+
+/////////////////////
+// RISC-V
+/////////////////////
+pub const R_RISCV_NONE: u32 = 0;
+pub const R_RISCV_32: u32 = 1;
+pub const R_RISCV_64: u32 = 2;
+pub const R_RISCV_RELATIVE: u32 = 3;
+pub const R_RISCV_COPY: u32 = 4;
+pub const R_RISCV_JUMP_SLOT: u32 = 5;
+pub const R_RISCV_TLS_DTPMOD32: u32 = 6;
+pub const R_RISCV_TLS_DTPMOD64: u32 = 7;
+pub const R_RISCV_TLS_DTPREL32: u32 = 8;
+pub const R_RISCV_TLS_DTPREL64: u32 = 9;
+pub const R_RISCV_TLS_TPREL32: u32 = 10;
+pub const R_RISCV_TLS_TPREL64: u32 = 11;
+pub const R_RISCV_TLSDESC: u32 = 12;
+pub const R_RISCV_BRANCH: u32 = 16;
+pub const R_RISCV_JAL: u32 = 17;
+pub const R_RISCV_CALL: u32 = 18;
+pub const R_RISCV_CALL_PLT: u32 = 19;
+pub const R_RISCV_GOT_HI20: u32 = 20;
+pub const R_RISCV_TLS_GOT_HI20: u32 = 21;
+pub const R_RISCV_TLS_GD_HI20: u32 = 22;
+pub const R_RISCV_PCREL_HI20: u32 = 23;
+pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+pub const R_RISCV_HI20: u32 = 26;
+pub const R_RISCV_LO12_I: u32 = 27;
+pub const R_RISCV_LO12_S: u32 = 28;
+pub const R_RISCV_TPREL_HI20: u32 = 29;
+pub const R_RISCV_TPREL_LO12_I: u32 = 30;
+pub const R_RISCV_TPREL_LO12_S: u32 = 31;
+pub const R_RISCV_TPREL_ADD: u32 = 32;
+pub const R_RISCV_ADD8: u32 = 33;
+pub const R_RISCV_ADD16: u32 = 34;
+pub const R_RISCV_ADD32: u32 = 35;
+pub const R_RISCV_ADD64: u32 = 36;
+pub const R_RISCV_SUB8: u32 = 37;
+pub const R_RISCV_SUB16: u32 = 38;
+pub const R_RISCV_SUB32: u32 = 39;
+pub const R_RISCV_SUB64: u32 = 40;
+pub const R_RISCV_GNU_VTINHERIT: u32 = 41;
+pub const R_RISCV_GNU_VTENTRY: u32 = 42;
+pub const R_RISCV_ALIGN: u32 = 43;
+pub const R_RISCV_RVC_BRANCH: u32 = 44;
+pub const R_RISCV_RVC_JUMP: u32 = 45;
+pub const R_RISCV_RVC_LUI: u32 = 46;
+pub const R_RISCV_GPREL_I: u32 = 47;
+pub const R_RISCV_GPREL_S: u32 = 48;
+pub const R_RISCV_TPREL_I: u32 = 49;
+pub const R_RISCV_TPREL_S: u32 = 50;
+pub const R_RISCV_RELAX: u32 = 51;
+pub const R_RISCV_SUB6: u32 = 52;
+pub const R_RISCV_SET6: u32 = 53;
+pub const R_RISCV_SET8: u32 = 54;
+pub const R_RISCV_SET16: u32 = 55;
+pub const R_RISCV_SET32: u32 = 56;
+pub const R_RISCV_32_PCREL: u32 = 57;
+pub const R_RISCV_IRELATIVE: u32 = 58;
+pub const R_RISCV_PLT32: u32 = 59;
+pub const R_RISCV_SET_ULEB128: u32 = 60;
+pub const R_RISCV_SUB_ULEB128: u32 = 61;
+pub const R_RISCV_TLSDESC_HI20: u32 = 62;
+pub const R_RISCV_TLSDESC_LOAD_LO12: u32 = 63;
+pub const R_RISCV_TLSDESC_ADD_LO12: u32 = 64;
+pub const R_RISCV_TLSDESC_CALL: u32 = 65;
+
+/////////////////////
+// LoongArch
+/////////////////////
+pub const R_LARCH_NONE: u32 = 0;
+pub const R_LARCH_32: u32 = 1;
+pub const R_LARCH_64: u32 = 2;
+pub const R_LARCH_RELATIVE: u32 = 3;
+pub const R_LARCH_COPY: u32 = 4;
+pub const R_LARCH_JUMP_SLOT: u32 = 5;
+pub const R_LARCH_TLS_DTPMOD32: u32 = 6;
+pub const R_LARCH_TLS_DTPMOD64: u32 = 7;
+pub const R_LARCH_TLS_DTPREL32: u32 = 8;
+pub const R_LARCH_TLS_DTPREL64: u32 = 9;
+pub const R_LARCH_TLS_TPREL32: u32 = 10;
+pub const R_LARCH_TLS_TPREL64: u32 = 11;
+pub const R_LARCH_IRELATIVE: u32 = 12;
+pub const R_LARCH_TLS_DESC32: u32 = 13;
+pub const R_LARCH_TLS_DESC64: u32 = 14;
+pub const R_LARCH_MARK_LA: u32 = 20;
+pub const R_LARCH_MARK_PCREL: u32 = 21;
+pub const R_LARCH_SOP_PUSH_PCREL: u32 = 22;
+pub const R_LARCH_SOP_PUSH_ABSOLUTE: u32 = 23;
+pub const R_LARCH_SOP_PUSH_DUP: u32 = 24;
+pub const R_LARCH_SOP_PUSH_GPREL: u32 = 25;
+pub const R_LARCH_SOP_PUSH_TLS_TPREL: u32 = 26;
+pub const R_LARCH_SOP_PUSH_TLS_GOT: u32 = 27;
+pub const R_LARCH_SOP_PUSH_TLS_GD: u32 = 28;
+pub const R_LARCH_SOP_PUSH_PLT_PCREL: u32 = 29;
+pub const R_LARCH_SOP_ASSERT: u32 = 30;
+pub const R_LARCH_SOP_NOT: u32 = 31;
+pub const R_LARCH_SOP_SUB: u32 = 32;
+pub const R_LARCH_SOP_SL: u32 = 33;
+pub const R_LARCH_SOP_SR: u32 = 34;
+pub const R_LARCH_SOP_ADD: u32 = 35;
+pub const R_LARCH_SOP_AND: u32 = 36;
+pub const R_LARCH_SOP_IF_ELSE: u32 = 37;
+pub const R_LARCH_SOP_POP_32_S_10_5: u32 = 38;
+pub const R_LARCH_SOP_POP_32_U_10_12: u32 = 39;
+pub const R_LARCH_SOP_POP_32_S_10_12: u32 = 40;
+pub const R_LARCH_SOP_POP_32_S_10_16: u32 = 41;
+pub const R_LARCH_SOP_POP_32_S_10_16_S2: u32 = 42;
+pub const R_LARCH_SOP_POP_32_S_5_20: u32 = 43;
+pub const R_LARCH_SOP_POP_32_S_0_5_10_16_S2: u32 = 44;
+pub const R_LARCH_SOP_POP_32_S_0_10_10_16_S2: u32 = 45;
+pub const R_LARCH_SOP_POP_32_U: u32 = 46;
+pub const R_LARCH_ADD8: u32 = 47;
+pub const R_LARCH_ADD16: u32 = 48;
+pub const R_LARCH_ADD24: u32 = 49;
+pub const R_LARCH_ADD32: u32 = 50;
+pub const R_LARCH_ADD64: u32 = 51;
+pub const R_LARCH_SUB8: u32 = 52;
+pub const R_LARCH_SUB16: u32 = 53;
+pub const R_LARCH_SUB24: u32 = 54;
+pub const R_LARCH_SUB32: u32 = 55;
+pub const R_LARCH_SUB64: u32 = 56;
+pub const R_LARCH_GNU_VTINHERIT: u32 = 57;
+pub const R_LARCH_GNU_VTENTRY: u32 = 58;
+pub const R_LARCH_B16: u32 = 64;
+pub const R_LARCH_B21: u32 = 65;
+pub const R_LARCH_B26: u32 = 66;
+pub const R_LARCH_ABS_HI20: u32 = 67;
+pub const R_LARCH_ABS_LO12: u32 = 68;
+pub const R_LARCH_ABS64_LO20: u32 = 69;
+pub const R_LARCH_ABS64_HI12: u32 = 70;
+pub const R_LARCH_PCALA_HI20: u32 = 71;
+pub const R_LARCH_PCALA_LO12: u32 = 72;
+pub const R_LARCH_PCALA64_LO20: u32 = 73;
+pub const R_LARCH_PCALA64_HI12: u32 = 74;
+pub const R_LARCH_GOT_PC_HI20: u32 = 75;
+pub const R_LARCH_GOT_PC_LO12: u32 = 76;
+pub const R_LARCH_GOT64_PC_LO20: u32 = 77;
+pub const R_LARCH_GOT64_PC_HI12: u32 = 78;
+pub const R_LARCH_GOT_HI20: u32 = 79;
+pub const R_LARCH_GOT_LO12: u32 = 80;
+pub const R_LARCH_GOT64_LO20: u32 = 81;
+pub const R_LARCH_GOT64_HI12: u32 = 82;
+pub const R_LARCH_TLS_LE_HI20: u32 = 83;
+pub const R_LARCH_TLS_LE_LO12: u32 = 84;
+pub const R_LARCH_TLS_LE64_LO20: u32 = 85;
+pub const R_LARCH_TLS_LE64_HI12: u32 = 86;
+pub const R_LARCH_TLS_IE_PC_HI20: u32 = 87;
+pub const R_LARCH_TLS_IE_PC_LO12: u32 = 88;
+pub const R_LARCH_TLS_IE64_PC_LO20: u32 = 89;
+pub const R_LARCH_TLS_IE64_PC_HI12: u32 = 90;
+pub const R_LARCH_TLS_IE_HI20: u32 = 91;
+pub const R_LARCH_TLS_IE_LO12: u32 = 92;
+pub const R_LARCH_TLS_IE64_LO20: u32 = 93;
+pub const R_LARCH_TLS_IE64_HI12: u32 = 94;
+pub const R_LARCH_TLS_LD_PC_HI20: u32 = 95;
+pub const R_LARCH_TLS_LD_HI20: u32 = 96;
+pub const R_LARCH_TLS_GD_PC_HI20: u32 = 97;
+pub const R_LARCH_TLS_GD_HI20: u32 = 98;
+pub const R_LARCH_32_PCREL: u32 = 99;
+pub const R_LARCH_RELAX: u32 = 100;
+pub const R_LARCH_ALIGN: u32 = 102;
+pub const R_LARCH_PCREL20_S2: u32 = 103;
+pub const R_LARCH_ADD6: u32 = 105;
+pub const R_LARCH_SUB6: u32 = 106;
+pub const R_LARCH_ADD_ULEB128: u32 = 107;
+pub const R_LARCH_SUB_ULEB128: u32 = 108;
+pub const R_LARCH_64_PCREL: u32 = 109;
+pub const R_LARCH_CALL36: u32 = 110;
+pub const R_LARCH_TLS_DESC_PC_HI20: u32 = 111;
+pub const R_LARCH_TLS_DESC_PC_LO12: u32 = 112;
+pub const R_LARCH_TLS_DESC64_PC_LO20: u32 = 113;
+pub const R_LARCH_TLS_DESC64_PC_HI12: u32 = 114;
+pub const R_LARCH_TLS_DESC_HI20: u32 = 115;
+pub const R_LARCH_TLS_DESC_LO12: u32 = 116;
+pub const R_LARCH_TLS_DESC64_LO20: u32 = 117;
+pub const R_LARCH_TLS_DESC64_HI12: u32 = 118;
+pub const R_LARCH_TLS_DESC_LD: u32 = 119;
+pub const R_LARCH_TLS_DESC_CALL: u32 = 120;
+pub const R_LARCH_TLS_LE_HI20_R: u32 = 121;
+pub const R_LARCH_TLS_LE_ADD_R: u32 = 122;
+pub const R_LARCH_TLS_LE_LO12_R: u32 = 123;
+pub const R_LARCH_TLS_LD_PCREL20_S2: u32 = 124;
+pub const R_LARCH_TLS_GD_PCREL20_S2: u32 = 125;
+pub const R_LARCH_TLS_DESC_PCREL20_S2: u32 = 126;
+
+/////////////////////
+// PowerPC
+/////////////////////
+pub const R_PPC_NONE: u32 = 0;
+/// 32bit absolute address
+pub const R_PPC_ADDR32: u32 = 1;
+/// 26bit address, 2 bits ignored.
+pub const R_PPC_ADDR24: u32 = 2;
+/// 16bit absolute address
+pub const R_PPC_ADDR16: u32 = 3;
+/// Lower 16bit of absolute address
+pub const R_PPC_ADDR16_LO: u32 = 4;
+/// High 16bit of absolute address
+pub const R_PPC_ADDR16_HI: u32 = 5;
+/// Adjusted high 16bit
+pub const R_PPC_ADDR16_HA: u32 = 6;
+/// 16bit address, 2 bits ignored
+pub const R_PPC_ADDR14: u32 = 7;
+pub const R_PPC_ADDR14_BRTAKEN: u32 = 8;
+pub const R_PPC_ADDR14_BRNTAKEN: u32 = 9;
+/// PC relative 26 bit
+pub const R_PPC_REL24: u32 = 10;
+/// PC relative 16 bit
+pub const R_PPC_REL14: u32 = 11;
+pub const R_PPC_REL14_BRTAKEN: u32 = 12;
+pub const R_PPC_REL14_BRNTAKEN: u32 = 13;
+pub const R_PPC_GOT16: u32 = 14;
+pub const R_PPC_GOT16_LO: u32 = 15;
+pub const R_PPC_GOT16_HI: u32 = 16;
+pub const R_PPC_GOT16_HA: u32 = 17;
+pub const R_PPC_PLTREL24: u32 = 18;
+pub const R_PPC_COPY: u32 = 19;
+pub const R_PPC_GLOB_DAT: u32 = 20;
+pub const R_PPC_JMP_SLOT: u32 = 21;
+pub const R_PPC_RELATIVE: u32 = 22;
+pub const R_PPC_LOCAL24PC: u32 = 23;
+pub const R_PPC_UADDR32: u32 = 24;
+pub const R_PPC_UADDR16: u32 = 25;
+pub const R_PPC_REL32: u32 = 26;
+pub const R_PPC_PLT32: u32 = 27;
+pub const R_PPC_PLTREL32: u32 = 28;
+pub const R_PPC_PLT16_LO: u32 = 29;
+pub const R_PPC_PLT16_HI: u32 = 30;
+pub const R_PPC_PLT16_HA: u32 = 31;
+pub const R_PPC_SDAREL16: u32 = 32;
+pub const R_PPC_SECTOFF: u32 = 33;
+pub const R_PPC_SECTOFF_LO: u32 = 34;
+pub const R_PPC_SECTOFF_HI: u32 = 35;
+pub const R_PPC_SECTOFF_HA: u32 = 36;
+/// None (sym+add)@tls
+pub const R_PPC_TLS: u32 = 67;
+/// Word32 (sym+add)@dtpmod
+pub const R_PPC_DTPMOD32: u32 = 68;
+/// Half16* (sym+add)@tprel
+pub const R_PPC_TPREL16: u32 = 69;
+/// Half16 (sym+add)@tprel@l
+pub const R_PPC_TPREL16_LO: u32 = 70;
+/// Half16 (sym+add)@tprel@h
+pub const R_PPC_TPREL16_HI: u32 = 71;
+/// Half16 (sym+add)@tprel@ha
+pub const R_PPC_TPREL16_HA: u32 = 72;
+/// Word32 (sym+add)@tprel
+pub const R_PPC_TPREL32: u32 = 73;
+/// Half16* (sym+add)@dtprel
+pub const R_PPC_DTPREL16: u32 = 74;
+/// Half16 (sym+add)@dtprel@l
+pub const R_PPC_DTPREL16_LO: u32 = 75;
+/// Half16 (sym+add)@dtprel@h
+pub const R_PPC_DTPREL16_HI: u32 = 76;
+/// Half16 (sym+add)@dtprel@ha
+pub const R_PPC_DTPREL16_HA: u32 = 77;
+/// Word32 (sym+add)@dtprel
+pub const R_PPC_DTPREL32: u32 = 78;
+/// Half16* (sym+add)@got@tlsgd
+pub const R_PPC_GOT_TLSGD16: u32 = 79;
+/// Half16 (sym+add)@got@tlsgd@l
+pub const R_PPC_GOT_TLSGD16_LO: u32 = 80;
+/// Half16 (sym+add)@got@tlsgd@h
+pub const R_PPC_GOT_TLSGD16_HI: u32 = 81;
+/// Half16 (sym+add)@got@tlsgd@ha
+pub const R_PPC_GOT_TLSGD16_HA: u32 = 82;
+/// Half16* (sym+add)@got@tlsld
+pub const R_PPC_GOT_TLSLD16: u32 = 83;
+/// Half16 (sym+add)@got@tlsld@l
+pub const R_PPC_GOT_TLSLD16_LO: u32 = 84;
+/// Half16 (sym+add)@got@tlsld@h
+pub const R_PPC_GOT_TLSLD16_HI: u32 = 85;
+/// Half16 (sym+add)@got@tlsld@ha
+pub const R_PPC_GOT_TLSLD16_HA: u32 = 86;
+/// Half16* (sym+add)@got@tprel
+pub const R_PPC_GOT_TPREL16: u32 = 87;
+/// Half16 (sym+add)@got@tprel@l
+pub const R_PPC_GOT_TPREL16_LO: u32 = 88;
+/// Half16 (sym+add)@got@tprel@h
+pub const R_PPC_GOT_TPREL16_HI: u32 = 89;
+/// Half16 (sym+add)@got@tprel@ha
+pub const R_PPC_GOT_TPREL16_HA: u32 = 90;
+/// Half16* (sym+add)@got@dtprel
+pub const R_PPC_GOT_DTPREL16: u32 = 91;
+/// Half16* (sym+add)@got@dtprel@l
+pub const R_PPC_GOT_DTPREL16_LO: u32 = 92;
+/// Half16* (sym+add)@got@dtprel@h
+pub const R_PPC_GOT_DTPREL16_HI: u32 = 93;
+/// Half16* (sym+add)@got@dtprel@ha
+pub const R_PPC_GOT_DTPREL16_HA: u32 = 94;
+/// None (sym+add)@tlsgd
+pub const R_PPC_TLSGD: u32 = 95;
+/// None (sym+add)@tlsld
+pub const R_PPC_TLSLD: u32 = 96;
+pub const R_PPC_EMB_NADDR32: u32 = 101;
+pub const R_PPC_EMB_NADDR16: u32 = 102;
+pub const R_PPC_EMB_NADDR16_LO: u32 = 103;
+pub const R_PPC_EMB_NADDR16_HI: u32 = 104;
+pub const R_PPC_EMB_NADDR16_HA: u32 = 105;
+pub const R_PPC_EMB_SDAI16: u32 = 106;
+pub const R_PPC_EMB_SDA2I16: u32 = 107;
+pub const R_PPC_EMB_SDA2REL: u32 = 108;
+/// 16 bit offset in SDA
+pub const R_PPC_EMB_SDA21: u32 = 109;
+pub const R_PPC_EMB_MRKREF: u32 = 110;
+pub const R_PPC_EMB_RELSEC16: u32 = 111;
+pub const R_PPC_EMB_RELST_LO: u32 = 112;
+pub const R_PPC_EMB_RELST_HI: u32 = 113;
+pub const R_PPC_EMB_RELST_HA: u32 = 114;
+pub const R_PPC_EMB_BIT_FLD: u32 = 115;
+/// 16 bit relative offset in SDA
+pub const R_PPC_EMB_RELSDA: u32 = 116;
+/// Like EMB_SDA21, but lower 16 bit
+pub const R_PPC_DIAB_SDA21_LO: u32 = 180;
+/// Like EMB_SDA21, but high 16 bit
+pub const R_PPC_DIAB_SDA21_HI: u32 = 181;
+/// Like EMB_SDA21, adjusted high 16
+pub const R_PPC_DIAB_SDA21_HA: u32 = 182;
+/// Like EMB_RELSDA, but lower 16 bit
+pub const R_PPC_DIAB_RELSDA_LO: u32 = 183;
+/// Like EMB_RELSDA, but high 16 bit
+pub const R_PPC_DIAB_RELSDA_HI: u32 = 184;
+/// Like EMB_RELSDA, adjusted high 16
+pub const R_PPC_DIAB_RELSDA_HA: u32 = 185;
+pub const R_PPC_IRELATIVE: u32 = 248;
+/// Half16 (sym+add-.)
+pub const R_PPC_REL16: u32 = 249;
+/// Half16 (sym+add-.)@l
+pub const R_PPC_REL16_LO: u32 = 250;
+/// Half16 (sym+add-.)@h
+pub const R_PPC_REL16_HI: u32 = 251;
+/// Half16 (sym+add-.)@ha
+pub const R_PPC_REL16_HA: u32 = 252;
+pub const R_PPC_TOC16: u32 = 255;
+
+/////////////////////
+// PowerPC64
+/////////////////////
+pub const R_PPC64_NONE: u32 = 0;
+/// 32bit absolute address
+pub const R_PPC64_ADDR32: u32 = 1;
+/// 26bit address, word aligned
+pub const R_PPC64_ADDR24: u32 = 2;
+/// 16bit absolute address
+pub const R_PPC64_ADDR16: u32 = 3;
+/// Lower 16bits of address
+pub const R_PPC64_ADDR16_LO: u32 = 4;
+/// High 16bits of address.
+pub const R_PPC64_ADDR16_HI: u32 = 5;
+/// Adjusted high 16bits.
+pub const R_PPC64_ADDR16_HA: u32 = 6;
+/// 16bit address, word aligned
+pub const R_PPC64_ADDR14: u32 = 7;
+pub const R_PPC64_ADDR14_BRTAKEN: u32 = 8;
+pub const R_PPC64_ADDR14_BRNTAKEN: u32 = 9;
+/// PC-rel. 26 bit, word aligned
+pub const R_PPC64_REL24: u32 = 10;
+/// PC relative 16 bit
+pub const R_PPC64_REL14: u32 = 11;
+pub const R_PPC64_REL14_BRTAKEN: u32 = 12;
+pub const R_PPC64_REL14_BRNTAKEN: u32 = 13;
+pub const R_PPC64_GOT16: u32 = 14;
+pub const R_PPC64_GOT16_LO: u32 = 15;
+pub const R_PPC64_GOT16_HI: u32 = 16;
+pub const R_PPC64_GOT16_HA: u32 = 17;
+pub const R_PPC64_COPY: u32 = 19;
+pub const R_PPC64_GLOB_DAT: u32 = 20;
+pub const R_PPC64_JMP_SLOT: u32 = 21;
+pub const R_PPC64_RELATIVE: u32 = 22;
+pub const R_PPC64_UADDR32: u32 = 24;
+pub const R_PPC64_UADDR16: u32 = 25;
+pub const R_PPC64_REL32: u32 = 26;
+pub const R_PPC64_PLT32: u32 = 27;
+pub const R_PPC64_PLTREL32: u32 = 28;
+pub const R_PPC64_PLT16_LO: u32 = 29;
+pub const R_PPC64_PLT16_HI: u32 = 30;
+pub const R_PPC64_PLT16_HA: u32 = 31;
+pub const R_PPC64_SECTOFF: u32 = 33;
+pub const R_PPC64_SECTOFF_LO: u32 = 34;
+pub const R_PPC64_SECTOFF_HI: u32 = 35;
+pub const R_PPC64_SECTOFF_HA: u32 = 36;
+/// Word30 (S + A - P) >> 2
+pub const R_PPC64_ADDR30: u32 = 37;
+/// Doubleword64 S + A
+pub const R_PPC64_ADDR64: u32 = 38;
+/// Half16 #higher(S + A)
+pub const R_PPC64_ADDR16_HIGHER: u32 = 39;
+/// Half16 #highera(S + A)
+pub const R_PPC64_ADDR16_HIGHERA: u32 = 40;
+/// Half16 #highest(S + A)
+pub const R_PPC64_ADDR16_HIGHEST: u32 = 41;
+/// Half16 #highesta(S + A)
+pub const R_PPC64_ADDR16_HIGHESTA: u32 = 42;
+/// Doubleword64 S + A
+pub const R_PPC64_UADDR64: u32 = 43;
+/// Doubleword64 S + A - P
+pub const R_PPC64_REL64: u32 = 44;
+/// Doubleword64 L + A
+pub const R_PPC64_PLT64: u32 = 45;
+/// Doubleword64 L + A - P
+pub const R_PPC64_PLTREL64: u32 = 46;
+/// Half16* S + A - .TOC
+pub const R_PPC64_TOC16: u32 = 47;
+/// Half16 #lo(S + A - .TOC.)
+pub const R_PPC64_TOC16_LO: u32 = 48;
+/// Half16 #hi(S + A - .TOC.)
+pub const R_PPC64_TOC16_HI: u32 = 49;
+/// Half16 #ha(S + A - .TOC.)
+pub const R_PPC64_TOC16_HA: u32 = 50;
+/// Doubleword64 .TOC
+pub const R_PPC64_TOC: u32 = 51;
+/// Half16* M + A
+pub const R_PPC64_PLTGOT16: u32 = 52;
+/// Half16 #lo(M + A)
+pub const R_PPC64_PLTGOT16_LO: u32 = 53;
+/// Half16 #hi(M + A)
+pub const R_PPC64_PLTGOT16_HI: u32 = 54;
+/// Half16 #ha(M + A)
+pub const R_PPC64_PLTGOT16_HA: u32 = 55;
+/// Half16ds* (S + A) >> 2
+pub const R_PPC64_ADDR16_DS: u32 = 56;
+/// Half16ds #lo(S + A) >> 2
+pub const R_PPC64_ADDR16_LO_DS: u32 = 57;
+/// Half16ds* (G + A) >> 2
+pub const R_PPC64_GOT16_DS: u32 = 58;
+/// Half16ds #lo(G + A) >> 2
+pub const R_PPC64_GOT16_LO_DS: u32 = 59;
+/// Half16ds #lo(L + A) >> 2
+pub const R_PPC64_PLT16_LO_DS: u32 = 60;
+/// Half16ds* (R + A) >> 2
+pub const R_PPC64_SECTOFF_DS: u32 = 61;
+/// Half16ds #lo(R + A) >> 2
+pub const R_PPC64_SECTOFF_LO_DS: u32 = 62;
+/// Half16ds* (S + A - .TOC.) >> 2
+pub const R_PPC64_TOC16_DS: u32 = 63;
+/// Half16ds #lo(S + A - .TOC.) >> 2
+pub const R_PPC64_TOC16_LO_DS: u32 = 64;
+/// Half16ds* (M + A) >> 2
+pub const R_PPC64_PLTGOT16_DS: u32 = 65;
+/// Half16ds #lo(M + A) >> 2
+pub const R_PPC64_PLTGOT16_LO_DS: u32 = 66;
+/// None (sym+add)@tls
+pub const R_PPC64_TLS: u32 = 67;
+/// Doubleword64 (sym+add)@dtpmod
+pub const R_PPC64_DTPMOD64: u32 = 68;
+/// Half16* (sym+add)@tprel
+pub const R_PPC64_TPREL16: u32 = 69;
+/// Half16 (sym+add)@tprel@l
+pub const R_PPC64_TPREL16_LO: u32 = 70;
+/// Half16 (sym+add)@tprel@h
+pub const R_PPC64_TPREL16_HI: u32 = 71;
+/// Half16 (sym+add)@tprel@ha
+pub const R_PPC64_TPREL16_HA: u32 = 72;
+/// Doubleword64 (sym+add)@tprel
+pub const R_PPC64_TPREL64: u32 = 73;
+/// Half16* (sym+add)@dtprel
+pub const R_PPC64_DTPREL16: u32 = 74;
+/// Half16 (sym+add)@dtprel@l
+pub const R_PPC64_DTPREL16_LO: u32 = 75;
+/// Half16 (sym+add)@dtprel@h
+pub const R_PPC64_DTPREL16_HI: u32 = 76;
+/// Half16 (sym+add)@dtprel@ha
+pub const R_PPC64_DTPREL16_HA: u32 = 77;
+/// Doubleword64 (sym+add)@dtprel
+pub const R_PPC64_DTPREL64: u32 = 78;
+/// Half16* (sym+add)@got@tlsgd
+pub const R_PPC64_GOT_TLSGD16: u32 = 79;
+/// Half16 (sym+add)@got@tlsgd@l
+pub const R_PPC64_GOT_TLSGD16_LO: u32 = 80;
+/// Half16 (sym+add)@got@tlsgd@h
+pub const R_PPC64_GOT_TLSGD16_HI: u32 = 81;
+/// Half16 (sym+add)@got@tlsgd@ha
+pub const R_PPC64_GOT_TLSGD16_HA: u32 = 82;
+/// Half16* (sym+add)@got@tlsld
+pub const R_PPC64_GOT_TLSLD16: u32 = 83;
+/// Half16 (sym+add)@got@tlsld@l
+pub const R_PPC64_GOT_TLSLD16_LO: u32 = 84;
+/// Half16 (sym+add)@got@tlsld@h
+pub const R_PPC64_GOT_TLSLD16_HI: u32 = 85;
+/// Half16 (sym+add)@got@tlsld@ha
+pub const R_PPC64_GOT_TLSLD16_HA: u32 = 86;
+/// Half16ds* (sym+add)@got@tprel
+pub const R_PPC64_GOT_TPREL16_DS: u32 = 87;
+/// Half16ds (sym+add)@got@tprel@l
+pub const R_PPC64_GOT_TPREL16_LO_DS: u32 = 88;
+/// Half16 (sym+add)@got@tprel@h
+pub const R_PPC64_GOT_TPREL16_HI: u32 = 89;
+/// Half16 (sym+add)@got@tprel@ha
+pub const R_PPC64_GOT_TPREL16_HA: u32 = 90;
+/// Half16ds* (sym+add)@got@dtprel
+pub const R_PPC64_GOT_DTPREL16_DS: u32 = 91;
+/// Half16ds (sym+add)@got@dtprel@l
+pub const R_PPC64_GOT_DTPREL16_LO_DS: u32 = 92;
+/// Half16 (sym+add)@got@dtprel@h
+pub const R_PPC64_GOT_DTPREL16_HI: u32 = 93;
+/// Half16 (sym+add)@got@dtprel@ha
+pub const R_PPC64_GOT_DTPREL16_HA: u32 = 94;
+/// Half16ds* (sym+add)@tprel
+pub const R_PPC64_TPREL16_DS: u32 = 95;
+/// Half16ds (sym+add)@tprel@l
+pub const R_PPC64_TPREL16_LO_DS: u32 = 96;
+/// Half16 (sym+add)@tprel@higher
+pub const R_PPC64_TPREL16_HIGHER: u32 = 97;
+/// Half16 (sym+add)@tprel@highera
+pub const R_PPC64_TPREL16_HIGHERA: u32 = 98;
+/// Half16 (sym+add)@tprel@highest
+pub const R_PPC64_TPREL16_HIGHEST: u32 = 99;
+/// Half16 (sym+add)@tprel@highesta
+pub const R_PPC64_TPREL16_HIGHESTA: u32 = 100;
+/// Half16ds* (sym+add)@dtprel
+pub const R_PPC64_DTPREL16_DS: u32 = 101;
+/// Half16ds (sym+add)@dtprel@l
+pub const R_PPC64_DTPREL16_LO_DS: u32 = 102;
+/// Half16 (sym+add)@dtprel@higher
+pub const R_PPC64_DTPREL16_HIGHER: u32 = 103;
+/// Half16 (sym+add)@dtprel@highera
+pub const R_PPC64_DTPREL16_HIGHERA: u32 = 104;
+/// Half16 (sym+add)@dtprel@highest
+pub const R_PPC64_DTPREL16_HIGHEST: u32 = 105;
+/// Half16 (sym+add)@dtprel@highesta
+pub const R_PPC64_DTPREL16_HIGHESTA: u32 = 106;
+/// None (sym+add)@tlsgd
+pub const R_PPC64_TLSGD: u32 = 107;
+/// None (sym+add)@tlsld
+pub const R_PPC64_TLSLD: u32 = 108;
+/// None
+pub const R_PPC64_TOCSAVE: u32 = 109;
+pub const R_PPC64_ADDR16_HIGH: u32 = 110;
+pub const R_PPC64_ADDR16_HIGHA: u32 = 111;
+pub const R_PPC64_TPREL16_HIGH: u32 = 112;
+pub const R_PPC64_TPREL16_HIGHA: u32 = 113;
+pub const R_PPC64_DTPREL16_HIGH: u32 = 114;
+pub const R_PPC64_DTPREL16_HIGHA: u32 = 115;
+pub const R_PPC64_REL24_NOTOC: u32 = 116;
+pub const R_PPC64_ADDR64_LOCAL: u32 = 117;
+pub const R_PPC64_ENTRY: u32 = 118;
+pub const R_PPC64_PLTSEQ: u32 = 119;
+pub const R_PPC64_PLTCALL: u32 = 120;
+pub const R_PPC64_PLTSEQ_NOTOC: u32 = 121;
+pub const R_PPC64_PLTCALL_NOTOC: u32 = 122;
+pub const R_PPC64_PCREL_OPT: u32 = 123;
+pub const R_PPC64_REL24_P9NOTOC: u32 = 124;
+pub const R_PPC64_D34: u32 = 128;
+pub const R_PPC64_D34_LO: u32 = 129;
+pub const R_PPC64_D34_HI30: u32 = 130;
+pub const R_PPC64_D34_HA30: u32 = 131;
+pub const R_PPC64_PCREL34: u32 = 132;
+pub const R_PPC64_GOT_PCREL34: u32 = 133;
+pub const R_PPC64_PLT_PCREL34: u32 = 134;
+pub const R_PPC64_PLT_PCREL34_NOTOC: u32 = 135;
+pub const R_PPC64_ADDR16_HIGHER34: u32 = 136;
+pub const R_PPC64_ADDR16_HIGHERA34: u32 = 137;
+pub const R_PPC64_ADDR16_HIGHEST34: u32 = 138;
+pub const R_PPC64_ADDR16_HIGHESTA34: u32 = 139;
+pub const R_PPC64_REL16_HIGHER34: u32 = 140;
+pub const R_PPC64_REL16_HIGHERA34: u32 = 141;
+pub const R_PPC64_REL16_HIGHEST34: u32 = 142;
+pub const R_PPC64_REL16_HIGHESTA34: u32 = 143;
+pub const R_PPC64_D28: u32 = 144;
+pub const R_PPC64_PCREL28: u32 = 145;
+pub const R_PPC64_TPREL34: u32 = 146;
+pub const R_PPC64_DTPREL34: u32 = 147;
+pub const R_PPC64_GOT_TLSGD_PCREL34: u32 = 148;
+pub const R_PPC64_GOT_TLSLD_PCREL34: u32 = 149;
+pub const R_PPC64_GOT_TPREL_PCREL34: u32 = 150;
+pub const R_PPC64_GOT_DTPREL_PCREL34: u32 = 151;
+pub const R_PPC64_REL16_HIGH: u32 = 240;
+pub const R_PPC64_REL16_HIGHA: u32 = 241;
+pub const R_PPC64_REL16_HIGHER: u32 = 242;
+pub const R_PPC64_REL16_HIGHERA: u32 = 243;
+pub const R_PPC64_REL16_HIGHEST: u32 = 244;
+pub const R_PPC64_REL16_HIGHESTA: u32 = 245;
+pub const R_PPC64_REL16DX_HA: u32 = 246;
+pub const R_PPC64_JMP_IREL: u32 = 247;
+pub const R_PPC64_IRELATIVE: u32 = 248;
+/// Half16 (sym+add-.)
+pub const R_PPC64_REL16: u32 = 249;
+/// Half16 (sym+add-.)@l
+pub const R_PPC64_REL16_LO: u32 = 250;
+/// Half16 (sym+add-.)@h
+pub const R_PPC64_REL16_HI: u32 = 251;
+/// Half16 (sym+add-.)@ha
+pub const R_PPC64_REL16_HA: u32 = 252;
+pub const R_PPC64_GNU_VTINHERIT: u32 = 253;
+pub const R_PPC64_GNU_VTENTRY: u32 = 254;
+
+/////////////////////
+// s390 and s390x
+/////////////////////
+/// No reloc.
+pub const R_390_NONE: u32 = 0;
+/// Direct 8 bit.
+pub const R_390_8: u32 = 1;
+/// Direct 12 bit.
+pub const R_390_12: u32 = 2;
+/// Direct 16 bit.
+pub const R_390_16: u32 = 3;
+/// Direct 32 bit.
+pub const R_390_32: u32 = 4;
+/// PC relative 32 bit.
+pub const R_390_PC32: u32 = 5;
+/// 12 bit GOT offset.
+pub const R_390_GOT12: u32 = 6;
+/// 32 bit GOT offset.
+pub const R_390_GOT32: u32 = 7;
+/// 32 bit PC relative PLT address.
+pub const R_390_PLT32: u32 = 8;
+/// Copy symbol at runtime.
+pub const R_390_COPY: u32 = 9;
+/// Create GOT entry.
+pub const R_390_GLOB_DAT: u32 = 10;
+/// Create PLT entry.
+pub const R_390_JMP_SLOT: u32 = 11;
+/// Adjust by program base.
+pub const R_390_RELATIVE: u32 = 12;
+/// 32 bit offset to GOT.
+pub const R_390_GOTOFF32: u32 = 13;
+/// 32 bit PC relative offset to GOT.
+pub const R_390_GOTPC: u32 = 14;
+/// 16 bit GOT offset.
+pub const R_390_GOT16: u32 = 15;
+/// PC relative 16 bit.
+pub const R_390_PC16: u32 = 16;
+/// PC relative 16 bit shifted by 1.
+pub const R_390_PC16DBL: u32 = 17;
+/// 16 bit PC rel. PLT shifted by 1.
+pub const R_390_PLT16DBL: u32 = 18;
+/// PC relative 32 bit shifted by 1.
+pub const R_390_PC32DBL: u32 = 19;
+/// 32 bit PC rel. PLT shifted by 1.
+pub const R_390_PLT32DBL: u32 = 20;
+/// 32 bit PC rel. GOT shifted by 1.
+pub const R_390_GOTPCDBL: u32 = 21;
+/// Direct 64 bit.
+pub const R_390_64: u32 = 22;
+/// PC relative 64 bit.
+pub const R_390_PC64: u32 = 23;
+/// 64 bit GOT offset.
+pub const R_390_GOT64: u32 = 24;
+/// 64 bit PC relative PLT address.
+pub const R_390_PLT64: u32 = 25;
+/// 32 bit PC rel. to GOT entry >> 1.
+pub const R_390_GOTENT: u32 = 26;
+/// 16 bit offset to GOT.
+pub const R_390_GOTOFF16: u32 = 27;
+/// 64 bit offset to GOT.
+pub const R_390_GOTOFF64: u32 = 28;
+/// 12 bit offset to jump slot.
+pub const R_390_GOTPLT12: u32 = 29;
+/// 16 bit offset to jump slot.
+pub const R_390_GOTPLT16: u32 = 30;
+/// 32 bit offset to jump slot.
+pub const R_390_GOTPLT32: u32 = 31;
+/// 64 bit offset to jump slot.
+pub const R_390_GOTPLT64: u32 = 32;
+/// 32 bit rel. offset to jump slot.
+pub const R_390_GOTPLTENT: u32 = 33;
+/// 16 bit offset from GOT to PLT.
+pub const R_390_PLTOFF16: u32 = 34;
+/// 32 bit offset from GOT to PLT.
+pub const R_390_PLTOFF32: u32 = 35;
+/// 16 bit offset from GOT to PLT.
+pub const R_390_PLTOFF64: u32 = 36;
+/// Tag for load insn in TLS code.
+pub const R_390_TLS_LOAD: u32 = 37;
+/// Tag for function call in general dynamic TLS code.
+pub const R_390_TLS_GDCALL: u32 = 38;
+/// Tag for function call in local dynamic TLS code.
+pub const R_390_TLS_LDCALL: u32 = 39;
+/// Direct 32 bit for general dynamic thread local data.
+pub const R_390_TLS_GD32: u32 = 40;
+/// Direct 64 bit for general dynamic thread local data.
+pub const R_390_TLS_GD64: u32 = 41;
+/// 12 bit GOT offset for static TLS block offset.
+pub const R_390_TLS_GOTIE12: u32 = 42;
+/// 32 bit GOT offset for static TLS block offset.
+pub const R_390_TLS_GOTIE32: u32 = 43;
+/// 64 bit GOT offset for static TLS block offset.
+pub const R_390_TLS_GOTIE64: u32 = 44;
+/// Direct 32 bit for local dynamic thread local data in LE code.
+pub const R_390_TLS_LDM32: u32 = 45;
+/// Direct 64 bit for local dynamic thread local data in LE code.
+pub const R_390_TLS_LDM64: u32 = 46;
+/// 32 bit address of GOT entry for negated static TLS block offset.
+pub const R_390_TLS_IE32: u32 = 47;
+/// 64 bit address of GOT entry for negated static TLS block offset.
+pub const R_390_TLS_IE64: u32 = 48;
+/// 32 bit rel. offset to GOT entry for negated static TLS block offset.
+pub const R_390_TLS_IEENT: u32 = 49;
+/// 32 bit negated offset relative to static TLS block.
+pub const R_390_TLS_LE32: u32 = 50;
+/// 64 bit negated offset relative to static TLS block.
+pub const R_390_TLS_LE64: u32 = 51;
+/// 32 bit offset relative to TLS block.
+pub const R_390_TLS_LDO32: u32 = 52;
+/// 64 bit offset relative to TLS block.
+pub const R_390_TLS_LDO64: u32 = 53;
+/// ID of module containing symbol.
+pub const R_390_TLS_DTPMOD: u32 = 54;
+/// Offset in TLS block.
+pub const R_390_TLS_DTPOFF: u32 = 55;
+/// Negated offset in static TLS block.
+pub const R_390_TLS_TPOFF: u32 = 56;
+/// Direct 20 bit.
+pub const R_390_20: u32 = 57;
+/// 20 bit GOT offset.
+pub const R_390_GOT20: u32 = 58;
+/// 20 bit offset to jump slot.
+pub const R_390_GOTPLT20: u32 = 59;
+/// 20 bit GOT offset for static TLS block offset.
+pub const R_390_TLS_GOTIE20: u32 = 60;
+/// STT_GNU_IFUNC relocation.
+pub const R_390_IRELATIVE: u32 = 61;
+pub const R_390_PC12DBL: u32 = 62;
+pub const R_390_PLT12DBL: u32 = 63;
+pub const R_390_PC24DBL: u32 = 64;
+pub const R_390_PLT24DBL: u32 = 65;
+
+/////////////////////
+// SPARC
+/////////////////////
+/// No reloc
+pub const R_SPARC_NONE: u32 = 0;
+/// Direct 8 bit
+pub const R_SPARC_8: u32 = 1;
+/// Direct 16 bit
+pub const R_SPARC_16: u32 = 2;
+/// Direct 32 bit
+pub const R_SPARC_32: u32 = 3;
+/// PC relative 8 bit
+pub const R_SPARC_DISP8: u32 = 4;
+/// PC relative 16 bit
+pub const R_SPARC_DISP16: u32 = 5;
+/// PC relative 32 bit
+pub const R_SPARC_DISP32: u32 = 6;
+/// PC relative 30 bit shifted
+pub const R_SPARC_WDISP30: u32 = 7;
+/// PC relative 22 bit shifted
+pub const R_SPARC_WDISP22: u32 = 8;
+/// High 22 bit
+pub const R_SPARC_HI22: u32 = 9;
+/// Direct 22 bit
+pub const R_SPARC_22: u32 = 10;
+/// Direct 13 bit
+pub const R_SPARC_13: u32 = 11;
+/// Truncated 10 bit
+pub const R_SPARC_LO10: u32 = 12;
+/// Truncated 10 bit GOT entry
+pub const R_SPARC_GOT10: u32 = 13;
+/// 13 bit GOT entry
+pub const R_SPARC_GOT13: u32 = 14;
+/// 22 bit GOT entry shifted
+pub const R_SPARC_GOT22: u32 = 15;
+/// PC relative 10 bit truncated
+pub const R_SPARC_PC10: u32 = 16;
+/// PC relative 22 bit shifted
+pub const R_SPARC_PC22: u32 = 17;
+/// 30 bit PC relative PLT address
+pub const R_SPARC_WPLT30: u32 = 18;
+/// Copy symbol at runtime
+pub const R_SPARC_COPY: u32 = 19;
+/// Create GOT entry
+pub const R_SPARC_GLOB_DAT: u32 = 20;
+/// Create PLT entry
+pub const R_SPARC_JMP_SLOT: u32 = 21;
+/// Adjust by program base
+pub const R_SPARC_RELATIVE: u32 = 22;
+/// Direct 32 bit unaligned
+pub const R_SPARC_UA32: u32 = 23;
+/// Direct 32 bit ref to PLT entry
+pub const R_SPARC_PLT32: u32 = 24;
+/// High 22 bit PLT entry
+pub const R_SPARC_HIPLT22: u32 = 25;
+/// Truncated 10 bit PLT entry
+pub const R_SPARC_LOPLT10: u32 = 26;
+/// PC rel 32 bit ref to PLT entry
+pub const R_SPARC_PCPLT32: u32 = 27;
+/// PC rel high 22 bit PLT entry
+pub const R_SPARC_PCPLT22: u32 = 28;
+/// PC rel trunc 10 bit PLT entry
+pub const R_SPARC_PCPLT10: u32 = 29;
+/// Direct 10 bit
+pub const R_SPARC_10: u32 = 30;
+/// Direct 11 bit
+pub const R_SPARC_11: u32 = 31;
+/// Direct 64 bit
+pub const R_SPARC_64: u32 = 32;
+/// 10bit with secondary 13bit addend
+pub const R_SPARC_OLO10: u32 = 33;
+/// Top 22 bits of direct 64 bit
+pub const R_SPARC_HH22: u32 = 34;
+/// High middle 10 bits of ...
+pub const R_SPARC_HM10: u32 = 35;
+/// Low middle 22 bits of ...
+pub const R_SPARC_LM22: u32 = 36;
+/// Top 22 bits of pc rel 64 bit
+pub const R_SPARC_PC_HH22: u32 = 37;
+/// High middle 10 bit of ...
+pub const R_SPARC_PC_HM10: u32 = 38;
+/// Low miggle 22 bits of ...
+pub const R_SPARC_PC_LM22: u32 = 39;
+/// PC relative 16 bit shifted
+pub const R_SPARC_WDISP16: u32 = 40;
+/// PC relative 19 bit shifted
+pub const R_SPARC_WDISP19: u32 = 41;
+/// Was part of v9 ABI but was removed
+pub const R_SPARC_GLOB_JMP: u32 = 42;
+/// Direct 7 bit
+pub const R_SPARC_7: u32 = 43;
+/// Direct 5 bit
+pub const R_SPARC_5: u32 = 44;
+/// Direct 6 bit
+pub const R_SPARC_6: u32 = 45;
+/// PC relative 64 bit
+pub const R_SPARC_DISP64: u32 = 46;
+/// Direct 64 bit ref to PLT entry
+pub const R_SPARC_PLT64: u32 = 47;
+/// High 22 bit complemented
+pub const R_SPARC_HIX22: u32 = 48;
+/// Truncated 11 bit complemented
+pub const R_SPARC_LOX10: u32 = 49;
+/// Direct high 12 of 44 bit
+pub const R_SPARC_H44: u32 = 50;
+/// Direct mid 22 of 44 bit
+pub const R_SPARC_M44: u32 = 51;
+/// Direct low 10 of 44 bit
+pub const R_SPARC_L44: u32 = 52;
+/// Global register usage
+pub const R_SPARC_REGISTER: u32 = 53;
+/// Direct 64 bit unaligned
+pub const R_SPARC_UA64: u32 = 54;
+/// Direct 16 bit unaligned
+pub const R_SPARC_UA16: u32 = 55;
+pub const R_SPARC_TLS_GD_HI22: u32 = 56;
+pub const R_SPARC_TLS_GD_LO10: u32 = 57;
+pub const R_SPARC_TLS_GD_ADD: u32 = 58;
+pub const R_SPARC_TLS_GD_CALL: u32 = 59;
+pub const R_SPARC_TLS_LDM_HI22: u32 = 60;
+pub const R_SPARC_TLS_LDM_LO10: u32 = 61;
+pub const R_SPARC_TLS_LDM_ADD: u32 = 62;
+pub const R_SPARC_TLS_LDM_CALL: u32 = 63;
+pub const R_SPARC_TLS_LDO_HIX22: u32 = 64;
+pub const R_SPARC_TLS_LDO_LOX10: u32 = 65;
+pub const R_SPARC_TLS_LDO_ADD: u32 = 66;
+pub const R_SPARC_TLS_IE_HI22: u32 = 67;
+pub const R_SPARC_TLS_IE_LO10: u32 = 68;
+pub const R_SPARC_TLS_IE_LD: u32 = 69;
+pub const R_SPARC_TLS_IE_LDX: u32 = 70;
+pub const R_SPARC_TLS_IE_ADD: u32 = 71;
+pub const R_SPARC_TLS_LE_HIX22: u32 = 72;
+pub const R_SPARC_TLS_LE_LOX10: u32 = 73;
+pub const R_SPARC_TLS_DTPMOD32: u32 = 74;
+pub const R_SPARC_TLS_DTPMOD64: u32 = 75;
+pub const R_SPARC_TLS_DTPOFF32: u32 = 76;
+pub const R_SPARC_TLS_DTPOFF64: u32 = 77;
+pub const R_SPARC_TLS_TPOFF32: u32 = 78;
+pub const R_SPARC_TLS_TPOFF64: u32 = 79;
+pub const R_SPARC_GOTDATA_HIX22: u32 = 80;
+pub const R_SPARC_GOTDATA_LOX10: u32 = 81;
+pub const R_SPARC_GOTDATA_OP_HIX22: u32 = 82;
+pub const R_SPARC_GOTDATA_OP_LOX10: u32 = 83;
+pub const R_SPARC_GOTDATA_OP: u32 = 84;
+pub const R_SPARC_H34: u32 = 85;
+pub const R_SPARC_SIZE32: u32 = 86;
+pub const R_SPARC_SIZE64: u32 = 87;
+pub const R_SPARC_WDISP10: u32 = 88;
+pub const R_SPARC_JMP_IREL: u32 = 248;
+pub const R_SPARC_IRELATIVE: u32 = 249;
+pub const R_SPARC_GNU_VTINHERIT: u32 = 250;
+pub const R_SPARC_GNU_VTENTRY: u32 = 251;
+pub const R_SPARC_REV32: u32 = 252;
 
 #[inline]
 pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
@@ -844,6 +1736,7 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         _ => "R_UNKNOWN_386",
         }},
         EM_X86_64 => { match typ {
+        R_X86_64_NONE => "X86_64_NONE",
         R_X86_64_64 => "X86_64_64",
         R_X86_64_PC32 => "X86_64_PC32",
         R_X86_64_GOT32 => "X86_64_GOT32",
@@ -882,8 +1775,8 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         R_X86_64_TLSDESC => "X86_64_TLSDESC",
         R_X86_64_IRELATIVE => "X86_64_IRELATIVE",
         R_X86_64_RELATIVE64 => "X86_64_RELATIVE64",
-        R_X86_64_GOTPCRELX => "R_X86_64_GOTPCRELX",
-        R_X86_64_REX_GOTPCRELX => "R_X86_64_REX_GOTPCRELX",
+        R_X86_64_GOTPCRELX => "X86_64_GOTPCRELX",
+        R_X86_64_REX_GOTPCRELX => "X86_64_REX_GOTPCRELX",
         _ => "R_UNKNOWN_X86_64",
         }},
         // openrisc
@@ -927,6 +1820,7 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         }},
         // arm64
         EM_AARCH64 => { match typ {
+        R_AARCH64_NONE => "AARCH64_NONE",
         R_AARCH64_P32_ABS32 => "AARCH64_P32_ABS32",
         R_AARCH64_P32_COPY => "AARCH64_P32_COPY",
         R_AARCH64_P32_GLOB_DAT => "AARCH64_P32_GLOB_DAT",
@@ -1063,6 +1957,7 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         }},
         // arm
         EM_ARM => { match typ {
+        R_ARM_NONE => "ARM_NONE",
         R_ARM_PC24 => "ARM_PC24",
         R_ARM_ABS32 => "ARM_ABS32",
         R_ARM_REL32 => "ARM_REL32",
@@ -1190,58 +2085,688 @@ pub fn r_to_str(typ: u32, machine: u16) -> &'static str {
         }},
         // MIPS
         EM_MIPS | EM_MIPS_RS3_LE | EM_MIPS_X => { match typ {
-        R_MIPS_NONE => "R_MIPS_NONE",
-        R_MIPS_16 => "R_MIPS_16",
-        R_MIPS_32 => "R_MIPS_32",
-        R_MIPS_REL32 => "R_MIPS_REL32",
-        R_MIPS_26 => "R_MIPS_26",
-        R_MIPS_HI16 => "R_MIPS_HI16",
-        R_MIPS_LO16 => "R_MIPS_LO16",
-        R_MIPS_GPREL16 => "R_MIPS_GPREL16",
-        R_MIPS_LITERAL => "R_MIPS_LITERAL",
-        R_MIPS_GOT16 => "R_MIPS_GOT16",
-        R_MIPS_PC16 => "R_MIPS_PC16",
-        R_MIPS_CALL16 => "R_MIPS_CALL16",
-        R_MIPS_GPREL32 => "R_MIPS_GPREL32",
-        R_MIPS_SHIFT5 => "R_MIPS_SHIFT5",
-        R_MIPS_SHIFT6 => "R_MIPS_SHIFT6",
-        R_MIPS_64 => "R_MIPS_64",
-        R_MIPS_GOT_DISP => "R_MIPS_GOT_DISP",
-        R_MIPS_GOT_PAGE => "R_MIPS_GOT_PAGE",
-        R_MIPS_GOT_OFST => "R_MIPS_GOT_OFST",
-        R_MIPS_GOT_HI16 => "R_MIPS_GOT_HI16",
-        R_MIPS_GOT_LO16 => "R_MIPS_GOT_LO16",
-        R_MIPS_SUB => "R_MIPS_SUB",
-        R_MIPS_INSERT_A => "R_MIPS_INSERT_A",
-        R_MIPS_INSERT_B => "R_MIPS_INSERT_B",
-        R_MIPS_DELETE => "R_MIPS_DELETE",
-        R_MIPS_HIGHER => "R_MIPS_HIGHER",
-        R_MIPS_HIGHEST => "R_MIPS_HIGHEST",
-        R_MIPS_CALL_HI16 => "R_MIPS_CALL_HI16",
-        R_MIPS_CALL_LO16 => "R_MIPS_CALL_LO16",
-        R_MIPS_SCN_DISP => "R_MIPS_SCN_DISP",
-        R_MIPS_REL16 => "R_MIPS_REL16",
-        R_MIPS_ADD_IMMEDIATE => "R_MIPS_ADD_IMMEDIATE",
-        R_MIPS_PJUMP => "R_MIPS_PJUMP",
-        R_MIPS_RELGOT => "R_MIPS_RELGOT",
-        R_MIPS_JALR => "R_MIPS_JALR",
-        R_MIPS_TLS_DTPMOD32 => "R_MIPS_TLS_DTPMOD32",
-        R_MIPS_TLS_DTPREL32 => "R_MIPS_TLS_DTPREL32",
-        R_MIPS_TLS_DTPMOD64 => "R_MIPS_TLS_DTPMOD64",
-        R_MIPS_TLS_DTPREL64 => "R_MIPS_TLS_DTPREL64",
-        R_MIPS_TLS_GD => "R_MIPS_TLS_GD",
-        R_MIPS_TLS_LDM => "R_MIPS_TLS_LDM",
-        R_MIPS_TLS_DTPREL_HI16 => "R_MIPS_TLS_DTPREL_HI16",
-        R_MIPS_TLS_DTPREL_LO16 => "R_MIPS_TLS_DTPREL_LO16",
-        R_MIPS_TLS_GOTTPREL => "R_MIPS_TLS_GOTTPREL",
-        R_MIPS_TLS_TPREL32 => "R_MIPS_TLS_TPREL32",
-        R_MIPS_TLS_TPREL64 => "R_MIPS_TLS_TPREL64",
-        R_MIPS_TLS_TPREL_HI16 => "R_MIPS_TLS_TPREL_HI16",
-        R_MIPS_TLS_TPREL_LO16 => "R_MIPS_TLS_TPREL_LO16",
-        R_MIPS_GLOB_DAT => "R_MIPS_GLOB_DAT",
-        R_MIPS_COPY => "R_MIPS_COPY",
-        R_MIPS_JUMP_SLOT => "R_MIPS_JUMP_SLOT",
+        R_MIPS_NONE => "MIPS_NONE",
+        R_MIPS_16 => "MIPS_16",
+        R_MIPS_32 => "MIPS_32",
+        R_MIPS_REL32 => "MIPS_REL32",
+        R_MIPS_26 => "MIPS_26",
+        R_MIPS_HI16 => "MIPS_HI16",
+        R_MIPS_LO16 => "MIPS_LO16",
+        R_MIPS_GPREL16 => "MIPS_GPREL16",
+        R_MIPS_LITERAL => "MIPS_LITERAL",
+        R_MIPS_GOT16 => "MIPS_GOT16",
+        R_MIPS_PC16 => "MIPS_PC16",
+        R_MIPS_CALL16 => "MIPS_CALL16",
+        R_MIPS_GPREL32 => "MIPS_GPREL32",
+        R_MIPS_SHIFT5 => "MIPS_SHIFT5",
+        R_MIPS_SHIFT6 => "MIPS_SHIFT6",
+        R_MIPS_64 => "MIPS_64",
+        R_MIPS_GOT_DISP => "MIPS_GOT_DISP",
+        R_MIPS_GOT_PAGE => "MIPS_GOT_PAGE",
+        R_MIPS_GOT_OFST => "MIPS_GOT_OFST",
+        R_MIPS_GOT_HI16 => "MIPS_GOT_HI16",
+        R_MIPS_GOT_LO16 => "MIPS_GOT_LO16",
+        R_MIPS_SUB => "MIPS_SUB",
+        R_MIPS_INSERT_A => "MIPS_INSERT_A",
+        R_MIPS_INSERT_B => "MIPS_INSERT_B",
+        R_MIPS_DELETE => "MIPS_DELETE",
+        R_MIPS_HIGHER => "MIPS_HIGHER",
+        R_MIPS_HIGHEST => "MIPS_HIGHEST",
+        R_MIPS_CALL_HI16 => "MIPS_CALL_HI16",
+        R_MIPS_CALL_LO16 => "MIPS_CALL_LO16",
+        R_MIPS_SCN_DISP => "MIPS_SCN_DISP",
+        R_MIPS_REL16 => "MIPS_REL16",
+        R_MIPS_ADD_IMMEDIATE => "MIPS_ADD_IMMEDIATE",
+        R_MIPS_PJUMP => "MIPS_PJUMP",
+        R_MIPS_RELGOT => "MIPS_RELGOT",
+        R_MIPS_JALR => "MIPS_JALR",
+        R_MIPS_TLS_DTPMOD32 => "MIPS_TLS_DTPMOD32",
+        R_MIPS_TLS_DTPREL32 => "MIPS_TLS_DTPREL32",
+        R_MIPS_TLS_DTPMOD64 => "MIPS_TLS_DTPMOD64",
+        R_MIPS_TLS_DTPREL64 => "MIPS_TLS_DTPREL64",
+        R_MIPS_TLS_GD => "MIPS_TLS_GD",
+        R_MIPS_TLS_LDM => "MIPS_TLS_LDM",
+        R_MIPS_TLS_DTPREL_HI16 => "MIPS_TLS_DTPREL_HI16",
+        R_MIPS_TLS_DTPREL_LO16 => "MIPS_TLS_DTPREL_LO16",
+        R_MIPS_TLS_GOTTPREL => "MIPS_TLS_GOTTPREL",
+        R_MIPS_TLS_TPREL32 => "MIPS_TLS_TPREL32",
+        R_MIPS_TLS_TPREL64 => "MIPS_TLS_TPREL64",
+        R_MIPS_TLS_TPREL_HI16 => "MIPS_TLS_TPREL_HI16",
+        R_MIPS_TLS_TPREL_LO16 => "MIPS_TLS_TPREL_LO16",
+        R_MIPS_GLOB_DAT => "MIPS_GLOB_DAT",
+        R_MIPS_PC21_S2 => "MIPS_PC21_S2",
+        R_MIPS_PC26_S2 => "MIPS_PC26_S2",
+        R_MIPS_PC18_S3 => "MIPS_PC18_S3",
+        R_MIPS_PC19_S2 => "MIPS_PC19_S2",
+        R_MIPS_PCHI16 => "MIPS_PCHI16",
+        R_MIPS_PCLO16 => "MIPS_PCLO16",
+        R_MIPS_COPY => "MIPS_COPY",
+        R_MIPS_JUMP_SLOT => "MIPS_JUMP_SLOT",
+        R_MIPS_PC32 => "MIPS_PC32",
+        R_MIPS_EH => "MIPS_EH",
+        R_MIPS_GNU_REL16_S2 => "MIPS_GNU_REL16_S2",
+        R_MIPS_GNU_VTINHERIT => "MIPS_GNU_VTINHERIT",
+        R_MIPS_GNU_VTENTRY => "MIPS_GNU_VTENTRY",
         _ => "R_UNKNOWN_MIPS",
-        }} _ => "R_UNKNOWN"
+        }},
+        // RISC-V
+        EM_RISCV => { match typ {
+        R_RISCV_NONE => "RISCV_NONE",
+        R_RISCV_32 => "RISCV_32",
+        R_RISCV_64 => "RISCV_64",
+        R_RISCV_RELATIVE => "RISCV_RELATIVE",
+        R_RISCV_COPY => "RISCV_COPY",
+        R_RISCV_JUMP_SLOT => "RISCV_JUMP_SLOT",
+        R_RISCV_TLS_DTPMOD32 => "RISCV_TLS_DTPMOD32",
+        R_RISCV_TLS_DTPMOD64 => "RISCV_TLS_DTPMOD64",
+        R_RISCV_TLS_DTPREL32 => "RISCV_TLS_DTPREL32",
+        R_RISCV_TLS_DTPREL64 => "RISCV_TLS_DTPREL64",
+        R_RISCV_TLS_TPREL32 => "RISCV_TLS_TPREL32",
+        R_RISCV_TLS_TPREL64 => "RISCV_TLS_TPREL64",
+        R_RISCV_TLSDESC => "RISCV_TLSDESC",
+        R_RISCV_BRANCH => "RISCV_BRANCH",
+        R_RISCV_JAL => "RISCV_JAL",
+        R_RISCV_CALL => "RISCV_CALL",
+        R_RISCV_CALL_PLT => "RISCV_CALL_PLT",
+        R_RISCV_GOT_HI20 => "RISCV_GOT_HI20",
+        R_RISCV_TLS_GOT_HI20 => "RISCV_TLS_GOT_HI20",
+        R_RISCV_TLS_GD_HI20 => "RISCV_TLS_GD_HI20",
+        R_RISCV_PCREL_HI20 => "RISCV_PCREL_HI20",
+        R_RISCV_PCREL_LO12_I => "RISCV_PCREL_LO12_I",
+        R_RISCV_PCREL_LO12_S => "RISCV_PCREL_LO12_S",
+        R_RISCV_HI20 => "RISCV_HI20",
+        R_RISCV_LO12_I => "RISCV_LO12_I",
+        R_RISCV_LO12_S => "RISCV_LO12_S",
+        R_RISCV_TPREL_HI20 => "RISCV_TPREL_HI20",
+        R_RISCV_TPREL_LO12_I => "RISCV_TPREL_LO12_I",
+        R_RISCV_TPREL_LO12_S => "RISCV_TPREL_LO12_S",
+        R_RISCV_TPREL_ADD => "RISCV_TPREL_ADD",
+        R_RISCV_ADD8 => "RISCV_ADD8",
+        R_RISCV_ADD16 => "RISCV_ADD16",
+        R_RISCV_ADD32 => "RISCV_ADD32",
+        R_RISCV_ADD64 => "RISCV_ADD64",
+        R_RISCV_SUB8 => "RISCV_SUB8",
+        R_RISCV_SUB16 => "RISCV_SUB16",
+        R_RISCV_SUB32 => "RISCV_SUB32",
+        R_RISCV_SUB64 => "RISCV_SUB64",
+        R_RISCV_GNU_VTINHERIT => "RISCV_GNU_VTINHERIT",
+        R_RISCV_GNU_VTENTRY => "RISCV_GNU_VTENTRY",
+        R_RISCV_ALIGN => "RISCV_ALIGN",
+        R_RISCV_RVC_BRANCH => "RISCV_RVC_BRANCH",
+        R_RISCV_RVC_JUMP => "RISCV_RVC_JUMP",
+        R_RISCV_RVC_LUI => "RISCV_RVC_LUI",
+        R_RISCV_GPREL_I => "RISCV_GPREL_I",
+        R_RISCV_GPREL_S => "RISCV_GPREL_S",
+        R_RISCV_TPREL_I => "RISCV_TPREL_I",
+        R_RISCV_TPREL_S => "RISCV_TPREL_S",
+        R_RISCV_RELAX => "RISCV_RELAX",
+        R_RISCV_SUB6 => "RISCV_SUB6",
+        R_RISCV_SET6 => "RISCV_SET6",
+        R_RISCV_SET8 => "RISCV_SET8",
+        R_RISCV_SET16 => "RISCV_SET16",
+        R_RISCV_SET32 => "RISCV_SET32",
+        R_RISCV_32_PCREL => "RISCV_32_PCREL",
+        R_RISCV_IRELATIVE => "RISCV_IRELATIVE",
+        R_RISCV_PLT32 => "RISCV_PLT32",
+        R_RISCV_SET_ULEB128 => "RISCV_SET_ULEB128",
+        R_RISCV_SUB_ULEB128 => "RISCV_SUB_ULEB128",
+        R_RISCV_TLSDESC_HI20 => "RISCV_TLSDESC_HI20",
+        R_RISCV_TLSDESC_LOAD_LO12 => "RISCV_TLSDESC_LOAD_LO12",
+        R_RISCV_TLSDESC_ADD_LO12 => "RISCV_TLSDESC_ADD_LO12",
+        R_RISCV_TLSDESC_CALL => "RISCV_TLSDESC_CALL",
+        _ => "R_UNKNOWN_RISCV",
+        }},
+        // LoongArch
+        EM_LOONGARCH => { match typ {
+        R_LARCH_NONE => "LARCH_NONE",
+        R_LARCH_32 => "LARCH_32",
+        R_LARCH_64 => "LARCH_64",
+        R_LARCH_RELATIVE => "LARCH_RELATIVE",
+        R_LARCH_COPY => "LARCH_COPY",
+        R_LARCH_JUMP_SLOT => "LARCH_JUMP_SLOT",
+        R_LARCH_TLS_DTPMOD32 => "LARCH_TLS_DTPMOD32",
+        R_LARCH_TLS_DTPMOD64 => "LARCH_TLS_DTPMOD64",
+        R_LARCH_TLS_DTPREL32 => "LARCH_TLS_DTPREL32",
+        R_LARCH_TLS_DTPREL64 => "LARCH_TLS_DTPREL64",
+        R_LARCH_TLS_TPREL32 => "LARCH_TLS_TPREL32",
+        R_LARCH_TLS_TPREL64 => "LARCH_TLS_TPREL64",
+        R_LARCH_IRELATIVE => "LARCH_IRELATIVE",
+        R_LARCH_TLS_DESC32 => "LARCH_TLS_DESC32",
+        R_LARCH_TLS_DESC64 => "LARCH_TLS_DESC64",
+        R_LARCH_MARK_LA => "LARCH_MARK_LA",
+        R_LARCH_MARK_PCREL => "LARCH_MARK_PCREL",
+        R_LARCH_SOP_PUSH_PCREL => "LARCH_SOP_PUSH_PCREL",
+        R_LARCH_SOP_PUSH_ABSOLUTE => "LARCH_SOP_PUSH_ABSOLUTE",
+        R_LARCH_SOP_PUSH_DUP => "LARCH_SOP_PUSH_DUP",
+        R_LARCH_SOP_PUSH_GPREL => "LARCH_SOP_PUSH_GPREL",
+        R_LARCH_SOP_PUSH_TLS_TPREL => "LARCH_SOP_PUSH_TLS_TPREL",
+        R_LARCH_SOP_PUSH_TLS_GOT => "LARCH_SOP_PUSH_TLS_GOT",
+        R_LARCH_SOP_PUSH_TLS_GD => "LARCH_SOP_PUSH_TLS_GD",
+        R_LARCH_SOP_PUSH_PLT_PCREL => "LARCH_SOP_PUSH_PLT_PCREL",
+        R_LARCH_SOP_ASSERT => "LARCH_SOP_ASSERT",
+        R_LARCH_SOP_NOT => "LARCH_SOP_NOT",
+        R_LARCH_SOP_SUB => "LARCH_SOP_SUB",
+        R_LARCH_SOP_SL => "LARCH_SOP_SL",
+        R_LARCH_SOP_SR => "LARCH_SOP_SR",
+        R_LARCH_SOP_ADD => "LARCH_SOP_ADD",
+        R_LARCH_SOP_AND => "LARCH_SOP_AND",
+        R_LARCH_SOP_IF_ELSE => "LARCH_SOP_IF_ELSE",
+        R_LARCH_SOP_POP_32_S_10_5 => "LARCH_SOP_POP_32_S_10_5",
+        R_LARCH_SOP_POP_32_U_10_12 => "LARCH_SOP_POP_32_U_10_12",
+        R_LARCH_SOP_POP_32_S_10_12 => "LARCH_SOP_POP_32_S_10_12",
+        R_LARCH_SOP_POP_32_S_10_16 => "LARCH_SOP_POP_32_S_10_16",
+        R_LARCH_SOP_POP_32_S_10_16_S2 => "LARCH_SOP_POP_32_S_10_16_S2",
+        R_LARCH_SOP_POP_32_S_5_20 => "LARCH_SOP_POP_32_S_5_20",
+        R_LARCH_SOP_POP_32_S_0_5_10_16_S2 => "LARCH_SOP_POP_32_S_0_5_10_16_S2",
+        R_LARCH_SOP_POP_32_S_0_10_10_16_S2 => "LARCH_SOP_POP_32_S_0_10_10_16_S2",
+        R_LARCH_SOP_POP_32_U => "LARCH_SOP_POP_32_U",
+        R_LARCH_ADD8 => "LARCH_ADD8",
+        R_LARCH_ADD16 => "LARCH_ADD16",
+        R_LARCH_ADD24 => "LARCH_ADD24",
+        R_LARCH_ADD32 => "LARCH_ADD32",
+        R_LARCH_ADD64 => "LARCH_ADD64",
+        R_LARCH_SUB8 => "LARCH_SUB8",
+        R_LARCH_SUB16 => "LARCH_SUB16",
+        R_LARCH_SUB24 => "LARCH_SUB24",
+        R_LARCH_SUB32 => "LARCH_SUB32",
+        R_LARCH_SUB64 => "LARCH_SUB64",
+        R_LARCH_GNU_VTINHERIT => "LARCH_GNU_VTINHERIT",
+        R_LARCH_GNU_VTENTRY => "LARCH_GNU_VTENTRY",
+        R_LARCH_B16 => "LARCH_B16",
+        R_LARCH_B21 => "LARCH_B21",
+        R_LARCH_B26 => "LARCH_B26",
+        R_LARCH_ABS_HI20 => "LARCH_ABS_HI20",
+        R_LARCH_ABS_LO12 => "LARCH_ABS_LO12",
+        R_LARCH_ABS64_LO20 => "LARCH_ABS64_LO20",
+        R_LARCH_ABS64_HI12 => "LARCH_ABS64_HI12",
+        R_LARCH_PCALA_HI20 => "LARCH_PCALA_HI20",
+        R_LARCH_PCALA_LO12 => "LARCH_PCALA_LO12",
+        R_LARCH_PCALA64_LO20 => "LARCH_PCALA64_LO20",
+        R_LARCH_PCALA64_HI12 => "LARCH_PCALA64_HI12",
+        R_LARCH_GOT_PC_HI20 => "LARCH_GOT_PC_HI20",
+        R_LARCH_GOT_PC_LO12 => "LARCH_GOT_PC_LO12",
+        R_LARCH_GOT64_PC_LO20 => "LARCH_GOT64_PC_LO20",
+        R_LARCH_GOT64_PC_HI12 => "LARCH_GOT64_PC_HI12",
+        R_LARCH_GOT_HI20 => "LARCH_GOT_HI20",
+        R_LARCH_GOT_LO12 => "LARCH_GOT_LO12",
+        R_LARCH_GOT64_LO20 => "LARCH_GOT64_LO20",
+        R_LARCH_GOT64_HI12 => "LARCH_GOT64_HI12",
+        R_LARCH_TLS_LE_HI20 => "LARCH_TLS_LE_HI20",
+        R_LARCH_TLS_LE_LO12 => "LARCH_TLS_LE_LO12",
+        R_LARCH_TLS_LE64_LO20 => "LARCH_TLS_LE64_LO20",
+        R_LARCH_TLS_LE64_HI12 => "LARCH_TLS_LE64_HI12",
+        R_LARCH_TLS_IE_PC_HI20 => "LARCH_TLS_IE_PC_HI20",
+        R_LARCH_TLS_IE_PC_LO12 => "LARCH_TLS_IE_PC_LO12",
+        R_LARCH_TLS_IE64_PC_LO20 => "LARCH_TLS_IE64_PC_LO20",
+        R_LARCH_TLS_IE64_PC_HI12 => "LARCH_TLS_IE64_PC_HI12",
+        R_LARCH_TLS_IE_HI20 => "LARCH_TLS_IE_HI20",
+        R_LARCH_TLS_IE_LO12 => "LARCH_TLS_IE_LO12",
+        R_LARCH_TLS_IE64_LO20 => "LARCH_TLS_IE64_LO20",
+        R_LARCH_TLS_IE64_HI12 => "LARCH_TLS_IE64_HI12",
+        R_LARCH_TLS_LD_PC_HI20 => "LARCH_TLS_LD_PC_HI20",
+        R_LARCH_TLS_LD_HI20 => "LARCH_TLS_LD_HI20",
+        R_LARCH_TLS_GD_PC_HI20 => "LARCH_TLS_GD_PC_HI20",
+        R_LARCH_TLS_GD_HI20 => "LARCH_TLS_GD_HI20",
+        R_LARCH_32_PCREL => "LARCH_32_PCREL",
+        R_LARCH_RELAX => "LARCH_RELAX",
+        R_LARCH_ALIGN => "LARCH_ALIGN",
+        R_LARCH_PCREL20_S2 => "LARCH_PCREL20_S2",
+        R_LARCH_ADD6 => "LARCH_ADD6",
+        R_LARCH_SUB6 => "LARCH_SUB6",
+        R_LARCH_ADD_ULEB128 => "LARCH_ADD_ULEB128",
+        R_LARCH_SUB_ULEB128 => "LARCH_SUB_ULEB128",
+        R_LARCH_64_PCREL => "LARCH_64_PCREL",
+        R_LARCH_CALL36 => "LARCH_CALL36",
+        R_LARCH_TLS_DESC_PC_HI20 => "LARCH_TLS_DESC_PC_HI20",
+        R_LARCH_TLS_DESC_PC_LO12 => "LARCH_TLS_DESC_PC_LO12",
+        R_LARCH_TLS_DESC64_PC_LO20 => "LARCH_TLS_DESC64_PC_LO20",
+        R_LARCH_TLS_DESC64_PC_HI12 => "LARCH_TLS_DESC64_PC_HI12",
+        R_LARCH_TLS_DESC_HI20 => "LARCH_TLS_DESC_HI20",
+        R_LARCH_TLS_DESC_LO12 => "LARCH_TLS_DESC_LO12",
+        R_LARCH_TLS_DESC64_LO20 => "LARCH_TLS_DESC64_LO20",
+        R_LARCH_TLS_DESC64_HI12 => "LARCH_TLS_DESC64_HI12",
+        R_LARCH_TLS_DESC_LD => "LARCH_TLS_DESC_LD",
+        R_LARCH_TLS_DESC_CALL => "LARCH_TLS_DESC_CALL",
+        R_LARCH_TLS_LE_HI20_R => "LARCH_TLS_LE_HI20_R",
+        R_LARCH_TLS_LE_ADD_R => "LARCH_TLS_LE_ADD_R",
+        R_LARCH_TLS_LE_LO12_R => "LARCH_TLS_LE_LO12_R",
+        R_LARCH_TLS_LD_PCREL20_S2 => "LARCH_TLS_LD_PCREL20_S2",
+        R_LARCH_TLS_GD_PCREL20_S2 => "LARCH_TLS_GD_PCREL20_S2",
+        R_LARCH_TLS_DESC_PCREL20_S2 => "LARCH_TLS_DESC_PCREL20_S2",
+        _ => "R_UNKNOWN_LARCH",
+        }},
+        // PowerPC
+        EM_PPC => { match typ {
+        R_PPC_NONE => "PPC_NONE",
+        R_PPC_ADDR32 => "PPC_ADDR32",
+        R_PPC_ADDR24 => "PPC_ADDR24",
+        R_PPC_ADDR16 => "PPC_ADDR16",
+        R_PPC_ADDR16_LO => "PPC_ADDR16_LO",
+        R_PPC_ADDR16_HI => "PPC_ADDR16_HI",
+        R_PPC_ADDR16_HA => "PPC_ADDR16_HA",
+        R_PPC_ADDR14 => "PPC_ADDR14",
+        R_PPC_ADDR14_BRTAKEN => "PPC_ADDR14_BRTAKEN",
+        R_PPC_ADDR14_BRNTAKEN => "PPC_ADDR14_BRNTAKEN",
+        R_PPC_REL24 => "PPC_REL24",
+        R_PPC_REL14 => "PPC_REL14",
+        R_PPC_REL14_BRTAKEN => "PPC_REL14_BRTAKEN",
+        R_PPC_REL14_BRNTAKEN => "PPC_REL14_BRNTAKEN",
+        R_PPC_GOT16 => "PPC_GOT16",
+        R_PPC_GOT16_LO => "PPC_GOT16_LO",
+        R_PPC_GOT16_HI => "PPC_GOT16_HI",
+        R_PPC_GOT16_HA => "PPC_GOT16_HA",
+        R_PPC_PLTREL24 => "PPC_PLTREL24",
+        R_PPC_COPY => "PPC_COPY",
+        R_PPC_GLOB_DAT => "PPC_GLOB_DAT",
+        R_PPC_JMP_SLOT => "PPC_JMP_SLOT",
+        R_PPC_RELATIVE => "PPC_RELATIVE",
+        R_PPC_LOCAL24PC => "PPC_LOCAL24PC",
+        R_PPC_UADDR32 => "PPC_UADDR32",
+        R_PPC_UADDR16 => "PPC_UADDR16",
+        R_PPC_REL32 => "PPC_REL32",
+        R_PPC_PLT32 => "PPC_PLT32",
+        R_PPC_PLTREL32 => "PPC_PLTREL32",
+        R_PPC_PLT16_LO => "PPC_PLT16_LO",
+        R_PPC_PLT16_HI => "PPC_PLT16_HI",
+        R_PPC_PLT16_HA => "PPC_PLT16_HA",
+        R_PPC_SDAREL16 => "PPC_SDAREL16",
+        R_PPC_SECTOFF => "PPC_SECTOFF",
+        R_PPC_SECTOFF_LO => "PPC_SECTOFF_LO",
+        R_PPC_SECTOFF_HI => "PPC_SECTOFF_HI",
+        R_PPC_SECTOFF_HA => "PPC_SECTOFF_HA",
+        R_PPC_TLS => "PPC_TLS",
+        R_PPC_DTPMOD32 => "PPC_DTPMOD32",
+        R_PPC_TPREL16 => "PPC_TPREL16",
+        R_PPC_TPREL16_LO => "PPC_TPREL16_LO",
+        R_PPC_TPREL16_HI => "PPC_TPREL16_HI",
+        R_PPC_TPREL16_HA => "PPC_TPREL16_HA",
+        R_PPC_TPREL32 => "PPC_TPREL32",
+        R_PPC_DTPREL16 => "PPC_DTPREL16",
+        R_PPC_DTPREL16_LO => "PPC_DTPREL16_LO",
+        R_PPC_DTPREL16_HI => "PPC_DTPREL16_HI",
+        R_PPC_DTPREL16_HA => "PPC_DTPREL16_HA",
+        R_PPC_DTPREL32 => "PPC_DTPREL32",
+        R_PPC_GOT_TLSGD16 => "PPC_GOT_TLSGD16",
+        R_PPC_GOT_TLSGD16_LO => "PPC_GOT_TLSGD16_LO",
+        R_PPC_GOT_TLSGD16_HI => "PPC_GOT_TLSGD16_HI",
+        R_PPC_GOT_TLSGD16_HA => "PPC_GOT_TLSGD16_HA",
+        R_PPC_GOT_TLSLD16 => "PPC_GOT_TLSLD16",
+        R_PPC_GOT_TLSLD16_LO => "PPC_GOT_TLSLD16_LO",
+        R_PPC_GOT_TLSLD16_HI => "PPC_GOT_TLSLD16_HI",
+        R_PPC_GOT_TLSLD16_HA => "PPC_GOT_TLSLD16_HA",
+        R_PPC_GOT_TPREL16 => "PPC_GOT_TPREL16",
+        R_PPC_GOT_TPREL16_LO => "PPC_GOT_TPREL16_LO",
+        R_PPC_GOT_TPREL16_HI => "PPC_GOT_TPREL16_HI",
+        R_PPC_GOT_TPREL16_HA => "PPC_GOT_TPREL16_HA",
+        R_PPC_GOT_DTPREL16 => "PPC_GOT_DTPREL16",
+        R_PPC_GOT_DTPREL16_LO => "PPC_GOT_DTPREL16_LO",
+        R_PPC_GOT_DTPREL16_HI => "PPC_GOT_DTPREL16_HI",
+        R_PPC_GOT_DTPREL16_HA => "PPC_GOT_DTPREL16_HA",
+        R_PPC_TLSGD => "PPC_TLSGD",
+        R_PPC_TLSLD => "PPC_TLSLD",
+        R_PPC_EMB_NADDR32 => "PPC_EMB_NADDR32",
+        R_PPC_EMB_NADDR16 => "PPC_EMB_NADDR16",
+        R_PPC_EMB_NADDR16_LO => "PPC_EMB_NADDR16_LO",
+        R_PPC_EMB_NADDR16_HI => "PPC_EMB_NADDR16_HI",
+        R_PPC_EMB_NADDR16_HA => "PPC_EMB_NADDR16_HA",
+        R_PPC_EMB_SDAI16 => "PPC_EMB_SDAI16",
+        R_PPC_EMB_SDA2I16 => "PPC_EMB_SDA2I16",
+        R_PPC_EMB_SDA2REL => "PPC_EMB_SDA2REL",
+        R_PPC_EMB_SDA21 => "PPC_EMB_SDA21",
+        R_PPC_EMB_MRKREF => "PPC_EMB_MRKREF",
+        R_PPC_EMB_RELSEC16 => "PPC_EMB_RELSEC16",
+        R_PPC_EMB_RELST_LO => "PPC_EMB_RELST_LO",
+        R_PPC_EMB_RELST_HI => "PPC_EMB_RELST_HI",
+        R_PPC_EMB_RELST_HA => "PPC_EMB_RELST_HA",
+        R_PPC_EMB_BIT_FLD => "PPC_EMB_BIT_FLD",
+        R_PPC_EMB_RELSDA => "PPC_EMB_RELSDA",
+        R_PPC_DIAB_SDA21_LO => "PPC_DIAB_SDA21_LO",
+        R_PPC_DIAB_SDA21_HI => "PPC_DIAB_SDA21_HI",
+        R_PPC_DIAB_SDA21_HA => "PPC_DIAB_SDA21_HA",
+        R_PPC_DIAB_RELSDA_LO => "PPC_DIAB_RELSDA_LO",
+        R_PPC_DIAB_RELSDA_HI => "PPC_DIAB_RELSDA_HI",
+        R_PPC_DIAB_RELSDA_HA => "PPC_DIAB_RELSDA_HA",
+        R_PPC_IRELATIVE => "PPC_IRELATIVE",
+        R_PPC_REL16 => "PPC_REL16",
+        R_PPC_REL16_LO => "PPC_REL16_LO",
+        R_PPC_REL16_HI => "PPC_REL16_HI",
+        R_PPC_REL16_HA => "PPC_REL16_HA",
+        R_PPC_TOC16 => "PPC_TOC16",
+        _ => "R_UNKNOWN_PPC",
+        }},
+        // PowerPC64
+        EM_PPC64 => { match typ {
+        R_PPC64_NONE => "PPC64_NONE",
+        R_PPC64_ADDR32 => "PPC64_ADDR32",
+        R_PPC64_ADDR24 => "PPC64_ADDR24",
+        R_PPC64_ADDR16 => "PPC64_ADDR16",
+        R_PPC64_ADDR16_LO => "PPC64_ADDR16_LO",
+        R_PPC64_ADDR16_HI => "PPC64_ADDR16_HI",
+        R_PPC64_ADDR16_HA => "PPC64_ADDR16_HA",
+        R_PPC64_ADDR14 => "PPC64_ADDR14",
+        R_PPC64_ADDR14_BRTAKEN => "PPC64_ADDR14_BRTAKEN",
+        R_PPC64_ADDR14_BRNTAKEN => "PPC64_ADDR14_BRNTAKEN",
+        R_PPC64_REL24 => "PPC64_REL24",
+        R_PPC64_REL14 => "PPC64_REL14",
+        R_PPC64_REL14_BRTAKEN => "PPC64_REL14_BRTAKEN",
+        R_PPC64_REL14_BRNTAKEN => "PPC64_REL14_BRNTAKEN",
+        R_PPC64_GOT16 => "PPC64_GOT16",
+        R_PPC64_GOT16_LO => "PPC64_GOT16_LO",
+        R_PPC64_GOT16_HI => "PPC64_GOT16_HI",
+        R_PPC64_GOT16_HA => "PPC64_GOT16_HA",
+        R_PPC64_COPY => "PPC64_COPY",
+        R_PPC64_GLOB_DAT => "PPC64_GLOB_DAT",
+        R_PPC64_JMP_SLOT => "PPC64_JMP_SLOT",
+        R_PPC64_RELATIVE => "PPC64_RELATIVE",
+        R_PPC64_UADDR32 => "PPC64_UADDR32",
+        R_PPC64_UADDR16 => "PPC64_UADDR16",
+        R_PPC64_REL32 => "PPC64_REL32",
+        R_PPC64_PLT32 => "PPC64_PLT32",
+        R_PPC64_PLTREL32 => "PPC64_PLTREL32",
+        R_PPC64_PLT16_LO => "PPC64_PLT16_LO",
+        R_PPC64_PLT16_HI => "PPC64_PLT16_HI",
+        R_PPC64_PLT16_HA => "PPC64_PLT16_HA",
+        R_PPC64_SECTOFF => "PPC64_SECTOFF",
+        R_PPC64_SECTOFF_LO => "PPC64_SECTOFF_LO",
+        R_PPC64_SECTOFF_HI => "PPC64_SECTOFF_HI",
+        R_PPC64_SECTOFF_HA => "PPC64_SECTOFF_HA",
+        R_PPC64_ADDR30 => "PPC64_ADDR30",
+        R_PPC64_ADDR64 => "PPC64_ADDR64",
+        R_PPC64_ADDR16_HIGHER => "PPC64_ADDR16_HIGHER",
+        R_PPC64_ADDR16_HIGHERA => "PPC64_ADDR16_HIGHERA",
+        R_PPC64_ADDR16_HIGHEST => "PPC64_ADDR16_HIGHEST",
+        R_PPC64_ADDR16_HIGHESTA => "PPC64_ADDR16_HIGHESTA",
+        R_PPC64_UADDR64 => "PPC64_UADDR64",
+        R_PPC64_REL64 => "PPC64_REL64",
+        R_PPC64_PLT64 => "PPC64_PLT64",
+        R_PPC64_PLTREL64 => "PPC64_PLTREL64",
+        R_PPC64_TOC16 => "PPC64_TOC16",
+        R_PPC64_TOC16_LO => "PPC64_TOC16_LO",
+        R_PPC64_TOC16_HI => "PPC64_TOC16_HI",
+        R_PPC64_TOC16_HA => "PPC64_TOC16_HA",
+        R_PPC64_TOC => "PPC64_TOC",
+        R_PPC64_PLTGOT16 => "PPC64_PLTGOT16",
+        R_PPC64_PLTGOT16_LO => "PPC64_PLTGOT16_LO",
+        R_PPC64_PLTGOT16_HI => "PPC64_PLTGOT16_HI",
+        R_PPC64_PLTGOT16_HA => "PPC64_PLTGOT16_HA",
+        R_PPC64_ADDR16_DS => "PPC64_ADDR16_DS",
+        R_PPC64_ADDR16_LO_DS => "PPC64_ADDR16_LO_DS",
+        R_PPC64_GOT16_DS => "PPC64_GOT16_DS",
+        R_PPC64_GOT16_LO_DS => "PPC64_GOT16_LO_DS",
+        R_PPC64_PLT16_LO_DS => "PPC64_PLT16_LO_DS",
+        R_PPC64_SECTOFF_DS => "PPC64_SECTOFF_DS",
+        R_PPC64_SECTOFF_LO_DS => "PPC64_SECTOFF_LO_DS",
+        R_PPC64_TOC16_DS => "PPC64_TOC16_DS",
+        R_PPC64_TOC16_LO_DS => "PPC64_TOC16_LO_DS",
+        R_PPC64_PLTGOT16_DS => "PPC64_PLTGOT16_DS",
+        R_PPC64_PLTGOT16_LO_DS => "PPC64_PLTGOT16_LO_DS",
+        R_PPC64_TLS => "PPC64_TLS",
+        R_PPC64_DTPMOD64 => "PPC64_DTPMOD64",
+        R_PPC64_TPREL16 => "PPC64_TPREL16",
+        R_PPC64_TPREL16_LO => "PPC64_TPREL16_LO",
+        R_PPC64_TPREL16_HI => "PPC64_TPREL16_HI",
+        R_PPC64_TPREL16_HA => "PPC64_TPREL16_HA",
+        R_PPC64_TPREL64 => "PPC64_TPREL64",
+        R_PPC64_DTPREL16 => "PPC64_DTPREL16",
+        R_PPC64_DTPREL16_LO => "PPC64_DTPREL16_LO",
+        R_PPC64_DTPREL16_HI => "PPC64_DTPREL16_HI",
+        R_PPC64_DTPREL16_HA => "PPC64_DTPREL16_HA",
+        R_PPC64_DTPREL64 => "PPC64_DTPREL64",
+        R_PPC64_GOT_TLSGD16 => "PPC64_GOT_TLSGD16",
+        R_PPC64_GOT_TLSGD16_LO => "PPC64_GOT_TLSGD16_LO",
+        R_PPC64_GOT_TLSGD16_HI => "PPC64_GOT_TLSGD16_HI",
+        R_PPC64_GOT_TLSGD16_HA => "PPC64_GOT_TLSGD16_HA",
+        R_PPC64_GOT_TLSLD16 => "PPC64_GOT_TLSLD16",
+        R_PPC64_GOT_TLSLD16_LO => "PPC64_GOT_TLSLD16_LO",
+        R_PPC64_GOT_TLSLD16_HI => "PPC64_GOT_TLSLD16_HI",
+        R_PPC64_GOT_TLSLD16_HA => "PPC64_GOT_TLSLD16_HA",
+        R_PPC64_GOT_TPREL16_DS => "PPC64_GOT_TPREL16_DS",
+        R_PPC64_GOT_TPREL16_LO_DS => "PPC64_GOT_TPREL16_LO_DS",
+        R_PPC64_GOT_TPREL16_HI => "PPC64_GOT_TPREL16_HI",
+        R_PPC64_GOT_TPREL16_HA => "PPC64_GOT_TPREL16_HA",
+        R_PPC64_GOT_DTPREL16_DS => "PPC64_GOT_DTPREL16_DS",
+        R_PPC64_GOT_DTPREL16_LO_DS => "PPC64_GOT_DTPREL16_LO_DS",
+        R_PPC64_GOT_DTPREL16_HI => "PPC64_GOT_DTPREL16_HI",
+        R_PPC64_GOT_DTPREL16_HA => "PPC64_GOT_DTPREL16_HA",
+        R_PPC64_TPREL16_DS => "PPC64_TPREL16_DS",
+        R_PPC64_TPREL16_LO_DS => "PPC64_TPREL16_LO_DS",
+        R_PPC64_TPREL16_HIGHER => "PPC64_TPREL16_HIGHER",
+        R_PPC64_TPREL16_HIGHERA => "PPC64_TPREL16_HIGHERA",
+        R_PPC64_TPREL16_HIGHEST => "PPC64_TPREL16_HIGHEST",
+        R_PPC64_TPREL16_HIGHESTA => "PPC64_TPREL16_HIGHESTA",
+        R_PPC64_DTPREL16_DS => "PPC64_DTPREL16_DS",
+        R_PPC64_DTPREL16_LO_DS => "PPC64_DTPREL16_LO_DS",
+        R_PPC64_DTPREL16_HIGHER => "PPC64_DTPREL16_HIGHER",
+        R_PPC64_DTPREL16_HIGHERA => "PPC64_DTPREL16_HIGHERA",
+        R_PPC64_DTPREL16_HIGHEST => "PPC64_DTPREL16_HIGHEST",
+        R_PPC64_DTPREL16_HIGHESTA => "PPC64_DTPREL16_HIGHESTA",
+        R_PPC64_TLSGD => "PPC64_TLSGD",
+        R_PPC64_TLSLD => "PPC64_TLSLD",
+        R_PPC64_TOCSAVE => "PPC64_TOCSAVE",
+        R_PPC64_ADDR16_HIGH => "PPC64_ADDR16_HIGH",
+        R_PPC64_ADDR16_HIGHA => "PPC64_ADDR16_HIGHA",
+        R_PPC64_TPREL16_HIGH => "PPC64_TPREL16_HIGH",
+        R_PPC64_TPREL16_HIGHA => "PPC64_TPREL16_HIGHA",
+        R_PPC64_DTPREL16_HIGH => "PPC64_DTPREL16_HIGH",
+        R_PPC64_DTPREL16_HIGHA => "PPC64_DTPREL16_HIGHA",
+        R_PPC64_REL24_NOTOC => "PPC64_REL24_NOTOC",
+        R_PPC64_ADDR64_LOCAL => "PPC64_ADDR64_LOCAL",
+        R_PPC64_ENTRY => "PPC64_ENTRY",
+        R_PPC64_PLTSEQ => "PPC64_PLTSEQ",
+        R_PPC64_PLTCALL => "PPC64_PLTCALL",
+        R_PPC64_PLTSEQ_NOTOC => "PPC64_PLTSEQ_NOTOC",
+        R_PPC64_PLTCALL_NOTOC => "PPC64_PLTCALL_NOTOC",
+        R_PPC64_PCREL_OPT => "PPC64_PCREL_OPT",
+        R_PPC64_REL24_P9NOTOC => "PPC64_REL24_P9NOTOC",
+        R_PPC64_D34 => "PPC64_D34",
+        R_PPC64_D34_LO => "PPC64_D34_LO",
+        R_PPC64_D34_HI30 => "PPC64_D34_HI30",
+        R_PPC64_D34_HA30 => "PPC64_D34_HA30",
+        R_PPC64_PCREL34 => "PPC64_PCREL34",
+        R_PPC64_GOT_PCREL34 => "PPC64_GOT_PCREL34",
+        R_PPC64_PLT_PCREL34 => "PPC64_PLT_PCREL34",
+        R_PPC64_PLT_PCREL34_NOTOC => "PPC64_PLT_PCREL34_NOTOC",
+        R_PPC64_ADDR16_HIGHER34 => "PPC64_ADDR16_HIGHER34",
+        R_PPC64_ADDR16_HIGHERA34 => "PPC64_ADDR16_HIGHERA34",
+        R_PPC64_ADDR16_HIGHEST34 => "PPC64_ADDR16_HIGHEST34",
+        R_PPC64_ADDR16_HIGHESTA34 => "PPC64_ADDR16_HIGHESTA34",
+        R_PPC64_REL16_HIGHER34 => "PPC64_REL16_HIGHER34",
+        R_PPC64_REL16_HIGHERA34 => "PPC64_REL16_HIGHERA34",
+        R_PPC64_REL16_HIGHEST34 => "PPC64_REL16_HIGHEST34",
+        R_PPC64_REL16_HIGHESTA34 => "PPC64_REL16_HIGHESTA34",
+        R_PPC64_D28 => "PPC64_D28",
+        R_PPC64_PCREL28 => "PPC64_PCREL28",
+        R_PPC64_TPREL34 => "PPC64_TPREL34",
+        R_PPC64_DTPREL34 => "PPC64_DTPREL34",
+        R_PPC64_GOT_TLSGD_PCREL34 => "PPC64_GOT_TLSGD_PCREL34",
+        R_PPC64_GOT_TLSLD_PCREL34 => "PPC64_GOT_TLSLD_PCREL34",
+        R_PPC64_GOT_TPREL_PCREL34 => "PPC64_GOT_TPREL_PCREL34",
+        R_PPC64_GOT_DTPREL_PCREL34 => "PPC64_GOT_DTPREL_PCREL34",
+        R_PPC64_REL16_HIGH => "PPC64_REL16_HIGH",
+        R_PPC64_REL16_HIGHA => "PPC64_REL16_HIGHA",
+        R_PPC64_REL16_HIGHER => "PPC64_REL16_HIGHER",
+        R_PPC64_REL16_HIGHERA => "PPC64_REL16_HIGHERA",
+        R_PPC64_REL16_HIGHEST => "PPC64_REL16_HIGHEST",
+        R_PPC64_REL16_HIGHESTA => "PPC64_REL16_HIGHESTA",
+        R_PPC64_REL16DX_HA => "PPC64_REL16DX_HA",
+        R_PPC64_JMP_IREL => "PPC64_JMP_IREL",
+        R_PPC64_IRELATIVE => "PPC64_IRELATIVE",
+        R_PPC64_REL16 => "PPC64_REL16",
+        R_PPC64_REL16_LO => "PPC64_REL16_LO",
+        R_PPC64_REL16_HI => "PPC64_REL16_HI",
+        R_PPC64_REL16_HA => "PPC64_REL16_HA",
+        R_PPC64_GNU_VTINHERIT => "PPC64_GNU_VTINHERIT",
+        R_PPC64_GNU_VTENTRY => "PPC64_GNU_VTENTRY",
+        _ => "R_UNKNOWN_PPC64",
+        }},
+        // s390 and s390x
+        EM_S390 => { match typ {
+        R_390_NONE => "390_NONE",
+        R_390_8 => "390_8",
+        R_390_12 => "390_12",
+        R_390_16 => "390_16",
+        R_390_32 => "390_32",
+        R_390_PC32 => "390_PC32",
+        R_390_GOT12 => "390_GOT12",
+        R_390_GOT32 => "390_GOT32",
+        R_390_PLT32 => "390_PLT32",
+        R_390_COPY => "390_COPY",
+        R_390_GLOB_DAT => "390_GLOB_DAT",
+        R_390_JMP_SLOT => "390_JMP_SLOT",
+        R_390_RELATIVE => "390_RELATIVE",
+        R_390_GOTOFF32 => "390_GOTOFF32",
+        R_390_GOTPC => "390_GOTPC",
+        R_390_GOT16 => "390_GOT16",
+        R_390_PC16 => "390_PC16",
+        R_390_PC16DBL => "390_PC16DBL",
+        R_390_PLT16DBL => "390_PLT16DBL",
+        R_390_PC32DBL => "390_PC32DBL",
+        R_390_PLT32DBL => "390_PLT32DBL",
+        R_390_GOTPCDBL => "390_GOTPCDBL",
+        R_390_64 => "390_64",
+        R_390_PC64 => "390_PC64",
+        R_390_GOT64 => "390_GOT64",
+        R_390_PLT64 => "390_PLT64",
+        R_390_GOTENT => "390_GOTENT",
+        R_390_GOTOFF16 => "390_GOTOFF16",
+        R_390_GOTOFF64 => "390_GOTOFF64",
+        R_390_GOTPLT12 => "390_GOTPLT12",
+        R_390_GOTPLT16 => "390_GOTPLT16",
+        R_390_GOTPLT32 => "390_GOTPLT32",
+        R_390_GOTPLT64 => "390_GOTPLT64",
+        R_390_GOTPLTENT => "390_GOTPLTENT",
+        R_390_PLTOFF16 => "390_PLTOFF16",
+        R_390_PLTOFF32 => "390_PLTOFF32",
+        R_390_PLTOFF64 => "390_PLTOFF64",
+        R_390_TLS_LOAD => "390_TLS_LOAD",
+        R_390_TLS_GDCALL => "390_TLS_GDCALL",
+        R_390_TLS_LDCALL => "390_TLS_LDCALL",
+        R_390_TLS_GD32 => "390_TLS_GD32",
+        R_390_TLS_GD64 => "390_TLS_GD64",
+        R_390_TLS_GOTIE12 => "390_TLS_GOTIE12",
+        R_390_TLS_GOTIE32 => "390_TLS_GOTIE32",
+        R_390_TLS_GOTIE64 => "390_TLS_GOTIE64",
+        R_390_TLS_LDM32 => "390_TLS_LDM32",
+        R_390_TLS_LDM64 => "390_TLS_LDM64",
+        R_390_TLS_IE32 => "390_TLS_IE32",
+        R_390_TLS_IE64 => "390_TLS_IE64",
+        R_390_TLS_IEENT => "390_TLS_IEENT",
+        R_390_TLS_LE32 => "390_TLS_LE32",
+        R_390_TLS_LE64 => "390_TLS_LE64",
+        R_390_TLS_LDO32 => "390_TLS_LDO32",
+        R_390_TLS_LDO64 => "390_TLS_LDO64",
+        R_390_TLS_DTPMOD => "390_TLS_DTPMOD",
+        R_390_TLS_DTPOFF => "390_TLS_DTPOFF",
+        R_390_TLS_TPOFF => "390_TLS_TPOFF",
+        R_390_20 => "390_20",
+        R_390_GOT20 => "390_GOT20",
+        R_390_GOTPLT20 => "390_GOTPLT20",
+        R_390_TLS_GOTIE20 => "390_TLS_GOTIE20",
+        R_390_IRELATIVE => "390_IRELATIVE",
+        R_390_PC12DBL => "390_PC12DBL",
+        R_390_PLT12DBL => "390_PLT12DBL",
+        R_390_PC24DBL => "390_PC24DBL",
+        R_390_PLT24DBL => "390_PLT24DBL",
+        _ => "R_UNKNOWN_390",
+        }},
+        // SPARC
+        EM_SPARC | EM_SPARC32PLUS | EM_SPARCV9 => { match typ {
+        R_SPARC_NONE => "SPARC_NONE",
+        R_SPARC_8 => "SPARC_8",
+        R_SPARC_16 => "SPARC_16",
+        R_SPARC_32 => "SPARC_32",
+        R_SPARC_DISP8 => "SPARC_DISP8",
+        R_SPARC_DISP16 => "SPARC_DISP16",
+        R_SPARC_DISP32 => "SPARC_DISP32",
+        R_SPARC_WDISP30 => "SPARC_WDISP30",
+        R_SPARC_WDISP22 => "SPARC_WDISP22",
+        R_SPARC_HI22 => "SPARC_HI22",
+        R_SPARC_22 => "SPARC_22",
+        R_SPARC_13 => "SPARC_13",
+        R_SPARC_LO10 => "SPARC_LO10",
+        R_SPARC_GOT10 => "SPARC_GOT10",
+        R_SPARC_GOT13 => "SPARC_GOT13",
+        R_SPARC_GOT22 => "SPARC_GOT22",
+        R_SPARC_PC10 => "SPARC_PC10",
+        R_SPARC_PC22 => "SPARC_PC22",
+        R_SPARC_WPLT30 => "SPARC_WPLT30",
+        R_SPARC_COPY => "SPARC_COPY",
+        R_SPARC_GLOB_DAT => "SPARC_GLOB_DAT",
+        R_SPARC_JMP_SLOT => "SPARC_JMP_SLOT",
+        R_SPARC_RELATIVE => "SPARC_RELATIVE",
+        R_SPARC_UA32 => "SPARC_UA32",
+        R_SPARC_PLT32 => "SPARC_PLT32",
+        R_SPARC_HIPLT22 => "SPARC_HIPLT22",
+        R_SPARC_LOPLT10 => "SPARC_LOPLT10",
+        R_SPARC_PCPLT32 => "SPARC_PCPLT32",
+        R_SPARC_PCPLT22 => "SPARC_PCPLT22",
+        R_SPARC_PCPLT10 => "SPARC_PCPLT10",
+        R_SPARC_10 => "SPARC_10",
+        R_SPARC_11 => "SPARC_11",
+        R_SPARC_64 => "SPARC_64",
+        R_SPARC_OLO10 => "SPARC_OLO10",
+        R_SPARC_HH22 => "SPARC_HH22",
+        R_SPARC_HM10 => "SPARC_HM10",
+        R_SPARC_LM22 => "SPARC_LM22",
+        R_SPARC_PC_HH22 => "SPARC_PC_HH22",
+        R_SPARC_PC_HM10 => "SPARC_PC_HM10",
+        R_SPARC_PC_LM22 => "SPARC_PC_LM22",
+        R_SPARC_WDISP16 => "SPARC_WDISP16",
+        R_SPARC_WDISP19 => "SPARC_WDISP19",
+        R_SPARC_GLOB_JMP => "SPARC_GLOB_JMP",
+        R_SPARC_7 => "SPARC_7",
+        R_SPARC_5 => "SPARC_5",
+        R_SPARC_6 => "SPARC_6",
+        R_SPARC_DISP64 => "SPARC_DISP64",
+        R_SPARC_PLT64 => "SPARC_PLT64",
+        R_SPARC_HIX22 => "SPARC_HIX22",
+        R_SPARC_LOX10 => "SPARC_LOX10",
+        R_SPARC_H44 => "SPARC_H44",
+        R_SPARC_M44 => "SPARC_M44",
+        R_SPARC_L44 => "SPARC_L44",
+        R_SPARC_REGISTER => "SPARC_REGISTER",
+        R_SPARC_UA64 => "SPARC_UA64",
+        R_SPARC_UA16 => "SPARC_UA16",
+        R_SPARC_TLS_GD_HI22 => "SPARC_TLS_GD_HI22",
+        R_SPARC_TLS_GD_LO10 => "SPARC_TLS_GD_LO10",
+        R_SPARC_TLS_GD_ADD => "SPARC_TLS_GD_ADD",
+        R_SPARC_TLS_GD_CALL => "SPARC_TLS_GD_CALL",
+        R_SPARC_TLS_LDM_HI22 => "SPARC_TLS_LDM_HI22",
+        R_SPARC_TLS_LDM_LO10 => "SPARC_TLS_LDM_LO10",
+        R_SPARC_TLS_LDM_ADD => "SPARC_TLS_LDM_ADD",
+        R_SPARC_TLS_LDM_CALL => "SPARC_TLS_LDM_CALL",
+        R_SPARC_TLS_LDO_HIX22 => "SPARC_TLS_LDO_HIX22",
+        R_SPARC_TLS_LDO_LOX10 => "SPARC_TLS_LDO_LOX10",
+        R_SPARC_TLS_LDO_ADD => "SPARC_TLS_LDO_ADD",
+        R_SPARC_TLS_IE_HI22 => "SPARC_TLS_IE_HI22",
+        R_SPARC_TLS_IE_LO10 => "SPARC_TLS_IE_LO10",
+        R_SPARC_TLS_IE_LD => "SPARC_TLS_IE_LD",
+        R_SPARC_TLS_IE_LDX => "SPARC_TLS_IE_LDX",
+        R_SPARC_TLS_IE_ADD => "SPARC_TLS_IE_ADD",
+        R_SPARC_TLS_LE_HIX22 => "SPARC_TLS_LE_HIX22",
+        R_SPARC_TLS_LE_LOX10 => "SPARC_TLS_LE_LOX10",
+        R_SPARC_TLS_DTPMOD32 => "SPARC_TLS_DTPMOD32",
+        R_SPARC_TLS_DTPMOD64 => "SPARC_TLS_DTPMOD64",
+        R_SPARC_TLS_DTPOFF32 => "SPARC_TLS_DTPOFF32",
+        R_SPARC_TLS_DTPOFF64 => "SPARC_TLS_DTPOFF64",
+        R_SPARC_TLS_TPOFF32 => "SPARC_TLS_TPOFF32",
+        R_SPARC_TLS_TPOFF64 => "SPARC_TLS_TPOFF64",
+        R_SPARC_GOTDATA_HIX22 => "SPARC_GOTDATA_HIX22",
+        R_SPARC_GOTDATA_LOX10 => "SPARC_GOTDATA_LOX10",
+        R_SPARC_GOTDATA_OP_HIX22 => "SPARC_GOTDATA_OP_HIX22",
+        R_SPARC_GOTDATA_OP_LOX10 => "SPARC_GOTDATA_OP_LOX10",
+        R_SPARC_GOTDATA_OP => "SPARC_GOTDATA_OP",
+        R_SPARC_H34 => "SPARC_H34",
+        R_SPARC_SIZE32 => "SPARC_SIZE32",
+        R_SPARC_SIZE64 => "SPARC_SIZE64",
+        R_SPARC_WDISP10 => "SPARC_WDISP10",
+        R_SPARC_JMP_IREL => "SPARC_JMP_IREL",
+        R_SPARC_IRELATIVE => "SPARC_IRELATIVE",
+        R_SPARC_GNU_VTINHERIT => "SPARC_GNU_VTINHERIT",
+        R_SPARC_GNU_VTENTRY => "SPARC_GNU_VTENTRY",
+        R_SPARC_REV32 => "SPARC_REV32",
+        _ => "R_UNKNOWN_SPARC",
+        }},
+        _ => "R_UNKNOWN"
     }
 }
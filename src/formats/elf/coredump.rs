@@ -0,0 +1,447 @@
+//! Process state Linux writes into the notes of core dumps: the status and
+//! registers of each thread, the process and its command line, the signal
+//! that killed it, the files it had mapped and its auxiliary vector.
+//!
+//! Layouts are those of the generic Linux structures, which most
+//! architectures share; 32-bit ones are those of x86 and ARM.
+#![allow(non_camel_case_types)]
+
+use failure::Error;
+use scroll::{self, Pread};
+
+use crate::{Problem, ResultExt};
+use crate::formats::Recovery;
+use super::*;
+
+/// Status of one thread, from NT_PRSTATUS.
+#[derive(Serialize, Debug)]
+pub struct Elf_prstatus {
+    pub si_signo:   i32,
+    pub si_code:    i32,
+    pub si_errno:   i32,
+    /// Signal being delivered when the dump was written
+    pub pr_cursig:  u16,
+    pub pr_sigpend: u64,
+    pub pr_sighold: u64,
+    pub pr_pid:     i32,
+    pub pr_ppid:    i32,
+    pub pr_pgrp:    i32,
+    pub pr_sid:     i32,
+    /// General purpose registers, in the order of [`register_names`]
+    pub registers:  Vec<u64>,
+}
+
+/// Process information, from NT_PRPSINFO.
+#[derive(Serialize, Debug)]
+pub struct Elf_prpsinfo {
+    pub pr_state:  u8,
+    /// State as a letter, like ps shows it
+    pub pr_sname:  char,
+    pub pr_zomb:   u8,
+    pub pr_nice:   i8,
+    pub pr_flag:   u64,
+    pub pr_uid:    u32,
+    pub pr_gid:    u32,
+    pub pr_pid:    i32,
+    pub pr_ppid:   i32,
+    pub pr_pgrp:   i32,
+    pub pr_sid:    i32,
+    /// Executable name, cut to 15 bytes
+    pub pr_fname:  String,
+    /// Command line, cut to 79 bytes
+    pub pr_psargs: String,
+}
+
+/// Signal that caused the dump, from NT_SIGINFO.
+#[derive(Serialize, Debug)]
+pub struct Elf_siginfo {
+    pub si_signo: i32,
+    pub si_errno: i32,
+    pub si_code:  i32,
+    /// Faulting address of SIGILL, SIGFPE, SIGSEGV and SIGBUS
+    pub si_addr:  Option<u64>,
+}
+
+/// File mapped into the process, from NT_FILE.
+#[derive(Serialize, Debug)]
+pub struct Elf_mapped_file {
+    pub start:  u64,
+    pub end:    u64,
+    /// Offset in the file of the start of the mapping
+    pub offset: u64,
+    pub name:   String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Elf_auxv {
+    pub a_type: u64,
+    pub a_val:  u64,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Elf_core {
+    pub threads:   Vec<Elf_prstatus>,
+    pub process:   Option<Elf_prpsinfo>,
+    pub signal:    Option<Elf_siginfo>,
+    pub page_size: u64,
+    pub files:     Vec<Elf_mapped_file>,
+    pub auxv:      Vec<Elf_auxv>,
+}
+
+/// Decodes the CORE notes of a core dump.
+pub fn read_core(rec: &mut Recovery, notes: &[Elf_note], is_32: bool, endianness: scroll::Endian) -> Result<Elf_core, Error> {
+    let mut core = Elf_core::default();
+
+    for note in notes.iter().filter(|note| note.name == "CORE") {
+        let what = note.type_to_str();
+        let desc = Desc { desc: &note.desc, is_32, endianness };
+        match note.n_type {
+            NT_PRSTATUS => if let Some(thread) = rec.recover(desc.prstatus().map(Some).within(what), None)? {
+                core.threads.push(thread);
+            },
+            NT_PRPSINFO => core.process = rec.recover(desc.prpsinfo().map(Some).within(what), None)?,
+            NT_SIGINFO => core.signal = rec.recover(desc.siginfo().map(Some).within(what), None)?,
+            NT_FILE => if let Some((page_size, files)) = rec.recover(desc.files().map(Some).within(what), None)? {
+                core.page_size = page_size;
+                core.files = files;
+            },
+            NT_AUXV => core.auxv = rec.recover(desc.auxv().within(what), Vec::new())?,
+            _ => {},
+        }
+    }
+
+    Ok(core)
+}
+
+/// Description of a note, read with the word size and byte order of the file.
+struct Desc<'a> {
+    desc:       &'a [u8],
+    is_32:      bool,
+    endianness: scroll::Endian,
+}
+
+impl<'a> Desc<'a> {
+
+    fn word_size(&self) -> usize {
+        if self.is_32 { 4 } else { 8 }
+    }
+
+    fn word(&self, offset: usize) -> Result<u64, Error> {
+        if self.is_32 {
+            self.u32(offset).map(u64::from)
+        } else {
+            self.desc.pread_with::<u64>(offset, self.endianness).at(offset as u64, "note word")
+        }
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32, Error> {
+        self.desc.pread_with::<u32>(offset, self.endianness).at(offset as u64, "note word")
+    }
+
+    fn i32(&self, offset: usize) -> Result<i32, Error> {
+        self.u32(offset).map(|value| value as i32)
+    }
+
+    /// Null terminated string in a fixed size field.
+    fn string(&self, offset: usize, size: usize) -> Result<String, Error> {
+        let field = self.desc.get(offset..offset + size)
+            .ok_or_else(|| Problem::msg(format!("{:#X} bytes do not fit in note of size {:#X}", size, self.desc.len())).at(offset as u64))?;
+        Ok(String::from_utf8_lossy(field.split(|b| *b == 0).next().unwrap_or(&[])).into_owned())
+    }
+
+    fn prstatus(&self) -> Result<Elf_prstatus, Error> {
+        // pr_sigpend and pr_sighold are words, so everything after moves with the word size
+        let (sighold, ids, reg) = if self.is_32 { (20, 24, 72) } else { (24, 32, 112) };
+        // Registers are followed by the pr_fpvalid int
+        let count = self.desc.len().saturating_sub(reg + 4) / self.word_size();
+
+        Ok(Elf_prstatus {
+            si_signo:   self.i32(0)?,
+            si_code:    self.i32(4)?,
+            si_errno:   self.i32(8)?,
+            pr_cursig:  self.desc.pread_with::<u16>(12, self.endianness).at(12, "pr_cursig")?,
+            pr_sigpend: self.word(16)?,
+            pr_sighold: self.word(sighold)?,
+            pr_pid:     self.i32(ids)?,
+            pr_ppid:    self.i32(ids + 4)?,
+            pr_pgrp:    self.i32(ids + 8)?,
+            pr_sid:     self.i32(ids + 12)?,
+            registers:  (0..count).map(|i| self.word(reg + i * self.word_size())).collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn prpsinfo(&self) -> Result<Elf_prpsinfo, Error> {
+        let byte = |offset: usize| self.desc.pread::<u8>(offset).at(offset as u64, "prpsinfo field");
+        // 32-bit layouts have 16-bit user and group ids
+        let (flag, uid, gid, ids, fname) = if self.is_32 {
+            (self.u32(4)? as u64,
+             self.desc.pread_with::<u16>(8, self.endianness).at(8, "pr_uid")? as u32,
+             self.desc.pread_with::<u16>(10, self.endianness).at(10, "pr_gid")? as u32,
+             12, 28)
+        } else {
+            (self.word(8)?, self.u32(16)?, self.u32(20)?, 24, 40)
+        };
+
+        Ok(Elf_prpsinfo {
+            pr_state:  byte(0)?,
+            pr_sname:  byte(1)? as char,
+            pr_zomb:   byte(2)?,
+            pr_nice:   byte(3)? as i8,
+            pr_flag:   flag,
+            pr_uid:    uid,
+            pr_gid:    gid,
+            pr_pid:    self.i32(ids)?,
+            pr_ppid:   self.i32(ids + 4)?,
+            pr_pgrp:   self.i32(ids + 8)?,
+            pr_sid:    self.i32(ids + 12)?,
+            pr_fname:  self.string(fname, 16)?,
+            // Arguments are joined with spaces, the last one included
+            pr_psargs: self.string(fname + 16, 80)?.trim_end().to_string(),
+        })
+    }
+
+    fn siginfo(&self) -> Result<Elf_siginfo, Error> {
+        let si_signo = self.i32(0)?;
+        // The union after the three ints is word aligned
+        let si_addr = match si_signo {
+            SIGILL | SIGFPE | SIGSEGV | SIGBUS => Some(self.word(if self.is_32 { 12 } else { 16 })?),
+            _ => None,
+        };
+
+        Ok(Elf_siginfo {
+            si_signo,
+            si_errno: self.i32(4)?,
+            si_code:  self.i32(8)?,
+            si_addr,
+        })
+    }
+
+    /// Page size the offsets count in, and the mapped files.
+    fn files(&self) -> Result<(u64, Vec<Elf_mapped_file>), Error> {
+        let word = self.word_size();
+        let count = self.word(0)? as usize;
+        let page_size = self.word(word)?;
+
+        // Ranges come first, then the names in the same order
+        let names_offset = count.checked_mul(3 * word)
+            .and_then(|size| size.checked_add(2 * word))
+            .filter(|&end| end <= self.desc.len())
+            .ok_or_else(|| Problem::msg(format!("{} mapped files do not fit in note of size {:#X}", count, self.desc.len())))?;
+        let mut names = self.desc[names_offset..].split(|b| *b == 0);
+
+        let mut files = Vec::with_capacity(count);
+        for i in 0..count {
+            let entry = 2 * word + i * 3 * word;
+            let name = names.next()
+                .ok_or_else(|| Problem::msg(format!("Mapped file #{} has no name", i)))?;
+            files.push(Elf_mapped_file {
+                start:  self.word(entry)?,
+                end:    self.word(entry + word)?,
+                offset: self.word(entry + 2 * word)?.saturating_mul(page_size),
+                name:   String::from_utf8_lossy(name).into_owned(),
+            });
+        }
+
+        Ok((page_size, files))
+    }
+
+    fn auxv(&self) -> Result<Vec<Elf_auxv>, Error> {
+        let word = self.word_size();
+        let mut auxv = Vec::new();
+
+        for offset in (0..self.desc.len() / (2 * word)).map(|i| i * 2 * word) {
+            let a_type = self.word(offset)?;
+            if a_type == AT_NULL {
+                break;
+            }
+            auxv.push(Elf_auxv {
+                a_type,
+                a_val: self.word(offset + word)?,
+            });
+        }
+
+        Ok(auxv)
+    }
+
+}
+
+//
+// SIGNALS
+//
+
+pub const SIGHUP: i32 = 1;
+pub const SIGINT: i32 = 2;
+pub const SIGQUIT: i32 = 3;
+pub const SIGILL: i32 = 4;
+pub const SIGTRAP: i32 = 5;
+pub const SIGABRT: i32 = 6;
+pub const SIGBUS: i32 = 7;
+pub const SIGFPE: i32 = 8;
+pub const SIGKILL: i32 = 9;
+pub const SIGUSR1: i32 = 10;
+pub const SIGSEGV: i32 = 11;
+pub const SIGUSR2: i32 = 12;
+pub const SIGPIPE: i32 = 13;
+pub const SIGALRM: i32 = 14;
+pub const SIGTERM: i32 = 15;
+pub const SIGSTKFLT: i32 = 16;
+pub const SIGCHLD: i32 = 17;
+pub const SIGCONT: i32 = 18;
+pub const SIGSTOP: i32 = 19;
+pub const SIGTSTP: i32 = 20;
+pub const SIGTTIN: i32 = 21;
+pub const SIGTTOU: i32 = 22;
+pub const SIGURG: i32 = 23;
+pub const SIGXCPU: i32 = 24;
+pub const SIGXFSZ: i32 = 25;
+pub const SIGVTALRM: i32 = 26;
+pub const SIGPROF: i32 = 27;
+pub const SIGWINCH: i32 = 28;
+pub const SIGIO: i32 = 29;
+pub const SIGPWR: i32 = 30;
+pub const SIGSYS: i32 = 31;
+
+/// Name of a Linux signal number, as numbered on x86 and ARM.
+pub fn signal_to_str(signo: i32) -> &'static str {
+    match signo {
+        SIGHUP => "SIGHUP",
+        SIGINT => "SIGINT",
+        SIGQUIT => "SIGQUIT",
+        SIGILL => "SIGILL",
+        SIGTRAP => "SIGTRAP",
+        SIGABRT => "SIGABRT",
+        SIGBUS => "SIGBUS",
+        SIGFPE => "SIGFPE",
+        SIGKILL => "SIGKILL",
+        SIGUSR1 => "SIGUSR1",
+        SIGSEGV => "SIGSEGV",
+        SIGUSR2 => "SIGUSR2",
+        SIGPIPE => "SIGPIPE",
+        SIGALRM => "SIGALRM",
+        SIGTERM => "SIGTERM",
+        SIGSTKFLT => "SIGSTKFLT",
+        SIGCHLD => "SIGCHLD",
+        SIGCONT => "SIGCONT",
+        SIGSTOP => "SIGSTOP",
+        SIGTSTP => "SIGTSTP",
+        SIGTTIN => "SIGTTIN",
+        SIGTTOU => "SIGTTOU",
+        SIGURG => "SIGURG",
+        SIGXCPU => "SIGXCPU",
+        SIGXFSZ => "SIGXFSZ",
+        SIGVTALRM => "SIGVTALRM",
+        SIGPROF => "SIGPROF",
+        SIGWINCH => "SIGWINCH",
+        SIGIO => "SIGIO",
+        SIGPWR => "SIGPWR",
+        SIGSYS => "SIGSYS",
+        _ => "UNKNOWN_SIGNAL",
+    }
+}
+
+//
+// AUXILIARY VECTOR
+//
+
+// End of vector
+pub const AT_NULL: u64 = 0;
+// Entry should be ignored
+pub const AT_IGNORE: u64 = 1;
+// File descriptor of program
+pub const AT_EXECFD: u64 = 2;
+// Program headers of the executable
+pub const AT_PHDR: u64 = 3;
+// Size of a program header entry
+pub const AT_PHENT: u64 = 4;
+// Number of program headers
+pub const AT_PHNUM: u64 = 5;
+// System page size
+pub const AT_PAGESZ: u64 = 6;
+// Base address of the interpreter
+pub const AT_BASE: u64 = 7;
+pub const AT_FLAGS: u64 = 8;
+// Entry point of the executable
+pub const AT_ENTRY: u64 = 9;
+pub const AT_NOTELF: u64 = 10;
+pub const AT_UID: u64 = 11;
+pub const AT_EUID: u64 = 12;
+pub const AT_GID: u64 = 13;
+pub const AT_EGID: u64 = 14;
+// Address of the platform name string
+pub const AT_PLATFORM: u64 = 15;
+// Processor capabilities
+pub const AT_HWCAP: u64 = 16;
+// Frequency of times()
+pub const AT_CLKTCK: u64 = 17;
+// Whether the executable is setuid or similar
+pub const AT_SECURE: u64 = 23;
+pub const AT_BASE_PLATFORM: u64 = 24;
+// Address of 16 random bytes
+pub const AT_RANDOM: u64 = 25;
+pub const AT_HWCAP2: u64 = 26;
+// Address of the executable file name string
+pub const AT_EXECFN: u64 = 31;
+pub const AT_SYSINFO: u64 = 32;
+// Address of the vDSO
+pub const AT_SYSINFO_EHDR: u64 = 33;
+// Size and alignment of the rseq area the C library registers
+pub const AT_RSEQ_FEATURE_SIZE: u64 = 27;
+pub const AT_RSEQ_ALIGN: u64 = 28;
+pub const AT_MINSIGSTKSZ: u64 = 51;
+
+pub fn at_to_str(a_type: u64) -> &'static str {
+    match a_type {
+        AT_NULL => "AT_NULL",
+        AT_IGNORE => "AT_IGNORE",
+        AT_EXECFD => "AT_EXECFD",
+        AT_PHDR => "AT_PHDR",
+        AT_PHENT => "AT_PHENT",
+        AT_PHNUM => "AT_PHNUM",
+        AT_PAGESZ => "AT_PAGESZ",
+        AT_BASE => "AT_BASE",
+        AT_FLAGS => "AT_FLAGS",
+        AT_ENTRY => "AT_ENTRY",
+        AT_NOTELF => "AT_NOTELF",
+        AT_UID => "AT_UID",
+        AT_EUID => "AT_EUID",
+        AT_GID => "AT_GID",
+        AT_EGID => "AT_EGID",
+        AT_PLATFORM => "AT_PLATFORM",
+        AT_HWCAP => "AT_HWCAP",
+        AT_CLKTCK => "AT_CLKTCK",
+        AT_SECURE => "AT_SECURE",
+        AT_BASE_PLATFORM => "AT_BASE_PLATFORM",
+        AT_RANDOM => "AT_RANDOM",
+        AT_HWCAP2 => "AT_HWCAP2",
+        AT_EXECFN => "AT_EXECFN",
+        AT_SYSINFO => "AT_SYSINFO",
+        AT_SYSINFO_EHDR => "AT_SYSINFO_EHDR",
+        AT_RSEQ_FEATURE_SIZE => "AT_RSEQ_FEATURE_SIZE",
+        AT_RSEQ_ALIGN => "AT_RSEQ_ALIGN",
+        AT_MINSIGSTKSZ => "AT_MINSIGSTKSZ",
+        _ => "AT_UNKNOWN",
+    }
+}
+
+//
+// REGISTERS
+//
+
+/// Names of the general purpose registers of NT_PRSTATUS, in order, for the
+/// architectures whose layout is known.
+pub fn register_names(machine: u16) -> Option<&'static [&'static str]> {
+    match machine {
+        EM_X86_64 => Some(&["r15", "r14", "r13", "r12", "rbp", "rbx", "r11", "r10", "r9", "r8",
+                            "rax", "rcx", "rdx", "rsi", "rdi", "orig_rax", "rip", "cs", "eflags",
+                            "rsp", "ss", "fs_base", "gs_base", "ds", "es", "fs", "gs"]),
+        EM_386 => Some(&["ebx", "ecx", "edx", "esi", "edi", "ebp", "eax", "ds", "es", "fs", "gs",
+                         "orig_eax", "eip", "cs", "eflags", "esp", "ss"]),
+        EM_AARCH64 => Some(&["x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10",
+                             "x11", "x12", "x13", "x14", "x15", "x16", "x17", "x18", "x19", "x20",
+                             "x21", "x22", "x23", "x24", "x25", "x26", "x27", "x28", "x29", "x30",
+                             "sp", "pc", "pstate"]),
+        EM_ARM => Some(&["r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10",
+                         "fp", "ip", "sp", "lr", "pc", "cpsr", "orig_r0"]),
+        _ => None,
+    }
+}
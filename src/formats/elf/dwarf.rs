@@ -0,0 +1,944 @@
+#![allow(non_upper_case_globals, non_camel_case_types)]
+//! DWARF debug information, versions 2 to 5: the compilation units of
+//! `.debug_info`, described by the attributes of their first entry, and the
+//! line tables of `.debug_line` that map addresses to source lines.
+//!
+//! Only the unit entries are decoded, the entries below them are skipped
+//! along with their units.
+
+use std::borrow::Cow;
+
+use failure::Error;
+use scroll::{self, Pread};
+
+use crate::{Problem, ResultExt};
+use crate::formats::Recovery;
+
+// Unit types of version 5 headers
+pub const DW_UT_compile: u8 = 0x01;
+pub const DW_UT_type: u8 = 0x02;
+pub const DW_UT_partial: u8 = 0x03;
+pub const DW_UT_skeleton: u8 = 0x04;
+pub const DW_UT_split_compile: u8 = 0x05;
+pub const DW_UT_split_type: u8 = 0x06;
+
+// Attributes of unit entries
+pub const DW_AT_stmt_list: u64 = 0x10;
+pub const DW_AT_low_pc: u64 = 0x11;
+pub const DW_AT_high_pc: u64 = 0x12;
+pub const DW_AT_language: u64 = 0x13;
+pub const DW_AT_name: u64 = 0x03;
+pub const DW_AT_comp_dir: u64 = 0x1b;
+pub const DW_AT_producer: u64 = 0x25;
+pub const DW_AT_str_offsets_base: u64 = 0x72;
+pub const DW_AT_addr_base: u64 = 0x73;
+pub const DW_AT_dwo_name: u64 = 0x76;
+pub const DW_AT_GNU_dwo_name: u64 = 0x2130;
+pub const DW_AT_GNU_addr_base: u64 = 0x2133;
+
+// Attribute forms
+pub const DW_FORM_addr: u64 = 0x01;
+pub const DW_FORM_block2: u64 = 0x03;
+pub const DW_FORM_block4: u64 = 0x04;
+pub const DW_FORM_data2: u64 = 0x05;
+pub const DW_FORM_data4: u64 = 0x06;
+pub const DW_FORM_data8: u64 = 0x07;
+pub const DW_FORM_string: u64 = 0x08;
+pub const DW_FORM_block: u64 = 0x09;
+pub const DW_FORM_block1: u64 = 0x0a;
+pub const DW_FORM_data1: u64 = 0x0b;
+pub const DW_FORM_flag: u64 = 0x0c;
+pub const DW_FORM_sdata: u64 = 0x0d;
+pub const DW_FORM_strp: u64 = 0x0e;
+pub const DW_FORM_udata: u64 = 0x0f;
+pub const DW_FORM_ref_addr: u64 = 0x10;
+pub const DW_FORM_ref1: u64 = 0x11;
+pub const DW_FORM_ref2: u64 = 0x12;
+pub const DW_FORM_ref4: u64 = 0x13;
+pub const DW_FORM_ref8: u64 = 0x14;
+pub const DW_FORM_ref_udata: u64 = 0x15;
+pub const DW_FORM_indirect: u64 = 0x16;
+pub const DW_FORM_sec_offset: u64 = 0x17;
+pub const DW_FORM_exprloc: u64 = 0x18;
+pub const DW_FORM_flag_present: u64 = 0x19;
+pub const DW_FORM_strx: u64 = 0x1a;
+pub const DW_FORM_addrx: u64 = 0x1b;
+pub const DW_FORM_ref_sup4: u64 = 0x1c;
+pub const DW_FORM_strp_sup: u64 = 0x1d;
+pub const DW_FORM_data16: u64 = 0x1e;
+pub const DW_FORM_line_strp: u64 = 0x1f;
+pub const DW_FORM_ref_sig8: u64 = 0x20;
+pub const DW_FORM_implicit_const: u64 = 0x21;
+pub const DW_FORM_loclistx: u64 = 0x22;
+pub const DW_FORM_rnglistx: u64 = 0x23;
+pub const DW_FORM_ref_sup8: u64 = 0x24;
+pub const DW_FORM_strx1: u64 = 0x25;
+pub const DW_FORM_strx2: u64 = 0x26;
+pub const DW_FORM_strx3: u64 = 0x27;
+pub const DW_FORM_strx4: u64 = 0x28;
+pub const DW_FORM_addrx1: u64 = 0x29;
+pub const DW_FORM_addrx2: u64 = 0x2a;
+pub const DW_FORM_addrx3: u64 = 0x2b;
+pub const DW_FORM_addrx4: u64 = 0x2c;
+pub const DW_FORM_GNU_addr_index: u64 = 0x1f01;
+pub const DW_FORM_GNU_str_index: u64 = 0x1f02;
+pub const DW_FORM_GNU_ref_alt: u64 = 0x1f20;
+pub const DW_FORM_GNU_strp_alt: u64 = 0x1f21;
+
+// Content of the directory and file entries of version 5 line tables
+pub const DW_LNCT_path: u64 = 0x1;
+pub const DW_LNCT_directory_index: u64 = 0x2;
+
+// Standard opcodes of line programs
+pub const DW_LNS_copy: u8 = 0x01;
+pub const DW_LNS_advance_pc: u8 = 0x02;
+pub const DW_LNS_advance_line: u8 = 0x03;
+pub const DW_LNS_set_file: u8 = 0x04;
+pub const DW_LNS_set_column: u8 = 0x05;
+pub const DW_LNS_negate_stmt: u8 = 0x06;
+pub const DW_LNS_basic_block: u8 = 0x07;
+pub const DW_LNS_const_add_pc: u8 = 0x08;
+pub const DW_LNS_fixed_advance_pc: u8 = 0x09;
+
+// Extended opcodes of line programs
+pub const DW_LNE_end_sequence: u8 = 0x01;
+pub const DW_LNE_set_address: u8 = 0x02;
+pub const DW_LNE_define_file: u8 = 0x03;
+
+// Source languages
+pub const DW_LANG_C89: u16 = 0x0001;
+pub const DW_LANG_C: u16 = 0x0002;
+pub const DW_LANG_Ada83: u16 = 0x0003;
+pub const DW_LANG_C_plus_plus: u16 = 0x0004;
+pub const DW_LANG_Cobol74: u16 = 0x0005;
+pub const DW_LANG_Cobol85: u16 = 0x0006;
+pub const DW_LANG_Fortran77: u16 = 0x0007;
+pub const DW_LANG_Fortran90: u16 = 0x0008;
+pub const DW_LANG_Pascal83: u16 = 0x0009;
+pub const DW_LANG_Modula2: u16 = 0x000a;
+pub const DW_LANG_Java: u16 = 0x000b;
+pub const DW_LANG_C99: u16 = 0x000c;
+pub const DW_LANG_Ada95: u16 = 0x000d;
+pub const DW_LANG_Fortran95: u16 = 0x000e;
+pub const DW_LANG_PLI: u16 = 0x000f;
+pub const DW_LANG_ObjC: u16 = 0x0010;
+pub const DW_LANG_ObjC_plus_plus: u16 = 0x0011;
+pub const DW_LANG_UPC: u16 = 0x0012;
+pub const DW_LANG_D: u16 = 0x0013;
+pub const DW_LANG_Python: u16 = 0x0014;
+pub const DW_LANG_OpenCL: u16 = 0x0015;
+pub const DW_LANG_Go: u16 = 0x0016;
+pub const DW_LANG_Modula3: u16 = 0x0017;
+pub const DW_LANG_Haskell: u16 = 0x0018;
+pub const DW_LANG_C_plus_plus_03: u16 = 0x0019;
+pub const DW_LANG_C_plus_plus_11: u16 = 0x001a;
+pub const DW_LANG_OCaml: u16 = 0x001b;
+pub const DW_LANG_Rust: u16 = 0x001c;
+pub const DW_LANG_C11: u16 = 0x001d;
+pub const DW_LANG_Swift: u16 = 0x001e;
+pub const DW_LANG_Julia: u16 = 0x001f;
+pub const DW_LANG_Dylan: u16 = 0x0020;
+pub const DW_LANG_C_plus_plus_14: u16 = 0x0021;
+pub const DW_LANG_Fortran03: u16 = 0x0022;
+pub const DW_LANG_Fortran08: u16 = 0x0023;
+pub const DW_LANG_RenderScript: u16 = 0x0024;
+pub const DW_LANG_BLISS: u16 = 0x0025;
+pub const DW_LANG_Kotlin: u16 = 0x0026;
+pub const DW_LANG_Zig: u16 = 0x0027;
+pub const DW_LANG_Crystal: u16 = 0x0028;
+pub const DW_LANG_C_plus_plus_17: u16 = 0x002a;
+pub const DW_LANG_C_plus_plus_20: u16 = 0x002b;
+pub const DW_LANG_C17: u16 = 0x002c;
+pub const DW_LANG_Fortran18: u16 = 0x002d;
+pub const DW_LANG_Ada2005: u16 = 0x002e;
+pub const DW_LANG_Ada2012: u16 = 0x002f;
+pub const DW_LANG_HIP: u16 = 0x0030;
+pub const DW_LANG_Assembly: u16 = 0x0031;
+pub const DW_LANG_C_sharp: u16 = 0x0032;
+pub const DW_LANG_Mojo: u16 = 0x0033;
+pub const DW_LANG_Mips_Assembler: u16 = 0x8001;
+
+/// Convert a DW_LANG value to the name of the language.
+pub fn lang_to_str(lang: u16) -> &'static str {
+    match lang {
+        DW_LANG_C89 => "C89",
+        DW_LANG_C => "C",
+        DW_LANG_Ada83 => "Ada83",
+        DW_LANG_C_plus_plus => "C++",
+        DW_LANG_Cobol74 => "Cobol74",
+        DW_LANG_Cobol85 => "Cobol85",
+        DW_LANG_Fortran77 => "Fortran77",
+        DW_LANG_Fortran90 => "Fortran90",
+        DW_LANG_Pascal83 => "Pascal83",
+        DW_LANG_Modula2 => "Modula2",
+        DW_LANG_Java => "Java",
+        DW_LANG_C99 => "C99",
+        DW_LANG_Ada95 => "Ada95",
+        DW_LANG_Fortran95 => "Fortran95",
+        DW_LANG_PLI => "PLI",
+        DW_LANG_ObjC => "ObjC",
+        DW_LANG_ObjC_plus_plus => "ObjC++",
+        DW_LANG_UPC => "UPC",
+        DW_LANG_D => "D",
+        DW_LANG_Python => "Python",
+        DW_LANG_OpenCL => "OpenCL",
+        DW_LANG_Go => "Go",
+        DW_LANG_Modula3 => "Modula3",
+        DW_LANG_Haskell => "Haskell",
+        DW_LANG_C_plus_plus_03 => "C++03",
+        DW_LANG_C_plus_plus_11 => "C++11",
+        DW_LANG_OCaml => "OCaml",
+        DW_LANG_Rust => "Rust",
+        DW_LANG_C11 => "C11",
+        DW_LANG_Swift => "Swift",
+        DW_LANG_Julia => "Julia",
+        DW_LANG_Dylan => "Dylan",
+        DW_LANG_C_plus_plus_14 => "C++14",
+        DW_LANG_Fortran03 => "Fortran03",
+        DW_LANG_Fortran08 => "Fortran08",
+        DW_LANG_RenderScript => "RenderScript",
+        DW_LANG_BLISS => "BLISS",
+        DW_LANG_Kotlin => "Kotlin",
+        DW_LANG_Zig => "Zig",
+        DW_LANG_Crystal => "Crystal",
+        DW_LANG_C_plus_plus_17 => "C++17",
+        DW_LANG_C_plus_plus_20 => "C++20",
+        DW_LANG_C17 => "C17",
+        DW_LANG_Fortran18 => "Fortran18",
+        DW_LANG_Ada2005 => "Ada2005",
+        DW_LANG_Ada2012 => "Ada2012",
+        DW_LANG_HIP => "HIP",
+        DW_LANG_Assembly => "Assembly",
+        DW_LANG_C_sharp => "C#",
+        DW_LANG_Mojo => "Mojo",
+        DW_LANG_Mips_Assembler => "MIPS assembler",
+        _ => "UNKNOWN_LANG",
+    }
+}
+
+/// Compilation unit of `.debug_info`.
+#[derive(Serialize, Debug, Default)]
+pub struct Dwarf_unit {
+    /// Offset of the unit header in `.debug_info`
+    pub offset:       u64,
+    pub version:      u16,
+    pub unit_type:    u8,
+    pub address_size: u8,
+    /// Main source file
+    pub name:         Option<String>,
+    /// Compiler, its version and often the flags it was given
+    pub producer:     Option<String>,
+    pub language:     Option<u16>,
+    /// Directory the compiler ran in
+    pub comp_dir:     Option<String>,
+    /// Split DWARF file holding the rest of the unit
+    pub dwo_name:     Option<String>,
+    pub low_pc:       Option<u64>,
+    pub high_pc:      Option<u64>,
+    /// Source files of the line table, with their directories
+    pub files:        Vec<String>,
+    #[serde(skip)]
+    pub lines:        Vec<Dwarf_line>,
+}
+
+/// Row of a line table.
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct Dwarf_line {
+    pub address:      u64,
+    /// Index in the files of the unit
+    pub file:         usize,
+    pub line:         u64,
+    pub column:       u64,
+    /// First address after a sequence of rows
+    pub end_sequence: bool,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Dwarf {
+    pub units: Vec<Dwarf_unit>,
+}
+
+/// Source line an address was compiled from.
+#[derive(Debug)]
+pub struct Location<'a> {
+    pub unit:   &'a Dwarf_unit,
+    pub file:   Option<&'a str>,
+    pub line:   u64,
+    pub column: u64,
+}
+
+impl Dwarf {
+
+    /// Source line of the instruction at `address`, from the line tables.
+    pub fn find_line(&self, address: u64) -> Option<Location<'_>> {
+        self.units.iter().find_map(|unit| {
+            unit.lines.windows(2)
+                .find(|rows| !rows[0].end_sequence && rows[0].address <= address && address < rows[1].address)
+                .map(|rows| Location {
+                    unit,
+                    file: unit.files.get(rows[0].file).map(String::as_str),
+                    line: rows[0].line,
+                    column: rows[0].column,
+                })
+        })
+    }
+
+}
+
+/// Contents of a debug section, with relocations applied, and its offset in
+/// the file. Empty when the file has no such section.
+#[derive(Debug, Default)]
+pub struct DebugSection<'a> {
+    pub data:   Cow<'a, [u8]>,
+    pub offset: u64,
+}
+
+/// The debug sections units and line tables are read from.
+#[derive(Debug, Default)]
+pub struct DebugSections<'a> {
+    pub info:        DebugSection<'a>,
+    pub abbrev:      DebugSection<'a>,
+    pub str:         DebugSection<'a>,
+    pub line_str:    DebugSection<'a>,
+    pub line:        DebugSection<'a>,
+    pub str_offsets: DebugSection<'a>,
+    pub addr:        DebugSection<'a>,
+}
+
+/// Reads every compilation unit of `.debug_info` and its line table.
+pub fn read_dwarf(rec: &mut Recovery, sections: &DebugSections, endianness: scroll::Endian) -> Result<Dwarf, Error> {
+    let mut units = Vec::new();
+    let mut offset = 0;
+
+    while offset < sections.info.data.len() {
+        let what = format!("compilation unit at {:#X}", offset);
+        // A unit with a broken length hides where the next one starts
+        let unit = rec.recover(read_unit(sections, offset, endianness).within(&what).map(Some), None)?;
+        let (mut unit, next) = match unit {
+            Some(unit) => unit,
+            None => break,
+        };
+        if let Some(stmt_list) = unit.1 {
+            let comp_dir = unit.0.comp_dir.clone();
+            let lines = read_lines(sections, &unit.0, stmt_list, comp_dir.as_deref(), endianness)
+                .within(format_args!("line table at {:#X}", stmt_list));
+            if let Some((files, lines)) = rec.recover(lines.within(&what).map(Some), None)? {
+                unit.0.files = files;
+                unit.0.lines = lines;
+            }
+        }
+        if unit.0.unit_type != DW_UT_type && unit.0.unit_type != DW_UT_split_type {
+            units.push(unit.0);
+        }
+        offset = next;
+    }
+
+    Ok(Dwarf { units })
+}
+
+/// Reads the header and the first entry of the unit at `offset`, returning
+/// the unit with the offset of its line table and where the next unit starts.
+fn read_unit(sections: &DebugSections, offset: usize, endianness: scroll::Endian) -> Result<((Dwarf_unit, Option<u64>), usize), Error> {
+    let mut info = Reader::new(&sections.info, offset, endianness);
+    let (length, offset_size) = info.initial_length()?;
+    let end = info.end_at(length)?;
+
+    let version = info.u16()?;
+    if !(2..=5).contains(&version) {
+        return Err(Error::from(Problem::msg(format!("Unsupported DWARF version {}", version)).at(info.file_offset(info.pos - 2))));
+    }
+    let (unit_type, address_size, abbrev_offset) = if version >= 5 {
+        let unit_type = info.u8()?;
+        let address_size = info.u8()?;
+        let abbrev_offset = info.offset(offset_size)?;
+        match unit_type {
+            DW_UT_skeleton | DW_UT_split_compile => { info.u64()?; },
+            DW_UT_type | DW_UT_split_type => { info.u64()?; info.offset(offset_size)?; },
+            _ => {},
+        }
+        (unit_type, address_size, abbrev_offset)
+    } else {
+        let abbrev_offset = info.offset(offset_size)?;
+        (DW_UT_compile, info.u8()?, abbrev_offset)
+    };
+    let format = Format { version, offset_size, address_size };
+
+    let mut unit = Dwarf_unit {
+        offset: offset as u64,
+        version,
+        unit_type,
+        address_size,
+        ..Dwarf_unit::default()
+    };
+
+    let code = info.uleb()?;
+    if code == 0 {
+        return Ok(((unit, None), end));
+    }
+    let attributes = find_abbrev(sections, abbrev_offset, code, endianness)?;
+
+    let mut values = Vec::with_capacity(attributes.len());
+    for (name, form, implicit) in attributes {
+        let value = info.value(form, implicit, format)?;
+        values.push((name, value));
+    }
+    let attribute = |name| values.iter().find(|(at, _)| *at == name).map(|(_, value)| value);
+
+    let str_offsets_base = attribute(DW_AT_str_offsets_base).and_then(Value::unsigned);
+    let addr_base = attribute(DW_AT_addr_base).or_else(|| attribute(DW_AT_GNU_addr_base)).and_then(Value::unsigned);
+    let resolve = Resolver { sections, format, str_offsets_base, addr_base, endianness };
+
+    unit.name = attribute(DW_AT_name).map(|value| resolve.string(value)).transpose()?;
+    unit.producer = attribute(DW_AT_producer).map(|value| resolve.string(value)).transpose()?;
+    unit.comp_dir = attribute(DW_AT_comp_dir).map(|value| resolve.string(value)).transpose()?;
+    unit.dwo_name = attribute(DW_AT_dwo_name).or_else(|| attribute(DW_AT_GNU_dwo_name)).map(|value| resolve.string(value)).transpose()?;
+    unit.language = attribute(DW_AT_language).and_then(Value::unsigned).map(|lang| lang as u16);
+    unit.low_pc = attribute(DW_AT_low_pc).map(|value| resolve.address(value)).transpose()?;
+    // Since version 4 the high bound can be a length from the low one
+    unit.high_pc = match attribute(DW_AT_high_pc) {
+        Some(&Value::Unsigned(size)) => unit.low_pc.map(|low_pc| low_pc.wrapping_add(size)),
+        Some(value) => Some(resolve.address(value)?),
+        None => None,
+    };
+    let stmt_list = attribute(DW_AT_stmt_list).and_then(Value::unsigned);
+
+    Ok(((unit, stmt_list), end))
+}
+
+/// Attributes of abbreviation `code` of the table at `offset` in `.debug_abbrev`,
+/// as their name, form and the value of implicit constants.
+fn find_abbrev(sections: &DebugSections, offset: u64, code: u64, endianness: scroll::Endian) -> Result<Vec<(u64, u64, i64)>, Error> {
+    let mut abbrev = Reader::new(&sections.abbrev, offset as usize, endianness);
+    loop {
+        let entry = abbrev.uleb()?;
+        if entry == 0 {
+            return Err(Error::from(Problem::msg(format!("Abbreviation {} not found", code))
+                                   .at(sections.abbrev.offset.saturating_add(offset))
+                                   .within("abbreviation table")));
+        }
+        let _tag = abbrev.uleb()?;
+        let _children = abbrev.u8()?;
+        let mut attributes = Vec::new();
+        loop {
+            let name = abbrev.uleb()?;
+            let form = abbrev.uleb()?;
+            if name == 0 && form == 0 {
+                break;
+            }
+            let implicit = if form == DW_FORM_implicit_const { abbrev.sleb()? } else { 0 };
+            attributes.push((name, form, implicit));
+        }
+        if entry == code {
+            return Ok(attributes);
+        }
+    }
+}
+
+/// Reads the header and runs the program of the line table at `offset` in
+/// `.debug_line`, returning the paths of its files and its rows.
+fn read_lines(sections: &DebugSections, unit: &Dwarf_unit, offset: u64, comp_dir: Option<&str>, endianness: scroll::Endian) -> Result<(Vec<String>, Vec<Dwarf_line>), Error> {
+    let mut line = Reader::new(&sections.line, offset as usize, endianness);
+    let (length, offset_size) = line.initial_length()?;
+    let end = line.end_at(length)?;
+
+    let version = line.u16()?;
+    if !(2..=5).contains(&version) {
+        return Err(Error::from(Problem::msg(format!("Unsupported line table version {}", version)).at(line.file_offset(line.pos - 2))));
+    }
+    let mut address_size = unit.address_size;
+    if version >= 5 {
+        address_size = line.u8()?;
+        let _segment_selector_size = line.u8()?;
+    }
+    let header_length = line.offset(offset_size)?;
+    let program = line.end_at(header_length)?;
+    let min_inst_length = line.u8()? as u64;
+    if version >= 4 {
+        let _max_ops_per_inst = line.u8()?;
+    }
+    let default_is_stmt = line.u8()? != 0;
+    let line_base = line.u8()? as i8 as i64;
+    let line_range = line.u8()?;
+    let opcode_base = line.u8()?;
+    let mut opcode_lengths = Vec::with_capacity(opcode_base as usize);
+    for _ in 1..opcode_base {
+        opcode_lengths.push(line.u8()?);
+    }
+    if line_range == 0 {
+        return Err(Error::from(Problem::msg("Line range is 0").at(line.file_offset(line.pos)).within("line table header")));
+    }
+
+    let format = Format { version, offset_size, address_size };
+    let resolve = Resolver { sections, format, str_offsets_base: None, addr_base: None, endianness };
+    let join = |dir: Option<&str>, path: String| match dir {
+        Some(dir) if !path.starts_with('/') && !dir.is_empty() => format!("{}/{}", dir.trim_end_matches('/'), path),
+        _ => path,
+    };
+
+    let mut files = Vec::new();
+    if version >= 5 {
+        // Entries are made of the fields their format lists, in that order
+        let entries = |line: &mut Reader| -> Result<Vec<(Option<String>, u64)>, Error> {
+            let format_count = line.u8()?;
+            let mut fields = Vec::with_capacity(format_count as usize);
+            for _ in 0..format_count {
+                fields.push((line.uleb()?, line.uleb()?));
+            }
+            let count_pos = line.pos;
+            let count = line.uleb()?;
+            // Entries without fields, or more entries than bytes left, only serve to exhaust memory
+            if (fields.is_empty() && count != 0) || count > end.saturating_sub(line.pos) as u64 {
+                return Err(Error::from(Problem::msg(format!("{} entries of {} fields do not fit in the header", count, fields.len()))
+                                       .at(line.file_offset(count_pos))));
+            }
+            let mut entries = Vec::new();
+            for _ in 0..count {
+                let mut entry = (None, 0);
+                for &(content, form) in &fields {
+                    let value = line.value(form, 0, format)?;
+                    match content {
+                        DW_LNCT_path => entry.0 = Some(resolve.string(&value)?),
+                        DW_LNCT_directory_index => entry.1 = value.unsigned().unwrap_or(0),
+                        _ => {},
+                    }
+                }
+                entries.push(entry);
+            }
+            Ok(entries)
+        };
+        let directories = entries(&mut line)?;
+        for (path, dir) in entries(&mut line)? {
+            let dir = directories.get(dir as usize).and_then(|(dir, _)| dir.as_ref()).map(String::as_str);
+            files.push(join(dir, path.unwrap_or_default()));
+        }
+    } else {
+        // Directory 0 is that of the compiler, left out of the table
+        let mut directories = Vec::new();
+        loop {
+            let dir = line.cstr()?;
+            if dir.is_empty() {
+                break;
+            }
+            directories.push(dir);
+        }
+        loop {
+            let path = line.cstr()?;
+            if path.is_empty() {
+                break;
+            }
+            let dir = line.uleb()?;
+            let _mtime = line.uleb()?;
+            let _length = line.uleb()?;
+            let dir = match dir {
+                0 => comp_dir,
+                dir => directories.get(dir as usize - 1).map(String::as_str),
+            };
+            files.push(join(dir, path));
+        }
+    }
+
+    // Files are numbered from 1 before version 5
+    let first_file = if version >= 5 { 0 } else { 1 };
+    let mut rows = Vec::new();
+    line.pos = program;
+
+    let mut address = 0u64;
+    let mut file = 1u64;
+    let mut number = 1u64;
+    let mut column = 0u64;
+    let mut _is_stmt = default_is_stmt;
+    let row = |rows: &mut Vec<Dwarf_line>, address, file: u64, number, column, end_sequence| rows.push(Dwarf_line {
+        address,
+        file: file.wrapping_sub(first_file) as usize,
+        line: number,
+        column,
+        end_sequence,
+    });
+
+    while line.pos < end {
+        let opcode = line.u8()?;
+        if opcode >= opcode_base {
+            let adjusted = opcode - opcode_base;
+            address = address.wrapping_add((adjusted / line_range) as u64 * min_inst_length);
+            number = (number as i64).wrapping_add(line_base + (adjusted % line_range) as i64) as u64;
+            row(&mut rows, address, file, number, column, false);
+            continue;
+        }
+        match opcode {
+            0 => {
+                let length = line.uleb()?;
+                let next = line.end_at(length)?;
+                if length == 0 {
+                    continue;
+                }
+                match line.u8()? {
+                    DW_LNE_end_sequence => {
+                        row(&mut rows, address, file, number, column, true);
+                        address = 0;
+                        file = 1;
+                        number = 1;
+                        column = 0;
+                        _is_stmt = default_is_stmt;
+                    },
+                    // The operand takes what is left of the opcode
+                    DW_LNE_set_address => address = match length - 1 {
+                        size @ (1 | 2 | 4 | 8) => line.address(size as u8)?,
+                        size => return Err(Error::from(Problem::msg(format!("Invalid address size {}", size))
+                                                       .at(line.file_offset(line.pos))
+                                                       .within("DW_LNE_set_address"))),
+                    },
+                    DW_LNE_define_file => {
+                        let path = line.cstr()?;
+                        let dir = line.uleb()?;
+                        let dir = if dir == 0 { comp_dir } else { None };
+                        files.push(join(dir, path));
+                    },
+                    _ => {},
+                }
+                line.pos = next;
+            },
+            DW_LNS_copy => row(&mut rows, address, file, number, column, false),
+            DW_LNS_advance_pc => address = address.wrapping_add(line.uleb()?.wrapping_mul(min_inst_length)),
+            DW_LNS_advance_line => number = (number as i64).wrapping_add(line.sleb()?) as u64,
+            DW_LNS_set_file => file = line.uleb()?,
+            DW_LNS_set_column => column = line.uleb()?,
+            DW_LNS_negate_stmt => _is_stmt = !_is_stmt,
+            DW_LNS_basic_block => {},
+            DW_LNS_const_add_pc => address = address.wrapping_add(((255 - opcode_base) / line_range) as u64 * min_inst_length),
+            DW_LNS_fixed_advance_pc => address = address.wrapping_add(line.u16()? as u64),
+            // Opcodes this reader does not know say how many arguments they take
+            _ => for _ in 0..opcode_lengths[opcode as usize - 1] {
+                line.uleb()?;
+            },
+        }
+    }
+
+    Ok((files, rows))
+}
+
+/// Encoding of a unit or line table.
+#[derive(Debug, Clone, Copy)]
+struct Format {
+    version:      u16,
+    /// 4 bytes for 32-bit DWARF, 8 for 64-bit DWARF
+    offset_size:  u8,
+    address_size: u8,
+}
+
+/// Attribute value, before strings and addresses are looked up.
+#[derive(Debug)]
+enum Value {
+    Unsigned(u64),
+    Signed(i64),
+    Address(u64),
+    String(String),
+    /// Offset in `.debug_str`
+    Strp(u64),
+    /// Offset in `.debug_line_str`
+    LineStrp(u64),
+    /// Index in the string offsets of the unit
+    Strx(u64),
+    /// Index in the addresses of the unit
+    Addrx(u64),
+    Other,
+}
+
+impl Value {
+    fn unsigned(&self) -> Option<u64> {
+        match *self {
+            Value::Unsigned(value) => Some(value),
+            Value::Signed(value) => Some(value as u64),
+            _ => None,
+        }
+    }
+}
+
+/// Looks up the strings and addresses values point to.
+struct Resolver<'a, 'b> {
+    sections:         &'a DebugSections<'b>,
+    format:           Format,
+    str_offsets_base: Option<u64>,
+    addr_base:        Option<u64>,
+    endianness:       scroll::Endian,
+}
+
+impl<'a, 'b> Resolver<'a, 'b> {
+
+    fn string(&self, value: &Value) -> Result<String, Error> {
+        match *value {
+            Value::String(ref string) => Ok(string.clone()),
+            Value::Strp(offset) => Reader::new(&self.sections.str, offset as usize, self.endianness).cstr(),
+            Value::LineStrp(offset) => Reader::new(&self.sections.line_str, offset as usize, self.endianness).cstr(),
+            Value::Strx(index) => {
+                // The base defaults to just after the header of the string offsets
+                let base = self.str_offsets_base.unwrap_or(2 * self.format.offset_size as u64);
+                let entry = entry(&self.sections.str_offsets, base, index, self.format.offset_size, "string offset")?;
+                let offset = Reader::new(&self.sections.str_offsets, entry, self.endianness).offset(self.format.offset_size)?;
+                Reader::new(&self.sections.str, offset as usize, self.endianness).cstr()
+            },
+            _ => Err(Error::from(Problem::msg(format!("Expected a string, found {:?}", value)))),
+        }
+    }
+
+    fn address(&self, value: &Value) -> Result<u64, Error> {
+        match *value {
+            Value::Address(address) | Value::Unsigned(address) => Ok(address),
+            Value::Addrx(index) => {
+                let base = self.addr_base.unwrap_or(8);
+                let entry = entry(&self.sections.addr, base, index, self.format.address_size, "address")?;
+                Reader::new(&self.sections.addr, entry, self.endianness).address(self.format.address_size)
+            },
+            _ => Err(Error::from(Problem::msg(format!("Expected an address, found {:?}", value)))),
+        }
+    }
+
+}
+
+/// Position of entry `index` of `size` bytes in the table at `base` of
+/// `section`, checked not to overflow.
+fn entry(section: &DebugSection, base: u64, index: u64, size: u8, what: &str) -> Result<usize, Error> {
+    index.checked_mul(size as u64)
+        .and_then(|offset| offset.checked_add(base))
+        .map(|entry| entry as usize)
+        .ok_or_else(|| {
+            let problem = Problem::msg(format!("Index {:#X} of {} table at {:#X} is out of range", index, what, base));
+            // A missing section has no offset to point at
+            Error::from(if section.data.is_empty() { problem } else { problem.at(section.offset.saturating_add(base)) })
+        })
+}
+
+/// Cursor over a debug section.
+struct Reader<'a> {
+    data:       &'a [u8],
+    /// Offset of the section in the file, for errors
+    base:       u64,
+    pos:        usize,
+    endianness: scroll::Endian,
+}
+
+impl<'a> Reader<'a> {
+
+    fn new(section: &'a DebugSection, pos: usize, endianness: scroll::Endian) -> Reader<'a> {
+        Reader { data: &section.data, base: section.offset, pos, endianness }
+    }
+
+    fn file_offset(&self, pos: usize) -> u64 {
+        // Positions come from the file, and may be anything
+        self.base.saturating_add(pos as u64)
+    }
+
+    fn read<T>(&mut self, size: usize, what: &str) -> Result<T, Error>
+        where T: scroll::ctx::TryFromCtx<'a, scroll::Endian, Error = scroll::Error, Size = usize> {
+
+        let value = self.data.pread_with::<T>(self.pos, self.endianness).at(self.file_offset(self.pos), what)?;
+        self.pos += size;
+        Ok(value)
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        self.read(1, "DWARF byte")
+    }
+
+    fn u16(&mut self) -> Result<u16, Error> {
+        self.read(2, "DWARF half word")
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        self.read(4, "DWARF word")
+    }
+
+    fn u64(&mut self) -> Result<u64, Error> {
+        self.read(8, "DWARF double word")
+    }
+
+    fn uleb(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= ((byte & 0x7F) as u64) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn sleb(&mut self) -> Result<i64, Error> {
+        let mut value = 0i64;
+        let mut shift = 0;
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= ((byte & 0x7F) as i64) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+                return Ok(value);
+            }
+        }
+    }
+
+    fn cstr(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        let string = self.data.get(start..)
+            .and_then(|rest| rest.iter().position(|&b| b == 0).map(|end| &rest[..end]))
+            .ok_or_else(|| Problem::msg("Unterminated string").at(self.file_offset(start)).within("DWARF string"))?;
+        self.pos += string.len() + 1;
+        Ok(String::from_utf8_lossy(string).into_owned())
+    }
+
+    fn offset(&mut self, offset_size: u8) -> Result<u64, Error> {
+        if offset_size == 8 { self.u64() } else { self.u32().map(u64::from) }
+    }
+
+    fn address(&mut self, address_size: u8) -> Result<u64, Error> {
+        match address_size {
+            1 => self.u8().map(u64::from),
+            2 => self.u16().map(u64::from),
+            4 => self.u32().map(u64::from),
+            8 => self.u64(),
+            _ => Err(Error::from(Problem::msg(format!("Invalid address size {}", address_size)).at(self.file_offset(self.pos)))),
+        }
+    }
+
+    /// Length of a unit or table, with the size of the offsets it uses.
+    fn initial_length(&mut self) -> Result<(u64, u8), Error> {
+        match self.u32()? {
+            0xFFFF_FFFF => Ok((self.u64()?, 8)),
+            length => Ok((length as u64, 4)),
+        }
+    }
+
+    /// Position `length` bytes past the current one, checked to lie inside the section.
+    fn end_at(&self, length: u64) -> Result<usize, Error> {
+        match (self.pos as u64).checked_add(length) {
+            Some(end) if end <= self.data.len() as u64 => Ok(end as usize),
+            _ => Err(Error::from(Problem::msg(format!("Length {:#X} runs past the end of the section", length))
+                                 .at(self.file_offset(self.pos)))),
+        }
+    }
+
+    fn skip(&mut self, length: u64) -> Result<Value, Error> {
+        self.pos = self.end_at(length)?;
+        Ok(Value::Other)
+    }
+
+    /// Reads a value of attribute form `form`.
+    fn value(&mut self, form: u64, implicit: i64, format: Format) -> Result<Value, Error> {
+        let value = match form {
+            DW_FORM_addr => Value::Address(self.address(format.address_size)?),
+            DW_FORM_data1 | DW_FORM_ref1 | DW_FORM_flag => Value::Unsigned(self.u8()? as u64),
+            DW_FORM_data2 | DW_FORM_ref2 => Value::Unsigned(self.u16()? as u64),
+            DW_FORM_data4 | DW_FORM_ref4 | DW_FORM_ref_sup4 => Value::Unsigned(self.u32()? as u64),
+            DW_FORM_data8 | DW_FORM_ref8 | DW_FORM_ref_sig8 | DW_FORM_ref_sup8 => Value::Unsigned(self.u64()?),
+            DW_FORM_data16 => self.skip(16)?,
+            DW_FORM_sdata => Value::Signed(self.sleb()?),
+            DW_FORM_udata | DW_FORM_ref_udata | DW_FORM_loclistx | DW_FORM_rnglistx => Value::Unsigned(self.uleb()?),
+            DW_FORM_string => Value::String(self.cstr()?),
+            DW_FORM_strp => Value::Strp(self.offset(format.offset_size)?),
+            DW_FORM_line_strp => Value::LineStrp(self.offset(format.offset_size)?),
+            DW_FORM_strp_sup | DW_FORM_GNU_strp_alt | DW_FORM_GNU_ref_alt | DW_FORM_sec_offset => {
+                Value::Unsigned(self.offset(format.offset_size)?)
+            },
+            // Version 2 wrote references to other units with the size of addresses
+            DW_FORM_ref_addr => if format.version == 2 {
+                Value::Unsigned(self.address(format.address_size)?)
+            } else {
+                Value::Unsigned(self.offset(format.offset_size)?)
+            },
+            DW_FORM_strx | DW_FORM_GNU_str_index => Value::Strx(self.uleb()?),
+            DW_FORM_strx1 => Value::Strx(self.u8()? as u64),
+            DW_FORM_strx2 => Value::Strx(self.u16()? as u64),
+            DW_FORM_strx3 => Value::Strx(self.u24()?),
+            DW_FORM_strx4 => Value::Strx(self.u32()? as u64),
+            DW_FORM_addrx | DW_FORM_GNU_addr_index => Value::Addrx(self.uleb()?),
+            DW_FORM_addrx1 => Value::Addrx(self.u8()? as u64),
+            DW_FORM_addrx2 => Value::Addrx(self.u16()? as u64),
+            DW_FORM_addrx3 => Value::Addrx(self.u24()?),
+            DW_FORM_addrx4 => Value::Addrx(self.u32()? as u64),
+            DW_FORM_block1 => { let length = self.u8()? as u64; self.skip(length)? },
+            DW_FORM_block2 => { let length = self.u16()? as u64; self.skip(length)? },
+            DW_FORM_block4 => { let length = self.u32()? as u64; self.skip(length)? },
+            DW_FORM_block | DW_FORM_exprloc => { let length = self.uleb()?; self.skip(length)? },
+            DW_FORM_flag_present => Value::Unsigned(1),
+            DW_FORM_implicit_const => Value::Signed(implicit),
+            DW_FORM_indirect => {
+                let form = self.uleb()?;
+                // Indirection does not nest, which also bounds the recursion
+                if form == DW_FORM_indirect {
+                    return Err(Error::from(Problem::msg("Nested indirect form").at(self.file_offset(self.pos))));
+                }
+                let implicit = if form == DW_FORM_implicit_const { self.sleb()? } else { 0 };
+                self.value(form, implicit, format)?
+            },
+            _ => return Err(Error::from(Problem::msg(format!("Unknown attribute form {:#X}", form)).at(self.file_offset(self.pos)))),
+        };
+        Ok(value)
+    }
+
+    fn u24(&mut self) -> Result<u64, Error> {
+        let bytes = [self.u8()? as u64, self.u8()? as u64, self.u8()? as u64];
+        Ok(if self.endianness == scroll::LE {
+            bytes[0] | bytes[1] << 8 | bytes[2] << 16
+        } else {
+            bytes[0] << 16 | bytes[1] << 8 | bytes[2]
+        })
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(data: &[u8]) -> DebugSection<'_> {
+        DebugSection { data: Cow::Borrowed(data), offset: 0 }
+    }
+
+    #[test]
+    fn leb128() {
+        let data = section(&[0xE5, 0x8E, 0x26, 0x7F, 0x80, 0x7F]);
+        let mut reader = Reader::new(&data, 0, scroll::LE);
+        assert_eq!(reader.uleb().unwrap(), 624485);
+        assert_eq!(reader.sleb().unwrap(), -1);
+        assert_eq!(reader.sleb().unwrap(), -128);
+        assert!(reader.uleb().is_err());
+    }
+
+    #[test]
+    fn line_program() {
+        // Version 2 table with one file, mapping 0x1000 to line 3 and 0x1004 to line 5
+        let mut line = vec![0, 0, 0, 0, 2, 0, 0, 0, 0, 0];
+        let header_start = line.len();
+        line.extend_from_slice(&[1, 1, 0xFB, 14, 13, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1]);
+        line.extend_from_slice(b"\0a.c\0\0\0\0\0");
+        let header_length = line.len() - header_start;
+        line[6] = header_length as u8;
+        line.extend_from_slice(&[0, 9, DW_LNE_set_address, 0, 0x10, 0, 0, 0, 0, 0, 0]);
+        line.extend_from_slice(&[DW_LNS_advance_line, 2, DW_LNS_copy]);
+        // Special opcode advancing the address by 4 and the line by 2
+        line.push(13 + 4 * 14 + (2 + 5));
+        line.extend_from_slice(&[DW_LNS_advance_pc, 2, 0, 1, DW_LNE_end_sequence]);
+        let length = line.len() - 4;
+        line[0] = length as u8;
+
+        let sections = DebugSections { line: section(&line), ..DebugSections::default() };
+        let unit = Dwarf_unit { address_size: 8, ..Dwarf_unit::default() };
+        let (files, lines) = read_lines(&sections, &unit, 0, Some("/src"), scroll::LE).unwrap();
+        assert_eq!(files, vec!["/src/a.c"]);
+
+        let dwarf = Dwarf { units: vec![Dwarf_unit { files, lines, ..unit }] };
+        let found = dwarf.find_line(0x1005).unwrap();
+        assert_eq!((found.file, found.line), (Some("/src/a.c"), 5));
+        assert_eq!(dwarf.find_line(0x1002).unwrap().line, 3);
+        assert!(dwarf.find_line(0x1006).is_none());
+        assert!(dwarf.find_line(0xFFF).is_none());
+    }
+
+}
@@ -0,0 +1,133 @@
+//! Exploit mitigations an ELF executable or library was built with, as
+//! checksec reports them.
+
+use scroll::Pread;
+
+use super::*;
+
+/// How much of the relocated data is made read-only after loading.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relro {
+    None,
+    /// Everything but the GOT entries of lazily bound functions
+    Partial,
+    /// Everything, since all symbols are bound at load time
+    Full,
+}
+
+/// Whether the file can be loaded anywhere in memory.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pie {
+    /// Executable linked at a fixed address
+    No,
+    /// Position independent executable
+    Pie,
+    /// Shared library
+    Dso,
+    /// Object file, not yet linked
+    Rel,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Hardening {
+    pub relro:  Relro,
+    /// Stack is not executable
+    pub nx:     bool,
+    pub pie:    Pie,
+    /// Uses `__stack_chk_fail`
+    pub canary: bool,
+    /// Checked variants of libc functions called, like `__memcpy_chk`
+    pub fortified: Vec<String>,
+    /// Compatible with Intel CET Indirect Branch Tracking
+    pub ibt:    bool,
+    /// Compatible with Intel CET Shadow Stack
+    pub shstk:  bool,
+}
+
+impl Elf {
+
+    /// Mitigations derived from program headers, dynamic entries, symbols and notes.
+    pub fn hardening(&self) -> Hardening {
+        let has_segment = |p_type| self.program_headers.iter().any(|header| header.p_type == p_type);
+        let dynamic = |tag| self.dynamic.iter().find(|entry| entry.d_tag == tag).map(|entry| entry.d_ptr);
+
+        let bind_now = dynamic(DT_BIND_NOW).is_some()
+            || dynamic(DT_FLAGS).is_some_and(|flags| flags & DF_BIND_NOW != 0)
+            || dynamic(DT_FLAGS_1).is_some_and(|flags| flags & DF_1_NOW != 0);
+        let relro = match (has_segment(PT_GNU_RELRO), bind_now) {
+            (false, _) => Relro::None,
+            (true, false) => Relro::Partial,
+            (true, true) => Relro::Full,
+        };
+
+        // Without PT_GNU_STACK loaders fall back to an executable stack
+        let nx = self.program_headers.iter()
+            .find(|header| header.p_type == PT_GNU_STACK)
+            .is_some_and(|header| header.p_flags & PF_X == 0);
+
+        let pie = match self.header.e_type {
+            ET_EXEC => Pie::No,
+            ET_REL => Pie::Rel,
+            // Linkers older than DF_1_PIE leave only the interpreter to tell executables apart
+            _ if dynamic(DT_FLAGS_1).is_some_and(|flags| flags & DF_1_PIE != 0) || has_segment(PT_INTERP) => Pie::Pie,
+            _ => Pie::Dso,
+        };
+
+        // Functions imported from libc, or linked in by static executables
+        let mut canary = false;
+        let mut fortified = Vec::new();
+        let symbols: Vec<(&Elf_symbol_header, &Vec<u8>)> = if self.dynsym.is_empty() {
+            self.symtab.iter().map(|sym| (sym, &self.symstr)).collect()
+        } else {
+            self.dynsym.iter().filter(|sym| sym.st_shndx as u32 == SHN_UNDEF).map(|sym| (sym, &self.dynstr)).collect()
+        };
+        for (sym, strtab) in symbols {
+            let name = match strtab.pread::<&str>(sym.st_name as usize) {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
+            if name == "__stack_chk_fail" || name == "__stack_chk_guard" {
+                canary = true;
+            }
+            else if name.starts_with("__") && name.ends_with("_chk") && !fortified.iter().any(|known| known == name) {
+                fortified.push(name.to_string());
+            }
+        }
+
+        let features = self.x86_features();
+
+        Hardening {
+            relro,
+            nx,
+            pie,
+            canary,
+            fortified,
+            ibt:   features & GNU_PROPERTY_X86_FEATURE_1_IBT != 0,
+            shstk: features & GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0,
+        }
+    }
+
+    /// GNU_PROPERTY_X86_FEATURE_1_AND bits of the NT_GNU_PROPERTY_TYPE_0
+    /// note. Other machines give the property number another meaning.
+    fn x86_features(&self) -> u32 {
+        if self.header.e_machine != EM_386 && self.header.e_machine != EM_X86_64 {
+            return 0;
+        }
+        let endianness = if self.header.e_ident.ei_data == ELFDATA2MSB { scroll::BE } else { scroll::LE };
+        // Properties are padded to the word size
+        let alignment = if self.header.e_ident.ei_class == ELFCLASS64 { 8 } else { 4 };
+
+        self.notes.iter()
+            .filter(|note| note.name == "GNU" && note.n_type == NT_GNU_PROPERTY_TYPE_0)
+            .fold(0, |features, note| features | property(&note.desc, GNU_PROPERTY_X86_FEATURE_1_AND, endianness, alignment))
+    }
+
+}
+
+/// Value of the `pr_type` property in the description of a
+/// NT_GNU_PROPERTY_TYPE_0 note, 0 if absent.
+fn property(desc: &[u8], pr_type: u32, endianness: scroll::Endian, alignment: usize) -> u32 {
+    note::read_properties(desc, endianness, alignment).iter()
+        .find(|property| property.pr_type == pr_type)
+        .map_or(0, |property| property.value(endianness))
+}
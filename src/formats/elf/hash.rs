@@ -0,0 +1,295 @@
+//! Hash tables the dynamic loader looks exported symbols up with: the SysV
+//! DT_HASH and the GNU DT_GNU_HASH, which adds a bloom filter in front.
+
+use scroll::Pread;
+
+use super::*;
+
+/// SysV hash table, one chain entry per dynamic symbol.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_hash {
+    /// First symbol of each chain, 0 for none
+    pub buckets: Vec<u32>,
+    /// Next symbol of each symbol's chain, 0 ending it
+    pub chains:  Vec<u32>,
+}
+
+/// GNU hash table, covering the symbols from `symoffset` on, sorted by bucket.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_gnu_hash {
+    /// Index of the first hashed symbol
+    pub symoffset:   u32,
+    pub bloom_shift: u32,
+    /// Size of a bloom filter word, 32 or 64 like addresses
+    pub bloom_bits:  u32,
+    pub bloom:       Vec<u64>,
+    /// First symbol of each chain, below `symoffset` for none
+    pub buckets:     Vec<u32>,
+    /// Hash of each symbol from `symoffset` on, the lowest bit ending a chain
+    pub chains:      Vec<u32>,
+}
+
+/// Why an exported symbol cannot be found through a hash table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Miss {
+    /// Before the first symbol of a GNU hash table
+    NotHashed,
+    /// Rejected by the bloom filter of a GNU hash table
+    Bloom,
+    /// Not in the chain of the bucket its name hashes to
+    Chain,
+}
+
+/// Exported dynamic symbol the loader would not find.
+#[derive(Debug)]
+pub struct Unreachable {
+    pub index: usize,
+    /// DT_HASH or DT_GNU_HASH
+    pub table: u64,
+    pub miss:  Miss,
+}
+
+/// Hash function of DT_HASH.
+pub fn elf_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |h, &c| {
+        let h = (h << 4).wrapping_add(c as u32);
+        (h ^ ((h & 0xF000_0000) >> 24)) & 0x0FFF_FFFF
+    })
+}
+
+/// Hash function of DT_GNU_HASH, from Bernstein.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |h, &c| h.wrapping_mul(33).wrapping_add(c as u32))
+}
+
+/// Reads the words of a table, checking first that they are all in `buf`.
+fn words(buf: &[u8], offset: u64, count: u64, size: u64, endianness: scroll::Endian, what: &'static str) -> Result<Vec<u64>, Error> {
+    let data = bytes(buf, offset, count.saturating_mul(size), what)?;
+    data.chunks(size as usize).enumerate()
+        .map(|(i, word)| if size == 4 {
+            word.pread_with::<u32>(0, endianness).map(u64::from)
+        } else {
+            word.pread_with::<u64>(0, endianness)
+        }.at(offset + i as u64 * size, what))
+        .collect()
+}
+
+/// Reads the DT_HASH table at file offset `offset`.
+pub fn read_hash(buf: &[u8], offset: u64, endianness: scroll::Endian) -> Result<Elf_hash, Error> {
+    let header = words(buf, offset, 2, 4, endianness, "DT_HASH header")?;
+    let buckets = words(buf, offset + 8, header[0], 4, endianness, "DT_HASH buckets")?;
+    let chains = words(buf, offset + 8 + header[0] * 4, header[1], 4, endianness, "DT_HASH chains")?;
+    Ok(Elf_hash {
+        buckets: buckets.into_iter().map(|word| word as u32).collect(),
+        chains: chains.into_iter().map(|word| word as u32).collect(),
+    })
+}
+
+/// Reads the DT_GNU_HASH table at file offset `offset`. Its size is not
+/// recorded anywhere, so the chains run to the end of the one starting last.
+pub fn read_gnu_hash(buf: &[u8], offset: u64, is_32: bool, endianness: scroll::Endian) -> Result<Elf_gnu_hash, Error> {
+    let header = words(buf, offset, 4, 4, endianness, "DT_GNU_HASH header")?;
+    let (nbuckets, symoffset, bloom_size, bloom_shift) = (header[0], header[1], header[2], header[3]);
+    let bloom_bits = if is_32 { 32 } else { 64 };
+    let bloom = words(buf, offset + 16, bloom_size, bloom_bits / 8, endianness, "DT_GNU_HASH bloom filter")?;
+    let buckets_offset = offset + 16 + bloom_size * (bloom_bits / 8);
+    let buckets = words(buf, buckets_offset, nbuckets, 4, endianness, "DT_GNU_HASH buckets")?;
+
+    let chains_offset = buckets_offset + nbuckets * 4;
+    let mut chains = Vec::new();
+    if let Some(&last) = buckets.iter().max().filter(|&&last| last >= symoffset) {
+        for i in symoffset.. {
+            let hash = words(buf, chains_offset + (i - symoffset) * 4, 1, 4, endianness, "DT_GNU_HASH chains")?[0] as u32;
+            chains.push(hash);
+            if i >= last && hash & 1 != 0 {
+                break;
+            }
+        }
+    }
+
+    Ok(Elf_gnu_hash {
+        symoffset: symoffset as u32,
+        bloom_shift: bloom_shift as u32,
+        bloom_bits: bloom_bits as u32,
+        bloom,
+        buckets: buckets.into_iter().map(|word| word as u32).collect(),
+        chains,
+    })
+}
+
+impl Elf_hash {
+
+    /// Number of symbols in each bucket.
+    pub fn chain_lengths(&self) -> Vec<usize> {
+        self.buckets.iter().map(|&first| {
+            let mut length = 0;
+            let mut i = first as usize;
+            // Looping chains end once they went through every symbol
+            while i != 0 && i < self.chains.len() && length < self.chains.len() {
+                length += 1;
+                i = self.chains[i] as usize;
+            }
+            length
+        }).collect()
+    }
+
+    /// Whether symbol `index` is in the chain `name` hashes to.
+    pub fn reaches(&self, index: usize, name: &[u8]) -> Result<(), Miss> {
+        if self.buckets.is_empty() {
+            return Err(Miss::Chain);
+        }
+        let mut i = self.buckets[elf_hash(name) as usize % self.buckets.len()] as usize;
+        let mut steps = 0;
+        while i != 0 && i < self.chains.len() && steps < self.chains.len() {
+            if i == index {
+                return Ok(());
+            }
+            i = self.chains[i] as usize;
+            steps += 1;
+        }
+        Err(Miss::Chain)
+    }
+
+}
+
+impl Elf_gnu_hash {
+
+    /// Number of dynamic symbols, hashed or not.
+    pub fn symbol_count(&self) -> u64 {
+        self.symoffset as u64 + self.chains.len() as u64
+    }
+
+    /// Number of symbols in each bucket.
+    pub fn chain_lengths(&self) -> Vec<usize> {
+        self.buckets.iter().map(|&first| {
+            let start = match first.checked_sub(self.symoffset) {
+                Some(start) if first != 0 => start as usize,
+                _ => return 0,
+            };
+            let mut length = 0;
+            for hash in self.chains.iter().skip(start) {
+                length += 1;
+                if hash & 1 != 0 {
+                    break;
+                }
+            }
+            length
+        }).collect()
+    }
+
+    /// Number of bits set in the bloom filter.
+    pub fn bloom_set(&self) -> u32 {
+        self.bloom.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Whether the loader finds symbol `index` named `name`: the bloom filter
+    /// must let its hash through, and its chain hold it with that hash. The
+    /// filter is indexed like glibc does, which assumes a power of 2 words.
+    pub fn reaches(&self, index: usize, name: &[u8]) -> Result<(), Miss> {
+        let symoffset = self.symoffset as usize;
+        if index < symoffset {
+            return Err(Miss::NotHashed);
+        }
+        let hash = gnu_hash(name);
+
+        let bits = self.bloom_bits;
+        let word = match self.bloom.len() {
+            0 => return Err(Miss::Bloom),
+            size => self.bloom[(hash / bits) as usize & (size - 1)],
+        };
+        let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> (self.bloom_shift % 32)) % bits));
+        if word & mask != mask {
+            return Err(Miss::Bloom);
+        }
+
+        if self.buckets.is_empty() {
+            return Err(Miss::Chain);
+        }
+        let first = self.buckets[hash as usize % self.buckets.len()] as usize;
+        if first == 0 || first < symoffset {
+            return Err(Miss::Chain);
+        }
+        for (i, chain) in self.chains.iter().enumerate().skip(first - symoffset) {
+            if i + symoffset == index && chain | 1 == hash | 1 {
+                return Ok(());
+            }
+            if chain & 1 != 0 {
+                break;
+            }
+        }
+        Err(Miss::Chain)
+    }
+
+}
+
+/// Exported symbols of `dynsym`, named in `dynstr`, that the hash tables do
+/// not lead to. Symbols with unreadable names are left to other checks.
+pub fn unreachable_symbols(hash: Option<&Elf_hash>, gnu_hash: Option<&Elf_gnu_hash>, dynsym: &[Elf_symbol_header], dynstr: &[u8]) -> Vec<Unreachable> {
+    let mut unreachable = Vec::new();
+    for (index, sym) in dynsym.iter().enumerate().skip(1) {
+        if sym.st_shndx as u32 == SHN_UNDEF || sym.st_info >> 4 == STB_LOCAL {
+            continue;
+        }
+        let name = match dynstr.pread::<&str>(sym.st_name as usize) {
+            Ok(name) if !name.is_empty() => name.as_bytes(),
+            _ => continue,
+        };
+        if let Some(Err(miss)) = hash.map(|hash| hash.reaches(index, name)) {
+            unreachable.push(Unreachable { index, table: DT_HASH, miss });
+        }
+        if let Some(Err(miss)) = gnu_hash.map(|gnu_hash| gnu_hash.reaches(index, name)) {
+            unreachable.push(Unreachable { index, table: DT_GNU_HASH, miss });
+        }
+    }
+    unreachable
+}
+
+impl Elf {
+
+    /// Exported dynamic symbols the loader would not find through the hash tables.
+    pub fn unreachable_symbols(&self) -> Vec<Unreachable> {
+        unreachable_symbols(self.hash.as_ref(), self.gnu_hash.as_ref(), &self.dynsym, &self.dynstr)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_functions() {
+        assert_eq!(elf_hash(b""), 0);
+        assert_eq!(elf_hash(b"printf"), 0x077905a6);
+        assert_eq!(gnu_hash(b""), 5381);
+        assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+    }
+
+    #[test]
+    fn lookups() {
+        // Symbols 1 and 2 in the only bucket, 3 left out
+        let hash = Elf_hash { buckets: vec![2], chains: vec![0, 0, 1, 0] };
+        assert_eq!(hash.chain_lengths(), [2]);
+        assert_eq!(hash.reaches(1, b"a"), Ok(()));
+        assert_eq!(hash.reaches(3, b"c"), Err(Miss::Chain));
+
+        // Symbol 1 hashed, with every bloom filter bit set but its chain
+        // holding the hash of another name
+        let mut gnu = Elf_gnu_hash {
+            symoffset: 1,
+            bloom_shift: 6,
+            bloom_bits: 64,
+            bloom: vec![!0],
+            buckets: vec![1],
+            chains: vec![gnu_hash(b"a") | 1],
+        };
+        assert_eq!(gnu.symbol_count(), 2);
+        assert_eq!(gnu.reaches(1, b"a"), Ok(()));
+        assert_eq!(gnu.reaches(1, b"c"), Err(Miss::Chain));
+        assert_eq!(gnu.reaches(0, b"a"), Err(Miss::NotHashed));
+        gnu.bloom = vec![0];
+        assert_eq!(gnu.reaches(1, b"a"), Err(Miss::Bloom));
+    }
+}
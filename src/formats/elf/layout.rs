@@ -0,0 +1,170 @@
+//! Where sections, segments and headers lie in an ELF file: the sections
+//! each segment holds, as readelf -l maps them, and the regions of the file
+//! in order, with the holes and overlaps between them.
+
+use super::*;
+
+/// What a region of the file holds.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionKind {
+    Header,
+    ProgramHeaders,
+    SectionHeaders,
+    /// Contents of the section at this index
+    Section(usize),
+    /// Bytes between two regions
+    Gap,
+    /// Bytes of segments past the last region, as in files without sections
+    SegmentData,
+    /// Bytes after the last region and the last segment
+    Trailing,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Region {
+    pub kind:     RegionKind,
+    pub offset:   u64,
+    pub size:     u64,
+    /// Region starting before this one and running into it
+    pub overlaps: Option<RegionKind>,
+}
+
+/// Whether `section` lies in `segment`, following binutils' strict
+/// ELF_SECTION_IN_SEGMENT: in its file bytes and addresses, TLS sections
+/// only in TLS, RELRO and LOAD segments, and .tbss only in PT_TLS.
+pub fn section_in_segment(section: &Elf_section_header, segment: &Elf_program_header) -> bool {
+    let tls = section.sh_flags & SHF_TLS as u64 != 0;
+    let alloc = section.sh_flags & SHF_ALLOC as u64 != 0;
+    let nobits = section.sh_type == SHT_NOBITS;
+    let tbss = tls && nobits;
+
+    let type_fits = if tls {
+        matches!(segment.p_type, PT_TLS | PT_GNU_RELRO | PT_LOAD)
+    } else {
+        segment.p_type != PT_TLS && segment.p_type != PT_PHDR
+    };
+    let needs_alloc = matches!(segment.p_type, PT_LOAD | PT_DYNAMIC | PT_GNU_EH_FRAME | PT_GNU_STACK | PT_GNU_RELRO);
+    if !type_fits || (needs_alloc && !alloc) || (tbss && segment.p_type != PT_TLS) {
+        return false;
+    }
+
+    // .tbss takes no room in the segments around it
+    let size = if tbss && segment.p_type != PT_TLS { 0 } else { section.sh_size };
+    let in_file = nobits || (section.sh_offset >= segment.p_offset
+        && section.sh_offset - segment.p_offset <= segment.p_filesz.wrapping_sub(1)
+        && (section.sh_offset - segment.p_offset).saturating_add(size) <= segment.p_filesz);
+    let in_memory = !alloc || (section.sh_addr >= segment.p_vaddr
+        && section.sh_addr - segment.p_vaddr <= segment.p_memsz.wrapping_sub(1)
+        && (section.sh_addr - segment.p_vaddr).saturating_add(size) <= segment.p_memsz);
+    if !in_file || !in_memory {
+        return false;
+    }
+
+    // Empty sections at the edges of PT_DYNAMIC and PT_NOTE are not theirs
+    if matches!(segment.p_type, PT_DYNAMIC | PT_NOTE) && section.sh_size == 0 && segment.p_memsz != 0 {
+        let inside_file = nobits || (section.sh_offset > segment.p_offset && section.sh_offset - segment.p_offset < segment.p_filesz);
+        let inside_memory = !alloc || (section.sh_addr > segment.p_vaddr && section.sh_addr - segment.p_vaddr < segment.p_memsz);
+        return inside_file && inside_memory;
+    }
+    true
+}
+
+impl Elf {
+
+    /// Indices of the sections each program header holds.
+    pub fn segment_sections(&self) -> Vec<Vec<usize>> {
+        self.program_headers.iter().map(|segment| {
+            self.section_headers.iter().enumerate()
+                .skip(1)
+                .filter(|(_, section)| section_in_segment(section, segment))
+                .map(|(i, _)| i)
+                .collect()
+        }).collect()
+    }
+
+    /// Regions of the file by offset: headers and section contents, with the
+    /// gaps between them and what follows the last one and the segments.
+    /// Empty and SHT_NOBITS sections take no room and are left out.
+    pub fn layout(&self) -> Vec<Region> {
+        let header = &self.header;
+        let mut regions: Vec<(RegionKind, u64, u64)> = vec![(RegionKind::Header, 0, header.e_ehsize as u64)];
+        if header.e_phnum != 0 {
+            regions.push((RegionKind::ProgramHeaders, header.e_phoff, header.e_phnum as u64 * header.e_phentsize as u64));
+        }
+        if !self.section_headers.is_empty() {
+            regions.push((RegionKind::SectionHeaders, header.e_shoff, self.section_headers.len() as u64 * header.e_shentsize as u64));
+        }
+        regions.extend(self.section_headers.iter().enumerate()
+                       .filter(|(_, section)| section.sh_type != SHT_NOBITS && section.sh_type != SHT_NULL && section.sh_size != 0)
+                       .map(|(i, section)| (RegionKind::Section(i), section.sh_offset, section.sh_size)));
+        regions.sort_by_key(|&(_, offset, size)| (offset, size));
+
+        let mut layout = Vec::new();
+        // Region reaching furthest so far
+        let mut last: Option<(RegionKind, u64)> = None;
+        for (kind, offset, size) in regions {
+            let end = offset.saturating_add(size);
+            let overlaps = match last {
+                Some((last_kind, last_end)) if offset < last_end => Some(last_kind),
+                Some((_, last_end)) if offset > last_end => {
+                    layout.push(Region { kind: RegionKind::Gap, offset: last_end, size: offset - last_end, overlaps: None });
+                    None
+                },
+                _ => None,
+            };
+            layout.push(Region { kind, offset, size, overlaps });
+            if last.is_none_or(|(_, last_end)| end > last_end) {
+                last = Some((kind, end));
+            }
+        }
+        // Segments go on where files without sections have none
+        let mut end = last.map_or(0, |(_, end)| end);
+        let segments_end = self.program_headers.iter()
+            .map(|header| header.p_offset.saturating_add(header.p_filesz))
+            .max()
+            .unwrap_or(0)
+            .min(self.file_size);
+        if segments_end > end {
+            layout.push(Region { kind: RegionKind::SegmentData, offset: end, size: segments_end - end, overlaps: None });
+            end = segments_end;
+        }
+        if end < self.file_size {
+            layout.push(Region { kind: RegionKind::Trailing, offset: end, size: self.file_size - end, overlaps: None });
+        }
+        layout
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(sh_type: u32, sh_flags: u32, sh_addr: u64, sh_offset: u64, sh_size: u64) -> Elf_section_header {
+        Elf_section_header {
+            sh_name: 0, sh_type, sh_flags: sh_flags as u64, sh_addr, sh_offset, sh_size,
+            sh_link: 0, sh_info: 0, sh_addralign: 0, sh_entsize: 0,
+        }
+    }
+
+    fn segment(p_type: u32, p_offset: u64, p_vaddr: u64, p_filesz: u64, p_memsz: u64) -> Elf_program_header {
+        Elf_program_header { p_type, p_flags: 0, p_offset, p_vaddr, p_paddr: p_vaddr, p_filesz, p_memsz, p_align: 0 }
+    }
+
+    #[test]
+    fn sections_in_segments() {
+        let load = segment(PT_LOAD, 0x1000, 0x2000, 0x100, 0x200);
+        let tls = segment(PT_TLS, 0x1080, 0x2080, 0x10, 0x20);
+        let data = section(SHT_PROGBITS, SHF_ALLOC | SHF_WRITE, 0x2000, 0x1000, 0x80);
+        let bss = section(SHT_NOBITS, SHF_ALLOC | SHF_WRITE, 0x2100, 0x1100, 0x100);
+        let tbss = section(SHT_NOBITS, SHF_ALLOC | SHF_WRITE | SHF_TLS, 0x2090, 0x1090, 0x10);
+        let comment = section(SHT_PROGBITS, 0, 0, 0x1010, 0x10);
+
+        assert!(section_in_segment(&data, &load));
+        assert!(section_in_segment(&bss, &load));
+        assert!(!section_in_segment(&tbss, &load));
+        assert!(section_in_segment(&tbss, &tls));
+        assert!(!section_in_segment(&data, &tls));
+        assert!(!section_in_segment(&comment, &load));
+    }
+}
@@ -3,15 +3,24 @@
 include!("constants_header.rs");
 include!("constants_relocation.rs");
 
-use scroll::{self, Pread, ctx};
+use scroll::{self, Pread, Pwrite};
 
 use failure::{
     Error,
 };
 
-use crate::Opt;
-use crate::Problem;
-use crate::format::{fmt_elf, fmt_elf_sym_table, fmt_elf_rel_table, fmt_elf_rela_table, fmt_elf_dynamic, align, fmt_indent};
+use crate::{Problem, ResultExt};
+use crate::formats::{align, bytes, Recovery};
+
+pub mod compress;
+pub mod coredump;
+pub mod dwarf;
+pub mod hardening;
+pub mod hash;
+pub mod layout;
+pub mod module;
+pub mod note;
+mod serialize;
 
 pub const ELF_MAGIC: &'static [u8; ELF_MAGIC_SIZE] = b"\x7FELF";
 pub const ELF_MAGIC_SIZE: usize = 4;
@@ -83,6 +92,8 @@ pub const PT_GNU_EH_FRAME: u32 = 0x6474e550;
 pub const PT_GNU_STACK: u32 = 0x6474e551;
 // Read-only after relocation
 pub const PT_GNU_RELRO: u32 = 0x6474e552;
+// GNU property notes for linker and run-time loaders
+pub const PT_GNU_PROPERTY: u32 = 0x6474e553;
 // Sun Specific segment
 pub const PT_LOSUNW: u32 = 0x6ffffffa;
 // Sun Specific segment
@@ -101,11 +112,11 @@ pub const PT_ARM_EXIDX: u32 = 0x70000001;
 pub const PT_HIPROC: u32 = 0x7fffffff;
 
 // Segment is executable
-const PF_X: u32 = 1 << 0;
+pub const PF_X: u32 = 1 << 0;
 // Segment is writable
-const PF_W: u32 = 1 << 1;
+pub const PF_W: u32 = 1 << 1;
 // Segment is readable
-const PF_R: u32 = 1 << 2;
+pub const PF_R: u32 = 1 << 2;
 
 #[inline]
 pub fn pt_to_str(pt: u32) -> &'static str {
@@ -123,6 +134,7 @@ pub fn pt_to_str(pt: u32) -> &'static str {
         PT_GNU_EH_FRAME => "PT_GNU_EH_FRAME",
         PT_GNU_STACK => "PT_GNU_STACK",
         PT_GNU_RELRO => "PT_GNU_RELRO",
+        PT_GNU_PROPERTY => "PT_GNU_PROPERTY",
         PT_SUNWBSS => "PT_SUNWBSS",
         PT_SUNWSTACK => "PT_SUNWSTACK",
         PT_HIOS => "PT_HIOS",
@@ -193,8 +205,10 @@ pub const SHT_PREINIT_ARRAY: u32 = 16;
 pub const SHT_GROUP: u32 = 17;
 // Extended section indeces.
 pub const SHT_SYMTAB_SHNDX: u32 = 18;
+// Packed relative relocations.
+pub const SHT_RELR: u32 = 19;
 // Number of defined types.
-pub const SHT_NUM: u32 = 19;
+pub const SHT_NUM: u32 = 20;
 // Start OS-specific.
 pub const SHT_LOOS: u32 = 0x60000000;
 // Object attributes.
@@ -300,6 +314,7 @@ pub fn sht_to_str(sht: u32) -> &'static str {
         SHT_PREINIT_ARRAY => "SHT_PREINIT_ARRAY",
         SHT_GROUP => "SHT_GROUP",
         SHT_SYMTAB_SHNDX => "SHT_SYMTAB_SHNDX",
+        SHT_RELR => "SHT_RELR",
         SHT_NUM => "SHT_NUM",
         SHT_LOOS => "SHT_LOOS",
         SHT_GNU_ATTRIBUTES => "SHT_GNU_ATTRIBUTES",
@@ -362,7 +377,7 @@ const STB_HIPROC: u8 = 15;
 
 // === Sym types ===
 // Symbol type is unspecified.
-const STT_NOTYPE: u8 = 0;
+pub const STT_NOTYPE: u8 = 0;
 // Symbol is a data object.
 pub const STT_OBJECT: u8 = 1;
 // Symbol is a code object.
@@ -416,7 +431,7 @@ pub fn type_to_str(typ: u8) -> &'static str {
     }
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct E_ident {
     pub ei_mag: [u8; 4],
@@ -425,10 +440,10 @@ pub struct E_ident {
     pub ei_version: u8,
     pub ei_osabi: u8,
     pub ei_abiversion: u8,
-    ei_pad: [u8; 7],
+    pub ei_pad: [u8; 7],
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_header_32 {
     e_ident:     E_ident,
@@ -447,7 +462,7 @@ struct Elf_header_32 {
     e_shstrndx:  u16,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct Elf_header {
     pub e_ident:     E_ident,
@@ -494,7 +509,7 @@ impl From<Elf_header_32> for Elf_header {
 // const SIZE_OF_ELF_HEADER_32: usize = 52;
 // const SIZE_OF_ELF_HEADER_64: usize = 64;
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_program_header_32 {
     p_type:   u32,
@@ -507,17 +522,17 @@ struct Elf_program_header_32 {
     p_align:  u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Elf_program_header {
-    p_type:   u32,
-    p_flags:  u32,
-    p_offset: u64,
-    p_vaddr:  u64,
-    p_paddr:  u64,
-    p_filesz: u64,
-    p_memsz:  u64,
-    p_align:  u64,
+pub struct Elf_program_header {
+    pub p_type:   u32,
+    pub p_flags:  u32,
+    pub p_offset: u64,
+    pub p_vaddr:  u64,
+    pub p_paddr:  u64,
+    pub p_filesz: u64,
+    pub p_memsz:  u64,
+    pub p_align:  u64,
 }
 
 impl From<Elf_program_header_32> for Elf_program_header {
@@ -542,7 +557,7 @@ impl From<Elf_program_header_32> for Elf_program_header {
 // const SIZE_OF_PROGRAM_HEADER_32: usize = 8 * 4;
 // const SIZE_OF_PROGRAM_HEADER_64: usize = 6 * 8 + 2 * 4;
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_section_header_32 {
     sh_name:      u32,
@@ -557,7 +572,7 @@ struct Elf_section_header_32 {
     sh_entsize:   u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct Elf_section_header {
     pub sh_name:      u32,
@@ -593,7 +608,7 @@ impl From<Elf_section_header_32> for Elf_section_header {
 
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_symbol_header_32 {
     st_name: u32,
@@ -604,7 +619,7 @@ struct Elf_symbol_header_32 {
     st_shndx: u16,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct Elf_symbol_header {
     pub st_name: u32,
@@ -632,14 +647,14 @@ impl From<Elf_symbol_header_32> for Elf_symbol_header {
 
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_rel_32 {
     r_offset: u32,
     r_info:   u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct Elf_rel {
     pub r_offset: u64,
@@ -659,7 +674,7 @@ impl From<Elf_rel_32> for Elf_rel {
 
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_rela_32 {
     r_offset: u32,
@@ -667,7 +682,7 @@ struct Elf_rela_32 {
     r_addend: i32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct Elf_rela {
     pub r_offset: u64,
@@ -689,12 +704,71 @@ impl From<Elf_rela_32> for Elf_rela {
 
 }
 
-#[derive(Debug)]
+/// Entries of a relocation table.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub enum Elf_reloc_entries {
+    Rel(Vec<Elf_rel>),
+    Rela(Vec<Elf_rela>),
+    /// Addresses of the packed relative relocations, unpacked
+    Relr(Vec<u64>),
+}
+
+/// Relocations of one SHT_REL, SHT_RELA or SHT_RELR section, or of a table
+/// the dynamic segment points to.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_relocations {
+    /// Section name, or the dynamic tag of the table
+    pub name:    String,
+    /// Section header index of the symbol table the entries refer to
+    pub sh_link: u32,
+    /// Section header index of the section the entries apply to, 0 for the
+    /// whole loaded image
+    pub sh_info: u32,
+    pub entries: Elf_reloc_entries,
+}
+
+impl Elf_relocations {
+
+    pub fn len(&self) -> usize {
+        match &self.entries {
+            Elf_reloc_entries::Rel(rels) => rels.len(),
+            Elf_reloc_entries::Rela(relas) => relas.len(),
+            Elf_reloc_entries::Relr(addresses) => addresses.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the entries refer to `.symtab` rather than `.dynsym`, as those
+    /// of object files do.
+    pub fn uses_symtab(&self, section_headers: &[Elf_section_header]) -> bool {
+        section_headers.get(self.sh_link as usize).is_some_and(|head| head.sh_type == SHT_SYMTAB)
+    }
+
+}
+
+/// Functions of a DT_PREINIT_ARRAY, DT_INIT_ARRAY or DT_FINI_ARRAY, which
+/// the loader calls before and after the program runs.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_init_array {
+    /// Dynamic tag of the array, also for arrays only found by section type
+    pub tag:       u64,
+    pub address:   u64,
+    /// Function addresses, with the relocation applied when the file holds 0
+    pub functions: Vec<u64>,
+}
+
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Elf_note {
-    n_type: u32,
-    name:   String,
-    desc:   Vec<u8>,
+pub struct Elf_note {
+    pub n_type: u32,
+    pub name:   String,
+    pub desc:   Vec<u8>,
 }
 
 pub const NT_GNU_ABI_TAG: u32 = 1;
@@ -703,32 +777,389 @@ pub const ELF_NOTE_OS_LINUX: u32 = 0;
 pub const ELF_NOTE_OS_GNU: u32 = 1;
 pub const ELF_NOTE_OS_SOLARIS2: u32 = 2;
 pub const ELF_NOTE_OS_FREEBSD: u32 = 3;
+pub const ELF_NOTE_OS_NETBSD: u32 = 4;
+pub const ELF_NOTE_OS_SYLLABLE: u32 = 5;
+pub const ELF_NOTE_OS_NACL: u32 = 6;
 pub const NT_GNU_HWCAP: u32 = 2;
 pub const NT_GNU_BUILD_ID: u32 = 3;
 pub const NT_GNU_GOLD_VERSION: u32 = 4;
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+#[inline]
+pub fn abi_os_to_str(os: u32) -> &'static str {
+    match os {
+        ELF_NOTE_OS_LINUX => "Linux",
+        ELF_NOTE_OS_GNU => "Hurd",
+        ELF_NOTE_OS_SOLARIS2 => "Solaris",
+        ELF_NOTE_OS_FREEBSD => "FreeBSD",
+        ELF_NOTE_OS_NETBSD => "NetBSD",
+        ELF_NOTE_OS_SYLLABLE => "Syllable",
+        ELF_NOTE_OS_NACL => "NaCl",
+        _ => "UNKNOWN_OS",
+    }
+}
+
+// Program properties of NT_GNU_PROPERTY_TYPE_0 notes
+// Stack size the program needs
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+// No copy relocations on protected data symbols
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+// Memory of the program must be sealed once mapped
+pub const GNU_PROPERTY_MEMORY_SEAL: u32 = 3;
+// Features every input object needs, ORed together
+pub const GNU_PROPERTY_1_NEEDED: u32 = 0xb0008000;
+// Accesses external symbols without copy relocations or canonical PLT entries
+pub const GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS: u32 = 0x1;
+// Start of processor-specific.
+pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000;
+// End of processor-specific.
+pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff;
+
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+// Compatible with Indirect Branch Tracking
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x1;
+// Compatible with Shadow Stack
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x2;
+// Compatible with Linear Address Masking of 48 and 57 bit addresses
+pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U48: u32 = 0x4;
+pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U57: u32 = 0x8;
+// Processor features used by some input object, and needed by all of them
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 0xc0010001;
+pub const GNU_PROPERTY_X86_FEATURE_2_NEEDED: u32 = 0xc0008001;
+pub const GNU_PROPERTY_X86_FEATURE_2_X86: u32 = 0x1;
+pub const GNU_PROPERTY_X86_FEATURE_2_X87: u32 = 0x2;
+pub const GNU_PROPERTY_X86_FEATURE_2_MMX: u32 = 0x4;
+pub const GNU_PROPERTY_X86_FEATURE_2_XMM: u32 = 0x8;
+pub const GNU_PROPERTY_X86_FEATURE_2_YMM: u32 = 0x10;
+pub const GNU_PROPERTY_X86_FEATURE_2_ZMM: u32 = 0x20;
+pub const GNU_PROPERTY_X86_FEATURE_2_FXSR: u32 = 0x40;
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVE: u32 = 0x80;
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT: u32 = 0x100;
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEC: u32 = 0x200;
+pub const GNU_PROPERTY_X86_FEATURE_2_TMM: u32 = 0x400;
+pub const GNU_PROPERTY_X86_FEATURE_2_MASK: u32 = 0x800;
+// x86-64 micro-architecture levels used by some input object, and needed by all of them
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+pub const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 0x1;
+pub const GNU_PROPERTY_X86_ISA_1_V2: u32 = 0x2;
+pub const GNU_PROPERTY_X86_ISA_1_V3: u32 = 0x4;
+pub const GNU_PROPERTY_X86_ISA_1_V4: u32 = 0x8;
+
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+// Compatible with Branch Target Identification
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 0x1;
+// Compatible with Pointer Authentication of return addresses
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 0x2;
+// Compatible with the Guarded Control Stack
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_GCS: u32 = 0x4;
+// Pointer authentication ABI, as a platform and version pair
+pub const GNU_PROPERTY_AARCH64_FEATURE_PAUTH: u32 = 0xc0000001;
+
+pub const GNU_PROPERTY_X86_FEATURE_1_FLAGS: [u32; 4] = [
+    GNU_PROPERTY_X86_FEATURE_1_IBT,
+    GNU_PROPERTY_X86_FEATURE_1_SHSTK,
+    GNU_PROPERTY_X86_FEATURE_1_LAM_U48,
+    GNU_PROPERTY_X86_FEATURE_1_LAM_U57,
+];
+
+pub const GNU_PROPERTY_X86_FEATURE_2_FLAGS: [u32; 12] = [
+    GNU_PROPERTY_X86_FEATURE_2_X86,
+    GNU_PROPERTY_X86_FEATURE_2_X87,
+    GNU_PROPERTY_X86_FEATURE_2_MMX,
+    GNU_PROPERTY_X86_FEATURE_2_XMM,
+    GNU_PROPERTY_X86_FEATURE_2_YMM,
+    GNU_PROPERTY_X86_FEATURE_2_ZMM,
+    GNU_PROPERTY_X86_FEATURE_2_FXSR,
+    GNU_PROPERTY_X86_FEATURE_2_XSAVE,
+    GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT,
+    GNU_PROPERTY_X86_FEATURE_2_XSAVEC,
+    GNU_PROPERTY_X86_FEATURE_2_TMM,
+    GNU_PROPERTY_X86_FEATURE_2_MASK,
+];
+
+pub const GNU_PROPERTY_X86_ISA_1_FLAGS: [u32; 4] = [
+    GNU_PROPERTY_X86_ISA_1_BASELINE,
+    GNU_PROPERTY_X86_ISA_1_V2,
+    GNU_PROPERTY_X86_ISA_1_V3,
+    GNU_PROPERTY_X86_ISA_1_V4,
+];
+
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_FLAGS: [u32; 3] = [
+    GNU_PROPERTY_AARCH64_FEATURE_1_BTI,
+    GNU_PROPERTY_AARCH64_FEATURE_1_PAC,
+    GNU_PROPERTY_AARCH64_FEATURE_1_GCS,
+];
+
+/// Name of property `pr_type`, whose processor-specific range depends on `machine`.
+#[inline]
+pub fn gnu_property_to_str(pr_type: u32, machine: u16) -> &'static str {
+    match (pr_type, machine) {
+        (GNU_PROPERTY_STACK_SIZE, _) => "GNU_PROPERTY_STACK_SIZE",
+        (GNU_PROPERTY_NO_COPY_ON_PROTECTED, _) => "GNU_PROPERTY_NO_COPY_ON_PROTECTED",
+        (GNU_PROPERTY_MEMORY_SEAL, _) => "GNU_PROPERTY_MEMORY_SEAL",
+        (GNU_PROPERTY_1_NEEDED, _) => "GNU_PROPERTY_1_NEEDED",
+        (GNU_PROPERTY_X86_FEATURE_1_AND, EM_386) | (GNU_PROPERTY_X86_FEATURE_1_AND, EM_X86_64) => "GNU_PROPERTY_X86_FEATURE_1_AND",
+        (GNU_PROPERTY_X86_FEATURE_2_USED, EM_386) | (GNU_PROPERTY_X86_FEATURE_2_USED, EM_X86_64) => "GNU_PROPERTY_X86_FEATURE_2_USED",
+        (GNU_PROPERTY_X86_FEATURE_2_NEEDED, EM_386) | (GNU_PROPERTY_X86_FEATURE_2_NEEDED, EM_X86_64) => "GNU_PROPERTY_X86_FEATURE_2_NEEDED",
+        (GNU_PROPERTY_X86_ISA_1_USED, EM_386) | (GNU_PROPERTY_X86_ISA_1_USED, EM_X86_64) => "GNU_PROPERTY_X86_ISA_1_USED",
+        (GNU_PROPERTY_X86_ISA_1_NEEDED, EM_386) | (GNU_PROPERTY_X86_ISA_1_NEEDED, EM_X86_64) => "GNU_PROPERTY_X86_ISA_1_NEEDED",
+        (GNU_PROPERTY_AARCH64_FEATURE_1_AND, EM_AARCH64) => "GNU_PROPERTY_AARCH64_FEATURE_1_AND",
+        (GNU_PROPERTY_AARCH64_FEATURE_PAUTH, EM_AARCH64) => "GNU_PROPERTY_AARCH64_FEATURE_PAUTH",
+        (GNU_PROPERTY_LOPROC..=GNU_PROPERTY_HIPROC, _) => "GNU_PROPERTY_PROC_SPECIFIC",
+        _ => "UNKNOWN_GNU_PROPERTY",
+    }
+}
+
+#[inline]
+pub fn x86_feature_1_to_str(flag: u32) -> &'static str {
+    match flag {
+        GNU_PROPERTY_X86_FEATURE_1_IBT => "GNU_PROPERTY_X86_FEATURE_1_IBT",
+        GNU_PROPERTY_X86_FEATURE_1_SHSTK => "GNU_PROPERTY_X86_FEATURE_1_SHSTK",
+        GNU_PROPERTY_X86_FEATURE_1_LAM_U48 => "GNU_PROPERTY_X86_FEATURE_1_LAM_U48",
+        GNU_PROPERTY_X86_FEATURE_1_LAM_U57 => "GNU_PROPERTY_X86_FEATURE_1_LAM_U57",
+        _ => "UNKNOWN_X86_FEATURE_1",
+    }
+}
+
+#[inline]
+pub fn x86_feature_2_to_str(flag: u32) -> &'static str {
+    match flag {
+        GNU_PROPERTY_X86_FEATURE_2_X86 => "GNU_PROPERTY_X86_FEATURE_2_X86",
+        GNU_PROPERTY_X86_FEATURE_2_X87 => "GNU_PROPERTY_X86_FEATURE_2_X87",
+        GNU_PROPERTY_X86_FEATURE_2_MMX => "GNU_PROPERTY_X86_FEATURE_2_MMX",
+        GNU_PROPERTY_X86_FEATURE_2_XMM => "GNU_PROPERTY_X86_FEATURE_2_XMM",
+        GNU_PROPERTY_X86_FEATURE_2_YMM => "GNU_PROPERTY_X86_FEATURE_2_YMM",
+        GNU_PROPERTY_X86_FEATURE_2_ZMM => "GNU_PROPERTY_X86_FEATURE_2_ZMM",
+        GNU_PROPERTY_X86_FEATURE_2_FXSR => "GNU_PROPERTY_X86_FEATURE_2_FXSR",
+        GNU_PROPERTY_X86_FEATURE_2_XSAVE => "GNU_PROPERTY_X86_FEATURE_2_XSAVE",
+        GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT => "GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT",
+        GNU_PROPERTY_X86_FEATURE_2_XSAVEC => "GNU_PROPERTY_X86_FEATURE_2_XSAVEC",
+        GNU_PROPERTY_X86_FEATURE_2_TMM => "GNU_PROPERTY_X86_FEATURE_2_TMM",
+        GNU_PROPERTY_X86_FEATURE_2_MASK => "GNU_PROPERTY_X86_FEATURE_2_MASK",
+        _ => "UNKNOWN_X86_FEATURE_2",
+    }
+}
+
+#[inline]
+pub fn x86_isa_1_to_str(flag: u32) -> &'static str {
+    match flag {
+        GNU_PROPERTY_X86_ISA_1_BASELINE => "GNU_PROPERTY_X86_ISA_1_BASELINE",
+        GNU_PROPERTY_X86_ISA_1_V2 => "GNU_PROPERTY_X86_ISA_1_V2",
+        GNU_PROPERTY_X86_ISA_1_V3 => "GNU_PROPERTY_X86_ISA_1_V3",
+        GNU_PROPERTY_X86_ISA_1_V4 => "GNU_PROPERTY_X86_ISA_1_V4",
+        _ => "UNKNOWN_X86_ISA_1",
+    }
+}
+
+#[inline]
+pub fn aarch64_feature_1_to_str(flag: u32) -> &'static str {
+    match flag {
+        GNU_PROPERTY_AARCH64_FEATURE_1_BTI => "GNU_PROPERTY_AARCH64_FEATURE_1_BTI",
+        GNU_PROPERTY_AARCH64_FEATURE_1_PAC => "GNU_PROPERTY_AARCH64_FEATURE_1_PAC",
+        GNU_PROPERTY_AARCH64_FEATURE_1_GCS => "GNU_PROPERTY_AARCH64_FEATURE_1_GCS",
+        _ => "UNKNOWN_AARCH64_FEATURE_1",
+    }
+}
+
+// Notes of other vendors, told apart by their name
+// Build ID of Go programs, named Go
+pub const NT_GO_BUILDID: u32 = 4;
+// API level and NDK of Android binaries, named Android
+pub const NT_ANDROID_TYPE_IDENT: u32 = 1;
+pub const NT_ANDROID_TYPE_KUSER: u32 = 3;
+pub const NT_ANDROID_TYPE_MEMTAG: u32 = 4;
+// Notes of FreeBSD binaries, named FreeBSD
+pub const NT_FREEBSD_ABI_TAG: u32 = 1;
+pub const NT_FREEBSD_NOINIT_TAG: u32 = 2;
+pub const NT_FREEBSD_ARCH_TAG: u32 = 3;
+pub const NT_FREEBSD_FEATURE_CTL: u32 = 4;
+// Opt-outs of NT_FREEBSD_FEATURE_CTL
+pub const NT_FREEBSD_FCTL_ASLR_DISABLE: u32 = 0x1;
+pub const NT_FREEBSD_FCTL_PROTMAX_DISABLE: u32 = 0x2;
+pub const NT_FREEBSD_FCTL_STKGAP_DISABLE: u32 = 0x4;
+pub const NT_FREEBSD_FCTL_WXNEEDED: u32 = 0x8;
+pub const NT_FREEBSD_FCTL_LA48: u32 = 0x10;
+// Version of NetBSD and OpenBSD binaries, named NetBSD or OpenBSD
+pub const NT_NETBSD_IDENT: u32 = 1;
+pub const NT_OPENBSD_IDENT: u32 = 1;
+// SystemTap probe of `.note.stapsdt`, named stapsdt
+pub const NT_STAPSDT: u32 = 3;
+// JSON package metadata of `.note.package`, named FDO
+pub const NT_FDO_PACKAGING_METADATA: u32 = 0xcafe1a7e;
+
+pub const NT_FREEBSD_FCTL_FLAGS: [u32; 5] = [
+    NT_FREEBSD_FCTL_ASLR_DISABLE,
+    NT_FREEBSD_FCTL_PROTMAX_DISABLE,
+    NT_FREEBSD_FCTL_STKGAP_DISABLE,
+    NT_FREEBSD_FCTL_WXNEEDED,
+    NT_FREEBSD_FCTL_LA48,
+];
+
+#[inline]
+pub fn freebsd_fctl_to_str(flag: u32) -> &'static str {
+    match flag {
+        NT_FREEBSD_FCTL_ASLR_DISABLE => "NT_FREEBSD_FCTL_ASLR_DISABLE",
+        NT_FREEBSD_FCTL_PROTMAX_DISABLE => "NT_FREEBSD_FCTL_PROTMAX_DISABLE",
+        NT_FREEBSD_FCTL_STKGAP_DISABLE => "NT_FREEBSD_FCTL_STKGAP_DISABLE",
+        NT_FREEBSD_FCTL_WXNEEDED => "NT_FREEBSD_FCTL_WXNEEDED",
+        NT_FREEBSD_FCTL_LA48 => "NT_FREEBSD_FCTL_LA48",
+        _ => "UNKNOWN_FCTL",
+    }
+}
+
+// Notes of core dumps, named CORE, or LINUX for the extended register sets
+// Thread status and general purpose registers
+pub const NT_PRSTATUS: u32 = 1;
+// Floating point registers
+pub const NT_PRFPREG: u32 = 2;
+// Process information and command line
+pub const NT_PRPSINFO: u32 = 3;
+pub const NT_TASKSTRUCT: u32 = 4;
+// Auxiliary vector the process was started with
+pub const NT_AUXV: u32 = 6;
+// Signal that caused the dump
+pub const NT_SIGINFO: u32 = 0x53494749;
+// Files mapped into the process
+pub const NT_FILE: u32 = 0x46494c45;
+pub const NT_PRXFPREG: u32 = 0x46e62b7f;
+pub const NT_X86_XSTATE: u32 = 0x202;
 
 impl Elf_note {
 
     pub fn type_to_str(&self) -> &'static str {
-        match self.n_type {
-            NT_GNU_ABI_TAG => "NT_GNU_ABI_TAG",
-            NT_GNU_HWCAP => "NT_GNU_HWCAP",
-            NT_GNU_BUILD_ID => "NT_GNU_BUILD_ID",
-            NT_GNU_GOLD_VERSION => "NT_GNU_GOLD_VERSION",
+        if self.name == "CORE" || self.name == "LINUX" {
+            return match self.n_type {
+                NT_PRSTATUS => "NT_PRSTATUS",
+                NT_PRFPREG => "NT_PRFPREG",
+                NT_PRPSINFO => "NT_PRPSINFO",
+                NT_TASKSTRUCT => "NT_TASKSTRUCT",
+                NT_AUXV => "NT_AUXV",
+                NT_SIGINFO => "NT_SIGINFO",
+                NT_FILE => "NT_FILE",
+                NT_PRXFPREG => "NT_PRXFPREG",
+                NT_X86_XSTATE => "NT_X86_XSTATE",
+                _ => "NT_UNKNOWN"
+            };
+        }
+        // Every vendor numbers its notes from 1
+        match (self.name.as_str(), self.n_type) {
+            ("GNU", NT_GNU_ABI_TAG) => "NT_GNU_ABI_TAG",
+            ("GNU", NT_GNU_HWCAP) => "NT_GNU_HWCAP",
+            ("GNU", NT_GNU_BUILD_ID) => "NT_GNU_BUILD_ID",
+            ("GNU", NT_GNU_GOLD_VERSION) => "NT_GNU_GOLD_VERSION",
+            ("GNU", NT_GNU_PROPERTY_TYPE_0) => "NT_GNU_PROPERTY_TYPE_0",
+            ("Go", NT_GO_BUILDID) => "NT_GO_BUILDID",
+            ("Android", NT_ANDROID_TYPE_IDENT) => "NT_ANDROID_TYPE_IDENT",
+            ("Android", NT_ANDROID_TYPE_KUSER) => "NT_ANDROID_TYPE_KUSER",
+            ("Android", NT_ANDROID_TYPE_MEMTAG) => "NT_ANDROID_TYPE_MEMTAG",
+            ("FreeBSD", NT_FREEBSD_ABI_TAG) => "NT_FREEBSD_ABI_TAG",
+            ("FreeBSD", NT_FREEBSD_NOINIT_TAG) => "NT_FREEBSD_NOINIT_TAG",
+            ("FreeBSD", NT_FREEBSD_ARCH_TAG) => "NT_FREEBSD_ARCH_TAG",
+            ("FreeBSD", NT_FREEBSD_FEATURE_CTL) => "NT_FREEBSD_FEATURE_CTL",
+            ("NetBSD", NT_NETBSD_IDENT) => "NT_NETBSD_IDENT",
+            ("OpenBSD", NT_OPENBSD_IDENT) => "NT_OPENBSD_IDENT",
+            ("stapsdt", NT_STAPSDT) => "NT_STAPSDT",
+            ("FDO", NT_FDO_PACKAGING_METADATA) => "NT_FDO_PACKAGING_METADATA",
             _ => "NT_UNKNOWN"
         }
     }
 
 }
 
-#[derive(Debug, Pread)]
+// Versym index of symbols local to the file
+pub const VER_NDX_LOCAL: u16 = 0;
+// Versym index of global symbols of the base version
+pub const VER_NDX_GLOBAL: u16 = 1;
+// Versym bit of symbols only reachable by naming their version
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+// Version definition naming the file itself
+pub const VER_FLG_BASE: u16 = 0x1;
+// Weak version dependency or definition
+pub const VER_FLG_WEAK: u16 = 0x2;
+
+#[derive(Serialize, Debug, Pread)]
+#[repr(C)]
+struct Elf_verneed_entry {
+    vn_version: u16,
+    vn_cnt:     u16,
+    vn_file:    u32,
+    vn_aux:     u32,
+    vn_next:    u32,
+}
+
+/// Versions needed from one library, from `.gnu.version_r`.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_verneed {
+    /// Offset of the library name in the linked string table
+    pub vn_file: u32,
+    pub aux:     Vec<Elf_vernaux>,
+}
+
+#[derive(Serialize, Debug, Pread)]
+#[repr(C)]
+pub struct Elf_vernaux {
+    pub vna_hash:  u32,
+    pub vna_flags: u16,
+    /// Versym index the version is referred to by
+    pub vna_other: u16,
+    pub vna_name:  u32,
+    pub vna_next:  u32,
+}
+
+#[derive(Serialize, Debug, Pread)]
+#[repr(C)]
+struct Elf_verdef_entry {
+    vd_version: u16,
+    vd_flags:   u16,
+    vd_ndx:     u16,
+    vd_cnt:     u16,
+    vd_hash:    u32,
+    vd_aux:     u32,
+    vd_next:    u32,
+}
+
+#[derive(Serialize, Debug, Pread)]
+#[repr(C)]
+struct Elf_verdaux {
+    vda_name: u32,
+    vda_next: u32,
+}
+
+/// Version defined by the file, from `.gnu.version_d`.
+#[allow(non_camel_case_types)]
+#[derive(Serialize, Debug)]
+pub struct Elf_verdef {
+    pub vd_flags: u16,
+    /// Versym index the version is referred to by
+    pub vd_ndx:   u16,
+    pub vd_hash:  u32,
+    /// Offsets in the linked string table of the version name, then of
+    /// the versions it inherits from
+    pub names:    Vec<u32>,
+}
+
+/// Version a dynamic symbol binds to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymbolVersion {
+    /// Offset of the version name in `dynstr`
+    pub name:    u32,
+    /// Only reachable by naming its version
+    pub hidden:  bool,
+    /// Defined by the file in `.gnu.version_d`, rather than needed from a
+    /// library in `.gnu.version_r`
+    pub defined: bool,
+}
+
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 struct Elf_dynamic_32 {
     d_tag: u32,
     d_ptr: u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
 pub struct Elf_dynamic {
     pub d_tag: u64,
@@ -748,285 +1179,1098 @@ impl From<Elf_dynamic_32> for Elf_dynamic {
 
 }
 
+// Marks end of dynamic section
+pub const DT_NULL: u64 = 0;
+// Name of needed library
+pub const DT_NEEDED: u64 = 1;
+// Size in bytes of PLT relocs
+pub const DT_PLTRELSZ: u64 = 2;
+// Processor defined value
+pub const DT_PLTGOT: u64 = 3;
+// Address of symbol hash table
+pub const DT_HASH: u64 = 4;
+// Address of string table
+pub const DT_STRTAB: u64 = 5;
+// Address of symbol table
+pub const DT_SYMTAB: u64 = 6;
+// Address of Rela relocs
+pub const DT_RELA: u64 = 7;
+// Total size of Rela relocs
+pub const DT_RELASZ: u64 = 8;
+// Size of one Rela reloc
+pub const DT_RELAENT: u64 = 9;
+// Size of string table
+pub const DT_STRSZ: u64 = 10;
+// Size of one symbol table entry
+pub const DT_SYMENT: u64 = 11;
+// Address of init function
+pub const DT_INIT: u64 = 12;
+// Address of termination function
+pub const DT_FINI: u64 = 13;
+// Name of shared object
+pub const DT_SONAME: u64 = 14;
+// Library search path (deprecated)
+pub const DT_RPATH: u64 = 15;
+// Start symbol search here
+pub const DT_SYMBOLIC: u64 = 16;
+// Address of Rel relocs
+pub const DT_REL: u64 = 17;
+// Total size of Rel relocs
+pub const DT_RELSZ: u64 = 18;
+// Size of one Rel reloc
+pub const DT_RELENT: u64 = 19;
+// Type of reloc in PLT
+pub const DT_PLTREL: u64 = 20;
+// For debugging; unspecified
+pub const DT_DEBUG: u64 = 21;
+// Reloc might modify .text
+pub const DT_TEXTREL: u64 = 22;
+// Address of PLT relocs
+pub const DT_JMPREL: u64 = 23;
+// Process relocations of object
+pub const DT_BIND_NOW: u64 = 24;
+// Array with addresses of init fct
+pub const DT_INIT_ARRAY: u64 = 25;
+// Array with addresses of fini fct
+pub const DT_FINI_ARRAY: u64 = 26;
+// Size in bytes of DT_INIT_ARRAY
+pub const DT_INIT_ARRAYSZ: u64 = 27;
+// Size in bytes of DT_FINI_ARRAY
+pub const DT_FINI_ARRAYSZ: u64 = 28;
+// Library search path
+pub const DT_RUNPATH: u64 = 29;
+// Flags for the object being loaded
+pub const DT_FLAGS: u64 = 30;
+// Start of encoded range
+pub const DT_ENCODING: u64 = 32;
+// Array with addresses of preinit fct
+pub const DT_PREINIT_ARRAY: u64 = 32;
+// size in bytes of DT_PREINIT_ARRAY
+pub const DT_PREINIT_ARRAYSZ: u64 = 33;
+// Address of SYMTAB_SHNDX section
+pub const DT_SYMTAB_SHNDX: u64 = 34;
+// Total size of RELR relative relocations
+pub const DT_RELRSZ: u64 = 35;
+// Address of RELR relative relocations
+pub const DT_RELR: u64 = 36;
+// Size of one RELR relative relocation
+pub const DT_RELRENT: u64 = 37;
+// Number used
+pub const DT_NUM: u64 = 38;
+// Start of OS-specific
+pub const DT_LOOS: u64 = 0x6000000d;
+// End of OS-specific
+pub const DT_HIOS: u64 = 0x6ffff000;
+// Start of processor-specific
+pub const DT_LOPROC: u64 = 0x70000000;
+// End of processor-specific
+pub const DT_HIPROC: u64 = 0x7fffffff;
+// Most used by any processor
+// const DT_PROCNUM: u64 = DT_MIPS_NUM;
+
+// DT_* entries which fall between DT_VALRNGLO & DT_VALRNGHI use the
+// Dyn.d_un.d_val field of the Elf*_Dyn structure.
+pub const DT_VALRNGLO: u64 = 0x6ffffd00;
+// Prelinking timestamp
+pub const DT_GNU_PRELINKED: u64 = 0x6ffffdf5;
+// Size of conflict section
+pub const DT_GNU_CONFLICTSZ: u64 = 0x6ffffdf6;
+// Size of library list
+pub const DT_GNU_LIBLISTSZ: u64 = 0x6ffffdf7;
+//
+pub const DT_CHECKSUM: u64 = 0x6ffffdf8;
+//
+pub const DT_PLTPADSZ: u64 = 0x6ffffdf9;
+//
+pub const DT_MOVEENT: u64 = 0x6ffffdfa;
+//
+pub const DT_MOVESZ: u64 = 0x6ffffdfb;
+// Feature selection (DTF_*)
+pub const DT_FEATURE_1: u64 = 0x6ffffdfc;
+// Flags for DT_* entries, effecting the following DT_* entry (DF_P1_*)
+pub const DT_POSFLAG_1: u64 = 0x6ffffdfd;
+// Size of syminfo table (in bytes)
+pub const DT_SYMINSZ: u64 = 0x6ffffdfe;
+// Entry size of syminfo
+pub const DT_SYMINENT: u64 = 0x6ffffdff;
+pub const DT_VALRNGHI: u64 = 0x6ffffdff;
+
+// DT_* entries which fall between DT_ADDRRNGHI & DT_ADDRRNGLO use the
+// Dyn.d_un.d_ptr field of the Elf*_Dyn structure.
+//
+// If any adjustment is made to the ELF object after it has been
+// built these entries will need to be adjusted.
+pub const DT_ADDRRNGLO: u64 = 0x6ffffe00;
+// GNU-style hash table
+pub const DT_GNU_HASH: u64 = 0x6ffffef5;
+//
+pub const DT_TLSDESC_PLT: u64 = 0x6ffffef6;
+//
+pub const DT_TLSDESC_GOT: u64 = 0x6ffffef7;
+// Start of conflict section
+pub const DT_GNU_CONFLICT: u64 = 0x6ffffef8;
+// Library list
+pub const DT_GNU_LIBLIST: u64 = 0x6ffffef9;
+// Configuration information
+pub const DT_CONFIG: u64 = 0x6ffffefa;
+// Dependency auditing
+pub const DT_DEPAUDIT: u64 = 0x6ffffefb;
+// Object auditing
+pub const DT_AUDIT: u64 = 0x6ffffefc;
+// PLT padding
+pub const DT_PLTPAD: u64 = 0x6ffffefd;
+// Move table
+pub const DT_MOVETAB: u64 = 0x6ffffefe;
+// Syminfo table
+pub const DT_SYMINFO: u64 = 0x6ffffeff;
+//
+pub const DT_ADDRRNGHI: u64 = 0x6ffffeff;
+
+//DT_ADDRTAGIDX(tag)	(DT_ADDRRNGHI - (tag))	/* Reverse order! */
+pub const DT_ADDRNUM: u64 = 11;
+
+// The versioning entry types. The next are defined as part of the GNU extension
+pub const DT_VERSYM: u64 = 0x6ffffff0;
+pub const DT_RELACOUNT: u64 = 0x6ffffff9;
+pub const DT_RELCOUNT: u64 = 0x6ffffffa;
+// State flags, see DF_1_* below
+pub const DT_FLAGS_1: u64 = 0x6ffffffb;
+// Address of version definition table
+pub const DT_VERDEF: u64 = 0x6ffffffc;
+// Number of version definitions
+pub const DT_VERDEFNUM: u64 = 0x6ffffffd;
+// Address of table with needed versions
+pub const DT_VERNEED: u64 = 0x6ffffffe;
+// Number of needed versions
+pub const DT_VERNEEDNUM: u64 = 0x6fffffff;
+
+// Shared object to load before self
+pub const DT_AUXILIARY: u64 = 0x7ffffffd;
+// Shared object to get values from
+pub const DT_FILTER: u64 = 0x7fffffff;
+
+// Tags whose value is an offset in the dynamic string table
+pub const DT_STRING_TAGS: [u64; 9] = [
+    DT_NEEDED,
+    DT_SONAME,
+    DT_RPATH,
+    DT_RUNPATH,
+    DT_AUXILIARY,
+    DT_FILTER,
+    DT_CONFIG,
+    DT_DEPAUDIT,
+    DT_AUDIT,
+];
+
+// DT_FLAGS values
+// Object may use $ORIGIN
+pub const DF_ORIGIN: u64 = 0x1;
+// Symbol resolution starts in the object itself
+pub const DF_SYMBOLIC: u64 = 0x2;
+// Relocations may modify non-writable segments
+pub const DF_TEXTREL: u64 = 0x4;
+// Bind all symbols at load time
+pub const DF_BIND_NOW: u64 = 0x8;
+// Object uses the static TLS model
+pub const DF_STATIC_TLS: u64 = 0x10;
+
+pub const DF_FLAGS: [u64; 5] = [
+    DF_ORIGIN,
+    DF_SYMBOLIC,
+    DF_TEXTREL,
+    DF_BIND_NOW,
+    DF_STATIC_TLS,
+];
+
+#[inline]
+pub fn df_to_str(df: u64) -> &'static str {
+    match df {
+        DF_ORIGIN => "DF_ORIGIN",
+        DF_SYMBOLIC => "DF_SYMBOLIC",
+        DF_TEXTREL => "DF_TEXTREL",
+        DF_BIND_NOW => "DF_BIND_NOW",
+        DF_STATIC_TLS => "DF_STATIC_TLS",
+        _ => "UNKNOWN_DF",
+    }
+}
+
+// DT_FLAGS_1 values
+// Set RTLD_NOW for this object
+pub const DF_1_NOW: u64 = 0x1;
+// Set RTLD_GLOBAL for this object
+pub const DF_1_GLOBAL: u64 = 0x2;
+// Set RTLD_GROUP for this object
+pub const DF_1_GROUP: u64 = 0x4;
+// Set RTLD_NODELETE for this object
+pub const DF_1_NODELETE: u64 = 0x8;
+// Trigger filtee loading at runtime
+pub const DF_1_LOADFLTR: u64 = 0x10;
+// Set RTLD_INITFIRST for this object
+pub const DF_1_INITFIRST: u64 = 0x20;
+// Set RTLD_NOOPEN for this object
+pub const DF_1_NOOPEN: u64 = 0x40;
+// $ORIGIN must be handled
+pub const DF_1_ORIGIN: u64 = 0x80;
+// Direct binding enabled
+pub const DF_1_DIRECT: u64 = 0x100;
+//
+pub const DF_1_TRANS: u64 = 0x200;
+// Object is used to interpose
+pub const DF_1_INTERPOSE: u64 = 0x400;
+// Ignore default lib search path
+pub const DF_1_NODEFLIB: u64 = 0x800;
+// Object can't be dldump'ed
+pub const DF_1_NODUMP: u64 = 0x1000;
+// Configuration alternative created
+pub const DF_1_CONFALT: u64 = 0x2000;
+// Filtee terminates filters search
+pub const DF_1_ENDFILTEE: u64 = 0x4000;
+// Disp reloc applied at build time
+pub const DF_1_DISPRELDNE: u64 = 0x8000;
+// Disp reloc applied at run-time
+pub const DF_1_DISPRELPND: u64 = 0x10000;
+// Object has no-direct binding
+pub const DF_1_NODIRECT: u64 = 0x20000;
+//
+pub const DF_1_IGNMULDEF: u64 = 0x40000;
+//
+pub const DF_1_NOKSYMS: u64 = 0x80000;
+//
+pub const DF_1_NOHDR: u64 = 0x100000;
+// Object is modified after built
+pub const DF_1_EDITED: u64 = 0x200000;
+//
+pub const DF_1_NORELOC: u64 = 0x400000;
+// Object has individual interposers
+pub const DF_1_SYMINTPOSE: u64 = 0x800000;
+// Global auditing required
+pub const DF_1_GLOBAUDIT: u64 = 0x1000000;
+// Singleton symbols are used
+pub const DF_1_SINGLETON: u64 = 0x2000000;
+//
+pub const DF_1_STUB: u64 = 0x4000000;
+// Object is a position independent executable
+pub const DF_1_PIE: u64 = 0x8000000;
+//
+pub const DF_1_KMOD: u64 = 0x10000000;
+//
+pub const DF_1_WEAKFILTER: u64 = 0x20000000;
+//
+pub const DF_1_NOCOMMON: u64 = 0x40000000;
+
+pub const DF_1_FLAGS: [u64; 31] = [
+    DF_1_NOW,
+    DF_1_GLOBAL,
+    DF_1_GROUP,
+    DF_1_NODELETE,
+    DF_1_LOADFLTR,
+    DF_1_INITFIRST,
+    DF_1_NOOPEN,
+    DF_1_ORIGIN,
+    DF_1_DIRECT,
+    DF_1_TRANS,
+    DF_1_INTERPOSE,
+    DF_1_NODEFLIB,
+    DF_1_NODUMP,
+    DF_1_CONFALT,
+    DF_1_ENDFILTEE,
+    DF_1_DISPRELDNE,
+    DF_1_DISPRELPND,
+    DF_1_NODIRECT,
+    DF_1_IGNMULDEF,
+    DF_1_NOKSYMS,
+    DF_1_NOHDR,
+    DF_1_EDITED,
+    DF_1_NORELOC,
+    DF_1_SYMINTPOSE,
+    DF_1_GLOBAUDIT,
+    DF_1_SINGLETON,
+    DF_1_STUB,
+    DF_1_PIE,
+    DF_1_KMOD,
+    DF_1_WEAKFILTER,
+    DF_1_NOCOMMON,
+];
+
+#[inline]
+pub fn df_1_to_str(df: u64) -> &'static str {
+    match df {
+        DF_1_NOW => "DF_1_NOW",
+        DF_1_GLOBAL => "DF_1_GLOBAL",
+        DF_1_GROUP => "DF_1_GROUP",
+        DF_1_NODELETE => "DF_1_NODELETE",
+        DF_1_LOADFLTR => "DF_1_LOADFLTR",
+        DF_1_INITFIRST => "DF_1_INITFIRST",
+        DF_1_NOOPEN => "DF_1_NOOPEN",
+        DF_1_ORIGIN => "DF_1_ORIGIN",
+        DF_1_DIRECT => "DF_1_DIRECT",
+        DF_1_TRANS => "DF_1_TRANS",
+        DF_1_INTERPOSE => "DF_1_INTERPOSE",
+        DF_1_NODEFLIB => "DF_1_NODEFLIB",
+        DF_1_NODUMP => "DF_1_NODUMP",
+        DF_1_CONFALT => "DF_1_CONFALT",
+        DF_1_ENDFILTEE => "DF_1_ENDFILTEE",
+        DF_1_DISPRELDNE => "DF_1_DISPRELDNE",
+        DF_1_DISPRELPND => "DF_1_DISPRELPND",
+        DF_1_NODIRECT => "DF_1_NODIRECT",
+        DF_1_IGNMULDEF => "DF_1_IGNMULDEF",
+        DF_1_NOKSYMS => "DF_1_NOKSYMS",
+        DF_1_NOHDR => "DF_1_NOHDR",
+        DF_1_EDITED => "DF_1_EDITED",
+        DF_1_NORELOC => "DF_1_NORELOC",
+        DF_1_SYMINTPOSE => "DF_1_SYMINTPOSE",
+        DF_1_GLOBAUDIT => "DF_1_GLOBAUDIT",
+        DF_1_SINGLETON => "DF_1_SINGLETON",
+        DF_1_STUB => "DF_1_STUB",
+        DF_1_PIE => "DF_1_PIE",
+        DF_1_KMOD => "DF_1_KMOD",
+        DF_1_WEAKFILTER => "DF_1_WEAKFILTER",
+        DF_1_NOCOMMON => "DF_1_NOCOMMON",
+        _ => "UNKNOWN_DF_1",
+    }
+}
+
+// DT_POSFLAG_1 values
+// Lazyload following object
+pub const DF_P1_LAZYLOAD: u64 = 0x1;
+// Symbols from next object are not generally available
+pub const DF_P1_GROUPPERM: u64 = 0x2;
+
+// DT_FEATURE_1 values
+pub const DTF_1_PARINIT: u64 = 0x1;
+pub const DTF_1_CONFEXP: u64 = 0x2;
+
+#[inline]
+pub fn tag_to_str(tag: u64) -> &'static str {
+    match tag {
+        DT_NULL => "DT_NULL",
+        DT_NEEDED => "DT_NEEDED",
+        DT_PLTRELSZ => "DT_PLTRELSZ",
+        DT_PLTGOT => "DT_PLTGOT",
+        DT_HASH => "DT_HASH",
+        DT_STRTAB => "DT_STRTAB",
+        DT_SYMTAB => "DT_SYMTAB",
+        DT_RELA => "DT_RELA",
+        DT_RELASZ => "DT_RELASZ",
+        DT_RELAENT => "DT_RELAENT",
+        DT_STRSZ => "DT_STRSZ",
+        DT_SYMENT => "DT_SYMENT",
+        DT_INIT => "DT_INIT",
+        DT_FINI => "DT_FINI",
+        DT_SONAME => "DT_SONAME",
+        DT_RPATH => "DT_RPATH",
+        DT_SYMBOLIC => "DT_SYMBOLIC",
+        DT_REL => "DT_REL",
+        DT_RELSZ => "DT_RELSZ",
+        DT_RELENT => "DT_RELENT",
+        DT_PLTREL => "DT_PLTREL",
+        DT_DEBUG => "DT_DEBUG",
+        DT_TEXTREL => "DT_TEXTREL",
+        DT_JMPREL => "DT_JMPREL",
+        DT_BIND_NOW => "DT_BIND_NOW",
+        DT_INIT_ARRAY => "DT_INIT_ARRAY",
+        DT_FINI_ARRAY => "DT_FINI_ARRAY",
+        DT_INIT_ARRAYSZ => "DT_INIT_ARRAYSZ",
+        DT_FINI_ARRAYSZ => "DT_FINI_ARRAYSZ",
+        DT_RUNPATH => "DT_RUNPATH",
+        DT_FLAGS => "DT_FLAGS",
+        DT_PREINIT_ARRAY => "DT_PREINIT_ARRAY",
+        DT_PREINIT_ARRAYSZ => "DT_PREINIT_ARRAYSZ",
+        DT_SYMTAB_SHNDX => "DT_SYMTAB_SHNDX",
+        DT_RELRSZ => "DT_RELRSZ",
+        DT_RELR => "DT_RELR",
+        DT_RELRENT => "DT_RELRENT",
+        DT_NUM => "DT_NUM",
+        DT_LOOS => "DT_LOOS",
+        DT_HIOS => "DT_HIOS",
+        DT_LOPROC => "DT_LOPROC",
+        DT_VERSYM => "DT_VERSYM",
+        DT_RELACOUNT => "DT_RELACOUNT",
+        DT_RELCOUNT => "DT_RELCOUNT",
+        DT_GNU_HASH => "DT_GNU_HASH",
+        DT_VERDEF => "DT_VERDEF",
+        DT_VERDEFNUM => "DT_VERDEFNUM",
+        DT_VERNEED => "DT_VERNEED",
+        DT_VERNEEDNUM => "DT_VERNEEDNUM",
+        DT_FLAGS_1 => "DT_FLAGS_1",
+        DT_GNU_PRELINKED => "DT_GNU_PRELINKED",
+        DT_GNU_CONFLICTSZ => "DT_GNU_CONFLICTSZ",
+        DT_GNU_LIBLISTSZ => "DT_GNU_LIBLISTSZ",
+        DT_CHECKSUM => "DT_CHECKSUM",
+        DT_PLTPADSZ => "DT_PLTPADSZ",
+        DT_MOVEENT => "DT_MOVEENT",
+        DT_MOVESZ => "DT_MOVESZ",
+        DT_FEATURE_1 => "DT_FEATURE_1",
+        DT_POSFLAG_1 => "DT_POSFLAG_1",
+        DT_SYMINSZ => "DT_SYMINSZ",
+        DT_SYMINENT => "DT_SYMINENT",
+        DT_TLSDESC_PLT => "DT_TLSDESC_PLT",
+        DT_TLSDESC_GOT => "DT_TLSDESC_GOT",
+        DT_GNU_CONFLICT => "DT_GNU_CONFLICT",
+        DT_GNU_LIBLIST => "DT_GNU_LIBLIST",
+        DT_CONFIG => "DT_CONFIG",
+        DT_DEPAUDIT => "DT_DEPAUDIT",
+        DT_AUDIT => "DT_AUDIT",
+        DT_PLTPAD => "DT_PLTPAD",
+        DT_MOVETAB => "DT_MOVETAB",
+        DT_SYMINFO => "DT_SYMINFO",
+        DT_AUXILIARY => "DT_AUXILIARY",
+        DT_FILTER => "DT_FILTER",
+        _ => "UNKNOWN_TAG",
+    }
+}
+
 // const SIZE_OF_SECTION_HEADER_32: usize = 10 * 4;
 // const SIZE_OF_SECTION_HEADER_64: usize = 6 * 8 + 4 * 4;
 
+/// Parsed ELF file. 32-bit files are widened to the 64-bit structures.
 pub struct Elf {
-    opt:             Opt,
-
-    header:          Elf_header,
-    program_headers: Vec<Elf_program_header>,
-    section_headers: Vec<Elf_section_header>,
-    sh_strtab:       Vec<u8>,
+    pub header:          Elf_header,
+    pub program_headers: Vec<Elf_program_header>,
+    pub section_headers: Vec<Elf_section_header>,
+    /// Contents of the section header string table
+    pub sh_strtab:       Vec<u8>,
+    /// Compression headers of the compressed sections
+    pub compressed_sections: Vec<compress::Elf_compression>,
+    /// Size of the whole file, which may go on past the last section
+    pub file_size:       u64,
+
+    pub symtab:          Vec<Elf_symbol_header>,
+    pub symstr:          Vec<u8>,
+
+    pub dynsym:          Vec<Elf_symbol_header>,
+    pub dynstr:          Vec<u8>,
+
+    /// Every relocation table, in section header order
+    pub relocations:     Vec<Elf_relocations>,
+
+    pub dynamic:         Vec<Elf_dynamic>,
+    pub init_arrays:     Vec<Elf_init_array>,
+
+    /// Symbol hash tables of the dynamic loader
+    pub hash:            Option<hash::Elf_hash>,
+    pub gnu_hash:        Option<hash::Elf_gnu_hash>,
+
+    pub notes:           Vec<Elf_note>,
+
+    /// Versym index of each dynamic symbol, from `.gnu.version`
+    pub versym:          Vec<u16>,
+    pub verneed:         Vec<Elf_verneed>,
+    pub verdef:          Vec<Elf_verdef>,
+
+    /// Process state of core dumps
+    pub core:            Option<coredump::Elf_core>,
+    /// Metadata of Linux kernel modules
+    pub module:          Option<module::Elf_module>,
+
+    /// Compilation units and line tables of files built with debug information
+    pub dwarf:           Option<dwarf::Dwarf>,
+}
 
-    symtab:          Vec<Elf_symbol_header>,
-    symstr:          Vec<u8>,
+impl Elf {
 
-    dynsym:          Vec<Elf_symbol_header>,
-    dynstr:          Vec<u8>,
+    /// Symbol table index of relocation info `r_info`.
+    pub fn r_sym(&self, r_info: u64) -> usize {
+        r_sym(r_info, self.header.e_ident.ei_class == ELFCLASS32)
+    }
 
-    reldyn:          Vec<Elf_rel>,
-    relplt:          Vec<Elf_rel>,
+    /// Relocation type of relocation info `r_info`, the first one for 64-bit MIPS.
+    pub fn r_type(&self, r_info: u64) -> u32 {
+        r_type(r_info, self.header.e_ident.ei_class == ELFCLASS32, self.header.e_machine)
+    }
 
-    reladyn:         Vec<Elf_rela>,
-    relaplt:         Vec<Elf_rela>,
+    /// Name of the relocation type of relocation info `r_info`. 64-bit MIPS
+    /// relocations apply up to three types in turn, named in order.
+    pub fn r_type_name(&self, r_info: u64) -> String {
+        let machine = self.header.e_machine;
+        if is_mips64(self.header.e_ident.ei_class == ELFCLASS32, machine) {
+            let types = [r_info & 0xFF, (r_info >> 8) & 0xFF, (r_info >> 16) & 0xFF];
+            let last = types.iter().rposition(|&typ| typ != R_MIPS_NONE as u64).unwrap_or(0);
+            return types[..=last].iter().map(|&typ| r_to_str(typ as u32, machine)).collect::<Vec<_>>().join("/");
+        }
+        r_to_str(self.r_type(r_info), machine).to_string()
+    }
 
-    dynamic:         Vec<Elf_dynamic>,
+    /// Version dynamic symbol `index` binds to. None for symbols that are
+    /// local, of the base version or unversioned.
+    pub fn symbol_version(&self, index: usize) -> Option<SymbolVersion> {
+        let versym = *self.versym.get(index)?;
+        let ndx = versym & !VERSYM_HIDDEN;
+        if ndx == VER_NDX_LOCAL || ndx == VER_NDX_GLOBAL {
+            return None;
+        }
+        let hidden = versym & VERSYM_HIDDEN != 0;
+
+        let needed = self.verneed.iter()
+            .flat_map(|need| &need.aux)
+            .find(|aux| aux.vna_other == ndx)
+            .map(|aux| SymbolVersion { name: aux.vna_name, hidden, defined: false });
+        let defined = || self.verdef.iter()
+            .find(|def| def.vd_ndx == ndx)
+            .and_then(|def| def.names.first())
+            .map(|&name| SymbolVersion { name, hidden, defined: true });
+        needed.or_else(defined)
+    }
 
-    notes:           Vec<Elf_note>,
 }
 
 impl super::FileFormat for Elf {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
+        Elf::parse_with(buf, &mut Recovery::strict())
+    }
+
+    fn parse_with(buf: &[u8], rec: &mut Recovery) -> Result<Self, Error> {
 
-        let e_ident = buf.pread_with::<E_ident>(0, scroll::BE)?;
-        let bit_format = e_ident.ei_class;
+        let e_ident = buf.pread_with::<E_ident>(0, scroll::BE)
+            .at(0, "e_ident")?;
         let endianness = match e_ident.ei_data {
             ELFDATA2LSB => scroll::LE,
             ELFDATA2MSB => scroll::BE,
             _ => {
-                return Err(Error::from(Problem::Msg(format!("Invalid EI_DATA"))));
+                return Err(Error::from(Problem::msg(format!("Invalid EI_DATA {}", e_ident.ei_data)).at(5)));
+            },
+        };
+        let is_32 = match e_ident.ei_class {
+            ELFCLASS32 => true,
+            ELFCLASS64 => false,
+            _ => {
+                return Err(Error::from(Problem::msg(format!("Invalid EI_CLASS {}", e_ident.ei_class)).at(4)));
+            },
+        };
+
+        let header = if is_32 {
+            buf.pread_with::<Elf_header_32>(0, endianness).map(Elf_header::from)
+        } else {
+            buf.pread_with::<Elf_header>(0, endianness)
+        }.at(0, "ELF header")?;
+
+        let program_headers = rec.table(buf,
+                                        header.e_phoff,
+                                        header.e_phnum as u64 * header.e_phentsize as u64,
+                                        header.e_phentsize as u64,
+                                        "ELF program header",
+                                        |offset| if is_32 {
+                                            buf.pread_with::<Elf_program_header_32>(offset, endianness).map(Elf_program_header::from)
+                                        } else {
+                                            buf.pread_with(offset, endianness)
+                                        })?;
+
+        let section_headers = rec.table(buf,
+                                        header.e_shoff,
+                                        header.e_shnum as u64 * header.e_shentsize as u64,
+                                        header.e_shentsize as u64,
+                                        "ELF section header",
+                                        |offset| if is_32 {
+                                            buf.pread_with::<Elf_section_header_32>(offset, endianness).map(Elf_section_header::from)
+                                        } else {
+                                            buf.pread_with(offset, endianness)
+                                        })?;
+
+        let sh_strtab = match section_headers.get(header.e_shstrndx as usize) {
+            Some(strtab) => rec.recover(bytes(buf, strtab.sh_offset, strtab.sh_size, "section header string table").map(<[u8]>::to_vec), Vec::new())?,
+            None if section_headers.is_empty() => Vec::new(),
+            None => {
+                let field = if is_32 { 0x32 } else { 0x3E };
+                rec.warn(Error::from(Problem::msg(format!("Invalid e_shstrndx {}", header.e_shstrndx)).at(field)))?;
+                Vec::new()
             },
         };
-        let header;
-        let mut program_headers;
-        let mut section_headers;
-        let mut sh_strtab;
+        let sh_strtab_offset = section_headers.get(header.e_shstrndx as usize).map_or(0, |strtab| strtab.sh_offset);
+
         let mut symtab = Vec::new();
         let mut symstr = Vec::new();
         let mut dynsym = Vec::new();
         let mut dynstr = Vec::new();
-        let mut reldyn = Vec::new();
-        let mut relplt = Vec::new();
-        let mut reladyn = Vec::new();
-        let mut relaplt = Vec::new();
+        let mut relocations = Vec::new();
         let mut dynamic = Vec::new();
         let mut notes  = Vec::new();
+        let mut versym = Vec::new();
+        let mut verneed = Vec::new();
+        let mut verdef = Vec::new();
+
+        let read_syms = |rec: &mut Recovery, head: &Elf_section_header| rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF symbol", |offset| if is_32 {
+            buf.pread_with::<Elf_symbol_header_32>(offset, endianness).map(Elf_symbol_header::from)
+        } else {
+            buf.pread_with(offset, endianness)
+        });
+        let mips64 = is_mips64(is_32, header.e_machine);
+        let read_rels = |rec: &mut Recovery, head: &Elf_section_header| rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF relocation", |offset| if is_32 {
+            buf.pread_with::<Elf_rel_32>(offset, endianness).map(Elf_rel::from)
+        } else if mips64 {
+            buf.pread_with::<Elf_rel>(offset, endianness).map(|rel| Elf_rel { r_info: mips64_r_info(rel.r_info, endianness), ..rel })
+        } else {
+            buf.pread_with(offset, endianness)
+        });
+        let read_relas = |rec: &mut Recovery, head: &Elf_section_header| rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF relocation", |offset| if is_32 {
+            buf.pread_with::<Elf_rela_32>(offset, endianness).map(Elf_rela::from)
+        } else if mips64 {
+            buf.pread_with::<Elf_rela>(offset, endianness).map(|rela| Elf_rela { r_info: mips64_r_info(rela.r_info, endianness), ..rela })
+        } else {
+            buf.pread_with(offset, endianness)
+        });
+        let read_relr = |rec: &mut Recovery, head: &Elf_section_header| rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF relative relocation", |offset| if is_32 {
+            buf.pread_with::<u32>(offset, endianness).map(u64::from)
+        } else {
+            buf.pread_with(offset, endianness)
+        }).map(|words| unpack_relr(&words, is_32));
+        // None if the string table could not be read, which is reported here or,
+        // for out-of-range links, by the checks of each section header
+        let linked_strtab = |rec: &mut Recovery, head: &Elf_section_header| -> Result<Option<(Vec<u8>, u64)>, Error> {
+            match section_headers.get(head.sh_link as usize) {
+                Some(strtab) => {
+                    let strs = bytes(buf, strtab.sh_offset, strtab.sh_size, "symbol string table")
+                        .map(|strs| Some((strs.to_vec(), strtab.sh_offset)));
+                    rec.recover(strs, None)
+                },
+                None => Ok(None),
+            }
+        };
+        // Notes follow each other in a section or segment, padded to its
+        // alignment: 4 bytes, or 8 for some GNU notes of 64-bit files
+        let read_notes = |rec: &mut Recovery, notes: &mut Vec<Elf_note>, offset: u64, size: u64, alignment: u64| -> Result<(), Error> {
+            let alignment = if alignment == 8 { 8 } else { 4 };
+            let end = offset.saturating_add(size);
+            let mut offset = offset;
+            while offset < end {
+                let note = (|| -> Result<(Elf_note, u64), Error> {
+                    let note = bytes(buf, offset, 12, "note header")?;
+                    let namesz = note.pread_with::<u32>(0, endianness)? as u64;
+                    let descsz = note.pread_with::<u32>(4, endianness)? as u64;
+                    let n_type = note.pread_with::<u32>(8, endianness)?;
+                    // Name is null terminated and padded like the description
+                    let name = bytes(buf, offset + 12, namesz, "note name")?;
+                    let name = String::from_utf8_lossy(name.split(|b| *b == 0).next().unwrap_or(&[])).into_owned();
+                    let desc_offset = offset + 12 + align(4, namesz as usize) as u64;
+                    let desc = bytes(buf, desc_offset, descsz, "note desc")?.to_vec();
+                    let next = align(alignment, (desc_offset + descsz) as usize) as u64;
+                    Ok((Elf_note {
+                        n_type,
+                        name,
+                        desc,
+                    }, next))
+                })();
+                match rec.recover(note.map(Some), None)? {
+                    Some((note, next)) => {
+                        notes.push(note);
+                        offset = next;
+                    },
+                    None => break,
+                }
+            }
+            Ok(())
+        };
+        let name = |head: &Elf_section_header| sh_strtab.pread::<&str>(head.sh_name as usize)
+            .at(sh_strtab_offset + head.sh_name as u64, "section name");
+        let (link_field, entsize) = if is_32 { (24, 40) } else { (40, 64) };
+        let mut dynstr_offset = 0;
+        let mut dynstr_broken = false;
+        let mut symtab_broken = false;
+        let mut invalid_names = 0;
+        let mut compressed_sections = Vec::new();
+
+        for (i, head) in section_headers.iter().enumerate() {
+            let section = match name(head) {
+                Ok(name) if !name.is_empty() => format!("section {}", name),
+                Ok(_) => format!("section #{}", i),
+                Err(err) => {
+                    // Once the string table is broken, every name is
+                    if invalid_names == 0 {
+                        rec.warn(err)?;
+                    }
+                    invalid_names += 1;
+                    format!("section #{}", i)
+                },
+            };
+            let section_name = name(head).unwrap_or("");
 
-        match bit_format {
-
-            ELFCLASS32 => {
-                header = Elf_header::from(buf.pread_with::<Elf_header_32>(0, endianness)?);
-
-                program_headers = Vec::with_capacity(header.e_phnum as usize);
-                for i in 0..header.e_phnum as usize {
-                    let p_head = Elf_program_header::from(
-                        buf.pread_with::<Elf_program_header_32>(
-                            header.e_phoff as usize + i * header.e_phentsize as usize, endianness)?);
+            rec.within(&section, |rec| {
 
-                    program_headers.push(p_head);
+                if head.sh_link as usize >= section_headers.len() {
+                    let field = header.e_shoff + i as u64 * entsize + link_field;
+                    rec.warn(Error::from(Problem::msg(format!("Invalid sh_link {}", head.sh_link)).at(field)))?;
                 }
 
-                section_headers = Vec::with_capacity(header.e_shnum as usize);
-                for i in 0..header.e_shnum as usize {
-                    let s_head = Elf_section_header::from(
-                        buf.pread_with::<Elf_section_header_32>(
-                            header.e_shoff as usize + i * header.e_shentsize as usize, endianness)?);
+                if let Some(compression) = rec.recover(compress::read_compression(buf, i, head, section_name, is_32, endianness), None)? {
+                    compressed_sections.push(compression);
+                }
 
-                    section_headers.push(s_head)
+                if head.sh_type == SHT_SYMTAB {
+                    symtab = read_syms(rec, head)?;
+                    symtab_broken = symtab.is_empty() && head.sh_size != 0;
+                    if let Some((strs, offset)) = linked_strtab(rec, head)? {
+                        check_symbol_names(rec, &symtab, &strs, offset)?;
+                        symstr = strs;
+                    }
+                }
+                if head.sh_type == SHT_DYNSYM {
+                    dynsym = read_syms(rec, head)?;
+                    match linked_strtab(rec, head)? {
+                        Some((strs, offset)) => {
+                            check_symbol_names(rec, &dynsym, &strs, offset)?;
+                            dynstr = strs;
+                            dynstr_offset = offset;
+                        },
+                        None => dynstr_broken = true,
+                    }
+                }
+                let entries = match head.sh_type {
+                    SHT_REL => Some(Elf_reloc_entries::Rel(read_rels(rec, head)?)),
+                    SHT_RELA => Some(Elf_reloc_entries::Rela(read_relas(rec, head)?)),
+                    SHT_RELR => Some(Elf_reloc_entries::Relr(read_relr(rec, head)?)),
+                    _ => None,
+                };
+                if let Some(entries) = entries {
+                    relocations.push(Elf_relocations {
+                        name: section_name.to_string(),
+                        sh_link: head.sh_link,
+                        sh_info: head.sh_info,
+                        entries,
+                    });
+                }
+                if head.sh_type == SHT_NOTE {
+                    read_notes(rec, &mut notes, head.sh_offset, head.sh_size, head.sh_addralign)?;
+                }
+                if head.sh_type == SHT_GNU_VERSYM {
+                    versym = rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF version symbol", |offset| {
+                        buf.pread_with::<u16>(offset, endianness)
+                    })?;
+                }
+                if head.sh_type == SHT_GNU_VERNEED {
+                    verneed = rec.recover(read_verneed(buf, head, endianness), Vec::new())?;
+                }
+                if head.sh_type == SHT_GNU_VERDEF {
+                    verdef = rec.recover(read_verdef(buf, head, endianness), Vec::new())?;
+                }
+                if head.sh_type == SHT_DYNAMIC {
+                    dynamic = rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF dynamic entry", |offset| if is_32 {
+                        buf.pread_with::<Elf_dynamic_32>(offset, endianness).map(Elf_dynamic::from)
+                    } else {
+                        buf.pread_with(offset, endianness)
+                    })?;
                 }
 
-                let shstr_idx = section_headers[header.e_shstrndx as usize].sh_offset as usize;
-                let shstr_size = section_headers[header.e_shstrndx as usize].sh_size as usize;
-                sh_strtab = buf[shstr_idx..shstr_idx + shstr_size].to_vec();
+                Ok(())
+            })?;
+        }
+        if invalid_names > 1 {
+            rec.warn(Error::from(Problem::msg(format!("{} more sections with invalid names", invalid_names - 1))))?;
+        }
 
-                for head in &section_headers {
-                    if head.sh_type == SHT_SYMTAB {
-                        let size = head.sh_size as usize / head.sh_entsize as usize;
-                        symtab = Vec::with_capacity(size);
-                        for i in 0..size {
-                            symtab.push(Elf_symbol_header::from(buf.pread_with::<Elf_symbol_header_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?));
-                        }
-                        let _symstr = &section_headers[head.sh_link as usize];
-                        symstr = buf[_symstr.sh_offset as usize.._symstr.sh_offset as usize + _symstr.sh_size as usize].to_vec();
-                    }
-                    if head.sh_type == SHT_DYNSYM {
-                        let size = head.sh_size as usize / head.sh_entsize as usize;
-                        dynsym = Vec::with_capacity(size);
-                        for i in 0..size {
-                            dynsym.push(Elf_symbol_header::from(buf.pread_with::<Elf_symbol_header_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?));
-                        }
-                        let _dynstr = &section_headers[head.sh_link as usize];
-                        dynstr = buf[_dynstr.sh_offset as usize.._dynstr.sh_offset as usize + _dynstr.sh_size as usize].to_vec();
+        // The loader finds the hash tables through the dynamic entries, which
+        // hand-patched files may point elsewhere than the sections
+        let read_hash_tables = |rec: &mut Recovery, dynamic: &[Elf_dynamic]| -> Result<(Option<hash::Elf_hash>, Option<hash::Elf_gnu_hash>), Error> {
+            let offset = |tag, sh_type| dynamic.iter()
+                .take_while(|entry| entry.d_tag != DT_NULL)
+                .find(|entry| entry.d_tag == tag)
+                .and_then(|entry| file_offset(&program_headers, entry.d_ptr))
+                .or_else(|| section_headers.iter().find(|head| head.sh_type == sh_type).map(|head| head.sh_offset));
+            let sysv = match offset(DT_HASH, SHT_HASH) {
+                Some(offset) => rec.recover(hash::read_hash(buf, offset, endianness).map(Some), None)?,
+                None => None,
+            };
+            let gnu = match offset(DT_GNU_HASH, SHT_GNU_HASH) {
+                Some(offset) => rec.recover(hash::read_gnu_hash(buf, offset, is_32, endianness).map(Some), None)?,
+                None => None,
+            };
+            Ok((sysv, gnu))
+        };
+        let mut hash_tables = None;
+
+        // Stripped files may have no section headers at all, but the dynamic
+        // segment still leads to everything the dynamic linker needs
+        if !section_headers.iter().any(|head| head.sh_type == SHT_DYNSYM) {
+            if let Some(segment) = program_headers.iter().find(|header| header.p_type == PT_DYNAMIC) {
+                rec.within("dynamic segment", |rec| {
+
+                    let (sym_size, rel_size, rela_size, relr_size, dyn_size) = if is_32 { (16, 8, 12, 4, 8) } else { (24, 16, 24, 8, 16) };
+                    if dynamic.is_empty() {
+                        dynamic = rec.table(buf, segment.p_offset, segment.p_filesz, dyn_size, "ELF dynamic entry", |offset| if is_32 {
+                            buf.pread_with::<Elf_dynamic_32>(offset, endianness).map(Elf_dynamic::from)
+                        } else {
+                            buf.pread_with(offset, endianness)
+                        })?;
                     }
-                    if head.sh_type == SHT_REL {
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rel.dyn" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            reldyn = Vec::with_capacity(size);
-                            for i in 0..size {
-                                reldyn.push(Elf_rel::from(buf.pread_with::<Elf_rel_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?));
-                            }
+                    let value = |tag| dynamic.iter()
+                        .take_while(|entry| entry.d_tag != DT_NULL)
+                        .find(|entry| entry.d_tag == tag)
+                        .map(|entry| entry.d_ptr);
+                    // Region of the file an address tag points to, as a section header for the table readers
+                    let region = |rec: &mut Recovery, tag, size: u64, entsize: u64, info: u64| -> Result<Option<Elf_section_header>, Error> {
+                        let vaddr = match value(tag) {
+                            Some(vaddr) => vaddr,
+                            None => return Ok(None),
+                        };
+                        match file_offset(&program_headers, vaddr) {
+                            Some(offset) => Ok(Some(Elf_section_header {
+                                sh_name: 0,
+                                sh_type: SHT_NULL,
+                                sh_flags: 0,
+                                sh_addr: vaddr,
+                                sh_offset: offset,
+                                sh_size: size,
+                                sh_link: 0,
+                                sh_info: info as u32,
+                                sh_addralign: 0,
+                                sh_entsize: entsize,
+                            })),
+                            None => {
+                                rec.warn(Error::from(Problem::msg(format!("{} {:#X} is not in any PT_LOAD segment", tag_to_str(tag), vaddr))))?;
+                                Ok(None)
+                            },
                         }
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rel.plt" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            relplt = Vec::with_capacity(size);
-                            for i in 0..size {
-                                relplt.push(Elf_rel::from(buf.pread_with::<Elf_rel_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?));
-                            }
+                    };
+
+                    if let Some(head) = region(rec, DT_STRTAB, value(DT_STRSZ).unwrap_or(0), 1, 0)? {
+                        match bytes(buf, head.sh_offset, head.sh_size, "dynamic string table") {
+                            Ok(strs) => {
+                                dynstr = strs.to_vec();
+                                dynstr_offset = head.sh_offset;
+                            },
+                            Err(err) => {
+                                rec.warn(err)?;
+                                dynstr_broken = true;
+                            },
                         }
                     }
-                    if head.sh_type == SHT_NOTE {
-                        let mut offset = head.sh_offset as usize;
-                        let namesz = buf.pread_with::<u32>(offset, endianness)?;
-                        let descsz = buf.pread_with::<u32>(offset + 4, endianness)?;
-                        let n_type = buf.pread_with::<u32>(offset + 8, endianness)?;
-                        let name = buf.pread_with::<&str>(offset + 12, ctx::StrCtx::Length(namesz as usize - 1))?.to_string();
-                        offset += 13;
-                        offset = align(8, offset);
-                        let desc = buf.pread_with::<&[u8]>(offset, descsz as usize)?.to_vec();
-                        notes.push( Elf_note {
-                            n_type,
-                            name,
-                            desc,
-                        });
+                    else {
+                        dynstr_broken = true;
                     }
-                    if head.sh_type == SHT_RELA {
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rela.dyn" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            reladyn = Vec::with_capacity(size);
-                            for i in 0..size {
-                                reladyn.push(Elf_rela::from(buf.pread_with::<Elf_rela_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)
-                                                            .map_err(|e| Problem::Msg(format!("Could not read  RelaDyn entry: {}" ,e)))?));
-                            }
-                        }
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rela.plt" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            relaplt = Vec::with_capacity(size);
-                            for i in 0..size {
-                                relaplt.push(Elf_rela::from(buf.pread_with::<Elf_rela_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)
-                                                            .map_err(|e| Problem::Msg(format!("Could not read  RelaPlt entry: {}" ,e)))?));
-                            }
+
+                    // Only the hash tables tell how many symbols there are
+                    let sym_entsize = value(DT_SYMENT).unwrap_or(sym_size);
+                    let (sysv, gnu) = read_hash_tables(rec, &dynamic)?;
+                    let count = match (&sysv, &gnu) {
+                        (Some(sysv), _) => sysv.chains.len() as u64,
+                        (None, Some(gnu)) => gnu.symbol_count(),
+                        // The string table usually follows the symbol table
+                        (None, None) => match (value(DT_SYMTAB), value(DT_STRTAB)) {
+                            (Some(symtab), Some(strtab)) if strtab > symtab && sym_entsize != 0 => (strtab - symtab) / sym_entsize,
+                            _ => 0,
+                        },
+                    };
+                    hash_tables = Some((sysv, gnu));
+                    if let Some(head) = region(rec, DT_SYMTAB, count.saturating_mul(sym_entsize), sym_entsize, 0)? {
+                        dynsym = read_syms(rec, &head)?;
+                        if !dynstr_broken {
+                            check_symbol_names(rec, &dynsym, &dynstr, dynstr_offset)?;
                         }
                     }
-                    if head.sh_type == SHT_DYNAMIC {
-                        let size = head.sh_size as usize / head.sh_entsize as usize;
-                        dynamic = Vec::with_capacity(size);
-                        for i in 0..size {
-                            dynamic.push(Elf_dynamic::from(buf.pread_with::<Elf_dynamic_32>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)
-                                                           .map_err(|e| Problem::Msg(format!("Could not read dynamic entry: {}" ,e)))?));
+
+                    let mut table = |tag, entries| relocations.push(Elf_relocations {
+                        name: tag_to_str(tag).to_string(),
+                        sh_link: 0,
+                        sh_info: 0,
+                        entries,
+                    });
+                    if let Some(head) = region(rec, DT_RELA, value(DT_RELASZ).unwrap_or(0), value(DT_RELAENT).unwrap_or(rela_size), 0)? {
+                        table(DT_RELA, Elf_reloc_entries::Rela(read_relas(rec, &head)?));
+                    }
+                    if let Some(head) = region(rec, DT_REL, value(DT_RELSZ).unwrap_or(0), value(DT_RELENT).unwrap_or(rel_size), 0)? {
+                        table(DT_REL, Elf_reloc_entries::Rel(read_rels(rec, &head)?));
+                    }
+                    if let Some(head) = region(rec, DT_RELR, value(DT_RELRSZ).unwrap_or(0), value(DT_RELRENT).unwrap_or(relr_size), 0)? {
+                        table(DT_RELR, Elf_reloc_entries::Relr(read_relr(rec, &head)?));
+                    }
+                    // DT_PLTREL tells which of the two the PLT relocations are
+                    if value(DT_PLTREL) == Some(DT_RELA) {
+                        if let Some(head) = region(rec, DT_JMPREL, value(DT_PLTRELSZ).unwrap_or(0), rela_size, 0)? {
+                            table(DT_JMPREL, Elf_reloc_entries::Rela(read_relas(rec, &head)?));
                         }
                     }
-                }
-
-            },
-            ELFCLASS64 => {
-                header = buf.pread_with::<Elf_header>(0, endianness)?;
-                program_headers = Vec::with_capacity(header.e_phnum as usize);
+                    else if let Some(head) = region(rec, DT_JMPREL, value(DT_PLTRELSZ).unwrap_or(0), rel_size, 0)? {
+                        table(DT_JMPREL, Elf_reloc_entries::Rel(read_rels(rec, &head)?));
+                    }
 
-                for i in 0..header.e_phnum as usize {
-                    let p_head = buf.pread_with(
-                        header.e_phoff as usize + i * header.e_phentsize as usize, endianness)?;
+                    if let Some(head) = region(rec, DT_VERSYM, dynsym.len() as u64 * 2, 2, 0)? {
+                        versym = rec.table(buf, head.sh_offset, head.sh_size, head.sh_entsize, "ELF version symbol", |offset| {
+                            buf.pread_with::<u16>(offset, endianness)
+                        })?;
+                    }
+                    if let Some(head) = region(rec, DT_VERNEED, 0, 0, value(DT_VERNEEDNUM).unwrap_or(0))? {
+                        verneed = rec.recover(read_verneed(buf, &head, endianness), Vec::new())?;
+                    }
+                    if let Some(head) = region(rec, DT_VERDEF, 0, 0, value(DT_VERDEFNUM).unwrap_or(0))? {
+                        verdef = rec.recover(read_verdef(buf, &head, endianness), Vec::new())?;
+                    }
 
-                    program_headers.push(p_head);
-                }
+                    Ok(())
+                })?;
+            }
+        }
+        // Core dumps and stripped files have notes in segments only
+        if !section_headers.iter().any(|head| head.sh_type == SHT_NOTE) {
+            for segment in program_headers.iter().filter(|header| header.p_type == PT_NOTE) {
+                rec.within("note segment", |rec| read_notes(rec, &mut notes, segment.p_offset, segment.p_filesz, segment.p_align))?;
+            }
+        }
+        let core = if header.e_type == ET_CORE {
+            Some(rec.within("core dump notes", |rec| coredump::read_core(rec, &notes, is_32, endianness))?)
+        } else {
+            None
+        };
 
-                section_headers = Vec::with_capacity(header.e_shnum as usize);
-                for i in 0..header.e_shnum as usize {
-                    let s_head = buf.pread_with(
-                        header.e_shoff as usize + i * header.e_shentsize as usize, endianness)?;
+        let is_module = header.e_type == ET_REL && section_headers.iter()
+            .any(|head| name(head).is_ok_and(|name| name == ".modinfo" || name == ".gnu.linkonce.this_module"));
+        let module = if is_module {
+            Some(rec.within("kernel module", |rec| module::read_module(rec, buf, &section_headers, &sh_strtab, &relocations,
+                                                                      &symtab, &symstr, is_32, endianness))?)
+        } else {
+            None
+        };
 
-                    section_headers.push(s_head)
+        // Debug sections of object files hold offsets their relocations fill in,
+        // and GNU tools used to compress them into .zdebug sections
+        let is_debug_section = |head: &Elf_section_header, wanted: &str| head.sh_type != SHT_NOBITS && name(head).is_ok_and(|name| {
+            name == wanted || name.strip_prefix(".zdebug").is_some_and(|rest| rest == &wanted[".debug".len()..])
+        });
+        let debug_section = |rec: &mut Recovery, wanted: &str| -> Result<dwarf::DebugSection<'_>, Error> {
+            let found = section_headers.iter()
+                .enumerate()
+                .find(|(_, head)| is_debug_section(head, wanted));
+            let (index, head) = match found {
+                Some(found) => found,
+                None => return Ok(dwarf::DebugSection::default()),
+            };
+            let compression = compressed_sections.iter().find(|compression| compression.section == index);
+            let mut data = match rec.recover(compress::section_data(buf, head, compression).within(wanted).map(Some), None)? {
+                Some(data) => data,
+                None => return Ok(dwarf::DebugSection::default()),
+            };
+            if header.e_type == ET_REL {
+                for relocs in relocations.iter().filter(|relocs| relocs.sh_info as usize == index) {
+                    let entries: Vec<(u64, u64, Option<i64>)> = match &relocs.entries {
+                        Elf_reloc_entries::Rela(relas) => relas.iter().map(|rela| (rela.r_offset, rela.r_info, Some(rela.r_addend))).collect(),
+                        Elf_reloc_entries::Rel(rels) => rels.iter().map(|rel| (rel.r_offset, rel.r_info, None)).collect(),
+                        Elf_reloc_entries::Relr(_) => continue,
+                    };
+                    let what = format!("section {}", relocs.name);
+                    rec.recover(relocate(data.to_mut(), &entries, &symtab, header.e_machine, is_32, endianness).within(what), ())?;
                 }
+            }
+            Ok(dwarf::DebugSection { data, offset: head.sh_offset })
+        };
+        let dwarf = if section_headers.iter().any(|head| is_debug_section(head, ".debug_info")) {
+            Some(rec.within("DWARF debug information", |rec| {
+                let sections = dwarf::DebugSections {
+                    info:        debug_section(rec, ".debug_info")?,
+                    abbrev:      debug_section(rec, ".debug_abbrev")?,
+                    str:         debug_section(rec, ".debug_str")?,
+                    line_str:    debug_section(rec, ".debug_line_str")?,
+                    line:        debug_section(rec, ".debug_line")?,
+                    str_offsets: debug_section(rec, ".debug_str_offsets")?,
+                    addr:        debug_section(rec, ".debug_addr")?,
+                };
+                dwarf::read_dwarf(rec, &sections, endianness)
+            })?)
+        } else {
+            None
+        };
 
-                let shstr_idx = section_headers[header.e_shstrndx as usize].sh_offset as usize;
-                let shstr_size = section_headers[header.e_shstrndx as usize].sh_size as usize;
-                sh_strtab = buf[shstr_idx..shstr_idx + shstr_size].to_vec();
+        let (hash, gnu_hash) = match hash_tables {
+            Some(tables) => tables,
+            None => read_hash_tables(rec, &dynamic)?,
+        };
 
-                for head in &section_headers {
-                    if head.sh_type == SHT_SYMTAB {
-                        let size = head.sh_size as usize / head.sh_entsize as usize;
-                        symtab = Vec::with_capacity(size);
-                        for i in 0..size {
-                            symtab.push(buf.pread_with::<Elf_symbol_header>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?);
-                        }
-                        let _symstr = &section_headers[head.sh_link as usize];
-                        symstr = buf[_symstr.sh_offset as usize.._symstr.sh_offset as usize + _symstr.sh_size as usize].to_vec();
-                    }
-                    if head.sh_type == SHT_DYNSYM {
-                        let size = head.sh_size as usize / head.sh_entsize as usize;
-                        dynsym = Vec::with_capacity(size);
-                        for i in 0..size {
-                            dynsym.push(buf.pread_with::<Elf_symbol_header>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?);
-                        }
-                        let _dynstr = &section_headers[head.sh_link as usize];
-                        dynstr = buf[_dynstr.sh_offset as usize.._dynstr.sh_offset as usize + _dynstr.sh_size as usize].to_vec();
-                    }
-                    if head.sh_type == SHT_REL {
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rel.dyn" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            reldyn = Vec::with_capacity(size);
-                            for i in 0..size {
-                                reldyn.push(buf.pread_with::<Elf_rel>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?);
-                            }
-                        }
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rel.plt" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            relplt = Vec::with_capacity(size);
-                            for i in 0..size {
-                                relplt.push(buf.pread_with::<Elf_rel>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)?);
-                            }
-                        }
-                    }
-                    if head.sh_type == SHT_NOTE {
-                        // Dunno why but it works that way. Is the 64bit version of this header not used?
-                        let mut offset = head.sh_offset as usize;
-                        let namesz = buf.pread_with::<u32>(offset, endianness)?;
-                        let descsz = buf.pread_with::<u32>(offset + 4, endianness)?;
-                        let n_type = buf.pread_with::<u32>(offset + 8, endianness)?;
-                        let name = buf.pread_with::<&str>(offset + 12, ctx::StrCtx::Length(namesz as usize - 1))?.to_string();
-                        offset += 13;
-                        offset = align(4, offset);
-                        let desc = buf.pread_with::<&[u8]>(offset, descsz as usize)?.to_vec();
-                        notes.push( Elf_note {
-                            n_type,
-                            name,
-                            desc,
+        // Relocations and dynamic entries refer to symbol and string tables, which may come after them
+        for relocs in &relocations {
+            let uses_symtab = relocs.uses_symtab(&section_headers);
+            // An unreadable symbol table was already reported
+            if uses_symtab && symtab_broken {
+                continue;
+            }
+            let symbols = if uses_symtab { symtab.len() } else { dynsym.len() };
+            let infos: Vec<u64> = match &relocs.entries {
+                Elf_reloc_entries::Rel(rels) => rels.iter().map(|rel| rel.r_info).collect(),
+                Elf_reloc_entries::Rela(relas) => relas.iter().map(|rela| rela.r_info).collect(),
+                Elf_reloc_entries::Relr(_) => Vec::new(),
+            };
+            let invalid: Vec<usize> = infos.into_iter().map(|info| r_sym(info, is_32)).filter(|&sym| sym >= symbols).collect();
+            if let Some(first) = invalid.first() {
+                rec.warn(Error::from(Problem::msg(format!("{} relocations with invalid symbol index, first is {}", invalid.len(), first))
+                                     .within(format!("section {}", relocs.name))))?;
+            }
+        }
+        // Object files hold zeros there, only their relocations tell the functions
+        let mut init_arrays = Vec::new();
+        if header.e_type != ET_REL {
+            let value = |tag| dynamic.iter()
+                .take_while(|entry| entry.d_tag != DT_NULL)
+                .find(|entry| entry.d_tag == tag)
+                .map(|entry| entry.d_ptr);
+            let pointer_size = if is_32 { 4 } else { 8 };
+            for (tag, size_tag, sh_type) in [(DT_PREINIT_ARRAY, DT_PREINIT_ARRAYSZ, SHT_PREINIT_ARRAY),
+                                             (DT_INIT_ARRAY, DT_INIT_ARRAYSZ, SHT_INIT_ARRAY),
+                                             (DT_FINI_ARRAY, DT_FINI_ARRAYSZ, SHT_FINI_ARRAY)] {
+                // Static executables have the sections only
+                let (address, offset, size) = match value(tag) {
+                    Some(vaddr) => match file_offset(&program_headers, vaddr) {
+                        Some(offset) => (vaddr, offset, value(size_tag).unwrap_or(0)),
+                        None => {
+                            rec.warn(Error::from(Problem::msg(format!("{} {:#X} is not in any PT_LOAD segment", tag_to_str(tag), vaddr))))?;
+                            continue;
+                        },
+                    },
+                    None => match section_headers.iter().find(|head| head.sh_type == sh_type) {
+                        Some(head) => (head.sh_addr, head.sh_offset, head.sh_size),
+                        None => continue,
+                    },
+                };
+                let functions = rec.within(tag_to_str(tag), |rec| rec.table(buf, offset, size, pointer_size, "function pointer", |offset| if is_32 {
+                    buf.pread_with::<u32>(offset, endianness).map(u64::from)
+                } else {
+                    buf.pread_with::<u64>(offset, endianness)
+                }))?;
+                // RELA relocations keep the address out of the file in PIE and libraries
+                let functions = functions.into_iter().enumerate().map(|(i, function)| {
+                    let slot = address + i as u64 * pointer_size;
+                    let relocated = relocations.iter()
+                        .filter_map(|relocs| match &relocs.entries {
+                            Elf_reloc_entries::Rela(relas) => relas.iter().find(|rela| rela.r_offset == slot),
+                            _ => None,
+                        })
+                        .next()
+                        .map(|rela| match r_sym(rela.r_info, is_32) {
+                            0 => rela.r_addend as u64,
+                            sym => dynsym.get(sym).map_or(0, |sym| sym.st_value).wrapping_add(rela.r_addend as u64),
                         });
+                    match (function, relocated) {
+                        (0, Some(relocated)) => relocated,
+                        _ => function,
                     }
-                    if head.sh_type == SHT_RELA {
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rela.dyn" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            reladyn = Vec::with_capacity(size);
-                            for i in 0..size {
-                                reladyn.push(buf.pread_with::<Elf_rela>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)
-                                             .map_err(|e| Problem::Msg(format!("Could not read RelaDyn entry: {}" ,e)))?);
-                            }
-                        }
-                        if sh_strtab.pread::<&str>(head.sh_name as usize)? == ".rela.plt" {
-                            let size = head.sh_size as usize / head.sh_entsize as usize;
-                            relaplt = Vec::with_capacity(size);
-                            for i in 0..size {
-                                relaplt.push(buf.pread_with::<Elf_rela>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)
-                                             .map_err(|e| Problem::Msg(format!("Could not read RelaPlt entry: {}" ,e)))?);
-                            }
-                        }
-                    }
-                    if head.sh_type == SHT_DYNAMIC {
-                        let size = head.sh_size as usize / head.sh_entsize as usize;
-                        dynamic = Vec::with_capacity(size);
-                        for i in 0..size {
-                            dynamic.push(buf.pread_with::<Elf_dynamic>(head.sh_offset as usize + i * head.sh_entsize as usize, endianness)
-                                         .map_err(|e| Problem::Msg(format!("Could not read dynamic entry: {}" ,e)))?);
-                        }
-                    }
-                }
+                }).collect();
+                init_arrays.push(Elf_init_array { tag, address, functions });
+            }
+        }
 
-            },
-            _ => {
-                return Err(Error::from(Problem::Msg(format!("Invalid EI_CLASS"))));
-            },
+        if !dynstr_broken {
+            for entry in dynamic.iter().filter(|entry| DT_STRING_TAGS.contains(&entry.d_tag)) {
+                rec.recover(dynstr.pread::<&str>(entry.d_ptr as usize)
+                            .map(drop)
+                            .at(dynstr_offset + entry.d_ptr, format_args!("{} name", tag_to_str(entry.d_tag))), ())?;
+            }
+        }
 
+        if !dynstr_broken {
+            let names = verneed.iter()
+                .flat_map(|need| std::iter::once((need.vn_file, "version dependency file"))
+                          .chain(need.aux.iter().map(|aux| (aux.vna_name, "version dependency name"))))
+                .chain(verdef.iter().flat_map(|def| def.names.iter().map(|&name| (name, "version definition name"))));
+            for (name, what) in names {
+                rec.recover(dynstr.pread::<&str>(name as usize)
+                            .map(drop)
+                            .at(dynstr_offset + name as u64, what), ())?;
+            }
         }
 
         Ok(Elf {
-            opt,
-
             header,
             program_headers,
             section_headers,
             sh_strtab,
+            compressed_sections,
+            file_size: buf.len() as u64,
 
             symtab,
             symstr,
@@ -1034,235 +2278,276 @@ impl super::FileFormat for Elf {
             dynsym,
             dynstr,
 
-            reldyn,
-            relplt,
-
-            reladyn,
-            relaplt,
+            relocations,
 
             dynamic,
+            init_arrays,
+
+            hash,
+            gnu_hash,
 
             notes,
+
+            versym,
+            verneed,
+            verdef,
+
+            core,
+            module,
+
+            dwarf,
         })
 
     }
 
-    fn print(&self) -> Result<(), Error> {
-        use ansi_term::Color;
-        use prettytable::{Table};
-
-        //
-        // ELF file
-        //
-        fmt_elf(&self.header);
-        println!();
-
-        //
-        // Program Headers
-        //
-        println!("{}({})",
-                 Color::White.paint("ProgramHeaders"),
-                 self.program_headers.len());
-        let mut table = Table::new();
-        let format = prettytable::format::FormatBuilder::new()
-            .column_separator(' ')
-            .borders(' ')
-            .padding(1, 1)
-            .build();
-        table.set_format(format);
-        table.add_row(row!["Idx", "Type", "Flags", "Offset", "Vaddr", "Paddr", "Filesz","Memsz", "Align"]);
-        for (i, header) in self.program_headers.iter().enumerate() {
-            use ansi_term::{Color, ANSIString, ANSIStrings};
-            let strings: &[ANSIString<'static>] = &[
-                if header.p_flags & PF_R != 0 { Color::Fixed(83).paint("R") } else { Color::Fixed(138).paint("-") },
-                if header.p_flags & PF_W != 0 { Color::Fixed(10).paint("W") } else { Color::Fixed(138).paint("-") },
-                if header.p_flags & PF_X != 0 { Color::Red.paint("X") } else { Color::Fixed(138).paint("-") },
-            ];
-            table.add_row(row![
-                i,
-                pt_to_str(header.p_type),
-                format!("{}", ANSIStrings(strings)),
-                Fy->format!("{:#X}", header.p_offset),
-                Fr->format!("{:#X}", header.p_vaddr),
-                Fr->format!("{:#X}", header.p_paddr),
-                Fg->format!("{:#X}", header.p_filesz),
-                Fg->format!("{:#X}", header.p_memsz),
-                format!("{:#X}", header.p_align),
-            ]);
-        }
-        table.printstd();
-        println!();
-
-        //
-        // Notes
-        //
-        if self.notes.len() > 0 {
-            println!("{}({})", Color::White.paint("Notes"), self.notes.len());
-            for (i, note) in self.notes.iter().enumerate() {
-                fmt_indent(format!("{} {} {} ",
-                             i,
-                             Color::Blue.paint(&note.name),
-                             Color::Yellow.paint(note.type_to_str())));
-                for b in &note.desc {
-                    print!("{:X}", b);
+}
+
+/// Symbol table index of relocation info `r_info`, which 32-bit files pack
+/// into a single word with the type.
+fn r_sym(r_info: u64, is_32: bool) -> usize {
+    if is_32 { (r_info >> 8) as usize } else { (r_info >> 32) as usize }
+}
+
+/// Relocation type of relocation info `r_info`. 64-bit MIPS packs three
+/// types in the low word, and 64-bit SPARC data for the type above it.
+fn r_type(r_info: u64, is_32: bool, machine: u16) -> u32 {
+    if is_32 || is_mips64(is_32, machine) || machine == EM_SPARCV9 {
+        (r_info & 0xFF) as u32
+    } else {
+        (r_info & 0xFFFF_FFFF) as u32
+    }
+}
+
+fn is_mips64(is_32: bool, machine: u16) -> bool {
+    !is_32 && (machine == EM_MIPS || machine == EM_MIPS_RS3_LE)
+}
+
+/// Relocation info of 64-bit MIPS, stored as the symbol followed by the
+/// bytes of the types whatever the byte order, laid out like in big endian
+/// files: symbol in the high word, then a special symbol and three types.
+fn mips64_r_info(r_info: u64, endianness: scroll::Endian) -> u64 {
+    if endianness == scroll::LE {
+        (r_info << 32) | ((r_info >> 32) as u32).swap_bytes() as u64
+    } else {
+        r_info
+    }
+}
+
+/// Addresses of packed relative relocations. An even word is the address
+/// of a relocation, and the following odd words are bitmaps of which of
+/// the next 31 or 63 words are relocated too.
+fn unpack_relr(words: &[u64], is_32: bool) -> Vec<u64> {
+    let (word_size, bits) = if is_32 { (4, 31) } else { (8, 63) };
+    let mut addresses = Vec::new();
+    let mut base = 0u64;
+    for &word in words {
+        if word & 1 == 0 {
+            addresses.push(word);
+            base = word.wrapping_add(word_size);
+        } else {
+            for bit in 0..bits {
+                if (word >> (bit + 1)) & 1 != 0 {
+                    addresses.push(base.wrapping_add(bit * word_size));
                 }
-                println!();
             }
-            println!();
+            base = base.wrapping_add(bits * word_size);
         }
+    }
+    addresses
+}
 
-        //
-        // Section headers
-        //
-        println!("{}({})",
-                 Color::White.paint("SectionHeaders"),
-                 self.section_headers.len());
-        let mut table = Table::new();
-        let format = prettytable::format::FormatBuilder::new()
-            .column_separator(' ')
-            .borders(' ')
-            .padding(1, 1)
-            .build();
-        table.set_format(format);
-        table.add_row(row!["Idx", "Name", "Type", "Flags", "Addr", "Offset", "Size", "Link", "Entsize", "Align"]);
-        for (i, header) in self.section_headers.iter().enumerate() {
-
-            let flags_cell = {
-                let shflags = header.sh_flags as u32;
-                if shflags != 0 {
-                    let mut flags = String::new();
-                    for flag in &SHF_FLAGS {
-                        let flag = *flag;
-                        if shflags & flag == flag {
-                            flags += &shf_to_str(flag).to_string().split_off(4);
-                            flags += " ";
-                        }
-                    }
-                    flags
-                }
-                else {
-                    "".to_owned()
-                }
-            };
-
-            table.add_row(row![
-                i,
-                self.sh_strtab.pread::<&str>(header.sh_name as usize)
-                    .map_err(|e| Problem::Msg(format!("Cannot read name: {}", e)))?,
-                sht_to_str(header.sh_type),
-                flags_cell,
-                Fr->format!("{:#X}", header.sh_addr),
-                Fy->format!("{:#X}", header.sh_offset),
-                Fg->format!("{:#X}", header.sh_size),
-                self.sh_strtab.pread::<&str>(self.section_headers[header.sh_link as usize].sh_name as usize)
-                    .map_err(|e| Problem::Msg(format!("Cannot read name of link: {}", e)))?,
-                format!("{:#X}", header.sh_entsize),
-                format!("{:#X}", header.sh_addralign),
-            ]);
-        }
-        table.printstd();
-        println!();
-
-        //
-        // Symbol table
-        //
-        println!("{}({})",
-                 Color::White.paint("SymbolTable"),
-                 self.symtab.len());
-        if self.symtab.len() > 0 {
-            fmt_elf_sym_table(&self.symtab, &self.symstr, &self.section_headers, &self.sh_strtab, self.opt.wrap_chars)
-                .map_err(|e| Problem::Msg(format!("Could not print Symbol table: {}", e)))?;
+/// Applies to `data` the relocations of an object file that write the
+/// address of a symbol, which is all debug sections use. Relocations given
+/// without an addend take it from the relocated bytes.
+fn relocate(data: &mut [u8], relocs: &[(u64, u64, Option<i64>)], symtab: &[Elf_symbol_header], machine: u16, is_32: bool, endianness: scroll::Endian) -> Result<(), Error> {
+    for (i, &(offset, info, addend)) in relocs.iter().enumerate() {
+        let (sym, typ) = (r_sym(info, is_32), r_type(info, is_32, machine));
+        let size = match (machine, typ) {
+            (EM_X86_64, R_X86_64_64) | (EM_AARCH64, R_AARCH64_ABS64) | (EM_RISCV, R_RISCV_64) | (EM_LOONGARCH, R_LARCH_64)
+            | (EM_PPC64, R_PPC64_ADDR64) | (EM_S390, R_390_64) | (EM_SPARCV9, R_SPARC_64) | (EM_SPARCV9, R_SPARC_UA64)
+            | (EM_MIPS, R_MIPS_64) => 8,
+            (EM_X86_64, R_X86_64_32) | (EM_X86_64, R_X86_64_32S) | (EM_AARCH64, R_AARCH64_ABS32)
+            | (EM_386, R_386_32) | (EM_ARM, R_ARM_ABS32) | (EM_RISCV, R_RISCV_32) | (EM_LOONGARCH, R_LARCH_32)
+            | (EM_PPC, R_PPC_ADDR32) | (EM_PPC64, R_PPC64_ADDR32) | (EM_S390, R_390_32) | (EM_MIPS, R_MIPS_32)
+            | (EM_SPARC, R_SPARC_32) | (EM_SPARC, R_SPARC_UA32) | (EM_SPARC32PLUS, R_SPARC_32) | (EM_SPARC32PLUS, R_SPARC_UA32)
+            | (EM_SPARCV9, R_SPARC_32) | (EM_SPARCV9, R_SPARC_UA32) => 4,
+            _ => continue,
+        };
+        let what = format!("relocation #{}", i);
+        let symbol = symtab.get(sym)
+            .ok_or_else(|| Problem::msg(format!("Invalid symbol index {}", sym)).within(&what))?;
+        let addend = match addend {
+            Some(addend) => addend as u64,
+            None if size == 8 => data.pread_with::<u64>(offset as usize, endianness).at(offset, &what)?,
+            None => data.pread_with::<u32>(offset as usize, endianness).at(offset, &what)? as u64,
+        };
+        let value = symbol.st_value.wrapping_add(addend);
+        if size == 8 {
+            data.pwrite_with::<u64>(value, offset as usize, endianness).at(offset, &what)?;
+        } else {
+            data.pwrite_with::<u32>(value as u32, offset as usize, endianness).at(offset, &what)?;
         }
-        println!();
-
-        //
-        // DynSym table
-        //
-        println!("{}({})",
-                 Color::White.paint("DynSymTable"),
-                 self.dynsym.len());
-        if self.dynsym.len() > 0 {
-            fmt_elf_sym_table(&self.dynsym, &self.dynstr, &self.section_headers, &self.sh_strtab, self.opt.wrap_chars)
-                .map_err(|e| Problem::Msg(format!("Could not print DynSym table: {}", e)))?;
+    }
+    Ok(())
+}
 
-        }
-        println!();
-
-        //
-        // RelDyn table
-        //
-        println!("{}({})",
-                 Color::White.paint("RelDynTable"),
-                 self.reldyn.len());
-        if self.reldyn.len() > 0 {
-            fmt_elf_rel_table(&self.reldyn, &self.dynsym, &self.dynstr, self.header.e_machine, self.opt.wrap_chars)
-                .map_err(|e| Problem::Msg(format!("Could not print RelDyn table: {}", e)))?;
+/// File offset of virtual address `vaddr`, if a PT_LOAD segment maps it from the file.
+fn file_offset(program_headers: &[Elf_program_header], vaddr: u64) -> Option<u64> {
+    program_headers.iter()
+        .filter(|header| header.p_type == PT_LOAD)
+        .find(|header| vaddr >= header.p_vaddr && vaddr - header.p_vaddr < header.p_filesz)
+        .map(|header| header.p_offset + (vaddr - header.p_vaddr))
+}
 
+/// Checks that every symbol of a table has a readable name in `strtab`,
+/// found at file offset `strtab_offset`.
+fn check_symbol_names(rec: &mut Recovery, symbols: &[Elf_symbol_header], strtab: &[u8], strtab_offset: u64) -> Result<(), Error> {
+    let mut invalid = symbols.iter().enumerate()
+        .filter_map(|(i, sym)| strtab.pread::<&str>(sym.st_name as usize)
+                    .at(strtab_offset + sym.st_name as u64, format_args!("name of ELF symbol #{}", i))
+                    .err());
+
+    if let Some(first) = invalid.next() {
+        rec.warn(first)?;
+        let more = invalid.count();
+        if more > 0 {
+            rec.warn(Error::from(Problem::msg(format!("{} more symbols with invalid names", more))))?;
         }
-        println!();
-
-        //
-        // RelPlt table
-        //
-        println!("{}({})",
-                 Color::White.paint("RelPltTable"),
-                 self.relplt.len());
-        if self.relplt.len() > 0 {
-            fmt_elf_rel_table(&self.relplt, &self.dynsym, &self.dynstr, self.header.e_machine, self.opt.wrap_chars)
-                .map_err(|e| Problem::Msg(format!("Could not print RelPlt table: {}", e)))?;
+    }
+
+    Ok(())
+}
+
+/// Reads the chain of `.gnu.version_r` entries and their versions. Each
+/// entry and version gives the distance to the next one, 0 ending the chain.
+fn read_verneed(buf: &[u8], head: &Elf_section_header, endianness: scroll::Endian) -> Result<Vec<Elf_verneed>, Error> {
+    let mut needed = Vec::new();
+    let mut offset = head.sh_offset;
+
+    for _ in 0..head.sh_info {
+        let entry = buf.pread_with::<Elf_verneed_entry>(offset as usize, endianness)
+            .at(offset, "ELF version dependency")?;
+
+        let mut aux = Vec::new();
+        let mut aux_offset = offset.saturating_add(entry.vn_aux as u64);
+        for _ in 0..entry.vn_cnt {
+            let version = buf.pread_with::<Elf_vernaux>(aux_offset as usize, endianness)
+                .at(aux_offset, "ELF version dependency version")?;
+            let next = version.vna_next;
+            aux.push(version);
+            if next == 0 {
+                break;
+            }
+            aux_offset = aux_offset.saturating_add(next as u64);
         }
-        println!();
-
-        //
-        // RelaDyn table
-        //
-        println!("{}({})",
-                 Color::White.paint("RelaDynTable"),
-                 self.reladyn.len());
-        if self.reladyn.len() > 0 {
-            fmt_elf_rela_table(&self.reladyn, &self.dynsym, &self.dynstr, self.header.e_machine, self.opt.wrap_chars)
-                .map_err(|e| Problem::Msg(format!("Could not print RelaDyn table: {}", e)))?;
+
+        needed.push(Elf_verneed {
+            vn_file: entry.vn_file,
+            aux,
+        });
+        if entry.vn_next == 0 {
+            break;
         }
-        println!();
-
-        //
-        // RelaPlt table
-        //
-        println!("{}({})",
-                 Color::White.paint("RelaPltTable"),
-                 self.relaplt.len());
-        if self.relaplt.len() > 0 {
-            fmt_elf_rela_table(&self.relaplt, &self.dynsym, &self.dynstr, self.header.e_machine, self.opt.wrap_chars)
-                .map_err(|e| Problem::Msg(format!("Could not print RelaPlt table: {}", e)))?;
+        offset = offset.saturating_add(entry.vn_next as u64);
+    }
+
+    Ok(needed)
+}
+
+/// Reads the chain of `.gnu.version_d` entries, laid out like `.gnu.version_r`.
+fn read_verdef(buf: &[u8], head: &Elf_section_header, endianness: scroll::Endian) -> Result<Vec<Elf_verdef>, Error> {
+    let mut defined = Vec::new();
+    let mut offset = head.sh_offset;
+
+    for _ in 0..head.sh_info {
+        let entry = buf.pread_with::<Elf_verdef_entry>(offset as usize, endianness)
+            .at(offset, "ELF version definition")?;
+
+        let mut names = Vec::new();
+        let mut aux_offset = offset.saturating_add(entry.vd_aux as u64);
+        for _ in 0..entry.vd_cnt {
+            let aux = buf.pread_with::<Elf_verdaux>(aux_offset as usize, endianness)
+                .at(aux_offset, "ELF version definition name")?;
+            names.push(aux.vda_name);
+            if aux.vda_next == 0 {
+                break;
+            }
+            aux_offset = aux_offset.saturating_add(aux.vda_next as u64);
         }
-        println!();
-
-        //
-        // Dynamic
-        //
-        if self.dynamic.len() > 0 {
-            println!("{}({})",
-                     Color::White.paint("Dynamic"),
-                     self.dynamic.len());
-            fmt_elf_dynamic(&self.dynamic, &self.dynstr)
-                .map_err(|e| Problem::Msg(format!("Could not print dynamic table: {}", e)))?;
-            println!();
+
+        defined.push(Elf_verdef {
+            vd_flags: entry.vd_flags,
+            vd_ndx:   entry.vd_ndx,
+            vd_hash:  entry.vd_hash,
+            names,
+        });
+        if entry.vd_next == 0 {
+            break;
         }
+        offset = offset.saturating_add(entry.vd_next as u64);
+    }
 
-        //
-        // Libraries
-        //
-        if self.dynamic.len() > 0 {
-            println!("{}", Color::White.paint("Libraries"));
-            for entry in self.dynamic.iter() {
-                if entry.d_tag == crate::format::DT_NEEDED {
-                    println!("{:>4}{}", "", Color::Blue.paint(self.dynstr.pread::<&str>(entry.d_ptr as usize)?));
-                }
-            }
+    Ok(defined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(sh_offset: u64, sh_size: u64, sh_info: u32) -> Elf_section_header {
+        Elf_section_header {
+            sh_name: 0, sh_type: 0, sh_flags: 0, sh_addr: 0, sh_offset, sh_size,
+            sh_link: 0, sh_info, sh_addralign: 0, sh_entsize: 0,
         }
+    }
 
-        Ok(())
+    #[test]
+    fn packed_relative_relocations() {
+        // An address, then a bitmap covering the 63 words after it and one
+        // covering the next 63, then a new address
+        let words = [0x10000, 0b1011, 0b11, 0x20000];
+        assert_eq!(unpack_relr(&words, false), [0x10000, 0x10008, 0x10018, 0x10200, 0x20000]);
+        assert_eq!(unpack_relr(&[0x1000, 0b101], true), [0x1000, 0x1008]);
+    }
 
+    #[test]
+    fn relocation_info() {
+        assert_eq!((r_sym(0x1201, true), r_type(0x1201, true, EM_386)), (0x12, R_386_32));
+        assert_eq!((r_sym(0x5_0000_0001, false), r_type(0x5_0000_0001, false, EM_X86_64)), (5, R_X86_64_64));
+        // R_SPARC_OLO10 keeps its extra offset above the type
+        assert_eq!(r_type(0x7_0000_2A21, false, EM_SPARCV9), R_SPARC_OLO10);
+
+        // Symbol 3 with R_MIPS_GPREL16, R_MIPS_SUB and R_MIPS_HI16, as read
+        // from a little and a big endian file
+        let info = mips64_r_info(0x0718_0500_0000_0003, scroll::LE);
+        assert_eq!(info, mips64_r_info(0x0000_0003_0005_1807, scroll::BE));
+        assert_eq!((r_sym(info, false), r_type(info, false, EM_MIPS)), (3, R_MIPS_GPREL16));
     }
 
+    #[test]
+    fn version_chains() {
+        // libc.so.6 needing GLIBC_2.2.5 (index 2) and GLIBC_2.14 (index 3),
+        // each entry pointing at the next one
+        let mut verneed = Vec::new();
+        verneed.extend_from_slice(&[1, 0, 2, 0, 0x10, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0]);
+        verneed.extend_from_slice(&[0, 0, 0, 0, 0, 0, 2, 0, 0x20, 0, 0, 0, 0x10, 0, 0, 0]);
+        verneed.extend_from_slice(&[0, 0, 0, 0, 2, 0, 3, 0, 0x30, 0, 0, 0, 0, 0, 0, 0]);
+
+        let needed = read_verneed(&verneed, &section(0, verneed.len() as u64, 1), scroll::LE).unwrap();
+        assert_eq!(needed.len(), 1);
+        assert_eq!(needed[0].vn_file, 0x10);
+        assert_eq!(needed[0].aux.iter().map(|aux| (aux.vna_other, aux.vna_name)).collect::<Vec<_>>(), [(2, 0x20), (3, 0x30)]);
+        assert_eq!(needed[0].aux[1].vna_flags, VER_FLG_WEAK);
+
+        // A definition inheriting from another, then cut off inside its parent
+        let mut verdef = Vec::new();
+        verdef.extend_from_slice(&[1, 0, 0, 0, 4, 0, 2, 0, 0, 0, 0, 0, 0x14, 0, 0, 0, 0, 0, 0, 0]);
+        verdef.extend_from_slice(&[0x40, 0, 0, 0, 8, 0, 0, 0, 0x50, 0, 0, 0, 0, 0, 0, 0]);
+
+        let defined = read_verdef(&verdef, &section(0, verdef.len() as u64, 1), scroll::LE).unwrap();
+        assert_eq!(defined[0].vd_ndx, 4);
+        assert_eq!(defined[0].names, [0x40, 0x50]);
+
+        assert!(read_verdef(&verdef[..0x1C], &section(0, 0x1C, 1), scroll::LE).is_err());
+    }
 }
@@ -0,0 +1,404 @@
+//! Linux kernel modules: the `.modinfo` tags, the CRCs of the symbols a
+//! module imports from `__versions`, its `struct module` in
+//! `.gnu.linkonce.this_module` and the signature appended to the file.
+#![allow(non_camel_case_types)]
+
+use scroll::Pread;
+
+use super::*;
+
+/// Ends the file of a signed module
+pub const MODULE_SIG_STRING: &[u8] = b"~Module signature appended~\n";
+/// Size of `struct module_signature`, right before [`MODULE_SIG_STRING`]
+pub const MODULE_SIG_SIZE: u64 = 12;
+
+/// Signed with a PGP key, before the kernel used PKCS#7
+pub const PKEY_ID_PGP: u8 = 0;
+/// Signed with an X.509 key, before the kernel used PKCS#7
+pub const PKEY_ID_X509: u8 = 1;
+/// PKCS#7 message, all the kernel has accepted since 4.3
+pub const PKEY_ID_PKCS7: u8 = 2;
+
+#[inline]
+pub fn pkey_id_to_str(id_type: u8) -> &'static str {
+    match id_type {
+        PKEY_ID_PGP => "PGP",
+        PKEY_ID_X509 => "X509",
+        PKEY_ID_PKCS7 => "PKCS7",
+        _ => "UNKNOWN_PKEY_ID",
+    }
+}
+
+/// `key=value` tag of `.modinfo`, from MODULE_LICENSE(), MODULE_ALIAS()
+/// and the like. Keys like `alias` may be given several times.
+#[derive(Serialize, Debug)]
+pub struct Elf_modinfo {
+    pub key:   String,
+    pub value: String,
+}
+
+/// Entry of `__versions`: the CRC of the prototype of an imported symbol,
+/// checked against the kernel's when the module is loaded.
+#[derive(Serialize, Debug)]
+pub struct Elf_modversion {
+    pub crc:  u32,
+    pub name: String,
+}
+
+/// Signer of a PKCS#7 module signature.
+#[derive(Serialize, Debug)]
+pub struct Elf_module_signer {
+    /// Issuer of the signing certificate, like `O=Build, CN=Build key`.
+    /// None when the signer is named by its key ID only.
+    pub issuer:    Option<String>,
+    /// Serial number of the certificate, or its subject key ID
+    pub key_id:    Vec<u8>,
+    pub digest:    String,
+    pub algorithm: String,
+    /// Size of the signature proper
+    pub size:      usize,
+}
+
+/// Signature appended to the module, described by `struct module_signature`.
+#[derive(Serialize, Debug)]
+pub struct Elf_module_signature {
+    /// File offset of the signature, where the module proper ends
+    pub offset:     u64,
+    pub algo:       u8,
+    pub hash:       u8,
+    pub id_type:    u8,
+    /// Signer name of PGP and X.509 signatures
+    pub signer:     Vec<u8>,
+    /// Key ID of PGP and X.509 signatures
+    pub key_id:     Vec<u8>,
+    pub sig_len:    u32,
+    /// Decoded PKCS#7 message, when it could be
+    pub pkcs7:      Option<Elf_module_signer>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Elf_module {
+    pub modinfo:   Vec<Elf_modinfo>,
+    pub versions:  Vec<Elf_modversion>,
+    /// `name` of `struct module`
+    pub name:      Option<String>,
+    /// Functions `struct module` points to, as `module_init()` and
+    /// `module_exit()` set them
+    pub init:      Option<String>,
+    pub exit:      Option<String>,
+    pub signature: Option<Elf_module_signature>,
+}
+
+impl Elf_module {
+
+    /// Values of the `.modinfo` tags called `key`, in file order.
+    pub fn modinfo<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.modinfo.iter().filter(move |info| info.key == key).map(|info| info.value.as_str())
+    }
+
+}
+
+/// MODULE_NAME_LEN, what is left of 64 bytes after an unsigned long.
+fn module_name_len(is_32: bool) -> usize {
+    if is_32 { 60 } else { 56 }
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes.split(|b| *b == 0).next().unwrap_or(&[])).into_owned()
+}
+
+/// Tags of `.modinfo`, null terminated and sometimes padded with more nulls.
+fn read_modinfo(data: &[u8]) -> Vec<Elf_modinfo> {
+    data.split(|b| *b == 0)
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| {
+            let tag = String::from_utf8_lossy(tag);
+            match tag.split_once('=') {
+                Some((key, value)) => Elf_modinfo { key: key.to_string(), value: value.to_string() },
+                None => Elf_modinfo { key: tag.into_owned(), value: String::new() },
+            }
+        })
+        .collect()
+}
+
+/// Signature at the end of `buf`, if there is one.
+fn read_signature(buf: &[u8]) -> Result<Option<Elf_module_signature>, Error> {
+    if !buf.ends_with(MODULE_SIG_STRING) {
+        return Ok(None);
+    }
+    let end = (buf.len() - MODULE_SIG_STRING.len()) as u64;
+    let offset = end.checked_sub(MODULE_SIG_SIZE)
+        .ok_or_else(|| Problem::msg("No room for the module signature header"))?;
+    let header = bytes(buf, offset, MODULE_SIG_SIZE, "module signature header")?;
+    let sig_len = header.pread_with::<u32>(8, scroll::BE).at(offset + 8, "signature size")?;
+    let (signer_len, key_id_len) = (header[3] as u64, header[4] as u64);
+
+    // Signer name, key ID and signature come before the header
+    let start = offset.checked_sub(sig_len as u64 + signer_len + key_id_len)
+        .ok_or_else(|| Problem::msg(format!("Signature of {:#X} bytes does not fit in the file", sig_len)).at(offset + 8))?;
+    let signature = &buf[(start + signer_len + key_id_len) as usize..offset as usize];
+    let pkcs7 = if header[2] == PKEY_ID_PKCS7 { read_pkcs7(signature) } else { None };
+    Ok(Some(Elf_module_signature {
+        offset: start,
+        algo: header[0],
+        hash: header[1],
+        id_type: header[2],
+        signer: buf[start as usize..(start + signer_len) as usize].to_vec(),
+        key_id: buf[(start + signer_len) as usize..(start + signer_len + key_id_len) as usize].to_vec(),
+        sig_len,
+        pkcs7,
+    }))
+}
+
+/// Reads the module data of an ELF relocatable file with a `.modinfo` or
+/// `.gnu.linkonce.this_module` section.
+#[allow(clippy::too_many_arguments)]
+pub fn read_module(rec: &mut Recovery, buf: &[u8], section_headers: &[Elf_section_header], sh_strtab: &[u8],
+                   relocations: &[Elf_relocations], symtab: &[Elf_symbol_header], symstr: &[u8],
+                   is_32: bool, endianness: scroll::Endian) -> Result<Elf_module, Error> {
+
+    let section = |wanted: &str| section_headers.iter()
+        .enumerate()
+        .find(|(_, head)| head.sh_type != SHT_NOBITS && sh_strtab.pread::<&str>(head.sh_name as usize).is_ok_and(|name| name == wanted));
+    let data = |head: &Elf_section_header, what: &str| bytes(buf, head.sh_offset, head.sh_size, what);
+    let symbol_name = |symbol: &Elf_symbol_header| symstr.pread::<&str>(symbol.st_name as usize).unwrap_or("");
+
+    let modinfo = match section(".modinfo") {
+        Some((_, head)) => rec.recover(data(head, ".modinfo").map(read_modinfo), Vec::new())?,
+        None => Vec::new(),
+    };
+
+    // An unsigned long CRC, then the rest of 64 bytes for the name
+    let name_len = module_name_len(is_32);
+    let entsize = 64;
+    let mut versions = match section("__versions") {
+        Some((_, head)) => rec.table(buf, head.sh_offset, head.sh_size, entsize, "module version", |offset| {
+            let crc = buf.pread_with::<u32>(if !is_32 && endianness == scroll::BE { offset + 4 } else { offset }, endianness)?;
+            Ok(Elf_modversion { crc, name: text(&buf[offset + 64 - name_len..offset + 64]) })
+        })?,
+        None => Vec::new(),
+    };
+    // Names too long for __versions have their CRCs in separate sections since Linux 6.13
+    if let (Some((_, crcs)), Some((_, names))) = (section("__version_ext_crcs"), section("__version_ext_names")) {
+        let names = rec.recover(data(names, "__version_ext_names").map(<[u8]>::to_vec), Vec::new())?;
+        let crcs = rec.table(buf, crcs.sh_offset, crcs.sh_size, 4, "module version CRC", |offset| buf.pread_with::<u32>(offset, endianness))?;
+        versions.extend(crcs.into_iter()
+            .zip(names.split(|b| *b == 0))
+            .map(|(crc, name)| Elf_modversion { crc, name: String::from_utf8_lossy(name).into_owned() }));
+    }
+
+    let (mut name, mut init, mut exit) = (None, None, None);
+    if let Some((index, head)) = section(".gnu.linkonce.this_module") {
+        // `name` follows the module state and the list of modules
+        let offset = if is_32 { 12 } else { 24 };
+        name = rec.recover(bytes(buf, head.sh_offset + offset, name_len as u64, "module name").map(|name| Some(text(name))), None)?;
+
+        // Pointers to the init and exit functions are only set by relocations
+        let relocated = relocations.iter()
+            .filter(|relocs| relocs.sh_info as usize == index)
+            .flat_map(|relocs| match &relocs.entries {
+                Elf_reloc_entries::Rela(relas) => relas.iter().map(|rela| rela.r_info).collect(),
+                Elf_reloc_entries::Rel(rels) => rels.iter().map(|rel| rel.r_info).collect(),
+                Elf_reloc_entries::Relr(_) => Vec::new(),
+            })
+            .filter_map(|r_info| symtab.get(r_sym(r_info, is_32)));
+        for symbol in relocated {
+            // Aliases of the functions given to module_init() and module_exit()
+            let function = || symtab.iter()
+                .find(|other| other.st_shndx == symbol.st_shndx && other.st_value == symbol.st_value
+                      && other.st_info & 0xF == STT_FUNC && !symbol_name(other).is_empty() && symbol_name(other) != symbol_name(symbol))
+                .map_or(symbol_name(symbol), symbol_name)
+                .to_string();
+            match symbol_name(symbol) {
+                "init_module" => init = Some(function()),
+                "cleanup_module" => exit = Some(function()),
+                _ => {},
+            }
+        }
+    }
+
+    let signature = rec.within("module signature", |rec| rec.recover(read_signature(buf), None))?;
+
+    Ok(Elf_module { modinfo, versions, name, init, exit, signature })
+}
+
+//
+// PKCS#7
+//
+
+/// DER encoded value.
+struct Der<'a> {
+    tag:  u8,
+    data: &'a [u8],
+}
+
+/// Values of the DER encoded `input`, in order.
+struct DerReader<'a>(&'a [u8]);
+
+impl<'a> Iterator for DerReader<'a> {
+    type Item = Der<'a>;
+
+    fn next(&mut self) -> Option<Der<'a>> {
+        let (&tag, rest) = self.0.split_first()?;
+        let (&first, rest) = rest.split_first()?;
+        let (len, rest) = if first & 0x80 == 0 {
+            (first as usize, rest)
+        } else {
+            let count = (first & 0x7F) as usize;
+            if count == 0 || count > 4 || rest.len() < count {
+                return None;
+            }
+            (rest[..count].iter().fold(0, |len, &b| len << 8 | b as usize), &rest[count..])
+        };
+        if rest.len() < len {
+            self.0 = &[];
+            return None;
+        }
+        self.0 = &rest[len..];
+        Some(Der { tag, data: &rest[..len] })
+    }
+}
+
+impl<'a> Der<'a> {
+    fn children(&self) -> DerReader<'a> {
+        DerReader(self.data)
+    }
+}
+
+const DER_INTEGER: u8      = 0x02;
+const DER_OCTET_STRING: u8 = 0x04;
+const DER_OID: u8          = 0x06;
+const DER_SEQUENCE: u8     = 0x30;
+const DER_SET: u8          = 0x31;
+/// `[0]`, constructed
+const DER_CONTEXT_0: u8    = 0xA0;
+/// `[0]`, primitive
+const DER_CONTEXT_0_PRIMITIVE: u8 = 0x80;
+
+/// Object identifier in dotted notation.
+fn oid(data: &[u8]) -> String {
+    let mut arcs = Vec::new();
+    let mut arc: u64 = 0;
+    for &b in data {
+        arc = arc << 7 | (b & 0x7F) as u64;
+        if b & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = std::cmp::min(arc / 40, 2);
+                arcs.push(first);
+                arcs.push(arc - first * 40);
+            } else {
+                arcs.push(arc);
+            }
+            arc = 0;
+        }
+    }
+    arcs.iter().map(u64::to_string).collect::<Vec<_>>().join(".")
+}
+
+/// Name of the algorithms and attributes found in module signatures, or
+/// the object identifier itself.
+pub fn oid_to_str(oid: &str) -> &str {
+    match oid {
+        "1.3.14.3.2.26" => "sha1",
+        "2.16.840.1.101.3.4.2.1" => "sha256",
+        "2.16.840.1.101.3.4.2.2" => "sha384",
+        "2.16.840.1.101.3.4.2.3" => "sha512",
+        "2.16.840.1.101.3.4.2.4" => "sha224",
+        "2.16.840.1.101.3.4.2.8" => "sha3-256",
+        "2.16.840.1.101.3.4.2.9" => "sha3-384",
+        "2.16.840.1.101.3.4.2.10" => "sha3-512",
+        "1.2.156.10197.1.401" => "sm3",
+        "1.2.840.113549.1.1.1" => "rsaEncryption",
+        "1.2.840.113549.1.1.5" => "sha1WithRSAEncryption",
+        "1.2.840.113549.1.1.11" => "sha256WithRSAEncryption",
+        "1.2.840.113549.1.1.12" => "sha384WithRSAEncryption",
+        "1.2.840.113549.1.1.13" => "sha512WithRSAEncryption",
+        "1.2.840.113549.1.1.14" => "sha224WithRSAEncryption",
+        "1.2.840.10045.2.1" => "ecPublicKey",
+        "1.2.840.10045.4.3.2" => "ecdsa-with-SHA256",
+        "1.2.840.10045.4.3.3" => "ecdsa-with-SHA384",
+        "1.2.840.10045.4.3.4" => "ecdsa-with-SHA512",
+        "1.2.156.10197.1.501" => "sm2-with-sm3",
+        "2.5.4.3" => "CN",
+        "2.5.4.6" => "C",
+        "2.5.4.7" => "L",
+        "2.5.4.8" => "ST",
+        "2.5.4.10" => "O",
+        "2.5.4.11" => "OU",
+        "1.2.840.113549.1.9.1" => "emailAddress",
+        _ => oid,
+    }
+}
+
+/// Algorithm of an AlgorithmIdentifier, by name.
+fn algorithm(identifier: &Der) -> Option<String> {
+    let oid = identifier.children().find(|value| value.tag == DER_OID)?;
+    let oid = self::oid(oid.data);
+    Some(oid_to_str(&oid).to_string())
+}
+
+/// X.501 name as `type=value` attributes separated by commas.
+fn name(name: &Der) -> String {
+    name.children()
+        .flat_map(|set| set.children())
+        .filter_map(|attribute| {
+            let mut parts = attribute.children();
+            let (kind, value) = (parts.next()?, parts.next()?);
+            Some(format!("{}={}", oid_to_str(&oid(kind.data)), String::from_utf8_lossy(value.data)))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Signer of the PKCS#7 SignedData message `sig`, as sign-file writes it:
+/// without certificates, signed attributes nor the module itself.
+fn read_pkcs7(sig: &[u8]) -> Option<Elf_module_signer> {
+    let content_info = DerReader(sig).next().filter(|value| value.tag == DER_SEQUENCE)?;
+    let signed_data = content_info.children().find(|value| value.tag == DER_CONTEXT_0)?
+        .children().next().filter(|value| value.tag == DER_SEQUENCE)?;
+    // Signer infos are the last set, after the digest algorithms set
+    let signer_info = signed_data.children().filter(|value| value.tag == DER_SET).last()?
+        .children().next().filter(|value| value.tag == DER_SEQUENCE)?;
+
+    let mut fields = signer_info.children().skip(1);
+    let sid = fields.next()?;
+    let (issuer, key_id) = match sid.tag {
+        DER_SEQUENCE => {
+            let mut parts = sid.children();
+            let issuer = parts.next()?;
+            let serial = parts.next().filter(|value| value.tag == DER_INTEGER)?;
+            (Some(name(&issuer)), serial.data.to_vec())
+        },
+        // Subject key identifier of CMS signer infos
+        DER_CONTEXT_0_PRIMITIVE => (None, sid.data.to_vec()),
+        _ => return None,
+    };
+    let digest = algorithm(&fields.next()?)?;
+    let mut next = fields.next()?;
+    if next.tag == DER_CONTEXT_0 {
+        next = fields.next()?;
+    }
+    let algorithm = algorithm(&next)?;
+    let signature = fields.next().filter(|value| value.tag == DER_OCTET_STRING)?;
+
+    Some(Elf_module_signer { issuer, key_id, digest, algorithm, size: signature.data.len() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modinfo_tags() {
+        let modinfo = read_modinfo(b"license=GPL\0alias=pci:v00008086d*\0\0\0alias=fs-hello\0intree\0");
+        let tags: Vec<(&str, &str)> = modinfo.iter().map(|info| (info.key.as_str(), info.value.as_str())).collect();
+        assert_eq!(tags, [("license", "GPL"), ("alias", "pci:v00008086d*"), ("alias", "fs-hello"), ("intree", "")]);
+    }
+
+    #[test]
+    fn object_identifiers() {
+        assert_eq!(oid(&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01]), "2.16.840.1.101.3.4.2.1");
+        assert_eq!(oid(&[0x55, 0x04, 0x03]), "2.5.4.3");
+        assert_eq!(oid_to_str("1.2.3"), "1.2.3");
+    }
+}
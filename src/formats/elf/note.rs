@@ -0,0 +1,194 @@
+//! Descriptions of notes, decoded by their owner and type: the GNU ABI tag,
+//! build ID and program properties, and the notes of Go, Android, the BSDs,
+//! SystemTap probes and package metadata. Core dump notes are read by
+//! [`super::coredump`].
+
+use scroll::Pread;
+
+use super::*;
+
+/// Entry of a NT_GNU_PROPERTY_TYPE_0 note.
+#[derive(Serialize, Debug)]
+pub struct Property {
+    pub pr_type: u32,
+    pub data:    Vec<u8>,
+}
+
+impl Property {
+
+    /// 32-bit value most properties hold, 0 when they hold none.
+    pub fn value(&self, endianness: scroll::Endian) -> u32 {
+        self.data.pread_with::<u32>(0, endianness).unwrap_or(0)
+    }
+
+}
+
+/// SystemTap probe of a NT_STAPSDT note.
+#[derive(Serialize, Debug)]
+pub struct Probe {
+    pub provider:  String,
+    pub name:      String,
+    /// Address of the probed instruction, as linked
+    pub pc:        u64,
+    /// Link time address of `.stapsdt.base`, to find where `pc` got loaded
+    pub base:      u64,
+    /// Address of the counter enabling the probe, 0 for none
+    pub semaphore: u64,
+    /// Arguments as `size@operand`, separated by spaces
+    pub args:      String,
+}
+
+/// Decoded description of a note.
+#[derive(Serialize, Debug)]
+pub enum NoteDesc {
+    /// NT_GNU_ABI_TAG: the OS and the oldest kernel version the file runs on
+    AbiTag { os: u32, version: [u32; 3] },
+    BuildId(Vec<u8>),
+    Properties(Vec<Property>),
+    /// Gold version, Go build ID, FreeBSD architecture or package metadata
+    Text(String),
+    /// FreeBSD, NetBSD and OpenBSD release the file was built for
+    Version(u32),
+    AndroidIdent { api_level: u32, ndk_version: Option<String>, ndk_build: Option<String> },
+    /// NT_FREEBSD_FEATURE_CTL opt-outs
+    FeatureCtl(u32),
+    Probe(Probe),
+    /// Notes telling all by their presence
+    Empty,
+    /// Unknown notes, and known ones too short for what they should hold
+    Bytes,
+}
+
+/// Text up to the first null byte.
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes.split(|b| *b == 0).next().unwrap_or(&[])).into_owned()
+}
+
+/// Properties of a NT_GNU_PROPERTY_TYPE_0 note description, each padded to
+/// `alignment`. A property running past the end ends the list.
+pub fn read_properties(desc: &[u8], endianness: scroll::Endian, alignment: usize) -> Vec<Property> {
+    let mut properties = Vec::new();
+    let mut offset = 0;
+    while let (Ok(pr_type), Ok(datasz)) = (desc.pread_with::<u32>(offset, endianness),
+                                           desc.pread_with::<u32>(offset + 4, endianness)) {
+        let end = offset + 8 + datasz as usize;
+        if end > desc.len() {
+            break;
+        }
+        properties.push(Property { pr_type, data: desc[offset + 8..end].to_vec() });
+        offset = align(alignment, end);
+    }
+    properties
+}
+
+/// Probe of a NT_STAPSDT note description: three addresses, then the
+/// provider, name and arguments as null terminated strings.
+fn read_probe(desc: &[u8], is_32: bool, endianness: scroll::Endian) -> Option<Probe> {
+    let size = if is_32 { 4 } else { 8 };
+    let address = |i: usize| if is_32 {
+        desc.pread_with::<u32>(i * size, endianness).map(u64::from).ok()
+    } else {
+        desc.pread_with::<u64>(i * size, endianness).ok()
+    };
+    let (pc, base, semaphore) = (address(0)?, address(1)?, address(2)?);
+    let mut strings = desc[3 * size..].split(|b| *b == 0).map(|string| String::from_utf8_lossy(string).into_owned());
+    Some(Probe {
+        provider: strings.next()?,
+        name: strings.next()?,
+        pc,
+        base,
+        semaphore,
+        args: strings.next().unwrap_or_default(),
+    })
+}
+
+impl Elf_note {
+
+    /// Decodes the description, whose layout depends on the name and type.
+    /// Program properties are padded to 8 bytes in 64-bit files.
+    pub fn decode(&self, is_32: bool, endianness: scroll::Endian) -> NoteDesc {
+        let word = |i: usize| self.desc.pread_with::<u32>(i * 4, endianness).ok();
+        let decoded = match (self.name.as_str(), self.n_type) {
+            ("GNU", NT_GNU_ABI_TAG) => match (word(0), word(1), word(2), word(3)) {
+                (Some(os), Some(major), Some(minor), Some(patch)) => Some(NoteDesc::AbiTag { os, version: [major, minor, patch] }),
+                _ => None,
+            },
+            ("GNU", NT_GNU_BUILD_ID) if !self.desc.is_empty() => Some(NoteDesc::BuildId(self.desc.clone())),
+            ("GNU", NT_GNU_PROPERTY_TYPE_0) => {
+                Some(NoteDesc::Properties(read_properties(&self.desc, endianness, if is_32 { 4 } else { 8 })))
+            },
+            ("GNU", NT_GNU_GOLD_VERSION) | ("Go", NT_GO_BUILDID) | ("FreeBSD", NT_FREEBSD_ARCH_TAG) | ("FDO", NT_FDO_PACKAGING_METADATA) => {
+                Some(NoteDesc::Text(text(&self.desc)))
+            },
+            ("FreeBSD", NT_FREEBSD_ABI_TAG) | ("NetBSD", NT_NETBSD_IDENT) | ("OpenBSD", NT_OPENBSD_IDENT) => word(0).map(NoteDesc::Version),
+            ("FreeBSD", NT_FREEBSD_FEATURE_CTL) => word(0).map(NoteDesc::FeatureCtl),
+            ("FreeBSD", NT_FREEBSD_NOINIT_TAG) => Some(NoteDesc::Empty),
+            // Newer NDKs add their version and build number, 64 bytes each
+            ("Android", NT_ANDROID_TYPE_IDENT) => word(0).map(|api_level| NoteDesc::AndroidIdent {
+                api_level,
+                ndk_version: self.desc.get(4..68).map(text),
+                ndk_build: self.desc.get(68..132).map(text),
+            }),
+            ("stapsdt", NT_STAPSDT) => read_probe(&self.desc, is_32, endianness).map(NoteDesc::Probe),
+            _ => None,
+        };
+        decoded.unwrap_or(NoteDesc::Bytes)
+    }
+
+}
+
+impl Elf {
+
+    /// Description of `note`, decoded.
+    pub fn note_desc(&self, note: &Elf_note) -> NoteDesc {
+        let endianness = if self.header.e_ident.ei_data == ELFDATA2MSB { scroll::BE } else { scroll::LE };
+        note.decode(self.header.e_ident.ei_class == ELFCLASS32, endianness)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(name: &str, n_type: u32, desc: &[u8]) -> Elf_note {
+        Elf_note { n_type, name: name.to_string(), desc: desc.to_vec() }
+    }
+
+    #[test]
+    fn vendor_notes() {
+        let abi_tag = note("GNU", NT_GNU_ABI_TAG, &[0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(matches!(abi_tag.decode(false, scroll::LE), NoteDesc::AbiTag { os: ELF_NOTE_OS_LINUX, version: [3, 2, 0] }));
+        assert!(matches!(note("GNU", NT_GNU_ABI_TAG, &[0; 8]).decode(false, scroll::LE), NoteDesc::Bytes));
+
+        // Same type, other owners
+        assert!(matches!(note("FreeBSD", NT_FREEBSD_ABI_TAG, &1400097u32.to_le_bytes()).decode(false, scroll::LE), NoteDesc::Version(1400097)));
+        assert!(matches!(note("Android", NT_ANDROID_TYPE_IDENT, &30u32.to_le_bytes()).decode(false, scroll::LE),
+                         NoteDesc::AndroidIdent { api_level: 30, ndk_version: None, ndk_build: None }));
+
+        let mut desc = Vec::new();
+        for address in [0x1137u64, 0x2004, 0x4010] {
+            desc.extend_from_slice(&address.to_le_bytes());
+        }
+        desc.extend_from_slice(b"myapp\0start\0-4@%edi 8@%rsi\0");
+        match note("stapsdt", NT_STAPSDT, &desc).decode(false, scroll::LE) {
+            NoteDesc::Probe(probe) => {
+                assert_eq!((probe.provider.as_str(), probe.name.as_str(), probe.args.as_str()), ("myapp", "start", "-4@%edi 8@%rsi"));
+                assert_eq!((probe.pc, probe.base, probe.semaphore), (0x1137, 0x2004, 0x4010));
+            },
+            other => panic!("decoded as {:?}", other),
+        }
+    }
+
+    #[test]
+    fn program_properties() {
+        // x86 ISA needed, then IBT and SHSTK, each padded to 8 bytes
+        let desc = [0x02, 0x80, 0x00, 0xC0, 4, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+                    0x02, 0x00, 0x00, 0xC0, 4, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0];
+        let properties = read_properties(&desc, scroll::LE, 8);
+        let types: Vec<(u32, u32)> = properties.iter().map(|property| (property.pr_type, property.value(scroll::LE))).collect();
+        assert_eq!(types, [(GNU_PROPERTY_X86_ISA_1_NEEDED, GNU_PROPERTY_X86_ISA_1_BASELINE),
+                           (GNU_PROPERTY_X86_FEATURE_1_AND, GNU_PROPERTY_X86_FEATURE_1_IBT | GNU_PROPERTY_X86_FEATURE_1_SHSTK)]);
+        assert_eq!(read_properties(&desc[..20], scroll::LE, 8).len(), 1);
+    }
+}
@@ -0,0 +1,128 @@
+//! Serialized form of an ELF file. String tables are left out, and the names
+//! they hold are given next to the offsets referring to them.
+
+use scroll::Pread;
+use serde::{Serialize, Serializer};
+
+use super::*;
+
+/// Structure along with the name its string table offset points to, null
+/// when the offset is out of the table.
+#[derive(Serialize)]
+struct Named<'a, T> {
+    name:  Option<&'a str>,
+    #[serde(flatten)]
+    inner: &'a T,
+}
+
+#[derive(Serialize)]
+struct Dynamic<'a> {
+    #[serde(flatten)]
+    entry:  &'a Elf_dynamic,
+    /// Value of the tags naming a string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    string: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Note<'a> {
+    #[serde(flatten)]
+    note:    &'a Elf_note,
+    decoded: note::NoteDesc,
+}
+
+#[derive(Serialize)]
+struct Verneed<'a> {
+    vn_file: u32,
+    file:    Option<&'a str>,
+    aux:     Vec<Named<'a, Elf_vernaux>>,
+}
+
+#[derive(Serialize)]
+struct Verdef<'a> {
+    #[serde(flatten)]
+    def:           &'a Elf_verdef,
+    version_names: Vec<Option<&'a str>>,
+}
+
+#[derive(Serialize)]
+struct ElfView<'a> {
+    header:              &'a Elf_header,
+    program_headers:     &'a [Elf_program_header],
+    section_headers:     Vec<Named<'a, Elf_section_header>>,
+    /// Indices of the sections each program header holds
+    segment_sections:    Vec<Vec<usize>>,
+    /// Regions of the file by offset, with the gaps between them
+    layout:              Vec<layout::Region>,
+    compressed_sections: &'a [compress::Elf_compression],
+    file_size:           u64,
+    symtab:              Vec<Named<'a, Elf_symbol_header>>,
+    dynsym:              Vec<Named<'a, Elf_symbol_header>>,
+    relocations:         &'a [Elf_relocations],
+    dynamic:             Vec<Dynamic<'a>>,
+    init_arrays:         &'a [Elf_init_array],
+    hash:                Option<&'a hash::Elf_hash>,
+    gnu_hash:            Option<&'a hash::Elf_gnu_hash>,
+    notes:               Vec<Note<'a>>,
+    versym:              &'a [u16],
+    verneed:             Vec<Verneed<'a>>,
+    verdef:              Vec<Verdef<'a>>,
+    core:                Option<&'a coredump::Elf_core>,
+    module:              Option<&'a module::Elf_module>,
+    dwarf:               Option<&'a dwarf::Dwarf>,
+    /// Mitigations of executables and shared libraries
+    hardening:           Option<hardening::Hardening>,
+}
+
+fn string_at<N: Into<u64>>(strtab: &[u8], offset: N) -> Option<&str> {
+    strtab.pread::<&str>(offset.into() as usize).ok()
+}
+
+fn named<'a, T>(items: &'a [T], strtab: &'a [u8], name: impl Fn(&T) -> u32) -> Vec<Named<'a, T>> {
+    items.iter().map(|inner| Named { name: string_at(strtab, name(inner)), inner }).collect()
+}
+
+impl Serialize for Elf {
+
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+
+        let linked = self.header.e_type == ET_EXEC || self.header.e_type == ET_DYN;
+        let view = ElfView {
+            header:              &self.header,
+            program_headers:     &self.program_headers,
+            section_headers:     named(&self.section_headers, &self.sh_strtab, |head| head.sh_name),
+            segment_sections:    self.segment_sections(),
+            layout:              self.layout(),
+            compressed_sections: &self.compressed_sections,
+            file_size:           self.file_size,
+            symtab:              named(&self.symtab, &self.symstr, |sym| sym.st_name),
+            dynsym:              named(&self.dynsym, &self.dynstr, |sym| sym.st_name),
+            relocations:         &self.relocations,
+            dynamic:             self.dynamic.iter().map(|entry| Dynamic {
+                entry,
+                string: Some(entry).filter(|entry| DT_STRING_TAGS.contains(&entry.d_tag))
+                    .and_then(|entry| string_at(&self.dynstr, entry.d_ptr)),
+            }).collect(),
+            init_arrays:         &self.init_arrays,
+            hash:                self.hash.as_ref(),
+            gnu_hash:            self.gnu_hash.as_ref(),
+            notes:               self.notes.iter().map(|note| Note { note, decoded: self.note_desc(note) }).collect(),
+            versym:              &self.versym,
+            verneed:             self.verneed.iter().map(|need| Verneed {
+                vn_file: need.vn_file,
+                file:    string_at(&self.dynstr, need.vn_file),
+                aux:     named(&need.aux, &self.dynstr, |aux| aux.vna_name),
+            }).collect(),
+            verdef:              self.verdef.iter().map(|def| Verdef {
+                def,
+                version_names: def.names.iter().map(|&name| string_at(&self.dynstr, name)).collect(),
+            }).collect(),
+            core:                self.core.as_ref(),
+            module:              self.module.as_ref(),
+            dwarf:               self.dwarf.as_ref(),
+            hardening:           if linked { Some(self.hardening()) } else { None },
+        };
+        view.serialize(serializer)
+    }
+
+}
@@ -4,35 +4,45 @@ include!("./class_constants.rs");
 use failure::{Error};
 use scroll::{self, Pread};
 
-use crate::Opt;
-use crate::Problem;
-use crate::format::{fmt_indentln};
+use crate::{Problem, ResultExt};
+use crate::formats::bytes;
 
 pub const CLASS_MAGIC: &'static [u8; CLASS_MAGIC_SIZE] = b"\xCA\xFE\xBA\xBE";
 pub const CLASS_MAGIC_SIZE: usize = 4;
 
-#[derive(Debug)]
-struct Class_header {
-    magic:            u32,
-    minor_ver:        u16,
-    major_ver:        u16,
-    const_pool_count: u16,
-    const_pool_tab:   Vec<Constants>,
-    access_flags:     u16,
-    this_class:       u16,
-    super_class:      u16,
-    interface_count:  u16,
-    interface_tab:    Vec<u16>,
-    field_count:      u16,
-    field_tab:        Vec<Field_info>,
-    method_count:     u16,
-    method_tab:       Vec<Method_info>,
-    attribute_count:  u16,
-    attribute_tab:    Vec<Attributes>,
+/// How deep attributes and annotation values may nest before the file is
+/// rejected, so crafted files cannot overflow the stack.
+const MAX_NESTING: usize = 64;
+
+fn check_nesting(depth: usize) -> Result<(), Error> {
+    if depth > MAX_NESTING {
+        return Err(Error::from(Problem::msg(format!("Attributes nested deeper than {} levels", MAX_NESTING))));
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Debug)]
+pub struct Class_header {
+    pub magic:            u32,
+    pub minor_ver:        u16,
+    pub major_ver:        u16,
+    pub const_pool_count: u16,
+    pub const_pool_tab:   Vec<Constants>,
+    pub access_flags:     u16,
+    pub this_class:       u16,
+    pub super_class:      u16,
+    pub interface_count:  u16,
+    pub interface_tab:    Vec<u16>,
+    pub field_count:      u16,
+    pub field_tab:        Vec<Field_info>,
+    pub method_count:     u16,
+    pub method_tab:       Vec<Method_info>,
+    pub attribute_count:  u16,
+    pub attribute_tab:    Vec<Attributes>,
 }
 
-#[derive(Debug, AsRefStr)]
-enum Constants {
+#[derive(Serialize, Debug, AsRefStr)]
+pub enum Constants {
     UTF8{len: u16, text: String},
     Integer(u32),
     Float(f32),
@@ -54,77 +64,85 @@ enum Constants {
 }
 
 impl Constants {
-    pub fn values_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        use Constants::*;
-        use ansi_term::Color;
-
+    /// Text of a `UTF8` constant, empty for any other kind.
+    pub fn as_utf8(&self) -> &str {
         match self {
-            UTF8{len: _len, text}                                  => format!("{}", text),
-            Integer(int)                                           => format!("{}", Color::Fixed(120).paint(format!("{}", int))),
-            Float(float)                                           => format!("{}", Color::Fixed(120).paint(format!("{}", float))),
-            Long(long)                                             => format!("{}", Color::Fixed(120).paint(format!("{}", long))),
-            Double(double)                                         => format!("{}", Color::Fixed(120).paint(format!("{}", double))),
-            ClassRef{name_idx}                                     => format!("{}({})",
-                                                                              Color::Yellow.paint(const_tab[*name_idx as usize].values_to_string(const_tab)),
-                                                                              Color::Red.paint(format!("{}", name_idx))),
-            StringRef{string_idx}                                  => format!("\"{}\"({})",
-                                                                              Color::Purple.paint(const_tab[*string_idx as usize].values_to_string(const_tab)),
-                                                                              Color::Red.paint(format!("{}", string_idx))),
-            FieldRef{class_idx, nametype_idx}                      => format!("{}, {}",
-                                                                              const_tab[*class_idx as usize].values_to_string(const_tab),
-                                                                              const_tab[*nametype_idx as usize].values_to_string(const_tab)),
-            MethodRef{class_idx, nametype_idx}                     => format!("{}, {}",
-                                                                              const_tab[*class_idx as usize].values_to_string(const_tab),
-                                                                              const_tab[*nametype_idx as usize].values_to_string(const_tab)),
-            InterfaceMethodRef{class_idx, nametype_idx}            => format!("{}, {}",
-                                                                              const_tab[*class_idx as usize].values_to_string(const_tab),
-                                                                              const_tab[*nametype_idx as usize].values_to_string(const_tab)),
-            NameAndType{name_idx, desc_idx}                        => format!("{}({}), {}({})",
-                                                                              Color::Blue.paint(const_tab[*name_idx as usize].values_to_string(const_tab)),
-                                                                              Color::Red.paint(format!("{}", name_idx)),
-                                                                              Color::Green.paint(const_tab[*desc_idx as usize].values_to_string(const_tab)),
-                                                                              Color::Red.paint(format!("{}", desc_idx))),
-            MethodHandle{kind, idx}                                => format!("{}, {}({})",
-                                                                              ref_kind_to_str(*kind),
-                                                                              const_tab[*idx as usize].values_to_string(const_tab),
-                                                                              Color::Red.paint(format!("{}", idx))),
-            MethodType{desc_idx}                                   => format!("{}({})",
-                                                                              const_tab[*desc_idx as usize].values_to_string(const_tab),
-                                                                              Color::Red.paint(format!("{}", desc_idx))),
-            Dynamic{bootstrap_method_attr_idx, nametype_idx}       => format!("Bootstrap: {} {}",
-                                                                              bootstrap_method_attr_idx,
-                                                                              const_tab[*nametype_idx as usize].values_to_string(const_tab)),
-            InvokeDynamic{bootstrap_method_attr_idx, nametype_idx} => format!("Bootstrap: {} {}",
-                                                                              bootstrap_method_attr_idx,
-                                                                              const_tab[*nametype_idx as usize].values_to_string(const_tab)),
-            Module{name_idx}                                       => format!("{}({})",
-                                                                              const_tab[*name_idx as usize].values_to_string(const_tab),
-                                                                              Color::Red.paint(format!("{}", name_idx))),
-            Package{name_idx}                                      => format!("{}({})",
-                                                                              const_tab[*name_idx as usize].values_to_string(const_tab),
-                                                                              Color::Red.paint(format!("{}", name_idx))),
-            Ghost                                                  => format!(""),
+            Constants::UTF8{text, ..} => text,
+            _                         => "",
         }
+    }
 
+    /// Reads the body of a constant pool entry tagged `tag`.
+    fn parse(buf: &[u8], offset: &mut usize, tag: u8) -> Result<Constants, Error> {
+        use Constants::*;
+
+        Ok(match tag {
+            CONSTANT_UTF8 => {
+                let len: u16 = buf.gread_with(offset, scroll::BE)?;
+                let text = std::str::from_utf8(bytes(buf, *offset as u64, len as u64, "UTF8 constant")?)?
+                    .to_string();
+                *offset += len as usize;
+                UTF8{len, text}
+            },
+            CONSTANT_INTEGER => Integer(buf.gread_with(offset, scroll::BE)?),
+            CONSTANT_FLOAT => Float(buf.gread_with(offset, scroll::BE)?),
+            CONSTANT_LONG => Long(buf.gread_with::<u64>(offset, scroll::BE)?),
+            CONSTANT_DOUBLE => Double(buf.gread_with::<f64>(offset, scroll::BE)?),
+            CONSTANT_CLASS => ClassRef{name_idx: buf.gread_with(offset, scroll::BE)?},
+            CONSTANT_STRING => StringRef{string_idx: buf.gread_with(offset, scroll::BE)?},
+            CONSTANT_FIELDREF => FieldRef {
+                class_idx: buf.gread_with(offset, scroll::BE)?,
+                nametype_idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_METHODREF => MethodRef {
+                class_idx: buf.gread_with(offset, scroll::BE)?,
+                nametype_idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_INTERFACEMETHODREF => InterfaceMethodRef {
+                class_idx: buf.gread_with(offset, scroll::BE)?,
+                nametype_idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_NAMEANDTYPE => NameAndType {
+                name_idx: buf.gread_with(offset, scroll::BE)?,
+                desc_idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_METHODHANDLE => MethodHandle {
+                kind: buf.gread_with(offset, scroll::BE)?,
+                idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_METHODTYPE => MethodType {desc_idx: buf.gread_with(offset, scroll::BE)?},
+            CONSTANT_DYNAMIC => Dynamic {
+                bootstrap_method_attr_idx: buf.gread_with(offset, scroll::BE)?,
+                nametype_idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_INVOKEDYNAMIC => InvokeDynamic {
+                bootstrap_method_attr_idx: buf.gread_with(offset, scroll::BE)?,
+                nametype_idx: buf.gread_with(offset, scroll::BE)?,
+            },
+            CONSTANT_MODULE => Module{name_idx: buf.gread_with(offset, scroll::BE)?},
+            CONSTANT_PACKAGE => Package{name_idx: buf.gread_with(offset, scroll::BE)?},
+            _ => return Err(Error::from(Problem::msg(format!("Invalid constant tag: {}", tag)))),
+        })
     }
 }
 
-#[derive(Debug)]
-struct Field_info {
-    access_flags: u16,
-    name_idx:     u16,
-    desc_idx:     u16,
-    attr_count:   u16,
-    attributes:   Vec<Attributes>,
+
+#[derive(Serialize, Debug)]
+pub struct Field_info {
+    pub access_flags: u16,
+    pub name_idx:     u16,
+    pub desc_idx:     u16,
+    pub attr_count:   u16,
+    pub attributes:   Vec<Attributes>,
 }
 
-#[derive(Debug)]
-struct Method_info {
-    access_flags: u16,
-    name_idx:     u16,
-    desc_idx:     u16,
-    attr_count:   u16,
-    attributes:   Vec<Attributes>,
+#[derive(Serialize, Debug)]
+pub struct Method_info {
+    pub access_flags: u16,
+    pub name_idx:     u16,
+    pub desc_idx:     u16,
+    pub attr_count:   u16,
+    pub attributes:   Vec<Attributes>,
 }
 
 // #[derive(Debug)]
@@ -134,8 +152,8 @@ struct Method_info {
 //     info:          Vec<u8>,
 // }
 
-#[derive(Debug, AsRefStr)]
-enum Attributes {
+#[derive(Serialize, Debug, AsRefStr)]
+pub enum Attributes {
     ConstantValue {
         name_idx:  u16,
         attr_len:  u32,
@@ -315,12 +333,28 @@ enum Attributes {
 impl Attributes {
 
     pub fn parse(buf: &[u8], offset: &mut usize, const_tab: &Vec<Constants>) -> Result<Attributes, Error> {
+        Attributes::parse_nested(buf, offset, const_tab, 0)
+    }
+
+    fn parse_nested(buf: &[u8], offset: &mut usize, const_tab: &Vec<Constants>, depth: usize) -> Result<Attributes, Error> {
+        check_nesting(depth)?;
+        let start = *offset as u64;
+        let name_idx = buf.gread_with::<u16>(offset, scroll::BE).at(start, "attribute")?;
+        let attr_len = buf.gread_with::<u32>(offset, scroll::BE).at(start, "attribute")?;
+        let name = const_tab.get(name_idx as usize)
+            .ok_or_else(|| Problem::msg(format!("Invalid attribute name index: {}", name_idx)))
+            .at(start, "attribute")?
+            .as_utf8();
+        Attributes::parse_info(buf, offset, const_tab, depth, name_idx, attr_len, name)
+            .at(start, format_args!("{} attribute", name))
+    }
+
+    /// Reads the `info` of the attribute called `name`.
+    fn parse_info(buf: &[u8], offset: &mut usize, const_tab: &Vec<Constants>, depth: usize,
+                  name_idx: u16, attr_len: u32, name: &str) -> Result<Attributes, Error> {
         use Attributes::*;
 
-        let name_idx = buf.gread_with::<u16>(offset, scroll::BE)?;
-        let attr_len = buf.gread_with::<u32>(offset, scroll::BE)?;
-        let name = const_tab[name_idx as usize].values_to_string(const_tab);
-        match name.as_str() {
+        match name {
 
             "ConstantValue" => Ok(ConstantValue{name_idx,attr_len,
                                                 const_idx: buf.gread_with(offset, scroll::BE)?}),
@@ -341,7 +375,7 @@ impl Attributes {
                 let attr_count     = buf.gread_with::<u16>(offset, scroll::BE)?;
                 let mut attributes = Vec::with_capacity(attr_count as usize);
                 for _ in 0..attr_count as usize {
-                    attributes.push(Attributes::parse(buf, offset, const_tab)?);
+                    attributes.push(Attributes::parse_nested(buf, offset, const_tab, depth + 1)?);
                 }
 
                 Ok(Code{name_idx,attr_len,
@@ -411,7 +445,7 @@ impl Attributes {
                             }
                             entries.push(FullFrame(frame_type, offset_delta, n_locals, locals, n_stack_items, stack_items));
                         },
-                        _ => return Err(Error::from(Problem::Msg(format!("Invalid/Unsupported stack map frame")))),
+                        _ => return Err(Error::from(Problem::msg(format!("Invalid/Unsupported stack map frame")))),
                     }
                 }
 
@@ -478,7 +512,7 @@ impl Attributes {
             },
             "SourceDebugExtension" => {
 
-                let debug_ext = buf[*offset..*offset + attr_len as usize].to_vec();
+                let debug_ext = bytes(buf, *offset as u64, attr_len as u64, "SourceDebugExtension")?.to_vec();
 
                 Ok(SourceDebugExtension{name_idx,attr_len,
                                         debug_ext,
@@ -799,319 +833,7 @@ impl Attributes {
             }
 
 
-            _ => return Err(Error::from(Problem::Msg(format!("Invalid/Unsupported attribute: {}", name)))),
-
-        }
-
-    }
-
-    pub fn values_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        use Attributes::*;
-        use ansi_term::Color;
-
-        match self {
-
-            ConstantValue{name_idx, attr_len: _, const_idx} => format!("{}: {}",
-                                                                    const_tab[*name_idx  as usize].values_to_string(const_tab),
-                                                                    const_tab[*const_idx as usize].values_to_string(const_tab)),
-            Code{name_idx, attr_len: _, max_stack, max_locals, code_length,
-                 code: _, ex_tab_len: _, ex_tab, attr_count: _, attributes} => {
-                let mut exc = String::new();
-                for ex in ex_tab {
-                    exc += &format!("  Start: {}, End: {}, Handler: {}, Type: {}\n",
-                                    ex.start_pc,
-                                    ex.end_pc,
-                                    ex.handler_pc,
-                                    const_tab[ex.catch_type as usize].values_to_string(const_tab));
-                }
-                let mut attr = String::new();
-                for at in attributes {
-                    attr += &format!("{}\n", at.values_to_string(const_tab));
-                }
-
-                format!("{}: Max stack: {}, Max locals: {}, Code_length: {}\n{}{}",
-                        Color::White.underline().paint(const_tab[*name_idx  as usize].values_to_string(const_tab)),
-                        Color::Purple.paint(max_stack.to_string()),
-                        Color::Cyan.paint(max_locals.to_string()),
-                        Color::Green.paint(code_length.to_string()),
-                        exc, attr)
-            },
-            StackMapTable{name_idx, attr_len: _, n_entries: _, entries} => {
-                let mut format = String::new();
-                for entry in entries {
-                    format += "  ";
-                    format += &entry.value_to_string(const_tab);
-                    format.push('\n');
-                }
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            Exceptions{name_idx, attr_len: _, n_of_ex: _, ex_idx_tab} => {
-                let mut format = String::new();
-                for ex in ex_idx_tab {
-                    format += &const_tab[*ex as usize].values_to_string(const_tab);
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            InnerClasses{name_idx: _, attr_len: _, n_of_classes: _, classes} => {
-                let mut format = String::new();
-                for class in classes {
-                    format += &format!("Inner: {}, ", const_tab[class.inner_class_info_idx as usize].values_to_string(const_tab));
-                    format += &format!("Outer: {}, ", const_tab[class.outer_class_info_idx as usize].values_to_string(const_tab));
-                    format += &format!("Inner name: {}, ", Color::Yellow.paint(const_tab[class.inner_name_idx as usize].values_to_string(const_tab)));
-                    format += &format!("Flags: {}\n", access_flags_to_str(class.inner_class_access_flags));
-                }
-
-                format!("{}",format)
-            },
-            EnclosingMethod{name_idx, attr_len: _, class_idx, method_idx} => {
-                format!("{}: {} {}",
-                        const_tab[*name_idx   as usize].values_to_string(const_tab),
-                        const_tab[*class_idx  as usize].values_to_string(const_tab),
-                        const_tab[*method_idx as usize].values_to_string(const_tab))
-            },
-            Synthetic{name_idx, attr_len: _, } => {
-                format!("{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab))
-            },
-            Signature{name_idx: _, attr_len: _, sig_idx} => {
-                format!("Sig: {}",
-                        Color::Yellow.paint(const_tab[*sig_idx as usize].values_to_string(const_tab)))
-            },
-            SourceFile{name_idx: _, attr_len: _, source_idx} => {
-                format!("{}",
-                        Color::Yellow.paint(const_tab[*source_idx as usize].values_to_string(const_tab)))
-            },
-            SourceDebugExtension{name_idx: _, attr_len: _, debug_ext} => {
-                format!("{}",
-                        std::str::from_utf8(debug_ext).unwrap())
-            },
-            LineNumberTable{name_idx, attr_len: _, line_num_tab_len: _, line_num_tab} => {
-                let mut format = String::new();
-                for line in line_num_tab {
-                    format += &format!("  pc: {}, line: {}\n",
-                                      Color::Red.paint(line.start_pc.to_string()),
-                                      Color::Blue.paint(line.line_num.to_string()))
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            LocalVariableTable{name_idx, attr_len: _, local_var_tab_len: _, local_var_tab} => {
-                let mut format = String::new();
-                for local in local_var_tab {
-                    format += &format!("  {}: {}, Start: {}, Idx: {}\n",
-                                       Color::Blue.paint(const_tab[local.name_idx as usize].values_to_string(const_tab)),
-                                       Color::Yellow.paint(const_tab[local.desc_idx as usize].values_to_string(const_tab)),
-                                       local.start_pc,
-                                       local.idx);
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            LocalVariableTypeTable{name_idx, attr_len: _, local_var_type_tab_len: _, local_var_type_tab} => {
-                let mut format = String::new();
-                for local in local_var_type_tab {
-                    format += &format!("  {}: {}, Start: {}, Idx: {}\n",
-                                       Color::Blue.paint(const_tab[local.name_idx as usize].values_to_string(const_tab)),
-                                       Color::Yellow.paint(const_tab[local.sig_idx as usize].values_to_string(const_tab)),
-                                       local.start_pc,
-                                       local.idx);
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            Deprecated{name_idx, attr_len: _, } => {
-                format!("{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab))
-            },
-            RuntimeVisibleAnnotations{name_idx, attr_len: _, num_anno: _, anno} => {
-                let mut format = String::new();
-                for ann in anno {
-                    format += &format!("  {}\n", ann.anno_to_string(const_tab));
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            RuntimeInvisibleAnnotations{name_idx, attr_len: _, num_anno: _, anno} => {
-                let mut format = String::new();
-                for ann in anno {
-                    format += &format!("  {}\n", ann.anno_to_string(const_tab));
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            RuntimeVisibleParameterAnnotations{name_idx, attr_len: _, num_params: _, param_anno} => {
-                let mut format = String::new();
-                for param in param_anno {
-                    for ann in &param.anno {
-                        format += &format!("  {}\n", ann.anno_to_string(const_tab));
-                    }
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            RuntimeInvisibleParameterAnnotations{name_idx, attr_len: _, num_params: _, param_anno} => {
-                let mut format = String::new();
-                for param in param_anno {
-                    for ann in &param.anno {
-                        format += &format!("  {}\n", ann.anno_to_string(const_tab));
-                    }
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            RuntimeVisibleTypeAnnotations{name_idx, attr_len: _, num_anno: _, anno} => {
-                let mut format = String::new();
-                for ann in anno {
-                    format += &format!("  {}\n", ann.values_to_string(const_tab));
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            RuntimeInvisibleTypeAnnotations{name_idx, attr_len: _, num_anno: _, anno} => {
-                let mut format = String::new();
-                for ann in anno {
-                    format += &format!("  {}\n", ann.values_to_string(const_tab));
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            AnnotationDefault{name_idx, attr_len: _, default_value} => {
-                format!("{}: {}",
-                        const_tab[*name_idx as usize].values_to_string(const_tab),
-                        default_value.value.value_to_string(const_tab))
-            },
-            BootstrapMethods{name_idx: _, attr_len: _, n_bootstrap_methods: _, bootstrap_methods} => {
-                let mut format = String::new();
-                for boot in bootstrap_methods {
-                    format += &format!("{}:\n", const_tab[boot.bootstrap_method_ref as usize].values_to_string(const_tab));
-                    for arg in &boot.bootstrap_args {
-                        format += &format!("  {}\n", const_tab[*arg as usize].values_to_string(const_tab))
-                    }
-                    format.push('\n');
-                }
-
-                format!("{}",
-                        format)
-            },
-            MethodParameters{name_idx, attr_len: _, params_count: _, params} => {
-                let mut format = String::new();
-                for param in params {
-                    format += &format!("  {}, {}\n",
-                                       const_tab[param.name_idx as usize].values_to_string(const_tab),
-                                       access_flags_to_str(param.access_flags))
-                }
-
-                format!("{}:\n{}",
-                        const_tab[*name_idx  as usize].values_to_string(const_tab),
-                        format)
-            },
-            Module{name_idx: _, attr_len: _, mod_name_idx, mod_flags, mod_ver_idx, requires_cnt: _, requires,
-                   exports_cnt: _, exports, opens_cnt: _, opens, uses_cnt: _, uses_idx, provides_cnt: _, provides} => {
-                let mut require = String::new();
-                for req in requires {
-                    require += &format!("  {} {} {}\n",
-                                        const_tab[req.idx as usize].values_to_string(const_tab),
-                                        access_flags_to_str(req.flags),
-                                        Color::Blue.paint(const_tab[req.ver_idx as usize].values_to_string(const_tab)));
-                }
-                let mut export = String::new();
-                for exp in exports {
-                    let mut exps = String::new();
-                    for id in &exp.to_idx {
-                        exps += &format!("{}, ",
-                                         const_tab[*id as usize].values_to_string(const_tab));
-                    }
-                    export += &format!("  {} {} {} {}\n",
-                                       const_tab[exp.idx as usize].values_to_string(const_tab),
-                                       access_flags_to_str(exp.flags),
-                                       if exps.len() > 0 { Color::Green.paint("Exports:") } else { Color::Black.paint("") },
-                                       exps);
-                }
-                let mut open = String::new();
-                for op in opens {
-                    let mut ops = String::new();
-                    for id in &op.to_idx {
-                        ops += &format!("{}",
-                                        const_tab[*id as usize].values_to_string(const_tab));
-                    }
-                    open += &format!("  {} {} {} {}\n",
-                                     const_tab[op.idx as usize].values_to_string(const_tab),
-                                     access_flags_to_str(op.flags),
-                                     if ops.len() > 0 { Color::Green.paint("Opens:") } else { Color::Black.paint("") },
-                                     ops);
-                }
-                let mut used = String::new();
-                for us in uses_idx {
-                    used += &format!("  {}\n",
-                                     const_tab[*us as usize].values_to_string(const_tab));
-                }
-                let mut provide = String::new();
-                for prov in provides {
-                    let mut provs = String::new();
-                    for id in &prov.with_idx {
-                        provs += &format!("{}",
-                                          const_tab[*id as usize].values_to_string(const_tab));
-                    }
-                    provide += &format!("  {} {} {}\n",
-                                        const_tab[prov.idx as usize].values_to_string(const_tab),
-                                        if provs.len() > 0 { Color::Green.paint("Provides:") } else { Color::Black.paint("") },
-                                        provs);
-                }
-
-                format!("{}: {} {}\nRequire:\n{}Exports:\n{}Opens:\n{}Uses:\n{}Provides:\n{}",
-                        const_tab[*mod_name_idx as usize].values_to_string(const_tab),
-                        access_flags_to_str(*mod_flags),
-                        const_tab[*mod_ver_idx as usize].values_to_string(const_tab),
-                        require, export, open, used, provide)
-            },
-            ModulePackages{name_idx: _, attr_len: _, package_cnt: _, package_idx} => {
-                let mut format = String::new();
-                for package in package_idx {
-                    format += &format!("{}\n", const_tab[*package as usize].values_to_string(const_tab))
-                }
-
-                format!("{}", format)
-            },
-            ModuleMainClass{name_idx: _, attr_len: _, main_class_idx} => {
-                format!("{}",
-                        const_tab[*main_class_idx as usize].values_to_string(const_tab))
-            },
-            NestHost{name_idx: _, attr_len: _, host_class_idx} => {
-                format!("{}",
-                        const_tab[*host_class_idx as usize].values_to_string(const_tab))
-            },
-            NestMembers{name_idx: _, attr_len: _, n_of_classes: _, classes} => {
-                let mut format = String::new();
-                for class in classes {
-                    format += &format!("{}\n",
-                                      const_tab[*class as usize].values_to_string(const_tab))
-                }
-
-                format!("{}",format)
-            },
+            _ => return Err(Error::from(Problem::msg(format!("Invalid/Unsupported attribute: {}", name)))),
 
         }
 
@@ -1119,8 +841,8 @@ impl Attributes {
 
 }
 
-#[derive(Debug)]
-enum StackMapFrame {
+#[derive(Serialize, Debug)]
+pub enum StackMapFrame {
     SameFrame(u8),
     SameLocals1StackItemFrame(u8, VerificationTypeInfo),
     SameLocals1StackItemFrameExt(u8, u16, VerificationTypeInfo),
@@ -1130,62 +852,9 @@ enum StackMapFrame {
     FullFrame(u8, u16, u16, Vec<VerificationTypeInfo>, u16, Vec<VerificationTypeInfo>),
 }
 
-impl StackMapFrame {
 
-    pub fn value_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        use StackMapFrame::*;
-        use ansi_term::Color;
-
-        match self {
-            SameFrame(frame_type) => format!("SAME({})",
-                                             Color::Red.paint(frame_type.to_string())),
-            SameLocals1StackItemFrame(frame_type, stack) => {
-                format!("SAME_LOCALS_1_STACK_ITEM({}): Stack: {}",
-                        Color::Red.paint(frame_type.to_string()),
-                        stack.value_to_string(const_tab))
-            },
-            SameLocals1StackItemFrameExt(frame_type, offset_delta, stack) => {
-                format!("SAME_LOCALS_1_STACK_ITEM_EXTENDED({}): Offset: {}, Stack: {}",
-                        Color::Red.paint(frame_type.to_string()),
-                        Color::Blue.paint(offset_delta.to_string()),
-                        stack.value_to_string(const_tab))
-            },
-            ChopFrame(frame_type, offset_delta) => {
-                format!("CHOP({}): Offset: {}",
-                        Color::Red.paint(frame_type.to_string()),
-                        Color::Blue.paint(offset_delta.to_string()))
-            },
-            SameFrameExt(frame_type, offset_delta) => {
-                format!("SAME_FRAME_EXTENDED({}): Offset: {}",
-                        Color::Red.paint(frame_type.to_string()),
-                        Color::Blue.paint(offset_delta.to_string()))
-            },
-            AppendFrame(frame_type, offset_delta, locals) => {
-                // WOW I understand functional programming !?
-                let format: String = locals.iter().map(|l| format!("\n    {}",l.value_to_string(const_tab))).collect();
-
-                format!("APPEND({}): Offset: {}\n  Locals: {}",
-                        Color::Red.paint(frame_type.to_string()),
-                        Color::Blue.paint(offset_delta.to_string()),
-                        format)
-            },
-            FullFrame(frame_type, offset_delta, _, locals, _, items) => {
-                let locs: String = locals.iter().map(|l| format!("\n    {}",l.value_to_string(const_tab))).collect();
-                let itms: String = items.iter().map(|l|  format!("\n    {}",l.value_to_string(const_tab))).collect();
-
-                format!("FULL_FRAME({}): Offset: {}\n  Locals: {}\n  Items: {}",
-                        Color::Red.paint(frame_type.to_string()),
-                        Color::Blue.paint(offset_delta.to_string()),
-                        locs,
-                        itms)
-            }
-        }
-    }
-
-}
-
-#[derive(Debug, AsRefStr)]
-enum VerificationTypeInfo {
+#[derive(Serialize, Debug, AsRefStr)]
+pub enum VerificationTypeInfo {
     TopVariable(u8),
     IntegerVariable(u8),
     FloatVariable(u8),
@@ -1212,91 +881,75 @@ impl VerificationTypeInfo {
             6 => Ok(UninitializedThisVariable(tag)),
             7 => Ok(ObjectVariable(tag, buf.gread_with(offset, scroll::BE)?)),
             8 => Ok(UninitializedVariable(tag, buf.gread_with(offset, scroll::BE)?)),
-            _ => Err(Error::from(Problem::Msg(format!("Invalid tag for VerificationTypeInfo")))),
-        }
-
-    }
-
-    pub fn value_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        use VerificationTypeInfo::*;
-
-        match self {
-            TopVariable(_)                   => format!("{}", self.as_ref()),
-            IntegerVariable(_)               => format!("{}", self.as_ref()),
-            FloatVariable(_)                 => format!("{}", self.as_ref()),
-            LongVariable(_)                  => format!("{}", self.as_ref()),
-            DoubleVariable(_)                => format!("{}", self.as_ref()),
-            NullVariable(_)                  => format!("{}", self.as_ref()),
-            UninitializedThisVariable(_)     => format!("{}", self.as_ref()),
-            ObjectVariable(_, idx)           => format!("{}: {}",
-                                                        self.as_ref(),
-                                                        const_tab[*idx as usize].values_to_string(const_tab)),
-            UninitializedVariable(_, offset) => format!("{}: {}",
-                                                        self.as_ref(),
-                                                        offset),
+            _ => Err(Error::from(Problem::msg(format!("Invalid tag for VerificationTypeInfo")))),
         }
 
     }
 
 }
 
-#[derive(Debug, Pread)]
-struct Exception {
-    start_pc:   u16,
-    end_pc:     u16,
-    handler_pc: u16,
-    catch_type: u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Exception {
+    pub start_pc:   u16,
+    pub end_pc:     u16,
+    pub handler_pc: u16,
+    pub catch_type: u16,
 }
 
-#[derive(Debug, Pread)]
-struct Inner_class {
-    inner_class_info_idx:     u16,
-    outer_class_info_idx:     u16,
-    inner_name_idx:           u16,
-    inner_class_access_flags: u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Inner_class {
+    pub inner_class_info_idx:     u16,
+    pub outer_class_info_idx:     u16,
+    pub inner_name_idx:           u16,
+    pub inner_class_access_flags: u16,
 }
 
-#[derive(Debug, Pread)]
-struct Line_number {
-    start_pc: u16,
-    line_num: u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Line_number {
+    pub start_pc: u16,
+    pub line_num: u16,
 }
 
-#[derive(Debug, Pread)]
-struct Local_variable {
-    start_pc: u16,
-    len:      u16,
-    name_idx: u16,
-    desc_idx: u16,
-    idx:      u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Local_variable {
+    pub start_pc: u16,
+    pub len:      u16,
+    pub name_idx: u16,
+    pub desc_idx: u16,
+    pub idx:      u16,
 }
 
-#[derive(Debug, Pread)]
-struct Local_variable_type {
-    start_pc: u16,
-    len:      u16,
-    name_idx: u16,
-    sig_idx:  u16,
-    idx:      u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Local_variable_type {
+    pub start_pc: u16,
+    pub len:      u16,
+    pub name_idx: u16,
+    pub sig_idx:  u16,
+    pub idx:      u16,
 }
 
-#[derive(Debug)]
-struct Annotation {
-    type_idx:        u16,
-    n_ele_val_pairs: u16,
-    ele_val_pairs:   Vec<Name_element_value>,
+#[derive(Serialize, Debug)]
+pub struct Annotation {
+    pub type_idx:        u16,
+    pub n_ele_val_pairs: u16,
+    pub ele_val_pairs:   Vec<Name_element_value>,
 }
 
 impl Annotation {
 
     pub fn parse(buf: &[u8], offset: &mut usize) -> Result<Self, Error> {
+        Annotation::parse_nested(buf, offset, 0)
+    }
+
+    fn parse_nested(buf: &[u8], offset: &mut usize, depth: usize) -> Result<Self, Error> {
+        check_nesting(depth)?;
         let type_idx = buf.gread_with::<u16>(offset, scroll::BE)?;
         let n_ele_val_pairs = buf.gread_with::<u16>(offset, scroll::BE)?;
         let mut ele_val_pairs = Vec::with_capacity(n_ele_val_pairs as usize);
         for _ in 0..n_ele_val_pairs {
             let name_idx = buf.gread_with::<u16>(offset, scroll::BE)?;
             let tag = buf.gread_with::<u8>(offset, scroll::BE)?;
-            ele_val_pairs.push(Name_element_value{name_idx ,value: Element_value {tag, value: Value::parse(tag, buf, offset)?}})
+            ele_val_pairs.push(Name_element_value{name_idx ,value: Element_value {tag, value: Value::parse_nested(tag, buf, offset, depth + 1)?}})
         }
         Ok(Annotation {
             type_idx,
@@ -1305,24 +958,10 @@ impl Annotation {
         })
     }
 
-    pub fn anno_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        use ansi_term::Color;
-
-        let mut anno = format!("{}",Color::Yellow.paint(const_tab[self.type_idx as usize].values_to_string(const_tab)));
-        anno.push('\n');
-
-        let mut format = String::new();
-        for val in &self.ele_val_pairs {
-            format += &format!("    {}\n", val.value.value.value_to_string(const_tab));
-        }
-        anno.push_str(&format);
-        anno
-    }
-
 }
 
-#[derive(Debug, AsRefStr)]
-enum Value {
+#[derive(Serialize, Debug, AsRefStr)]
+pub enum Value {
     ConstValueIdx(u16),
     EnumConstValue(u16,u16),
     ClassInfoIdx(u16),
@@ -1333,92 +972,58 @@ enum Value {
 impl Value {
 
     pub fn parse(tag: u8, buf: &[u8], offset: &mut usize) -> Result<Self, Error> {
+        Value::parse_nested(tag, buf, offset, 0)
+    }
+
+    fn parse_nested(tag: u8, buf: &[u8], offset: &mut usize, depth: usize) -> Result<Self, Error> {
         use Value::*;
 
+        check_nesting(depth)?;
         match tag {
 
             b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z' | b's' => Ok(ConstValueIdx(buf.gread_with(offset, scroll::BE)?)),
             b'e' => Ok(EnumConstValue(buf.gread_with(offset, scroll::BE)?, buf.gread_with(offset, scroll::BE)?)),
             b'c' => Ok(ClassInfoIdx(buf.gread_with(offset, scroll::BE)?)),
             b'@' => {
-                Ok(AnnotationValue(Annotation::parse(buf, offset)?))
+                Ok(AnnotationValue(Annotation::parse_nested(buf, offset, depth + 1)?))
             },
             b'[' => {
                 let num_values = buf.gread_with::<u16>(offset, scroll::BE)?;
                 let mut values = Vec::with_capacity(num_values as usize);
                 for _ in 0..num_values {
                     let tag = buf.gread_with::<u8>(offset, scroll::BE)?;
-                    values.push(Element_value { tag, value: Value::parse(tag, buf, offset)? })
+                    values.push(Element_value { tag, value: Value::parse_nested(tag, buf, offset, depth + 1)? })
                 }
                 Ok(ArrayValue(num_values, values))
             },
-            _ => panic!(),
-            // _ => Err(Error::from(Problem::Msg(format!("Invalid/unsupported tag for Value: {} at: {:#X}", tag, offset))))
-
-        }
-
-    }
-
-    pub fn value_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        use Value::*;
-        use ansi_term::Color;
-
-        match self {
-            ConstValueIdx(idx) => format!("{}: {}",
-                                          self.as_ref(),
-                                          const_tab[*idx as usize].values_to_string(const_tab)),
-            EnumConstValue(type_idx, name_idx) => format!("{}: {} {}",
-                                                          self.as_ref(),
-                                                          Color::Blue.paint(const_tab[*type_idx as usize].values_to_string(const_tab)),
-                                                          Color::Green.paint(const_tab[*name_idx as usize].values_to_string(const_tab))),
-            ClassInfoIdx(idx) => format!("{}: {}",
-                                         self.as_ref(),
-                                         const_tab[*idx as usize].values_to_string(const_tab)),
-            AnnotationValue(anno) => {
-                let mut format = String::new();
-                format += &format!("{}: {}:\n",
-                                   self.as_ref(),
-                                   Color::Blue.paint(const_tab[anno.type_idx as usize].values_to_string(const_tab)));
-                for ann in &anno.ele_val_pairs {
-                    format += &format!("{}", ann.value.value.value_to_string(const_tab));
-                }
-                format
-            },
-            ArrayValue(_, values) => {
-                let mut format = String::from(self.as_ref());
-                format.push('\n');
+            _ => Err(Error::from(Problem::msg(format!("Invalid/unsupported tag for Value: {} at: {:#X}", tag, offset)))),
 
-                for value in values {
-                    format += &format!("      {}\n", value.value.value_to_string(const_tab));
-                }
-                format
-            },
         }
 
     }
 
 }
 
-#[derive(Debug)]
-struct Element_value {
-    tag:   u8,
-    value: Value,
+#[derive(Serialize, Debug)]
+pub struct Element_value {
+    pub tag:   u8,
+    pub value: Value,
 }
 
-#[derive(Debug)]
-struct Parameter_annotations {
-    num_anno: u16,
-    anno:     Vec<Annotation>,
+#[derive(Serialize, Debug)]
+pub struct Parameter_annotations {
+    pub num_anno: u16,
+    pub anno:     Vec<Annotation>,
 }
 
-#[derive(Debug)]
-struct Type_annotation {
-    target_type:     u8,
-    target_info:     TargetInfo,
-    target_path:     Type_path,
-    type_idx:        u16,
-    n_ele_val_pairs: u16,
-    ele_val_pairs:   Vec<Name_element_value>,
+#[derive(Serialize, Debug)]
+pub struct Type_annotation {
+    pub target_type:     u8,
+    pub target_info:     TargetInfo,
+    pub target_path:     Type_path,
+    pub type_idx:        u16,
+    pub n_ele_val_pairs: u16,
+    pub ele_val_pairs:   Vec<Name_element_value>,
 }
 
 impl Type_annotation {
@@ -1451,25 +1056,10 @@ impl Type_annotation {
         })
     }
 
-    pub fn values_to_string(&self, const_tab: &Vec<Constants>) -> String {
-        let mut format = String::new();
-        for val in &self.ele_val_pairs {
-            format += &format!("{}: {}\n",
-                               val.value.value.value_to_string(const_tab),
-                               const_tab[val.name_idx as usize].values_to_string(const_tab))
-        }
-
-        format!("{}, {:?} {}: {}",
-                self.target_info.value_to_string(),
-                self.target_path,
-                const_tab[self.type_idx as usize].values_to_string(const_tab),
-                format)
-    }
-
 }
 
-#[derive(Debug, AsRefStr)]
-enum TargetInfo {
+#[derive(Serialize, Debug, AsRefStr)]
+pub enum TargetInfo {
     TypeParameter(u8),
     Supertype(u16),
     TypeParameterBound(u8, u8),
@@ -1506,7 +1096,7 @@ impl TargetInfo {
             0x42 => Ok(Catch(buf.gread_with(offset, scroll::BE)?)),
             0x43...0x46 => Ok(Offset(buf.gread_with(offset, scroll::BE)?)),
             0x48...0x4B => Ok(TypeArgument(buf.gread_with(offset, scroll::BE)?, buf.gread_with(offset, scroll::BE)?)),
-            _ => Err(Error::from(Problem::Msg(format!("Invalid/unsupported target type for target info: {}", target_type)))),
+            _ => Err(Error::from(Problem::msg(format!("Invalid/unsupported target type for target info: {}", target_type)))),
 
         }
 
@@ -1542,209 +1132,131 @@ impl TargetInfo {
 
 }
 
-#[derive(Debug, Pread)]
-struct Local_var {
-    start_pc: u16,
-    len:      u16,
-    idx:      u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Local_var {
+    pub start_pc: u16,
+    pub len:      u16,
+    pub idx:      u16,
 }
 
-#[derive(Debug)]
-struct Type_path {
-    path_len: u8,
-    path:     Vec<Path>,
+#[derive(Serialize, Debug)]
+pub struct Type_path {
+    pub path_len: u8,
+    pub path:     Vec<Path>,
 }
 
-#[derive(Debug, Pread)]
-struct Path {
-    kind: u8,
-    idx:  u8,
+#[derive(Serialize, Debug, Pread)]
+pub struct Path {
+    pub kind: u8,
+    pub idx:  u8,
 }
 
-#[derive(Debug)]
-struct Name_element_value {
-    name_idx: u16,
-    value:    Element_value,
+#[derive(Serialize, Debug)]
+pub struct Name_element_value {
+    pub name_idx: u16,
+    pub value:    Element_value,
 }
 
-#[derive(Debug)]
-struct Bootstrap_method {
-    bootstrap_method_ref: u16,
-    n_bootstrap_args: u16,
-    bootstrap_args: Vec<u16>,
+#[derive(Serialize, Debug)]
+pub struct Bootstrap_method {
+    pub bootstrap_method_ref: u16,
+    pub n_bootstrap_args: u16,
+    pub bootstrap_args: Vec<u16>,
 }
 
-#[derive(Debug, Pread)]
-struct Parameter {
-    name_idx: u16,
-    access_flags: u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Parameter {
+    pub name_idx: u16,
+    pub access_flags: u16,
 }
 
 
-#[derive(Debug, Pread)]
-struct Require {
-    idx:     u16,
-    flags:   u16,
-    ver_idx: u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Require {
+    pub idx:     u16,
+    pub flags:   u16,
+    pub ver_idx: u16,
 }
 
-#[derive(Debug)]
-struct Export {
-    idx:    u16,
-    flags:  u16,
-    to_cnt: u16,
-    to_idx: Vec<u16>,
+#[derive(Serialize, Debug)]
+pub struct Export {
+    pub idx:    u16,
+    pub flags:  u16,
+    pub to_cnt: u16,
+    pub to_idx: Vec<u16>,
 }
 
-#[derive(Debug)]
-struct Open {
-    idx:    u16,
-    flags:  u16,
-    to_cnt: u16,
-    to_idx: Vec<u16>,
+#[derive(Serialize, Debug)]
+pub struct Open {
+    pub idx:    u16,
+    pub flags:  u16,
+    pub to_cnt: u16,
+    pub to_idx: Vec<u16>,
 }
 
-#[derive(Debug)]
-struct Provide {
-    idx:      u16,
-    with_cnt: u16,
-    with_idx: Vec<u16>,
+#[derive(Serialize, Debug)]
+pub struct Provide {
+    pub idx:      u16,
+    pub with_cnt: u16,
+    pub with_idx: Vec<u16>,
 }
 
-#[derive(Debug)]
+/// Parsed Java class file.
+#[derive(Serialize, Debug)]
 pub struct JavaClass {
-    opt:     Opt,
-
-    class_header: Class_header,
+    pub class_header: Class_header,
 }
 
 impl super::FileFormat for JavaClass {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
 
         let offset = &mut 0;
 
-        let magic: u32 = buf.gread_with(offset, scroll::BE)?;
-        let minor_ver: u16 = buf.gread_with(offset, scroll::BE)?;
-        let major_ver: u16 = buf.gread_with(offset, scroll::BE)?;
-        let const_pool_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        let magic: u32 = buf.gread_with(offset, scroll::BE).at(0, "class header")?;
+        let minor_ver: u16 = buf.gread_with(offset, scroll::BE).at(0, "class header")?;
+        let major_ver: u16 = buf.gread_with(offset, scroll::BE).at(0, "class header")?;
+        let const_pool_count: u16 = buf.gread_with(offset, scroll::BE).at(0, "class header")?;
         let mut const_pool_tab = Vec::with_capacity(const_pool_count as usize);
         const_pool_tab.push(Constants::Ghost);
 
         let mut i = 1;
         while i < const_pool_count {
-            let tag: u8 = buf.gread(offset)?;
-
-            match tag {
-
-                CONSTANT_UTF8 => {
-                    let len: u16 = buf.gread_with(offset, scroll::BE)?;
-                    let text = std::str::from_utf8(&buf[*offset..*offset + len as usize])?
-                        .to_string();
-                    const_pool_tab.push(Constants::UTF8{len, text});
-                    *offset += len as usize;
-                },
-                CONSTANT_INTEGER => {
-                    const_pool_tab.push(Constants::Integer(buf.gread_with(offset, scroll::BE)?));
-                },
-                CONSTANT_FLOAT => {
-                    const_pool_tab.push(Constants::Float(buf.gread_with(offset, scroll::BE)?));
-                },
-                CONSTANT_LONG => {
-                    // All 8-byte constants take up two entries in the constant_pool table of the class file.
-                    const_pool_tab.push(Constants::Long(buf.gread_with::<u64>(offset, scroll::BE)?));
-                    const_pool_tab.push(Constants::Ghost);
-                    i += 1;
-                },
-                CONSTANT_DOUBLE => {
-                    // All 8-byte constants take up two entries in the constant_pool table of the class file.
-                    const_pool_tab.push(Constants::Double(buf.gread_with::<f64>(offset, scroll::BE)?));
-                    const_pool_tab.push(Constants::Ghost);
-                    i += 1;
-                },
-                CONSTANT_CLASS => {
-                    const_pool_tab.push(Constants::ClassRef{name_idx: buf.gread_with(offset, scroll::BE)?});
-                },
-                CONSTANT_STRING => {
-                    const_pool_tab.push(Constants::StringRef{string_idx: buf.gread_with(offset, scroll::BE)?});
-                },
-                CONSTANT_FIELDREF => {
-                    const_pool_tab.push(Constants::FieldRef {
-                        class_idx: buf.gread_with(offset, scroll::BE)?,
-                        nametype_idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_METHODREF => {
-                    const_pool_tab.push(Constants::MethodRef {
-                        class_idx: buf.gread_with(offset, scroll::BE)?,
-                        nametype_idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_INTERFACEMETHODREF => {
-                    const_pool_tab.push(Constants::InterfaceMethodRef {
-                        class_idx: buf.gread_with(offset, scroll::BE)?,
-                        nametype_idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_NAMEANDTYPE => {
-                    const_pool_tab.push(Constants::NameAndType {
-                        name_idx: buf.gread_with(offset, scroll::BE)?,
-                        desc_idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_METHODHANDLE => {
-                    const_pool_tab.push(Constants::MethodHandle {
-                        kind: buf.gread_with(offset, scroll::BE)?,
-                        idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_METHODTYPE => {
-                    const_pool_tab.push(Constants::MethodType {desc_idx: buf.gread_with(offset, scroll::BE)?});
-                },
-                CONSTANT_DYNAMIC => {
-                    const_pool_tab.push(Constants::Dynamic {
-                        bootstrap_method_attr_idx: buf.gread_with(offset, scroll::BE)?,
-                        nametype_idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_INVOKEDYNAMIC => {
-                    const_pool_tab.push(Constants::InvokeDynamic {
-                        bootstrap_method_attr_idx: buf.gread_with(offset, scroll::BE)?,
-                        nametype_idx: buf.gread_with(offset, scroll::BE)?,
-                    });
-                },
-                CONSTANT_MODULE => {
-                    const_pool_tab.push(Constants::Module{name_idx: buf.gread_with(offset, scroll::BE)?});
-                },
-                CONSTANT_PACKAGE => {
-                    const_pool_tab.push(Constants::Package{name_idx: buf.gread_with(offset, scroll::BE)?});
-                },
-                _ => return Err(Error::from(Problem::Msg(format!("Invalid constant tag: {} at: {:#X}, {:#X}", tag, offset, const_pool_tab.len())))),
-
+            let start = *offset;
+            let tag: u8 = buf.gread(offset).at(start as u64, format_args!("constant #{}", i))?;
+            let constant = Constants::parse(buf, offset, tag).at(start as u64, format_args!("constant #{}", i))?;
+
+            // All 8-byte constants take up two entries in the constant_pool table of the class file.
+            let wide = matches!(constant, Constants::Long(_) | Constants::Double(_));
+            const_pool_tab.push(constant);
+            if wide {
+                const_pool_tab.push(Constants::Ghost);
+                i += 1;
             }
 
             i += 1;
 
         }
 
-        let access_flags:    u16 = buf.gread_with(offset, scroll::BE)?;
-        let this_class:      u16 = buf.gread_with(offset, scroll::BE)?;
-        let super_class:     u16 = buf.gread_with(offset, scroll::BE)?;
-        let interface_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        let access_flags:    u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "access flags")?;
+        let this_class:      u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "this class")?;
+        let super_class:     u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "super class")?;
+        let interface_count: u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "interface count")?;
         let mut interface_tab = Vec::with_capacity(interface_count as usize);
 
-        for _ in 0..interface_count as usize {
-            interface_tab.push(buf.gread_with(offset, scroll::BE)?);
+        for i in 0..interface_count as usize {
+            interface_tab.push(buf.gread_with(offset, scroll::BE).at(*offset as u64, format_args!("interface #{}", i))?);
         }
 
-        let field_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        let field_count: u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "field count")?;
         let mut field_tab = Vec::with_capacity(field_count as usize);
-        for _ in 0..field_count as usize {
-            let access_flags: u16    = buf.gread_with(offset, scroll::BE)?;
-            let name_idx: u16        = buf.gread_with(offset, scroll::BE)?;
-            let desc_idx: u16  = buf.gread_with(offset, scroll::BE)?;
-            let attr_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        for i in 0..field_count as usize {
+            let start = *offset as u64;
+            let access_flags: u16    = buf.gread_with(offset, scroll::BE).at(start, format_args!("field #{}", i))?;
+            let name_idx: u16        = buf.gread_with(offset, scroll::BE).at(start, format_args!("field #{}", i))?;
+            let desc_idx: u16  = buf.gread_with(offset, scroll::BE).at(start, format_args!("field #{}", i))?;
+            let attr_count: u16 = buf.gread_with(offset, scroll::BE).at(start, format_args!("field #{}", i))?;
             let mut attributes       = Vec::with_capacity(attr_count as usize);
             for _ in 0..attr_count as usize {
                 // let attr_name_idx: u16 = buf.gread_with(offset, scroll::BE)?;
@@ -1752,7 +1264,7 @@ impl super::FileFormat for JavaClass {
                 // let info                    = buf[*offset..*offset + attr_len as usize].to_vec();
                 // *offset += attr_len as usize;
                 // attributes.push(Attribute_info {attr_name_idx, attr_len, info});
-                attributes.push(Attributes::parse(buf, offset, &const_pool_tab)?);
+                attributes.push(Attributes::parse(buf, offset, &const_pool_tab).at(start, format_args!("field #{}", i))?);
             }
             let field = Field_info {
                 access_flags,
@@ -1764,13 +1276,14 @@ impl super::FileFormat for JavaClass {
             field_tab.push(field);
         }
 
-        let method_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        let method_count: u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "method count")?;
         let mut method_tab = Vec::with_capacity(method_count as usize);
-        for _ in 0..method_count as usize {
-            let access_flags: u16    = buf.gread_with(offset, scroll::BE)?;
-            let name_idx: u16        = buf.gread_with(offset, scroll::BE)?;
-            let desc_idx: u16  = buf.gread_with(offset, scroll::BE)?;
-            let attr_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        for i in 0..method_count as usize {
+            let start = *offset as u64;
+            let access_flags: u16    = buf.gread_with(offset, scroll::BE).at(start, format_args!("method #{}", i))?;
+            let name_idx: u16        = buf.gread_with(offset, scroll::BE).at(start, format_args!("method #{}", i))?;
+            let desc_idx: u16  = buf.gread_with(offset, scroll::BE).at(start, format_args!("method #{}", i))?;
+            let attr_count: u16 = buf.gread_with(offset, scroll::BE).at(start, format_args!("method #{}", i))?;
             let mut attributes       = Vec::with_capacity(attr_count as usize);
             for _ in 0..attr_count as usize {
                 // let attr_name_idx: u16 = buf.gread_with(offset, scroll::BE)?;
@@ -1778,7 +1291,7 @@ impl super::FileFormat for JavaClass {
                 // let info                    = buf[*offset..*offset + attr_len as usize].to_vec();
                 // *offset += attr_len as usize;
                 // attributes.push(Attribute_info {attr_name_idx, attr_len, info});
-                attributes.push(Attributes::parse(buf, offset, &const_pool_tab)?);
+                attributes.push(Attributes::parse(buf, offset, &const_pool_tab).at(start, format_args!("method #{}", i))?);
             }
             let method = Method_info {
                 access_flags,
@@ -1790,7 +1303,7 @@ impl super::FileFormat for JavaClass {
             method_tab.push(method);
         }
 
-        let attribute_count: u16 = buf.gread_with(offset, scroll::BE)?;
+        let attribute_count: u16 = buf.gread_with(offset, scroll::BE).at(*offset as u64, "attribute count")?;
         let mut attribute_tab = Vec::with_capacity(attribute_count as usize);
         for _ in 0..attribute_count as usize {
             // let attr_name_idx: u16 = buf.gread_with(offset, scroll::BE)?;
@@ -1827,229 +1340,8 @@ impl super::FileFormat for JavaClass {
         };
 
         Ok(JavaClass {
-            opt,
-
             class_header,
         })
     }
 
-    fn print(&self) -> Result<(), Error> {
-        use ansi_term::Color;
-        use prettytable::Table;
-        use textwrap::fill;
-
-        //
-        // JAVA CLASS FILE
-        //
-        print!("JAVA_CLASS ");
-        println!("{}", Color::Blue.paint(java_version_to_str(
-            self.class_header.minor_ver,
-            self.class_header.major_ver)));
-        println!("{}", access_flags_to_str(self.class_header.access_flags));
-        println!("Class: {}",
-                 self.class_header.const_pool_tab[self.class_header.this_class as usize].values_to_string(&self.class_header.const_pool_tab));
-        if self.class_header.super_class > 0 {
-            println!("Super Class: {}",
-                     self.class_header.const_pool_tab[self.class_header.super_class as usize].values_to_string(&self.class_header.const_pool_tab));
-        }
-        println!();
-
-        //
-        // CONSTANTS
-        //
-        if self.class_header.const_pool_tab.len() >= 1 {
-            println!("{}", Color::White.underline().paint("Constants"));
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Type", "Value"]);
-
-            for (i, entry) in self.class_header.const_pool_tab.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    format!("{}", entry.as_ref()),
-                    fill(&entry.values_to_string(&self.class_header.const_pool_tab), self.opt.wrap_chars),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-
-        }
-
-        //
-        // INTERFACES
-        //
-        if self.class_header.interface_tab.len() >= 1 {
-            println!("{}", Color::White.underline().paint("Interfaces"));
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Name"]);
-
-            for (i, entry) in self.class_header.interface_tab.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    self.class_header.const_pool_tab[*entry as usize].values_to_string(&self.class_header.const_pool_tab),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // FIELDS
-        //
-        if self.class_header.field_tab.len() >= 1 {
-            println!("{}", Color::White.underline().paint("Fields"));
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Name", "Flags", "Descriptor", "Attributes"]);
-
-            for (i, entry) in self.class_header.field_tab.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                let mut values = String::new();
-                for attr in &entry.attributes {
-                    values.push_str(&attr.values_to_string(&self.class_header.const_pool_tab));
-                    values.push('\n');
-                };
-                let mut desc = self.class_header.const_pool_tab[entry.desc_idx as usize].values_to_string(&self.class_header.const_pool_tab);
-                if desc.len() > self.opt.wrap_chars / 4 {
-                    desc = format!("...{}", desc[desc.len() - self.opt.wrap_chars / 4..desc.len()].to_string());
-                }
-                table.add_row(row![
-                    i,
-                    self.class_header.const_pool_tab[entry.name_idx as usize].values_to_string(&self.class_header.const_pool_tab),
-                    access_flags_to_str(entry.access_flags),
-                    desc,
-                    // self.class_header.const_pool_tab[entry.desc_idx as usize].values_to_string(&self.class_header.const_pool_tab),
-                    fill(&values, self.opt.wrap_chars),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // METHODS
-        //
-        if self.class_header.method_tab.len() >= 1 {
-            println!("{}", Color::White.underline().paint("Methods"));
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Name", "Flags", "Desc", "Attributes"]);
-
-            for (i, entry) in self.class_header.method_tab.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                let mut values = String::new();
-                for attr in &entry.attributes {
-                    values.push_str(&attr.values_to_string(&self.class_header.const_pool_tab));
-                    values.push('\n');
-                };
-                let mut desc = self.class_header.const_pool_tab[entry.desc_idx as usize].values_to_string(&self.class_header.const_pool_tab);
-                if desc.len() > self.opt.wrap_chars / 4 {
-                    desc = format!("...{}", desc[desc.len() - self.opt.wrap_chars / 4..desc.len()].to_string());
-                }
-                table.add_row(row![
-                    i,
-                    self.class_header.const_pool_tab[entry.name_idx as usize].values_to_string(&self.class_header.const_pool_tab),
-                    access_flags_to_str(entry.access_flags),
-                    desc,
-                    // self.class_header.const_pool_tab[entry.desc_idx as usize].values_to_string(&self.class_header.const_pool_tab),
-                    fill(&values, self.opt.wrap_chars),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // ATTRIBUTES
-        //
-        if self.class_header.attribute_tab.len() >= 1 {
-            println!("{}", Color::White.underline().paint("Attributes"));
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Name", "Info"]);
-
-            for (i, entry) in self.class_header.attribute_tab.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    entry.as_ref(),
-                    fill(&entry.values_to_string(&self.class_header.const_pool_tab), self.opt.wrap_chars + 30),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        Ok(())
-
-    }
-
 }
@@ -1,4 +1,3 @@
-use crate::Opt;
 use failure::{Error};
 
 pub const JPG_MAGIC: &'static [u8; JPG_MAGIC_SIZE] = b"\xFF\xD8\xFF\xDB";
@@ -12,29 +11,19 @@ pub const _JPG_MAGIC_EXIF_1: &'static [u8; JPG_MAGIC_SIZE] = b"\xFF\xD8\xFF\xE1"
 pub const _JPG_MAGIC_EXIF_2: &'static [u8; _EXIF_SIZE] = b"\x45\x78\x69\x66\x00\x00";
 pub const _EXIF_SIZE: usize = 6;
 
-#[derive(Debug)]
+/// JPG image. Only detected for now, nothing is parsed yet.
+#[derive(Serialize, Debug)]
 pub struct Jpg {
-    opt: Opt,
 }
 
 impl super::FileFormat for Jpg {
     type Item = Self;
 
-    fn parse(opt: Opt, _buf: &[u8]) -> Result<Self, Error> {
+    fn parse(_buf: &[u8]) -> Result<Self, Error> {
 
         Ok(Jpg {
-            opt,
         })
 
     }
 
-    fn print(&self) -> Result<(), Error> {
-
-        println!("JPG");
-        println!("{:#X?}", self);
-
-        Ok(())
-    }
-
-
 }
@@ -3,9 +3,8 @@ include!("macho_constants.rs");
 use failure::{Error};
 use scroll::{self, Pread};
 
-use crate::Opt;
-use crate::Problem;
-use crate::format::{fmt_macho, fmt_macho_syms, fmt_macho_reloc, fmt_indentln};
+use crate::{Problem, ResultExt};
+use crate::formats::{bytes, table, Recovery};
 
 pub const MACHO_MAGIC_32: &'static [u8; MACHO_MAGIC_SIZE] = b"\xFE\xED\xFA\xCE";
 pub const MACHO_MAGIC_64: &'static [u8; MACHO_MAGIC_SIZE] = b"\xFE\xED\xFA\xCF";
@@ -15,7 +14,7 @@ pub const MACHO_MAGIC_SIZE: usize = 4;
 
 // pub const MACHO_FAT_MAGIC: &'static [u8; MACHO_MAGIC_SIZE] = b"\xCA\xFE\xBA\xBE";
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 pub struct Mach_header_32 {
     magic:          u32,
     pub cputype:    u32,
@@ -26,16 +25,16 @@ pub struct Mach_header_32 {
     pub flags:      u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 pub struct Mach_header {
-    magic:          u32,
+    pub magic:          u32,
     pub cputype:    u32,
     pub cpusubtype: u32,
     pub filetype:   u32,
-    n_cmds:         u32,
-    size_of_cmd:    u32,
+    pub n_cmds:         u32,
+    pub size_of_cmd:    u32,
     pub flags:      u32,
-    reserved:       u32,
+    pub reserved:       u32,
 }
 
 impl From<Mach_header_32> for Mach_header {
@@ -53,8 +52,8 @@ impl From<Mach_header_32> for Mach_header {
     }
 }
 
-#[derive(Debug, AsRefStr)]
-enum LoadCommand {
+#[derive(Serialize, Debug, AsRefStr)]
+pub enum LoadCommand {
     Segment(u32, Segment_command),
     Fvmlib(u32, Fvmlib_command),
     Dylib(u32, Dylib_command),
@@ -87,7 +86,7 @@ enum LoadCommand {
 }
 
 impl LoadCommand {
-    fn get_cmd_type(&self) -> u32 {
+    pub fn get_cmd_type(&self) -> u32 {
         use LoadCommand::*;
 
         match self  {
@@ -124,13 +123,13 @@ impl LoadCommand {
     }
 }
 
-#[derive(Debug, Pread, Clone)]
-struct Load_command {
-    cmd:    u32,
-    cmd_sz: u32,
+#[derive(Serialize, Debug, Pread, Clone)]
+pub struct Load_command {
+    pub cmd:    u32,
+    pub cmd_sz: u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 struct Segment_command_32 {
     cmd:       Load_command,
     seg_name:  [u8; 16],
@@ -144,18 +143,18 @@ struct Segment_command_32 {
     flags:     u32,
 }
 
-#[derive(Debug, Pread, Clone)]
-struct Segment_command {
-    cmd:       Load_command,
-    seg_name:  [u8; 16],
-    vm_addr:   u64,
-    vm_sz:     u64,
-    file_off:  u64,
-    file_sz:   u64,
-    max_prot:  u32,
-    init_prot: u32,
-    n_sects:   u32,
-    flags:     u32,
+#[derive(Serialize, Debug, Pread, Clone)]
+pub struct Segment_command {
+    pub cmd:       Load_command,
+    pub seg_name:  [u8; 16],
+    pub vm_addr:   u64,
+    pub vm_sz:     u64,
+    pub file_off:  u64,
+    pub file_sz:   u64,
+    pub max_prot:  u32,
+    pub init_prot: u32,
+    pub n_sects:   u32,
+    pub flags:     u32,
 }
 
 impl From<Segment_command_32> for Segment_command {
@@ -175,13 +174,13 @@ impl From<Segment_command_32> for Segment_command {
     }
 }
 
-#[derive(Debug)]
-struct Segment {
-    header: Segment_command,
-    sects:  Vec<Section>,
+#[derive(Serialize, Debug)]
+pub struct Segment {
+    pub header: Segment_command,
+    pub sects:  Vec<Section>,
 }
 
-#[derive(Debug, Pread, Clone)]
+#[derive(Serialize, Debug, Pread, Clone)]
 struct Section_32 {
     sect_name: [u8; 16],
     seg_name:  [u8; 16],
@@ -196,20 +195,20 @@ struct Section_32 {
     reserved2: u32,
 }
 
-#[derive(Debug, Pread, Clone)]
+#[derive(Serialize, Debug, Pread, Clone)]
 pub struct Section {
     pub sect_name: [u8; 16],
     pub seg_name:  [u8; 16],
-    addr:      u64,
-    size:      u64,
-    offset:    u32,
-    align:     u32,
-    reloff:    u32,
-    n_reloc:   u32,
-    flags:     u32,
-    reserved1: u32,
-    reserved2: u32,
-    reserved3: u32,
+    pub addr:      u64,
+    pub size:      u64,
+    pub offset:    u32,
+    pub align:     u32,
+    pub reloff:    u32,
+    pub n_reloc:   u32,
+    pub flags:     u32,
+    pub reserved1: u32,
+    pub reserved2: u32,
+    pub reserved3: u32,
 }
 
 impl From<Section_32> for Section {
@@ -231,86 +230,86 @@ impl From<Section_32> for Section {
     }
 }
 
-#[derive(Debug, Pread)]
-struct Fvmlib {
-    lc_str: u32,
-    minor_ver: u32,
-    header_adr: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Fvmlib {
+    pub lc_str: u32,
+    pub minor_ver: u32,
+    pub header_adr: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Fvmlib_command {
-    cmd: Load_command,
-    fvmlib: Fvmlib,
+#[derive(Serialize, Debug, Pread)]
+pub struct Fvmlib_command {
+    pub cmd: Load_command,
+    pub fvmlib: Fvmlib,
 }
 
-#[derive(Debug, Pread)]
-struct Dylib {
-    lc_str:    u32,
-    timestamp: u32,
-    cur_ver:   u32,
-    comp_ver:  u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Dylib {
+    pub lc_str:    u32,
+    pub timestamp: u32,
+    pub cur_ver:   u32,
+    pub comp_ver:  u32,
 }
 
-#[derive(Debug, Pread)]
-struct Dylib_command {
-    cmd: Load_command,
-    dylib: Dylib,
+#[derive(Serialize, Debug, Pread)]
+pub struct Dylib_command {
+    pub cmd: Load_command,
+    pub dylib: Dylib,
 }
 
-#[derive(Debug, Pread)]
-struct Sub_framework_command {
-    cmd: Load_command,
-    lc_str: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Sub_framework_command {
+    pub cmd: Load_command,
+    pub lc_str: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Sub_client_command {
-    cmd: Load_command,
-    lc_str: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Sub_client_command {
+    pub cmd: Load_command,
+    pub lc_str: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Sub_umbrella_command {
-    cmd: Load_command,
-    lc_str: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Sub_umbrella_command {
+    pub cmd: Load_command,
+    pub lc_str: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Sub_library_command {
-    cmd: Load_command,
-    lc_str: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Sub_library_command {
+    pub cmd: Load_command,
+    pub lc_str: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Prebound_dylib_command {
-    cmd: Load_command,
-    lc_str_name: u32,
-    n_modules: u32,
-    lc_str_mods: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Prebound_dylib_command {
+    pub cmd: Load_command,
+    pub lc_str_name: u32,
+    pub n_modules: u32,
+    pub lc_str_mods: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Dylinker_command {
-    cmd: Load_command,
-    lc_str: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Dylinker_command {
+    pub cmd: Load_command,
+    pub lc_str: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Thread_command {
-    cmd: Load_command,
-    flavor: u32,
-    cnt: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Thread_command {
+    pub cmd: Load_command,
+    pub flavor: u32,
+    pub cnt: u32,
     // state: ThreadState,
 }
 
-#[derive(Debug)]
-enum ThreadState {
+#[derive(Serialize, Debug)]
+pub enum ThreadState {
     X86,
     ARM,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 struct Routines_command_32 {
     cmd:       Load_command,
     init_addr: u32,
@@ -324,18 +323,18 @@ struct Routines_command_32 {
     reserved6: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Routines_command {
-    cmd:       Load_command,
-    init_addr: u64,
-    init_mod:  u64,
+#[derive(Serialize, Debug, Pread)]
+pub struct Routines_command {
+    pub cmd:       Load_command,
+    pub init_addr: u64,
+    pub init_mod:  u64,
 
-    reserved1: u64,
-    reserved2: u64,
-    reserved3: u64,
-    reserved4: u64,
-    reserved5: u64,
-    reserved6: u64,
+    pub reserved1: u64,
+    pub reserved2: u64,
+    pub reserved3: u64,
+    pub reserved4: u64,
+    pub reserved5: u64,
+    pub reserved6: u64,
 }
 
 impl From<Routines_command_32> for Routines_command {
@@ -355,16 +354,16 @@ impl From<Routines_command_32> for Routines_command {
     }
 }
 
-#[derive(Debug, Pread, Clone)]
-struct Symtab_command {
-    cmd:     Load_command,
-    sym_off: u32,
-    n_syms:  u32,
-    str_off: u32,
-    str_sz:  u32,
+#[derive(Serialize, Debug, Pread, Clone)]
+pub struct Symtab_command {
+    pub cmd:     Load_command,
+    pub sym_off: u32,
+    pub n_syms:  u32,
+    pub str_off: u32,
+    pub str_sz:  u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 struct Nlist_32 {
     n_un:    u32,
     n_type:  u8,
@@ -373,7 +372,7 @@ struct Nlist_32 {
     n_value: u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 pub struct Nlist {
     pub n_un:    u32,
     pub n_type:  u8,
@@ -394,62 +393,62 @@ impl From<Nlist_32> for Nlist {
     }
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 pub struct Symtab {
-    header: Symtab_command,
+    pub header: Symtab_command,
     pub syms:   Vec<Nlist>,
     pub strs:   Vec<u8>,
 }
 
-#[derive(Debug, Pread, Clone)]
-struct Dysymtab_command {
-    cmd: Load_command,
+#[derive(Serialize, Debug, Pread, Clone)]
+pub struct Dysymtab_command {
+    pub cmd: Load_command,
 
-    local_sym_idx: u32,
-    local_sym_n: u32,
-    ext_def_sym_idx: u32,
-    ext_def_sym_n: u32,
-    undef_sym_idx: u32,
-    undef_sym_n: u32,
+    pub local_sym_idx: u32,
+    pub local_sym_n: u32,
+    pub ext_def_sym_idx: u32,
+    pub ext_def_sym_n: u32,
+    pub undef_sym_idx: u32,
+    pub undef_sym_n: u32,
 
-    toc_off: u32,
-    toc_n: u32,
+    pub toc_off: u32,
+    pub toc_n: u32,
 
-    mod_tab_off: u32,
-    mod_tab_n: u32,
+    pub mod_tab_off: u32,
+    pub mod_tab_n: u32,
 
-    ext_ref_sym_off: u32,
-    ext_ref_sym_n: u32,
+    pub ext_ref_sym_off: u32,
+    pub ext_ref_sym_n: u32,
 
-    indirect_sym_off: u32,
-    indirect_sym_n: u32,
+    pub indirect_sym_off: u32,
+    pub indirect_sym_n: u32,
 
-    ext_rel_off: u32,
-    ext_rel_n: u32,
+    pub ext_rel_off: u32,
+    pub ext_rel_n: u32,
 
-    loc_rel_off: u32,
-    loc_ref_n: u32,
+    pub loc_rel_off: u32,
+    pub loc_ref_n: u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 pub struct Relocation_info {
     pub addr: u32,
     pub sym: u32,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 pub struct Relocation {
     pub sec:  Section,
     pub info: Vec<Relocation_info>,
 }
-// * a table of contents entry
-#[derive(Debug, Pread)]
-struct Dylib_toc {
-    sym_idx: u32,
-    mod_idx: u32,
+/// * a table of contents entry
+#[derive(Serialize, Debug, Pread)]
+pub struct Dylib_toc {
+    pub sym_idx: u32,
+    pub mod_idx: u32,
 }
 // * a module table entry
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 struct Dylib_module_32 {
     mod_name:              u32,
 
@@ -469,26 +468,26 @@ struct Dylib_module_32 {
     objc_module_info_addr: u32,
     objc_module_info_size: u32,
 }
-// * a module table entry
-#[derive(Debug, Pread)]
-struct Dylib_module {
-    mod_name:              u32,
+/// * a module table entry
+#[derive(Serialize, Debug, Pread)]
+pub struct Dylib_module {
+    pub mod_name:              u32,
 
-    ext_def_sym_idx:       u32,
-    ext_def_sym_n:         u32,
-    ref_sym_idx:           u32,
-    ref_sym_n:             u32,
-    local_sym_idx:         u32,
-    local_sym_n:           u32,
+    pub ext_def_sym_idx:       u32,
+    pub ext_def_sym_n:         u32,
+    pub ref_sym_idx:           u32,
+    pub ref_sym_n:             u32,
+    pub local_sym_idx:         u32,
+    pub local_sym_n:           u32,
 
-    ext_rel_idx:           u32,
-    ext_rel_n:             u32,
+    pub ext_rel_idx:           u32,
+    pub ext_rel_n:             u32,
 
-    init_iterm_idx:        u32,
-    init_nterm_n:          u32,
+    pub init_iterm_idx:        u32,
+    pub init_nterm_n:          u32,
 
-    objc_module_info_addr: u32,
-    objc_module_info_size: u64,
+    pub objc_module_info_addr: u32,
+    pub objc_module_info_size: u64,
 }
 
 impl From<Dylib_module_32> for Dylib_module {
@@ -515,49 +514,49 @@ impl From<Dylib_module_32> for Dylib_module {
     }
 }
 
-#[derive(Debug, Pread)]
-struct Dylib_reference {
-    refer: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Dylib_reference {
+    pub refer: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Twolevel_hints_command {
-    cmd:     Load_command,
-    offset:  u32,
-    n_hints: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Twolevel_hints_command {
+    pub cmd:     Load_command,
+    pub offset:  u32,
+    pub n_hints: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Twolevel_hint {
-    hint: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Twolevel_hint {
+    pub hint: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Prebind_cksum_command {
-    cmd:   Load_command,
-    cksum: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Prebind_cksum_command {
+    pub cmd:   Load_command,
+    pub cksum: u32,
 }
 
-#[derive(Debug, Pread)]
-struct UUID_command {
-    cmd:  Load_command,
-    uuid: [u8; 16],
+#[derive(Serialize, Debug, Pread)]
+pub struct UUID_command {
+    pub cmd:  Load_command,
+    pub uuid: [u8; 16],
 }
 
-#[derive(Debug, Pread)]
-struct Rpath_command {
-    cmd:    Load_command,
-    lc_str: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Rpath_command {
+    pub cmd:    Load_command,
+    pub lc_str: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Linkedit_data_command {
-    cmd:      Load_command,
-    data_off: u32,
-    data_sz:  u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Linkedit_data_command {
+    pub cmd:      Load_command,
+    pub data_off: u32,
+    pub data_sz:  u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 struct Encryption_info_command_32 {
     cmd:       Load_command,
     crypt_off: u32,
@@ -565,13 +564,13 @@ struct Encryption_info_command_32 {
     crypt_id:  u32,
 }
 
-#[derive(Debug, Pread)]
-struct Encryption_info_command {
-    cmd:       Load_command,
-    crypt_off: u32,
-    crypt_sz:  u32,
-    crypt_id:  u32,
-    pad:       u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Encryption_info_command {
+    pub cmd:       Load_command,
+    pub crypt_off: u32,
+    pub crypt_sz:  u32,
+    pub crypt_id:  u32,
+    pub pad:       u32,
 }
 
 impl From<Encryption_info_command_32> for Encryption_info_command {
@@ -586,117 +585,121 @@ impl From<Encryption_info_command_32> for Encryption_info_command {
     }
 }
 
-#[derive(Debug, Pread)]
-struct Version_min_command {
-    cmd: Load_command,
-    version: u32,
-    sdk: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Version_min_command {
+    pub cmd: Load_command,
+    pub version: u32,
+    pub sdk: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Build_version_command {
-    cmd:      Load_command,
-    platform: u32,
-    minos:    u32,
-    sdk:      u32,
-    n_tools:  u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Build_version_command {
+    pub cmd:      Load_command,
+    pub platform: u32,
+    pub minos:    u32,
+    pub sdk:      u32,
+    pub n_tools:  u32,
 }
 
-#[derive(Debug, Pread)]
-struct build_tool_version {
-    tool:    u32,
-    version: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct build_tool_version {
+    pub tool:    u32,
+    pub version: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Dyld_info_command {
-    cmd:           Load_command,
+#[derive(Serialize, Debug, Pread)]
+pub struct Dyld_info_command {
+    pub cmd:           Load_command,
 
-    rebase_off:    u32,
-    rebase_sz:     u32,
+    pub rebase_off:    u32,
+    pub rebase_sz:     u32,
 
-    bind_off:      u32,
-    bind_sz:       u32,
+    pub bind_off:      u32,
+    pub bind_sz:       u32,
 
-    weak_bind_off: u32,
-    weak_bind_sz:  u32,
+    pub weak_bind_off: u32,
+    pub weak_bind_sz:  u32,
 
-    lazy_bind_off: u32,
-    lazy_bind_sz:  u32,
+    pub lazy_bind_off: u32,
+    pub lazy_bind_sz:  u32,
 
-    export_off:    u32,
-    export_sz:     u32,
+    pub export_off:    u32,
+    pub export_sz:     u32,
 }
 
-#[derive(Debug)]
-struct Linker_option_command {
-    cmd:  Load_command,
-    cnt:  u32,
-    strs: Vec<String>,
+#[derive(Serialize, Debug)]
+pub struct Linker_option_command {
+    pub cmd:  Load_command,
+    pub cnt:  u32,
+    pub strs: Vec<String>,
 }
 
-#[derive(Debug, Pread)]
-struct Symseg_command {
-    cmd:    Load_command,
-    offset: u32,
-    size:   u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Symseg_command {
+    pub cmd:    Load_command,
+    pub offset: u32,
+    pub size:   u32,
 }
 
-#[derive(Debug)]
-struct Ident_command {
-    cmd:  Load_command,
-    strs: Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct Ident_command {
+    pub cmd:  Load_command,
+    pub strs: Vec<u8>,
 }
 
-#[derive(Debug, Pread)]
-struct Fvmfile_command {
-    cmd:         Load_command,
-    lc_str:      u32,
-    header_addr: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Fvmfile_command {
+    pub cmd:         Load_command,
+    pub lc_str:      u32,
+    pub header_addr: u32,
 }
 
-#[derive(Debug, Pread)]
-struct Entry_point_command {
-    cmd:       Load_command,
-    entry_off: u64,
-    stack_sz:  u64,
+#[derive(Serialize, Debug, Pread)]
+pub struct Entry_point_command {
+    pub cmd:       Load_command,
+    pub entry_off: u64,
+    pub stack_sz:  u64,
 }
 
-#[derive(Debug, Pread)]
-struct Source_version_command {
-    cmd: Load_command,
-    version: u64,
+#[derive(Serialize, Debug, Pread)]
+pub struct Source_version_command {
+    pub cmd: Load_command,
+    pub version: u64,
 }
 
-#[derive(Debug, Pread)]
-struct Note_command {
-    cmd: Load_command,
-    data_owner: [u8; 16],
-    offset: u64,
-    size: u64,
+#[derive(Serialize, Debug, Pread)]
+pub struct Note_command {
+    pub cmd: Load_command,
+    pub data_owner: [u8; 16],
+    pub offset: u64,
+    pub size: u64,
 }
 
-#[derive(Debug)]
+/// Parsed Mach-O file: header, load commands and the segments, sections,
+/// symbols and relocations they describe.
+#[derive(Serialize, Debug)]
 pub struct MachO {
-    opt:      Opt,
-
-    header:   Mach_header,
-    commands: Vec<LoadCommand>,
+    pub header:   Mach_header,
+    pub commands: Vec<LoadCommand>,
 
-    segments: Vec<Segment>,
-    sections: Vec<Section>,
+    pub segments: Vec<Segment>,
+    pub sections: Vec<Section>,
 
-    symtab:   Option<Symtab>,
-    dysymtab: Option<Dysymtab_command>,
+    pub symtab:   Option<Symtab>,
+    pub dysymtab: Option<Dysymtab_command>,
 
-    relocs:   Vec<Relocation>,
-    libs:     Vec<String>,
+    pub relocs:   Vec<Relocation>,
+    pub libs:     Vec<String>,
 }
 
 impl super::FileFormat for MachO {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
+        MachO::parse_with(buf, &mut Recovery::strict())
+    }
+
+    fn parse_with(buf: &[u8], rec: &mut Recovery) -> Result<Self, Error> {
         // const FAT_MAGIC: u32 = 0xCAFEBABE;
 
         const	MH_MAGIC: u32 = 0xFEEDFACE;
@@ -705,25 +708,24 @@ impl super::FileFormat for MachO {
         const MH_MAGIC_64: u32 =  0xFEEDFACF;
         const MH_CIGAM_64: u32 =  0xCFFAEDFE;
 
-        let endianness = match buf.pread::<u32>(0)? {
+        let magic = buf.pread::<u32>(0).at(0, "Mach-O magic")?;
+        let endianness = match magic {
             MH_MAGIC | MH_MAGIC_64 => scroll::LE,
             MH_CIGAM | MH_CIGAM_64 => scroll::BE,
-            // This should never happen as header has been already checked
-            _ => unreachable!(),
+            _ => return Err(Error::from(Problem::msg(format!("Invalid Mach-O magic {:#X}", magic)).at(0))),
         };
-        let is_64bit = match buf.pread::<u32>(0)? {
+        let is_64bit = match magic {
             MH_MAGIC | MH_CIGAM => false,
             MH_MAGIC_64 | MH_CIGAM_64 => true,
-            // This should never happen as header has been already checked
-            _ => unreachable!(),
+            _ => return Err(Error::from(Problem::msg(format!("Invalid Mach-O magic {:#X}", magic)).at(0))),
         };
 
         let offset = &mut 0;
         let header;
-        if is_64bit { header = buf.gread_with::<Mach_header>(offset, endianness)?; }
-        else { header = Mach_header::from(buf.gread_with::<Mach_header_32>(offset, endianness)?); }
+        if is_64bit { header = buf.gread_with::<Mach_header>(offset, endianness).at(0, "Mach-O header")?; }
+        else { header = Mach_header::from(buf.gread_with::<Mach_header_32>(offset, endianness).at(0, "Mach-O header")?); }
 
-        let mut commands = Vec::with_capacity(header.n_cmds as usize);
+        let mut commands = Vec::new();
         let mut segments = Vec::new();
         let mut sections = Vec::new();
 
@@ -733,169 +735,205 @@ impl super::FileFormat for MachO {
         let mut relocs   = Vec::new();
         let mut libs     = Vec::new();
 
-        for _ in 0..header.n_cmds {
-            let cmd = buf.pread_with::<u32>(*offset, endianness)?;
-            let cmd_sz = buf.pread_with::<u32>(*offset + 4, endianness)?;
-            commands.push( match cmd {
+        let mut read_command = |rec: &mut Recovery, cmd: u32, offset: usize| -> Result<LoadCommand, Error> {
+            Ok(match cmd {
                 LC_SEGMENT => {
-                    let segment = Segment_command::from(buf.pread_with::<Segment_command_32>(*offset, endianness)?);
-                    let mut sects   = Vec::with_capacity(segment.n_sects as usize);
-                    for i in 0..segment.n_sects as usize {
-                        let sec = Section::from(buf.pread_with::<Section_32>(*offset + 56 + (i * 68), endianness)?);
-                        let mut info = Vec::with_capacity(sec.n_reloc as usize);
-                        for j in 0..sec.n_reloc as usize {
-                            info.push(buf.pread_with(sec.reloff as usize + (j * 8), endianness)?);
-                        }
-                        if info.len() >= 1 {
+                    let segment = Segment_command::from(buf.pread_with::<Segment_command_32>(offset, endianness)?);
+                    let sects: Vec<Section> = table(buf, (offset + 56) as u64, segment.n_sects as u64 * 68, 68, "section",
+                                                    |off| Ok(Section::from(buf.pread_with::<Section_32>(off, endianness)?)))?;
+                    for sec in &sects {
+                        let info = rec.table(buf, sec.reloff as u64, sec.n_reloc as u64 * 8, 8, "relocation entry",
+                                             |off| buf.pread_with(off, endianness))?;
+                        if !info.is_empty() {
                             relocs.push( Relocation { sec: sec.clone(), info, } );
                         }
                         sections.push(sec.clone());
-                        sects.push(sec);
                     }
                     segments.push(Segment { header: segment.clone(), sects });
                     LoadCommand::Segment(cmd, segment)
                 },
                 LC_SEGMENT_64 => {
-                    let segment = buf.pread_with::<Segment_command>(*offset, endianness)?;
-                    let mut sects   = Vec::with_capacity(segment.n_sects as usize);
-                    for i in 0..segment.n_sects as usize {
-                        let sec = buf.pread_with::<Section>(*offset + 72 + (i * 80), endianness)?;
-                        let mut info = Vec::with_capacity(sec.n_reloc as usize);
-                        for j in 0..sec.n_reloc as usize {
-                            info.push(buf.pread_with(sec.reloff as usize + (j * 8), endianness)?);
-                        }
-                        if info.len() >= 1 {
+                    let segment = buf.pread_with::<Segment_command>(offset, endianness)?;
+                    let sects: Vec<Section> = table(buf, (offset + 72) as u64, segment.n_sects as u64 * 80, 80, "section",
+                                                    |off| buf.pread_with::<Section>(off, endianness))?;
+                    for sec in &sects {
+                        let info = rec.table(buf, sec.reloff as u64, sec.n_reloc as u64 * 8, 8, "relocation entry",
+                                             |off| buf.pread_with(off, endianness))?;
+                        if !info.is_empty() {
                             relocs.push( Relocation { sec: sec.clone(), info, } );
                         }
                         sections.push(sec.clone());
-                        sects.push(sec);
                     }
                     segments.push(Segment { header: segment.clone(), sects });
                     LoadCommand::Segment(cmd, segment)
                 },
                 LC_IDFVMLIB | LC_LOADFVMLIB => {
-                    LoadCommand::Fvmlib(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::Fvmlib(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_ID_DYLIB | LC_LOAD_DYLIB | LC_LOAD_WEAK_DYLIB | LC_REEXPORT_DYLIB => {
-                    let dylib = buf.pread_with::<Dylib_command>(*offset, endianness)?;
-                    libs.push(buf.pread::<&str>(*offset + dylib.dylib.lc_str as usize)?.to_string());
+                    let dylib = buf.pread_with::<Dylib_command>(offset, endianness)?;
+                    libs.push(buf.pread::<&str>(offset + dylib.dylib.lc_str as usize)?.to_string());
                     LoadCommand::Dylib(cmd, dylib)
                 },
                 LC_SUB_FRAMEWORK => {
-                    LoadCommand::SubFramework(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::SubFramework(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_SUB_CLIENT => {
-                    LoadCommand::SubClient(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::SubClient(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_SUB_UMBRELLA => {
-                    LoadCommand::SubUmbrella(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::SubUmbrella(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_SUB_LIBRARY => {
-                    LoadCommand::SubLibrary(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::SubLibrary(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_PREBOUND_DYLIB => {
-                    LoadCommand::PreboundDylib(cmd, buf.pread_with(*offset,endianness)?)
+                    LoadCommand::PreboundDylib(cmd, buf.pread_with(offset,endianness)?)
                 },
                 LC_ID_DYLINKER | LC_LOAD_DYLINKER | LC_DYLD_ENVIRONMENT => {
-                    LoadCommand::Dylinker(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::Dylinker(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_THREAD | LC_UNIXTHREAD => {
-                    LoadCommand::Thread(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::Thread(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_ROUTINES => {
-                    LoadCommand::Routines(cmd, Routines_command::from(buf.pread_with::<Routines_command_32>(*offset, endianness)?))
+                    LoadCommand::Routines(cmd, Routines_command::from(buf.pread_with::<Routines_command_32>(offset, endianness)?))
                 },
                 LC_ROUTINES_64 => {
-                    LoadCommand::Routines(cmd, buf.pread_with::<Routines_command>(*offset, endianness)?)
+                    LoadCommand::Routines(cmd, buf.pread_with::<Routines_command>(offset, endianness)?)
                 },
                 LC_SYMTAB => {
-                    let header = buf.pread_with::<Symtab_command>(*offset, endianness)?;
-                    let mut syms = Vec::with_capacity(header.n_syms as usize);
-                    for i in 0..header.n_syms as usize {
-                        if is_64bit { syms.push(buf.pread_with::<Nlist>(header.sym_off as usize + (i * 16), endianness)?); }
-                        else { syms.push(Nlist::from(buf.pread_with::<Nlist_32>(header.sym_off as usize + (i * 12), endianness)?)); }
+                    let header = buf.pread_with::<Symtab_command>(offset, endianness)?;
+                    let syms = if is_64bit {
+                        rec.table(buf, header.sym_off as u64, header.n_syms as u64 * 16, 16, "symbol",
+                                  |off| buf.pread_with::<Nlist>(off, endianness))?
                     }
-                    let strs = buf[header.str_off as usize..header.str_off as usize + header.str_sz as usize].to_vec();
+                    else {
+                        rec.table(buf, header.sym_off as u64, header.n_syms as u64 * 12, 12, "symbol",
+                                  |off| Ok(Nlist::from(buf.pread_with::<Nlist_32>(off, endianness)?)))?
+                    };
+                    let strs = rec.recover(bytes(buf, header.str_off as u64, header.str_sz as u64, "string table").map(<[u8]>::to_vec), Vec::new())?;
                     symtab = Some(Symtab { header: header.clone(), syms, strs });
                     LoadCommand::SymTab(cmd, header)
                 },
                 LC_DYSYMTAB => {
-                    let dy = buf.pread_with::<Dysymtab_command>(*offset, endianness)?;
+                    let dy = buf.pread_with::<Dysymtab_command>(offset, endianness)?;
                     dysymtab = Some(dy.clone());
                     LoadCommand::DySymTab(cmd, dy)
                 },
                 LC_TWOLEVEL_HINTS => {
-                    LoadCommand::TwolevelHints(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::TwolevelHints(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_PREBIND_CKSUM => {
-                    LoadCommand::PrebindCksum(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::PrebindCksum(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_UUID => {
-                    LoadCommand::UUID(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::UUID(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_RPATH => {
-                    LoadCommand::Rpath(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::Rpath(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_CODE_SIGNATURE | LC_SEGMENT_SPLIT_INFO | LC_FUNCTION_STARTS |
                 LC_DATA_IN_CODE | LC_DYLIB_CODE_SIGN_DRS | LC_LINKER_OPTIMIZATION_HINT => {
-                    LoadCommand::LinkeditData(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::LinkeditData(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_ENCRYPTION_INFO => {
                     LoadCommand::EncryptionInfo(cmd, Encryption_info_command::from(
-                        buf.pread_with::<Encryption_info_command_32>(*offset, endianness)?))
+                        buf.pread_with::<Encryption_info_command_32>(offset, endianness)?))
                 },
                 LC_ENCRYPTION_INFO_64 => {
-                    LoadCommand::EncryptionInfo(cmd, buf.pread_with::<Encryption_info_command>(*offset, endianness)?)
+                    LoadCommand::EncryptionInfo(cmd, buf.pread_with::<Encryption_info_command>(offset, endianness)?)
                 },
                 LC_VERSION_MIN_MACOSX | LC_VERSION_MIN_IPHONEOS |
                 LC_VERSION_MIN_WATCHOS | LC_VERSION_MIN_TVOS => {
-                    LoadCommand::VersionMin(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::VersionMin(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_BUILD_VERSION => {
-                    LoadCommand::BuildVersion(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::BuildVersion(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_DYLD_INFO | LC_DYLD_INFO_ONLY => {
-                    LoadCommand::DyldInfo(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::DyldInfo(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_LINKER_OPTION => {
-                    let comm = buf.pread_with::<Load_command>(*offset, endianness)?;
-                    let cnt  = buf.pread_with::<u32>(*offset + 8, endianness)?;
-                    let mut strs = Vec::with_capacity(cnt as usize);
+                    let comm = buf.pread_with::<Load_command>(offset, endianness)?;
+                    let cnt  = buf.pread_with::<u32>(offset + 8, endianness)?;
+                    let mut strs = Vec::new();
                     let stroff = &mut 0_usize;
-                    *stroff += *offset + 12;
+                    *stroff += offset + 12;
                     for _ in 0..cnt as usize {
                         strs.push(buf.gread::<&str>(stroff)?.to_string());
                     }
                     LoadCommand::LinkerOption(cmd, Linker_option_command { cmd: comm, cnt, strs })
                 },
                 LC_SYMSEG => {
-                    LoadCommand::Symseg(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::Symseg(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_IDENT => {
-                    let comm = buf.pread_with::<Load_command>(*offset, endianness)?;
-                    let strs = buf[*offset + 8..*offset + 8 + comm.cmd_sz as usize].to_vec();
+                    let comm = buf.pread_with::<Load_command>(offset, endianness)?;
+                    let strs = bytes(buf, offset as u64 + 8, comm.cmd_sz as u64 - 8, "ident strings")?.to_vec();
                     LoadCommand::Ident(cmd, Ident_command { cmd: comm, strs } )
                 }
                 LC_FVMFILE => {
-                    LoadCommand::FvmFile(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::FvmFile(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_MAIN => {
-                    LoadCommand::EntryPoint(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::EntryPoint(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_SOURCE_VERSION => {
-                    LoadCommand::SourceVersion(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::SourceVersion(cmd, buf.pread_with(offset, endianness)?)
                 },
                 LC_NOTE => {
-                    LoadCommand::Note(cmd, buf.pread_with(*offset, endianness)?)
+                    LoadCommand::Note(cmd, buf.pread_with(offset, endianness)?)
                 }
-                _ => return Err(Error::from(Problem::Msg(format!("Invalid/Unsupported command type: {} at: {:#X}", cmd, *offset)))),
-            });
+                _ => return Err(Error::from(Problem::msg(format!("Invalid/Unsupported command type: {}", cmd)))),
+            })
+        };
+
+        for i in 0..header.n_cmds {
+            // Without a size, the commands that follow cannot be found
+            let size = buf.pread_with::<u32>(*offset, endianness)
+                .and_then(|cmd| Ok((cmd, buf.pread_with::<u32>(*offset + 4, endianness)?)))
+                .at(*offset as u64, format_args!("load command #{}", i));
+            let (cmd, cmd_sz) = match rec.recover(size.map(Some), None)? {
+                Some(size) => size,
+                None => break,
+            };
+            if cmd_sz < 8 {
+                rec.warn(Error::from(Problem::msg(format!("Invalid load command size: {}", cmd_sz))
+                                     .at(*offset as u64 + 4)
+                                     .within(format!("load command #{}", i))))?;
+                break;
+            }
+            let what = format!("load command #{} ({})", i, segment_to_str(cmd));
+            let command = rec.within(what, |rec| read_command(rec, cmd, *offset)
+                                     .map_err(|e| Error::from(Problem::from(e).at(*offset as u64))));
+            if let Some(command) = rec.recover(command.map(Some), None)? {
+                commands.push(command);
+            }
             *offset += cmd_sz as usize;
         }
 
-        Ok(MachO {
-            opt,
+        // Ranges of the dynamic symbol table index into the symbol table
+        if let (Some(symtab), Some(dy)) = (&symtab, &dysymtab) {
+            let invalid = [(dy.local_sym_idx, dy.local_sym_n), (dy.ext_def_sym_idx, dy.ext_def_sym_n), (dy.undef_sym_idx, dy.undef_sym_n)]
+                .iter()
+                .find(|(idx, n)| *idx as u64 + *n as u64 > symtab.syms.len() as u64)
+                .copied();
+            if let Some((idx, n)) = invalid {
+                rec.warn(Error::from(Problem::msg(format!("Invalid symbol range: {} symbols at {}", n, idx))
+                                     .within("dynamic symbol table")))?;
+                dysymtab = None;
+            }
+        }
+
+        if let Some(symtab) = &symtab {
+            check_symbols(rec, symtab, sections.len())?;
+        }
+        for reloc in &relocs {
+            check_relocs(rec, reloc, symtab.as_ref().map_or(0, |symtab| symtab.syms.len()), sections.len())?;
+        }
 
+        Ok(MachO {
             header,
             commands,
 
@@ -911,192 +949,57 @@ impl super::FileFormat for MachO {
 
     }
 
-    fn print(&self) -> Result<(), Error> {
-        use ansi_term::Color;
-        use prettytable::Table;
-
-        //
-        // MACH-O FILE
-        //
-        fmt_macho(&self.header);
-        println!();
-
-        //
-        // COMMANDS
-        //
-        if self.commands.len() >= 1 {
-            println!("{}({})",
-                     Color::White.underline().paint("LoadCommands"),
-                     self.commands.len());
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Name"]);
-
-            for (i, entry) in self.commands.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    segment_to_str(entry.get_cmd_type()),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-
-        }
+}
 
-        //
-        // SEGMENTS
-        //
-        if self.segments.len() >= 1 {
-            println!("{}({})",
-                     Color::White.underline().paint("Segments"),
-                     self.segments.len());
-
-            for entry in &self.segments {
-
-                fmt_indentln(format!("{}({})",
-                                     Color::Fixed(75).paint(std::str::from_utf8(&entry.header.seg_name)?),
-                                     entry.sects.len()));
-
-                if entry.sects.len() >= 1 {
-
-                    //
-                    // SECTIONS
-                    //
-                    let mut trimmed = false;
-                    let mut table = Table::new();
-                    let format = prettytable::format::FormatBuilder::new()
-                        .borders(' ')
-                        .column_separator(' ')
-                        .padding(1, 1)
-                        .build();
-                    table.set_format(format);
-                    table.add_row(row!["","Idx", "Name", "Addr", "Size", "Offset", "Align", "RelOff", "Nreloc", "Flags"]);
-
-                    for (i, entry) in entry.sects.iter().enumerate() {
-                        if i == self.opt.trim_lines {
-                            trimmed = true;
-                            break;
-                        }
-                        table.add_row(row![
-                            "",
-                            i,
-                            std::str::from_utf8(&entry.sect_name)?,
-                            Fr->format!("{:#X}", entry.addr),
-                            Fg->format!("{:#X}", entry.size),
-                            Fy->format!("{:#X}", entry.offset),
-                            format!("{:#X}", entry.align),
-                            format!("{:#X}", entry.reloff),
-                            Fmr->entry.n_reloc,
-                            format!("{} {}",
-                                    Color::Blue.paint(section_type_to_str(entry.flags)),
-                                    section_attr_to_str(entry.flags))
-                        ]);
-                    }
-                    table.printstd();
-                    if trimmed {
-                        fmt_indentln(format!("Output trimmed..."));
-                    }
+/// Checks that symbols have readable names and refer to existing sections.
+fn check_symbols(rec: &mut Recovery, symtab: &Symtab, n_sects: usize) -> Result<(), Error> {
+    // A string table that could not be read was reported already
+    let names = symtab.strs.len() as u64 == symtab.header.str_sz as u64;
+    let mut invalid = symtab.syms.iter().enumerate()
+        .filter_map(|(i, sym)| {
+            if names {
+                if let Err(err) = symtab.strs.pread::<&str>(sym.n_un as usize) {
+                    return Some(Error::from(Problem::from(err)
+                                            .at(symtab.header.str_off as u64 + sym.n_un as u64)
+                                            .within(format!("name of symbol #{}", i))));
                 }
-                println!();
-            }
-        }
-
-        //
-        // SYMBOLS
-        //
-        if let Some(symtab) = &self.symtab {
-            println!("{}({})",
-                     Color::White.underline().paint("Symbols"),
-                     symtab.syms.len());
-
-            if let Some(dysymtab) = &self.dysymtab {
-                //
-                // LOCAL SYMBOLS
-                //
-                println!();
-                fmt_indentln(format!("{}({})",
-                                     Color::Fixed(75).paint("LocalSymbols"),
-                                     dysymtab.local_sym_n));
-                fmt_macho_syms(&symtab.syms[dysymtab.local_sym_idx as usize
-                                            ..dysymtab.local_sym_idx as usize + dysymtab.local_sym_n as usize],
-                               &symtab.strs,
-                               &self.sections,
-                               self.opt.trim_lines)?;
-
-                //
-                // EXTERNAL SYMBOLS
-                //
-                fmt_indentln(format!("{}({})",
-                                     Color::Fixed(75).paint("ExternalSymbols"),
-                                     dysymtab.ext_def_sym_n));
-                fmt_macho_syms(&symtab.syms[dysymtab.ext_def_sym_idx as usize
-                                            ..dysymtab.ext_def_sym_idx as usize + dysymtab.ext_def_sym_n as usize],
-                               &symtab.strs,
-                               &self.sections,
-                               self.opt.trim_lines)?;
-
-                //
-                // UNDEFINED SYMBOLS
-                //
-                fmt_indentln(format!("{}({})",
-                                     Color::Fixed(75).paint("UndefinedSymbols"),
-                                     dysymtab.undef_sym_n));
-                fmt_macho_syms(&symtab.syms[dysymtab.undef_sym_idx as usize
-                                            ..dysymtab.undef_sym_idx as usize + dysymtab.undef_sym_n as usize],
-                               &symtab.strs,
-                               &self.sections,
-                               self.opt.trim_lines)?;
-
             }
-
-            else  {
-                fmt_macho_syms(&symtab.syms, &symtab.strs, &self.sections, self.opt.trim_lines)?;
+            if sym.n_sect as usize > n_sects {
+                return Some(Error::from(Problem::msg(format!("Invalid section number: {}", sym.n_sect))
+                                        .within(format!("symbol #{}", i))));
             }
+            None
+        });
+
+    if let Some(first) = invalid.next() {
+        rec.warn(first)?;
+        let more = invalid.count();
+        if more > 0 {
+            rec.warn(Error::from(Problem::msg(format!("{} more invalid symbols", more))))?;
         }
+    }
 
-        //
-        // RELOCATIONS
-        //
-        if self.relocs.len() >= 1 {
-            println!("{}({})",
-                     Color::White.underline().paint("Relocations"),
-                     self.relocs.len());
-
-            if let Some(symtab) = &self.symtab {
-                fmt_macho_reloc(&self.relocs, symtab, &self.sections, self.opt.trim_lines)?;
-            }
-        }
-
-        //
-        // LIBRARIES
-        //
-        if self.libs.len() >= 1 {
-            println!("{}({})",
-                     Color::White.underline().paint("Libraries"),
-                     self.libs.len());
-
-            for lib in &self.libs {
-                fmt_indentln(format!("{}", Color::Blue.paint(lib)));
-            }
-
-        }
-
-        Ok(())
+    Ok(())
+}
 
+/// Checks that relocations refer to existing symbols or sections.
+fn check_relocs(rec: &mut Recovery, reloc: &Relocation, n_syms: usize, n_sects: usize) -> Result<(), Error> {
+    let invalid: Vec<u32> = reloc.info.iter()
+        .filter(|entry| {
+            let r_extern = entry.sym << 4 >> 31 == 1;
+            let r_sym    = (entry.sym << 8 >> 8) as usize;
+            if r_extern { r_sym >= n_syms } else { r_sym > n_sects }
+        })
+        .map(|entry| entry.sym << 8 >> 8)
+        .collect();
+
+    if let Some(first) = invalid.first() {
+        let name = format!("relocations of section {}.{}",
+                           String::from_utf8_lossy(&reloc.sec.seg_name).trim_end_matches('\0'),
+                           String::from_utf8_lossy(&reloc.sec.sect_name).trim_end_matches('\0'));
+        rec.warn(Error::from(Problem::msg(format!("{} relocations with invalid symbol or section number, first is {}", invalid.len(), first))
+                             .within(name)))?;
     }
 
+    Ok(())
 }
@@ -13,17 +13,139 @@ pub mod lua;
 
 // Archive formats
 pub mod zip;
+pub mod ar;
 
 // Document formats
 pub mod pdf;
 
-use crate::Opt;
 use failure::{Error};
 
+use crate::{Problem, ResultExt};
+
+/// Common interface of every format parser.
 pub trait FileFormat {
     type Item;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self::Item, Error>;
-    fn print(&self) -> Result<(), Error>;
+    /// Parse a whole file held in `buf`.
+    fn parse(buf: &[u8]) -> Result<Self::Item, Error>;
+
+    /// Parse `buf`, leaving it to `recovery` whether damaged parts of the
+    /// file fail the parse or are skipped with a warning. Formats without
+    /// anything to recover from parse strictly.
+    fn parse_with(buf: &[u8], _recovery: &mut Recovery) -> Result<Self::Item, Error> {
+        Self::parse(buf)
+    }
+
+}
+
+/// Decides what happens to problems a parser can recover from, like a
+/// truncated table or an out-of-range link: strict parsing fails on them,
+/// lenient parsing keeps them as warnings and carries on with what it has.
+#[derive(Debug, Default)]
+pub struct Recovery {
+    lenient: bool,
+    pub warnings: Vec<Problem>,
+}
+
+impl Recovery {
+    pub fn strict() -> Recovery {
+        Recovery::default()
+    }
+
+    pub fn lenient() -> Recovery {
+        Recovery { lenient: true, warnings: Vec::new() }
+    }
+
+    /// Fails with `err` when strict, keeps it as a warning when lenient.
+    pub fn warn(&mut self, err: Error) -> Result<(), Error> {
+        if !self.lenient {
+            return Err(err);
+        }
+        self.warnings.push(Problem::from(err));
+        Ok(())
+    }
+
+    /// Value of `res`, or `fallback` if it failed and parsing is lenient.
+    pub fn recover<T>(&mut self, res: Result<T, Error>, fallback: T) -> Result<T, Error> {
+        match res {
+            Ok(value) => Ok(value),
+            Err(err) => self.warn(err).map(|()| fallback),
+        }
+    }
+
+    /// Runs `parse` on a part of the file, recording `what` as the
+    /// structure enclosing its error or warnings.
+    pub fn within<T, D, F>(&mut self, what: D, parse: F) -> Result<T, Error>
+        where D: std::fmt::Display, F: FnOnce(&mut Recovery) -> Result<T, Error> {
+
+        let first = self.warnings.len();
+        let res = parse(self).within(&what);
+        for warning in &mut self.warnings[first..] {
+            warning.context.push(what.to_string());
+        }
+        res
+    }
+
+    /// Reads a table like [`table`]. When lenient, a table running past the
+    /// end of the file is cut down to the entries that fit.
+    pub fn table<T, F>(&mut self, buf: &[u8], offset: u64, size: u64, entsize: u64, what: &str, read: F) -> Result<Vec<T>, Error>
+        where F: Fn(usize) -> Result<T, scroll::Error> {
+
+        match table(buf, offset, size, entsize, what, &read) {
+            Ok(entries) => Ok(entries),
+            Err(err) => {
+                self.warn(err)?;
+                let fits = (buf.len() as u64).saturating_sub(offset);
+                Ok(table(buf, offset, std::cmp::min(size, fits), entsize, what, read).unwrap_or_default())
+            },
+        }
+    }
+}
+
+pub fn align(alignment: usize, mut offset: usize) -> usize {
+    let diff = offset % alignment;
+    if diff != 0 {
+        offset += alignment - diff;
+    }
+    offset
+}
+
+/// `size` bytes of `buf` starting at `offset`, checked to lie inside the file.
+pub fn bytes<'a>(buf: &'a [u8], offset: u64, size: u64, what: &str) -> Result<&'a [u8], Error> {
+    match offset.checked_add(size) {
+        Some(end) if end <= buf.len() as u64 => Ok(&buf[offset as usize..end as usize]),
+        _ => Err(Error::from(Problem::msg(format!("{:#X} bytes do not fit in file of size {:#X}", size, buf.len()))
+                             .at(offset)
+                             .within(what))),
+    }
+}
+
+/// Reads a table of `size` bytes at `offset` made of `entsize` bytes long
+/// entries, calling `read` with the file offset of each of them.
+///
+/// The whole table is checked to lie inside the file before anything is read,
+/// so bogus sizes fail early instead of allocating or looping forever. Errors
+/// name the failing entry as `what #i`.
+pub fn table<T, F>(buf: &[u8], offset: u64, size: u64, entsize: u64, what: &str, read: F) -> Result<Vec<T>, Error>
+    where F: Fn(usize) -> Result<T, scroll::Error> {
+
+    if size == 0 {
+        return Ok(Vec::new());
+    }
+    if entsize == 0 {
+        return Err(Error::from(Problem::msg("Entry size is 0").at(offset).within(format!("{} table", what))));
+    }
+
+    let count = size / entsize;
+    bytes(buf, offset, count * entsize, &format!("{} table", what))?;
+
+    let mut entries = Vec::with_capacity(count as usize);
+    for i in 0..count {
+        let entry_offset = offset + i * entsize;
+        let entry = read(entry_offset as usize)
+            .at(entry_offset, format_args!("{} #{}", what, i))?;
+        entries.push(entry);
+    }
 
+    Ok(entries)
 }
@@ -1,38 +1,32 @@
 use failure::{Error};
 use scroll::{self, Pread};
 
-use crate::Opt;
-// use crate::Problem;
+use crate::Problem;
 
 pub const PDF_MAGIC: &'static [u8; PDF_MAGIC_SIZE] = b"%PDF-";
 pub const PDF_MAGIC_SIZE: usize = 5;
 
+/// PDF document. Only the version from the file header is read.
+#[derive(Serialize)]
 pub struct Pdf {
-    opt:     Opt,
-
-    version: String,
+    pub version: String,
 }
 
 impl super::FileFormat for Pdf {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
 
         // Really not sure if this is how I should go about this. I need to read some docs!
-        let version = buf.pread_with::<&str>(PDF_MAGIC_SIZE, scroll::ctx::StrCtx::Delimiter(0x25))?.split_whitespace().next().unwrap().to_string();
+        let version = buf.pread_with::<&str>(PDF_MAGIC_SIZE, scroll::ctx::StrCtx::Delimiter(0x25))?
+            .split_whitespace()
+            .next()
+            .ok_or_else(|| Problem::msg("Missing PDF version".to_string()))?
+            .to_string();
 
         Ok(Pdf {
-            opt,
-
             version,
         })
     }
 
-    fn print(&self) -> Result<(), Error> {
-
-        println!("PDF{}", self.version);
-
-        Ok(())
-    }
-
 }
@@ -1,14 +1,11 @@
 #![allow(non_camel_case_types)]
 
 include!("pe_constants.rs");
-use ansi_term::Color;
 use scroll::{self, Pread};
 
-
-use crate::Opt;
-use crate::Problem;
+use crate::{Problem, ResultExt};
+use crate::formats::{bytes, table, Recovery};
 use failure::{Error};
-use crate::format::{fmt_indentln, fmt_pe};
 
 pub const PE_MAGIC: &'static [u8; PE_MAGIC_SIZE] = b"MZ";
 pub const PE_MAGIC_SIZE: usize = 2;
@@ -16,7 +13,7 @@ pub const PE_MAGIC_SIZE: usize = 2;
 const PE_SIGNATURE_OFFSET: usize = 0x3C;
 const PE_SIGNATURE: &'static [u8; 4] = b"PE\x00\x00";
 
-const PE32_MAGIC: u16 = 0x10b;
+pub const PE32_MAGIC: u16 = 0x10b;
 const PE32PLUS_MAGIC: u16 = 0x20b;
 
 const COFF_HEADER_OFFSER: usize = 4;
@@ -30,7 +27,7 @@ const PE32_WIN_FIELDS_OFFSET: usize = 24 + 28;
 const PE32_DATA_DIRS_OFFSET:  usize = 24 + 96;
 const PE32_SECTIONS_OFFSET:   usize = 24 + 224;
 
-#[derive(Pread, Debug)]
+#[derive(Serialize, Pread, Debug)]
 pub struct COFF_header {
     pub machine:           u16,
     pub n_of_sections:     u16,
@@ -41,20 +38,20 @@ pub struct COFF_header {
     pub characteristics:   u16,
 }
 
-#[derive(Pread, Debug)]
-struct Std_COFF_header {
-    magic:          u16,
-    major_link_ver: u8,
-    minor_link_ver: u8,
-    sz_of_code:     u32,
-    sz_of_init:     u32,
-    sz_of_uninit:   u32,
-    addr_of_entry:  u32,
-    base_of_code:   u32,
-    base_of_data:   u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Std_COFF_header {
+    pub magic:          u16,
+    pub major_link_ver: u8,
+    pub minor_link_ver: u8,
+    pub sz_of_code:     u32,
+    pub sz_of_init:     u32,
+    pub sz_of_uninit:   u32,
+    pub addr_of_entry:  u32,
+    pub base_of_code:   u32,
+    pub base_of_data:   u32,
 }
 
-#[derive(Pread, Debug)]
+#[derive(Serialize, Pread, Debug)]
 struct Windows_fields_32 {
     image_base:       u32,
     section_align:    u32,
@@ -79,29 +76,29 @@ struct Windows_fields_32 {
     n_of_rva:         u32,
 }
 
-#[derive(Pread, Debug)]
-struct Windows_fields {
-    image_base:       u64,
-    section_align:    u32,
-    file_align:       u32,
-    major_os_ver:     u16,
-    minor_os_ver:     u16,
-    major_img_ver:    u16,
-    minor_img_ver:    u16,
-    major_sub_ver:    u16,
-    minor_sub_ver:    u16,
-    win32_ver:        u32,
-    sz_of_img:        u32,
-    sz_of_headers:    u32,
-    checksum:         u32,
-    subsys:           u16,
-    dll_chara:        u16,
-    sz_stack_reserve: u64,
-    sz_stack_commit:  u64,
-    sz_heap_reserve:  u64,
-    sz_heap_commit:   u64,
-    loader_flags:     u32,
-    n_of_rva:         u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Windows_fields {
+    pub image_base:       u64,
+    pub section_align:    u32,
+    pub file_align:       u32,
+    pub major_os_ver:     u16,
+    pub minor_os_ver:     u16,
+    pub major_img_ver:    u16,
+    pub minor_img_ver:    u16,
+    pub major_sub_ver:    u16,
+    pub minor_sub_ver:    u16,
+    pub win32_ver:        u32,
+    pub sz_of_img:        u32,
+    pub sz_of_headers:    u32,
+    pub checksum:         u32,
+    pub subsys:           u16,
+    pub dll_chara:        u16,
+    pub sz_stack_reserve: u64,
+    pub sz_stack_commit:  u64,
+    pub sz_heap_reserve:  u64,
+    pub sz_heap_commit:   u64,
+    pub loader_flags:     u32,
+    pub n_of_rva:         u32,
 }
 
 impl From<Windows_fields_32> for Windows_fields {
@@ -134,59 +131,59 @@ impl From<Windows_fields_32> for Windows_fields {
 
 }
 
-#[derive(Pread, Debug)]
-struct Image_data_dir {
-    rva: u32,
-    sz:  u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Image_data_dir {
+    pub rva: u32,
+    pub sz:  u32,
 }
 
-#[derive(Debug)]
-struct Data_dirs {
-    dirs: Vec<Image_data_dir>,
+#[derive(Serialize, Debug)]
+pub struct Data_dirs {
+    pub dirs: Vec<Image_data_dir>,
 }
 
-#[derive(Debug)]
-struct COFF_optional_header {
-    std_coff:   Std_COFF_header,
-    win_fields: Windows_fields,
-    data_dirs:  Data_dirs,
+#[derive(Serialize, Debug)]
+pub struct COFF_optional_header {
+    pub std_coff:   Std_COFF_header,
+    pub win_fields: Windows_fields,
+    pub data_dirs:  Data_dirs,
 }
 
-#[derive(Pread, Debug)]
-struct Section_table {
-    name:            [u8; 8],
-    virt_sz:         u32,
-    virt_addr:       u32,
-    sz_raw_data:     u32,
-    ptr_raw_data:    u32,
-    ptr_relocs:      u32,
-    ptr_linenum:     u32,
-    n_relocs:        u16,
-    n_linenum:       u16,
-    characteristics: u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Section_table {
+    pub name:            [u8; 8],
+    pub virt_sz:         u32,
+    pub virt_addr:       u32,
+    pub sz_raw_data:     u32,
+    pub ptr_raw_data:    u32,
+    pub ptr_relocs:      u32,
+    pub ptr_linenum:     u32,
+    pub n_relocs:        u16,
+    pub n_linenum:       u16,
+    pub characteristics: u32,
 }
 
-#[derive(Pread, Debug)]
-struct COFF_reloc {
-    virt_addr:  u32,
-    symtab_idx: u32,
-    c_type:     u16,
+#[derive(Serialize, Pread, Debug)]
+pub struct COFF_reloc {
+    pub virt_addr:  u32,
+    pub symtab_idx: u32,
+    pub c_type:     u16,
 }
 
-#[derive(Pread, Debug)]
-struct COFF_linenum {
-    idx:      u32,
-    line_num: u16,
+#[derive(Serialize, Pread, Debug)]
+pub struct COFF_linenum {
+    pub idx:      u32,
+    pub line_num: u16,
 }
 
-#[derive(Pread, Debug)]
-struct COFF_symbol_table {
-    name:          [u8; 8],
-    value:         u32,
-    sec_num:       u16,
-    c_type:        u16,
-    storage_class: u8,
-    n_aux_sym:     u8,
+#[derive(Serialize, Pread, Debug)]
+pub struct COFF_symbol_table {
+    pub name:          [u8; 8],
+    pub value:         u32,
+    pub sec_num:       u16,
+    pub c_type:        u16,
+    pub storage_class: u8,
+    pub n_aux_sym:     u8,
 }
 
 // #[derive(Debug)]
@@ -195,104 +192,104 @@ struct COFF_symbol_table {
 //     text: String,
 // }
 
-#[derive(Pread, Debug)]
-struct Aux_sym_record_1 {
-    tag_index:   u32,
-    total_sz:    u32,
-    ptr_linenum: u32,
-    ptr_nextfn:  u32,
-    unused:      u16,
+#[derive(Serialize, Pread, Debug)]
+pub struct Aux_sym_record_1 {
+    pub tag_index:   u32,
+    pub total_sz:    u32,
+    pub ptr_linenum: u32,
+    pub ptr_nextfn:  u32,
+    pub unused:      u16,
 }
 
-#[derive(Debug)]
-struct Attr_cert_table {
-    dw_length:   u32,
-    w_revision:  u16,
-    w_cert_type: u16,
-    b_cert:      Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct Attr_cert_table {
+    pub dw_length:   u32,
+    pub w_revision:  u16,
+    pub w_cert_type: u16,
+    pub b_cert:      Vec<u8>,
 }
 
-#[derive(Debug, Pread)]
-struct Delay_import_table {
-    attr:            u32,
-    name:            u32,
-    mod_handle:      u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Delay_import_table {
+    pub attr:            u32,
+    pub name:            u32,
+    pub mod_handle:      u32,
     /// Delay Import Address Table
-    delay_iat:       u32,
+    pub delay_iat:       u32,
     /// Delay Import Name Table
-    delay_int:       u32,
+    pub delay_int:       u32,
     /// Bound Delay Import Table
-    bound_delay_it:  u32,
+    pub bound_delay_it:  u32,
     /// Unload Delay Import Table
-    unload_delay_it: u32,
-    time_stamp:      u32,
+    pub unload_delay_it: u32,
+    pub time_stamp:      u32,
 }
 
-#[derive(Pread, Debug)]
-struct Debug_dir {
-    characteristics: u32,
-    timedate_stamp:  u16,
-    major_ver:       u16,
-    minor_ver:       u16,
-    d_type:          u32,
-    sz_data:         u32,
-    addr_data:       u32,
-    ptr_data:        u32,
-}
-
-#[derive(Debug)]
-struct Export_dir {
-    header:        Export_dir_table,
-    func_addr:     Vec<u32>,
-    func_names:    Vec<String>,
-    func_ordinals: Vec<u16>,
-    funcs:         Vec<Export_func>,
-}
-
-#[derive(Debug)]
-struct Export_func {
-    addr: u32,
-    name: String,
-    ordinal: u16,
-}
-
-
-#[derive(Pread, Debug)]
-struct Export_dir_table {
-    export_flags:        u32,
-    timedate_stamp:      u32,
-    major_ver:           u16,
-    minor_ver:           u16,
-    name_rva:            u32,
-    ord_base:            u32,
-    addr_tab_entries:    u32,
-    n_name_ptr:          u32,
-    export_addr_tab_rva: u32,
-    name_ptr_rva:        u32,
-    ord_tab_rva:         u32,
-}
-
-#[derive(Pread, Debug)]
-struct Export_addr_table {
-    export_rva: u32,
-    forwarder_rva: u32,
-}
-
-#[derive(Debug)]
-struct Import_dir {
-    header:   Import_dir_table,
-    name:     String,
-    entries:  Vec<String>,
-    ordinals: Vec<u16>,
-}
-
-#[derive(Pread, Debug)]
-struct Import_dir_table {
-    import_lkup_tab_rva: u32,
-    timedate_stamp:      u32,
-    forwarder_chain:     u32,
-    name_rva:            u32,
-    import_addr_tab_rva: u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Debug_dir {
+    pub characteristics: u32,
+    pub timedate_stamp:  u16,
+    pub major_ver:       u16,
+    pub minor_ver:       u16,
+    pub d_type:          u32,
+    pub sz_data:         u32,
+    pub addr_data:       u32,
+    pub ptr_data:        u32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Export_dir {
+    pub header:        Export_dir_table,
+    pub func_addr:     Vec<u32>,
+    pub func_names:    Vec<String>,
+    pub func_ordinals: Vec<u16>,
+    pub funcs:         Vec<Export_func>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Export_func {
+    pub addr: u32,
+    pub name: String,
+    pub ordinal: u16,
+}
+
+
+#[derive(Serialize, Pread, Debug)]
+pub struct Export_dir_table {
+    pub export_flags:        u32,
+    pub timedate_stamp:      u32,
+    pub major_ver:           u16,
+    pub minor_ver:           u16,
+    pub name_rva:            u32,
+    pub ord_base:            u32,
+    pub addr_tab_entries:    u32,
+    pub n_name_ptr:          u32,
+    pub export_addr_tab_rva: u32,
+    pub name_ptr_rva:        u32,
+    pub ord_tab_rva:         u32,
+}
+
+#[derive(Serialize, Pread, Debug)]
+pub struct Export_addr_table {
+    pub export_rva: u32,
+    pub forwarder_rva: u32,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Import_dir {
+    pub header:   Import_dir_table,
+    pub name:     String,
+    pub entries:  Vec<String>,
+    pub ordinals: Vec<u16>,
+}
+
+#[derive(Serialize, Pread, Debug)]
+pub struct Import_dir_table {
+    pub import_lkup_tab_rva: u32,
+    pub timedate_stamp:      u32,
+    pub forwarder_chain:     u32,
+    pub name_rva:            u32,
+    pub import_addr_tab_rva: u32,
 }
 
 impl Import_dir_table {
@@ -302,7 +299,7 @@ impl Import_dir_table {
     }
 }
 
-#[derive(Pread, Debug)]
+#[derive(Serialize, Pread, Debug)]
 struct Tls_dir_32 {
     data_start_rva:  u32,
     data_end_rva:    u32,
@@ -312,66 +309,185 @@ struct Tls_dir_32 {
     characteristics: u32,
 }
 
-#[derive(Pread, Debug)]
-struct Tls_dir {
-    data_start_rva:  u64,
-    data_end_rva:    u64,
-    idx_addr:        u64,
-    callback_addr:   u64,
-    sz_zero_fill:    u32,
-    characteristics: u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Tls_dir {
+    pub data_start_rva:  u64,
+    pub data_end_rva:    u64,
+    pub idx_addr:        u64,
+    pub callback_addr:   u64,
+    pub sz_zero_fill:    u32,
+    pub characteristics: u32,
 }
 
-#[derive(Pread, Debug)]
-struct Rsrc_dir_tab {
-    characteristics: u32,
-    timedate_stamp:  u32,
-    major_ver:       u16,
-    minor_ver:       u16,
-    n_name_entries:  u16,
-    n_id_entries:    u16,
+#[derive(Serialize, Pread, Debug)]
+pub struct Rsrc_dir_tab {
+    pub characteristics: u32,
+    pub timedate_stamp:  u32,
+    pub major_ver:       u16,
+    pub minor_ver:       u16,
+    pub n_name_entries:  u16,
+    pub n_id_entries:    u16,
 }
 
-#[derive(Pread, Debug)]
-struct Rsrc_dir_entry {
-    name_id: u32,
-    data_subdir: u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Rsrc_dir_entry {
+    pub name_id: u32,
+    pub data_subdir: u32,
 }
 
-#[derive(Pread, Debug)]
-struct Rsrc_data_entry {
-    data_rva: u32,
-    size:     u32,
-    codepage: u32,
-    reserved: u32,
+#[derive(Serialize, Pread, Debug)]
+pub struct Rsrc_data_entry {
+    pub data_rva: u32,
+    pub size:     u32,
+    pub codepage: u32,
+    pub reserved: u32,
 }
 
-#[derive(Debug)]
+/// Parsed PE image: COFF headers, section table and the export, import and
+/// resource directories.
+#[derive(Serialize, Debug)]
 pub struct Pe {
-    opt:                  Opt,
+    pub coff:                 COFF_header,
+    pub coff_optional_header: Option<COFF_optional_header>,
+
+    pub sections:             Vec<Section_table>,
+
+    pub exports:              Option<Export_dir>,
+    pub imports:              Vec<Import_dir>,
+    pub resources:            Option<Rsrc_dir_tab>,
+}
+
+/// File offset of `rva` if it lies inside `sect`.
+fn rva_offset(sect: &Section_table, rva: u32) -> Option<usize> {
+    if rva >= sect.virt_addr && rva - sect.virt_addr < sect.virt_sz {
+        Some(sect.ptr_raw_data as usize + (rva - sect.virt_addr) as usize)
+    }
+    else {
+        None
+    }
+}
+
+/// File offset of `rva`, which must point into the same section as its directory.
+fn rva_to_offset(sect: &Section_table, rva: u32, what: &str) -> Result<usize, Error> {
+    rva.checked_sub(sect.virt_addr)
+        .map(|diff| sect.ptr_raw_data as usize + diff as usize)
+        .ok_or_else(|| Error::from(Problem::msg(format!("Invalid {} RVA {:#X}", what, rva))))
+}
 
-    coff:                 COFF_header,
-    coff_optional_header: Option<COFF_optional_header>,
+/// Export directory whose table is at `dir_offset` in `sect`.
+fn export_dir(buf: &[u8], sect: &Section_table, dir_offset: usize) -> Result<Export_dir, Error> {
+    let header = buf.pread_with::<Export_dir_table>(dir_offset, scroll::LE)
+        .at(dir_offset as u64, "export directory table")?;
+
+    let addr_offset = rva_to_offset(sect, header.export_addr_tab_rva, "export address table")?;
+    let func_addr: Vec<u32> = table(buf, addr_offset as u64, header.addr_tab_entries as u64 * 4, 4, "export address",
+                                    |off| buf.pread_with(off, scroll::LE))?;
+
+    let names_offset = rva_to_offset(sect, header.name_ptr_rva, "export name table")?;
+    let name_rvas: Vec<u32> = table(buf, names_offset as u64, header.n_name_ptr as u64 * 4, 4, "export name pointer",
+                                    |off| buf.pread_with(off, scroll::LE))?;
+    let mut func_names = Vec::with_capacity(name_rvas.len());
+    for name_rva in name_rvas {
+        let name_offset = rva_to_offset(sect, name_rva, "export name")?;
+        func_names.push(buf.pread::<&str>(name_offset).at(name_offset as u64, "export name")?.to_string());
+    }
+
+    let ordinals_offset = rva_to_offset(sect, header.ord_tab_rva, "export ordinal table")?;
+    let func_ordinals: Vec<u16> = table(buf, ordinals_offset as u64, header.n_name_ptr as u64 * 2, 2, "export ordinal",
+                                        |off| buf.pread_with(off, scroll::LE))?;
+    let mut funcs = Vec::with_capacity(func_addr.len());
 
-    sections:             Vec<Section_table>,
+    // https://stackoverflow.com/questions/5653316/pe-export-directory-tables-ordinalbase-field-ignored
+    for (name, ordinal) in func_names.iter().zip(&func_ordinals) {
+        let addr = func_addr.get(*ordinal as usize)
+            .ok_or_else(|| Problem::msg(format!("Export ordinal {} out of range of the address table", ordinal)))?;
+        funcs.push( Export_func { addr: *addr, name: name.clone(), ordinal: ordinal.wrapping_add(header.ord_base as u16) } );
+    }
+    for i in funcs.len()..func_addr.len() {
+        funcs.push( Export_func { addr: func_addr[i], name: "[NONAME]".to_owned(), ordinal: i as u16 } )
+    }
 
-    exports:              Option<Export_dir>,
-    imports:              Vec<Import_dir>,
-    resources:            Option<Rsrc_dir_tab>,
+    Ok(Export_dir {
+        header,
+        func_addr,
+        func_names,
+        func_ordinals,
+        funcs,
+    })
+}
+
+/// Functions imported from the library described by `header`.
+fn import_dir(buf: &[u8], sect: &Section_table, header: Import_dir_table, is_64: bool) -> Result<Import_dir, Error> {
+    // Original First Thunk
+    let entry_offset = &mut if header.import_lkup_tab_rva != 0 {
+        rva_to_offset(sect, header.import_lkup_tab_rva, "import lookup table")?
+    }
+    // First Thunk
+    else {
+        rva_to_offset(sect, header.import_addr_tab_rva, "import address table")?
+    };
+    let mut entries = Vec::new();
+    let mut ordinals = Vec::new();
+    // 64bit
+    if is_64 {
+        let mut entry = buf.gread_with::<u64>(entry_offset, scroll::LE)
+            .at(*entry_offset as u64, "import lookup entry")?;
+        while entry != 0 {
+            if entry & 0x8000000000000000 != 0 {
+                // by ordinal
+                ordinals.push((entry & 0x0000ffff) as u16);
+            }
+            else {
+                // by name
+                let name_offset = rva_to_offset(sect, entry as u32, "import name")?;
+                entries.push(buf.pread::<&str>(name_offset + 2).at(name_offset as u64 + 2, "import name")?.to_string());
+            }
+            entry = buf.gread_with::<u64>(entry_offset, scroll::LE)
+                .at(*entry_offset as u64, "import lookup entry")?;
+        }
+    }
+
+    // 32bit
+    else {
+        let mut entry = buf.gread_with::<u32>(entry_offset, scroll::LE)
+            .at(*entry_offset as u64, "import lookup entry")?;
+        while entry != 0 {
+            if entry & 0x80000000 != 0 {
+                // by ordinal
+                ordinals.push((entry & 0x0000ffff) as u16);
+            }
+            else {
+                // by name
+                let name_offset = rva_to_offset(sect, entry, "import name")?;
+                entries.push(buf.pread::<&str>(name_offset + 2).at(name_offset as u64 + 2, "import name")?.to_string());
+            }
+            entry = buf.gread_with::<u32>(entry_offset, scroll::LE)
+                .at(*entry_offset as u64, "import lookup entry")?;
+        }
+    }
+    let name_offset = rva_to_offset(sect, header.name_rva, "import library name")?;
+    let name = buf.pread::<&str>(name_offset).at(name_offset as u64, "import library name")?.to_string();
+    Ok(Import_dir { header, name, entries, ordinals })
 }
 
 impl super::FileFormat for Pe {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
+        Pe::parse_with(buf, &mut Recovery::strict())
+    }
+
+    fn parse_with(buf: &[u8], rec: &mut Recovery) -> Result<Self, Error> {
 
-        let pe_sig = buf.pread::<u32>(PE_SIGNATURE_OFFSET)? as usize;
+        let pe_sig = buf.pread::<u32>(PE_SIGNATURE_OFFSET)
+            .at(PE_SIGNATURE_OFFSET as u64, "PE signature offset")? as usize;
 
-        if &buf[pe_sig..pe_sig + 4] != PE_SIGNATURE {
-            return Err(Error::from(Problem::Msg(format!("Invalid PE signature"))));
+        if bytes(buf, pe_sig as u64, 4, "PE signature")? != PE_SIGNATURE {
+            return Err(Error::from(Problem::msg("Invalid PE signature").at(pe_sig as u64)));
         }
 
-        let coff = buf.pread_with::<COFF_header>(pe_sig + COFF_HEADER_OFFSER, scroll::LE)?;
+        let coff = buf.pread_with::<COFF_header>(pe_sig + COFF_HEADER_OFFSER, scroll::LE)
+            .at((pe_sig + COFF_HEADER_OFFSER) as u64, "COFF header")?;
         let mut coff_optional_header = None;
         let win_fields;
         let data_dirs;
@@ -380,45 +496,38 @@ impl super::FileFormat for Pe {
         let mut imports   = Vec::new();
         let mut resources = None;
 
-        let mut sections: Vec<Section_table> = Vec::with_capacity(coff.n_of_sections as usize);
+        let mut sections: Vec<Section_table> = Vec::new();
 
         if coff.sz_of_opt_header > 0 {
-            let mut std_coff = buf.pread_with::<Std_COFF_header>(pe_sig + STD_COFF_HEADER_OFFSET, scroll::LE)?;
+            let mut std_coff = buf.pread_with::<Std_COFF_header>(pe_sig + STD_COFF_HEADER_OFFSET, scroll::LE)
+                .at((pe_sig + STD_COFF_HEADER_OFFSET) as u64, "standard COFF fields")?;
 
+            let sections_offset;
             if std_coff.magic == PE32PLUS_MAGIC {
                 std_coff.base_of_data = 0;
-                win_fields = buf.pread_with(pe_sig + PE32PLUS_WIN_FIELDS_OFFSET, scroll::LE)?;
-                let mut dirs = Vec::with_capacity(16);
-
-                for i in 0..16 {
-                    dirs.push(buf.pread_with(pe_sig + PE32PLUS_DATA_DIRS_OFFSET + i * 8, scroll::LE)?);
-                }
+                win_fields = buf.pread_with(pe_sig + PE32PLUS_WIN_FIELDS_OFFSET, scroll::LE)
+                    .at((pe_sig + PE32PLUS_WIN_FIELDS_OFFSET) as u64, "Windows specific fields")?;
                 data_dirs = Data_dirs {
-                    dirs,
+                    dirs: table(buf, (pe_sig + PE32PLUS_DATA_DIRS_OFFSET) as u64, 16 * 8, 8, "data directory",
+                                |off| buf.pread_with(off, scroll::LE))?,
                 };
-
-                for i in 0..coff.n_of_sections as usize {
-                    sections.push(buf.pread_with(pe_sig + PE32PLUS_SECTIONS_OFFSET + i * 40, scroll::LE)?);
-                }
+                sections_offset = pe_sig + PE32PLUS_SECTIONS_OFFSET;
             }
             else if std_coff.magic == PE32_MAGIC {
-                win_fields = Windows_fields::from(buf.pread_with::<Windows_fields_32>(pe_sig + PE32_WIN_FIELDS_OFFSET, scroll::LE)?);
-                let mut dirs = Vec::with_capacity(16);
-
-                for i in 0..16 {
-                    dirs.push(buf.pread_with(pe_sig + PE32_DATA_DIRS_OFFSET + i * 8, scroll::LE)?);
-                }
-                data_dirs = Data_dirs{
-                    dirs,
+                win_fields = Windows_fields::from(buf.pread_with::<Windows_fields_32>(pe_sig + PE32_WIN_FIELDS_OFFSET, scroll::LE)
+                                                  .at((pe_sig + PE32_WIN_FIELDS_OFFSET) as u64, "Windows specific fields")?);
+                data_dirs = Data_dirs {
+                    dirs: table(buf, (pe_sig + PE32_DATA_DIRS_OFFSET) as u64, 16 * 8, 8, "data directory",
+                                |off| buf.pread_with(off, scroll::LE))?,
                 };
-
-                for i in 0..coff.n_of_sections as usize {
-                    sections.push(buf.pread_with(pe_sig + PE32_SECTIONS_OFFSET + i * 40, scroll::LE)?);
-                }
+                sections_offset = pe_sig + PE32_SECTIONS_OFFSET;
             }
             else {
-                return Err(Error::from(Problem::Msg(format!("Invalid PE magic"))));
+                return Err(Error::from(Problem::msg(format!("Invalid PE magic {:#X}", std_coff.magic))
+                                       .at((pe_sig + STD_COFF_HEADER_OFFSET) as u64)));
             }
+            sections = rec.table(buf, sections_offset as u64, coff.n_of_sections as u64 * 40, 40, "section header",
+                                 |off| buf.pread_with(off, scroll::LE))?;
             coff_optional_header = Some(COFF_optional_header {
                 std_coff,
                 win_fields,
@@ -433,119 +542,33 @@ impl super::FileFormat for Pe {
                     match i {
                         0 => {
                             for sect in &sections {
-                                if dir.rva >= sect.virt_addr && dir.rva < sect.virt_addr + sect.virt_sz {
-                                    let header = buf.pread_with::<Export_dir_table>(sect.ptr_raw_data as usize + (dir.rva - sect.virt_addr) as usize, scroll::LE)?;
-                                    let mut func_addr  = Vec::with_capacity(header.addr_tab_entries as usize);
-
-                                    let addr_offset = &mut (sect.ptr_raw_data as usize + (header.export_addr_tab_rva - sect.virt_addr) as usize);
-                                    for _ in 0..header.addr_tab_entries as usize {
-                                        func_addr.push(buf.gread_with(addr_offset, scroll::LE)?);
-                                    }
-                                    let mut func_names = Vec::with_capacity(header.n_name_ptr as usize);
-
-                                    let names_offset = &mut (sect.ptr_raw_data as usize + (header.name_ptr_rva - sect.virt_addr) as usize);
-                                    for _ in 0..header.n_name_ptr as usize {
-                                        let name_rva = sect.ptr_raw_data as usize + (buf.gread_with::<u32>(names_offset, scroll::LE)? - sect.virt_addr) as usize;
-                                        //TODO do name demangling
-                                        func_names.push(buf.pread::<&str>(name_rva)?.to_string());
-                                    }
-                                    let mut func_ordinals = Vec::with_capacity(header.n_name_ptr as usize);
-
-                                    let ordinals_offset = &mut (sect.ptr_raw_data as usize + (header.ord_tab_rva - sect.virt_addr) as usize);
-                                    for _ in 0..header.n_name_ptr as usize {
-                                        func_ordinals.push(buf.gread_with(ordinals_offset, scroll::LE)?);
-                                    }
-                                    let mut funcs = Vec::with_capacity(header.addr_tab_entries as usize);
-
-                                    // https://stackoverflow.com/questions/5653316/pe-export-directory-tables-ordinalbase-field-ignored
-                                    for i in 0..header.n_name_ptr as usize {
-                                        let ordinal =  func_ordinals[i] + header.ord_base as u16;
-                                        funcs.push( Export_func { addr: func_addr[ordinal as usize - header.ord_base as usize] , name: func_names[i].clone(), ordinal } );
-                                    }
-                                    for i in funcs.len()..header.addr_tab_entries as usize {
-                                        funcs.push( Export_func { addr: func_addr[i], name: "[NONAME]".to_owned(), ordinal: i as u16 } )
-                                    }
-
-
-
-                                    exports = Some(Export_dir {
-                                        header,
-                                        func_addr,
-                                        func_names,
-                                        func_ordinals,
-                                        funcs,
-                                    });
+                                if let Some(dir_offset) = rva_offset(sect, dir.rva) {
+                                    exports = rec.recover(export_dir(buf, sect, dir_offset).map(Some), None)?;
                                 }
                             }
                         },
                         1 => {
                             for sect in &sections {
-                                if dir.rva >= sect.virt_addr && dir.rva < sect.virt_addr + sect.virt_sz {
-                                    let offset = &mut (sect.ptr_raw_data as usize + (dir.rva - sect.virt_addr) as usize);
-                                    let mut header = buf.gread_with::<Import_dir_table>(offset, scroll::LE)?;
-                                    while !header.is_null() {
-                                        let entry_offset = &mut 0;
-                                        // Original First Thunk
-                                        if header.import_lkup_tab_rva != 0 {
-                                            *entry_offset += sect.ptr_raw_data as usize + (header.import_lkup_tab_rva - sect.virt_addr) as usize;
-                                        }
-                                        // First Thunk
-                                        else {
-                                            *entry_offset += sect.ptr_raw_data as usize + (header.import_addr_tab_rva - sect.virt_addr) as usize;
-                                        }
-                                        // 64bit
-                                        if opt_header.std_coff.magic == PE32PLUS_MAGIC {
-                                            let mut entry = buf.gread_with::<u64>(entry_offset, scroll::LE)?;
-                                            let mut entries = Vec::new();
-                                            let mut ordinals = Vec::new();
-                                            while entry != 0 {
-                                                if entry & 0x8000000000000000 != 0 {
-                                                    // by ordinal
-                                                    ordinals.push((entry & 0x0000ffff) as u16);
-                                                }
-                                                else {
-                                                    entry = sect.ptr_raw_data as u64 + (entry - sect.virt_addr as u64);
-                                                    // by name
-                                                    let name = buf.pread::<&str>(entry as usize + 2)?.to_string();
-                                                    entries.push(name);
-                                                }
-                                                entry = buf.gread_with::<u64>(entry_offset, scroll::LE)?;
-                                            }
-                                            let name = buf.pread::<&str>(sect.ptr_raw_data as usize + (header.name_rva - sect.virt_addr) as usize)?.to_string();
-                                            imports.push( Import_dir { header, name, entries, ordinals });
+                                if let Some(dir_offset) = rva_offset(sect, dir.rva) {
+                                    let offset = &mut { dir_offset };
+                                    let mut header = rec.recover(buf.gread_with::<Import_dir_table>(offset, scroll::LE)
+                                                                 .at(*offset as u64, "import directory table").map(Some), None)?;
+                                    while let Some(dir) = header.filter(|dir| !dir.is_null()) {
+                                        let is_64 = opt_header.std_coff.magic == PE32PLUS_MAGIC;
+                                        if let Some(import) = rec.recover(import_dir(buf, sect, dir, is_64).map(Some), None)? {
+                                            imports.push(import);
                                         }
-
-                                        // 32bit
-                                        else {
-                                            let mut entry = buf.gread_with::<u32>(entry_offset, scroll::LE)?;
-                                            println!("Offset: {:#X}", *entry_offset);
-                                            let mut entries = Vec::new();
-                                            let mut ordinals = Vec::new();
-                                            while entry != 0 {
-                                                if entry & 0x80000000 != 0 {
-                                                    // by ordinal
-                                                    ordinals.push((entry & 0x0000ffff) as u16);
-                                                }
-                                                else {
-                                                    entry = sect.ptr_raw_data + (entry - sect.virt_addr);
-                                                    // by name
-                                                    let name = buf.pread::<&str>(entry as usize + 2)?.to_string();
-                                                    entries.push(name);
-                                                }
-                                                entry = buf.gread_with::<u32>(entry_offset, scroll::LE)?;
-                                            }
-                                            let name = buf.pread::<&str>(sect.ptr_raw_data as usize + (header.name_rva - sect.virt_addr) as usize)?.to_string();
-                                            imports.push( Import_dir { header, name, entries, ordinals });
-                                        }
-                                        header = buf.gread_with::<Import_dir_table>(offset, scroll::LE)?;
+                                        header = rec.recover(buf.gread_with::<Import_dir_table>(offset, scroll::LE)
+                                                             .at(*offset as u64, "import directory table").map(Some), None)?;
                                     }
                                 }
                             }
                         },
                         2  => {
                             for sect in &sections {
-                                if dir.rva >= sect.virt_addr && dir.rva < sect.virt_addr + sect.virt_sz {
-                                    resources = Some(buf.pread_with(sect.ptr_raw_data as usize + (dir.rva - sect.virt_addr) as usize, scroll::LE)?);
+                                if let Some(dir_offset) = rva_offset(sect, dir.rva) {
+                                    resources = rec.recover(buf.pread_with(dir_offset, scroll::LE)
+                                                            .at(dir_offset as u64, "resource directory table").map(Some), None)?;
                                 }
                             }
                         },
@@ -557,8 +580,6 @@ impl super::FileFormat for Pe {
         }
 
         Ok(Pe {
-            opt,
-
             coff,
             coff_optional_header,
 
@@ -571,280 +592,5 @@ impl super::FileFormat for Pe {
 
     }
 
-    fn print(&self) -> Result<(), Error> {
-        use prettytable::Table;
-        // println!("{:#X?}", self);
-
-        //
-        // PE HEADER
-        //
-        fmt_pe(&self.coff);
-
-        if let Some(opt) = &self.coff_optional_header {
-            //
-            // OPTIONAL HEADER
-            //
-            println!("{}", Color::White.underline().paint("Optional Header"));
-            fmt_indentln(format!("Major linker version: {}, Minor linker version: {}",
-                     opt.std_coff.major_link_ver,
-                     opt.std_coff.minor_link_ver));
-            fmt_indentln(format!("Size of code: {}", Color::Green.paint(format!("{:#X}", opt.std_coff.sz_of_code))));
-            fmt_indentln(format!("Size of initialized: {}, Size of uninitialized: {}",
-                                 Color::Green.paint(format!("{:#X}", opt.std_coff.sz_of_init)),
-                                 Color::Green.paint(format!("{:#X}", opt.std_coff.sz_of_uninit))));
-            fmt_indentln(format!("Entry point: {}", Color::Red.paint(format!("{:#X}", opt.std_coff.addr_of_entry))));
-            fmt_indentln(format!("Base of code: {}", Color::Red.paint(format!("{:#X}", opt.std_coff.base_of_code))));
-            if opt.std_coff.magic == PE32_MAGIC {
-                fmt_indentln(format!("Base of data: {}", Color::Red.paint(format!("{:#X}", opt.std_coff.base_of_data))));
-            }
-
-            //
-            // WINDOWS FIELDS
-            //
-            println!();
-            println!("{}", Color::White.underline().paint("Windows Fields"));
-            fmt_indentln(format!("Image base: {}", Color::Red.paint(format!("{:#X}", opt.win_fields.image_base))));
-            fmt_indentln(format!("Section alignment: {:#X}, File alignment: {:#X}",
-                                 opt.win_fields.section_align,
-                                 opt.win_fields.file_align));
-            fmt_indentln(format!("Major OS version: {}, Minor OS version: {}",
-                                 opt.win_fields.major_os_ver,
-                                 opt.win_fields.minor_os_ver));
-            fmt_indentln(format!("Major image version: {}, Minor image version: {}",
-                                 opt.win_fields.major_img_ver,
-                                 opt.win_fields.minor_img_ver));
-            fmt_indentln(format!("Major subsys version: {}, Minor subsys version: {}",
-                                 opt.win_fields.major_sub_ver,
-                                 opt.win_fields.minor_sub_ver));
-            fmt_indentln(format!("Size of image: {}, Size of headers: {}",
-                                 Color::Green.paint(format!("{:#X}", opt.win_fields.sz_of_img)),
-                                 Color::Green.paint(format!("{:#X}", opt.win_fields.sz_of_headers))));
-            fmt_indentln(format!("Checksum: {:#X}", opt.win_fields.checksum));
-            fmt_indentln(format!("Subsystem: {}", Color::White.paint(subsys_to_str(opt.win_fields.subsys))));
-            fmt_indentln(format!("DLL Characteristics: {}", Color::White.paint(dllchara_to_str(opt.win_fields.dll_chara))));
-            fmt_indentln(format!("Size of stack commit: {}, Size of stack reserve: {}",
-                                 Color::Green.paint(format!("{:#X}", opt.win_fields.sz_stack_commit)),
-                                 Color::Green.paint(format!("{:#X}", opt.win_fields.sz_stack_reserve))));
-            fmt_indentln(format!("Size of heap commit: {}, Size of heap reserve: {}",
-                                 Color::Green.paint(format!("{:#X}", opt.win_fields.sz_heap_commit)),
-                                 Color::Green.paint(format!("{:#X}", opt.win_fields.sz_heap_reserve))));
-            fmt_indentln(format!("Loader flags: {:#X}", opt.win_fields.loader_flags));
-            fmt_indentln(format!("Number of Rva and Sizes: {}",
-                                 Color::Purple.paint(format!("{:#X}", opt.win_fields.n_of_rva))));
-
-            //
-            // DATA DIRECTORIES
-            //
-            println!();
-            println!("{}", Color::White.underline().paint("Data Directories"));
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row![r->"Idx", "Name", "VirtAddr", "Size"]);
-            for (i, data) in opt.data_dirs.dirs.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    DATA_DIRS[i],
-                    Fr->format!("{:#X}", data.rva),
-                    Fg->format!("{:#X}", data.sz),
-                ]);
-            }
-
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-
-            println!();
-        }
-
-        //
-        // SECTIONS
-        //
-        if self.sections.len() > 0 {
-            println!("{}({})",
-                     Color::White.underline().paint("Sections"),
-                     self.sections.len());
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row![r->"Idx", "Name", "VirtSz", "VirtAddr", "SzRawData",
-            "PtrRawData", "PtrRelocs", "PtrLineNum", "nRelocs", "nLinenum", "Characteristics"]);
-
-            for (i, sec) in self.sections.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    std::str::from_utf8(&sec.name)?,
-                    Fg->format!("{:#X}",sec.virt_sz),
-                    Fr->format!("{:#X}",sec.virt_addr),
-                    Fg->format!("{:#X}",sec.sz_raw_data),
-                    Fr->format!("{:#X}",sec.ptr_raw_data),
-                    Fr->format!("{:#X}",sec.ptr_relocs),
-                    Fr->format!("{:#X}",sec.ptr_linenum),
-                    Fm->format!("{:#X}",sec.n_relocs),
-                    Fm->format!("{:#X}",sec.n_linenum),
-                    section_chara_to_str(sec.characteristics),
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // IMPORTS
-        //
-        if self.imports.len() >= 1 {
-            let sum = self.imports.iter().fold(0, |sum, i| {
-                sum + i.entries.len() + i.ordinals.len()
-            });
-            println!("{}({})",
-                     Color::White.underline().paint("Imports"),
-                     sum);
-
-            for imp in &self.imports {
-                fmt_indentln(format!("{}({})({})",
-                         Color::Fixed(75).paint(&imp.name),
-                         imp.entries.len(),
-                         imp.ordinals.len()));
-
-                if imp.entries.len() >= 1 {
-
-                    let mut trimmed = false;
-                    let mut table = Table::new();
-                    let format = prettytable::format::FormatBuilder::new()
-                        .borders(' ')
-                        .column_separator(' ')
-                        .padding(1, 1)
-                        .build();
-                    table.set_format(format);
-                    table.add_row(row![" ", r->"Idx", "Name"]);
-
-                    for (i, entry) in imp.entries.iter().enumerate() {
-                        if i == self.opt.trim_lines {
-                            trimmed = true;
-                            break;
-                        }
-                        table.add_row(row![
-                            " ",
-                            i,
-                            Fy->entry,
-                        ]);
-                    }
-                    table.printstd();
-                    if trimmed {
-                        fmt_indentln(format!("Output trimmed..."));
-                    }
-                    println!();
-                }
-                if imp.ordinals.len() >= 1 {
-
-                    let mut trimmed = false;
-                    let mut table = Table::new();
-                    let format = prettytable::format::FormatBuilder::new()
-                        .borders(' ')
-                        .column_separator(' ')
-                        .padding(1, 1)
-                        .build();
-                    table.set_format(format);
-                    table.add_row(row![" ", r->"Idx", "Ordinal"]);
-
-                    for (i, entry) in imp.ordinals.iter().enumerate() {
-                        if i == self.opt.trim_lines {
-                            trimmed = true;
-                            break;
-                        }
-                        table.add_row(row![
-                            " ",
-                            i,
-                            Fb->entry,
-                        ]);
-                    }
-                    table.printstd();
-                    if trimmed {
-                        fmt_indentln(format!("Output trimmed..."));
-                    }
-                    println!();
-                }
-            }
-            println!();
-        }
-
-        //
-        // EXPORTS
-        //
-        if let Some(exports) = &self.exports {
-            println!("{}({})",
-                     Color::White.underline().paint("Exports"),
-                     exports.func_addr.len());
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .borders(' ')
-                .column_separator(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Addr", "Name", "Ordinal"]);
-
-            for (i, entry) in exports.funcs.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![
-                    i,
-                    Color::Red.paint(format!("{:#X}", entry.addr)),
-                    Fy->entry.name,
-                    Fb->entry.ordinal,
-                ]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-
-        }
-
-        //
-        // LIBRARIES
-        //
-        if self.imports.len() >= 1 {
-            println!("{}({})",
-                     Color::White.underline().paint("Libraries"),
-                     self.imports.len());
-
-            for lib in &self.imports {
-                fmt_indentln(format!("{}", Color::Blue.paint(&lib.name)));
-            }
-            println!();
-        }
-
-        Ok(())
-    }
-
 
 }
@@ -1,19 +1,17 @@
 use scroll::{self, Pread};
-use ansi_term::Color;
 use failure::Error;
 
-use crate::Opt;
-use crate::Problem;
-use crate::format::{fmt_indent, fmt_indentln, fmt_png_header};
+use crate::{Problem, ResultExt};
+use crate::formats::bytes;
 
 pub const PNG_HEADER: &'static [u8; PNG_HEADER_SIZE] = b"\x89PNG\x0D\x0A\x1A\x0A";
 pub const PNG_HEADER_SIZE: usize = 8;
 
-#[derive(Debug, Pread, PartialEq, Eq)]
+#[derive(Serialize, Debug, Pread, PartialEq, Eq)]
 #[repr(C)]
 pub struct Prefix {
     pub size: u32,
-    id:       u32,
+    pub id:       u32,
 }
 
 impl Prefix {
@@ -21,14 +19,14 @@ impl Prefix {
     #[inline]
     pub fn new(buf: &[u8], index: usize) -> Result<Self, Error> {
         let prefix = buf.pread_with(index, scroll::BE)
-            .map_err(|e| Problem::Msg(format!("Could not read chunk checksum: {}", e)))?;
+            .at(index as u64, "chunk prefix")?;
 
         Ok(prefix)
     }
 
 }
 
-#[derive(Debug, Pread, PartialEq, Eq)]
+#[derive(Serialize, Debug, Pread, PartialEq, Eq)]
 #[repr(C)]
 pub struct Postfix {
     pub checksum: u32,
@@ -39,74 +37,74 @@ impl Postfix {
     #[inline]
     pub fn new(buf: &[u8], index: usize) -> Result<Self, Error> {
         let postfix = buf.pread_with(index, scroll::BE)
-            .map_err(|e| Problem::Msg(format!("Could not read chunk checksum: {}", e)))?;
+            .at(index as u64, "chunk checksum")?;
 
         Ok(postfix)
     }
 
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct RGB {
-    red:     u8,
-    green:   u8,
-    blue:    u8,
+pub struct RGB {
+    pub red:     u8,
+    pub green:   u8,
+    pub blue:    u8,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct RGB_16 {
-    red:     u16,
-    green:   u16,
-    blue:    u16,
+pub struct RGB_16 {
+    pub red:     u16,
+    pub green:   u16,
+    pub blue:    u16,
 }
 
-#[derive(Debug, Pread, PartialEq, Eq)]
+#[derive(Serialize, Debug, Pread, PartialEq, Eq)]
 #[repr(C)]
-struct Ihdr {
-    prefix:      Prefix,
-    width:       i32,
-    height:      i32,
-    bpp:         u8,
-    color:       u8,
-    compression: u8,
-    filter:      u8,
-    interlace:   u8,
-    postfix:     Postfix,
+pub struct Ihdr {
+    pub prefix:      Prefix,
+    pub width:       i32,
+    pub height:      i32,
+    pub bpp:         u8,
+    pub color:       u8,
+    pub compression: u8,
+    pub filter:      u8,
+    pub interlace:   u8,
+    pub postfix:     Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Plte {
-    prefix:  Prefix,
-    rgb:     Vec<RGB>,
-    postfix: Postfix,
+pub struct Plte {
+    pub prefix:  Prefix,
+    pub rgb:     Vec<RGB>,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-// https://tools.ietf.org/html/rfc1950#page-5
-struct Zlib {
+/// https://tools.ietf.org/html/rfc1950#page-5
+pub struct Zlib {
     // bits 0 to 3  CM     Compression method
     // bits 4 to 7  CINFO  Compression info
-    cmf: u8,
+    pub cmf: u8,
 
     // bits 0 to 4  FCHECK  (check bits for CMF and FLG)
     // bit  5       FDICT   (preset dictionary)
     // bits 6 to 7  FLEVEL  (compression level)
-    flg:     u8,
-    deflate: Deflate,
-    adler:   u32,
+    pub flg:     u8,
+    pub deflate: Deflate,
+    pub adler:   u32,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-// https://tools.ietf.org/html/rfc1951#page-11
-struct Deflate {
+/// https://tools.ietf.org/html/rfc1951#page-11
+pub struct Deflate {
     // first bit       BFINAL
     // next 2 bits     BTYPE
-    flag: u8,
+    pub flag: u8,
     // 3.2.4. Non-compressed blocks (BTYPE=00)
 
     //     Any bits of input up to the next byte boundary are ignored.
@@ -119,151 +117,151 @@ struct Deflate {
 
     //     LEN is the number of data bytes in the block.  NLEN is the
     //     one's complement of LEN.
-    len: u16,
-    nlen: u16,
+    pub len: u16,
+    pub nlen: u16,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Idat {
-    prefix: Prefix,
-    postfix: Postfix,
+pub struct Idat {
+    pub prefix: Prefix,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread, PartialEq, Eq)]
+#[derive(Serialize, Debug, Pread, PartialEq, Eq)]
 #[repr(C)]
-struct Iend {
-    prefix:  Prefix,
-    postfix: Postfix,
+pub struct Iend {
+    pub prefix:  Prefix,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Bkgd {
-    prefix:  Prefix,
-    color:   bKGD,
-    postfix: Postfix,
+pub struct Bkgd {
+    pub prefix:  Prefix,
+    pub color:   bKGD,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Bkgd3 {
-    index: u8,
+pub struct Bkgd3 {
+    pub index: u8,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Bkgd04 {
-    gray: u16,
+pub struct Bkgd04 {
+    pub gray: u16,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Bkgd26 {
-    red:   u16,
-    green: u16,
-    blue : u16,
+pub struct Bkgd26 {
+    pub red:   u16,
+    pub green: u16,
+    pub blue : u16,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[allow(non_camel_case_types)]
-enum bKGD {
+pub enum bKGD {
     bKGD3(Bkgd3),
     bKGD04(Bkgd04),
     bKGD26(Bkgd26),
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Chrm {
-    prefix:  Prefix,
-    white_x: u32,
-    white_y: u32,
-    red_x:   u32,
-    red_y:   u32,
-    green_x: u32,
-    green_y: u32,
-    blue_x:  u32,
-    blue_y:  u32,
-    postfix: Postfix,
+pub struct Chrm {
+    pub prefix:  Prefix,
+    pub white_x: u32,
+    pub white_y: u32,
+    pub red_x:   u32,
+    pub red_y:   u32,
+    pub green_x: u32,
+    pub green_y: u32,
+    pub blue_x:  u32,
+    pub blue_y:  u32,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Gama {
-    prefix:  Prefix,
-    gamma:   u32,
-    postfix: Postfix,
+pub struct Gama {
+    pub prefix:  Prefix,
+    pub gamma:   u32,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Hist {
-    prefix:  Prefix,
-    entries: Vec<u16>,
+pub struct Hist {
+    pub prefix:  Prefix,
+    pub entries: Vec<u16>,
     // Sum of all entries
-    sum:     u64,
-    postfix: Postfix,
+    pub sum:     u64,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Phys {
-    prefix:  Prefix,
-    ppu_x:   u32,
-    ppu_y:   u32,
+pub struct Phys {
+    pub prefix:  Prefix,
+    pub ppu_x:   u32,
+    pub ppu_y:   u32,
     //0: unit is unknown
     //1: unit is the meter
-    unit:    u8,
-    postfix: Postfix,
+    pub unit:    u8,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Sbit {
-    prefix:  Prefix,
-    sbit:    sBIT,
-    postfix: Postfix,
+pub struct Sbit {
+    pub prefix:  Prefix,
+    pub sbit:    sBIT,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Sbit0 {
-    sig: u8,
+pub struct Sbit0 {
+    pub sig: u8,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Sbit2 {
-    rgb: RGB,
+pub struct Sbit2 {
+    pub rgb: RGB,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Sbit3 {
-    rgb: RGB,
+pub struct Sbit3 {
+    pub rgb: RGB,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Sbit4 {
-    sig:   u8,
-    alpha: u8,
+pub struct Sbit4 {
+    pub sig:   u8,
+    pub alpha: u8,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Sbit6 {
-    rgb:   RGB,
-    alpha: u8,
+pub struct Sbit6 {
+    pub rgb:   RGB,
+    pub alpha: u8,
 }
 
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
 #[allow(non_camel_case_types)]
-// https://tools.ietf.org/html/rfc2083#page-23
-enum sBIT {
+/// https://tools.ietf.org/html/rfc2083#page-23
+pub enum sBIT {
     sBIT0(Sbit0),
     sBIT2(Sbit2),
     sBIT3(Sbit3),
@@ -271,248 +269,259 @@ enum sBIT {
     sBIT6(Sbit6),
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Text {
-    prefix:  Prefix,
-    keyword: String,
-    text:    String,
-    postfix: Postfix,
+pub struct Text {
+    pub prefix:  Prefix,
+    pub keyword: String,
+    pub text:    String,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Time {
-    prefix:  Prefix,
-    year:    u16,
-    month:   u8,
-    day:     u8,
-    hour:    u8,
-    minute:  u8,
-    second:  u8,
-    postfix: Postfix,
+pub struct Time {
+    pub prefix:  Prefix,
+    pub year:    u16,
+    pub month:   u8,
+    pub day:     u8,
+    pub hour:    u8,
+    pub minute:  u8,
+    pub second:  u8,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Trns {
-    prefix:  Prefix,
-    data:    tRNS,
-    postfix: Postfix,
+pub struct Trns {
+    pub prefix:  Prefix,
+    pub data:    tRNS,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Trns0 {
-    gray: u16,
+pub struct Trns0 {
+    pub gray: u16,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Trns2 {
-    rgb: RGB_16,
+pub struct Trns2 {
+    pub rgb: RGB_16,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Trns3 {
-    alpha: Vec<u8>,
+pub struct Trns3 {
+    pub alpha: Vec<u8>,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
 #[allow(non_camel_case_types)]
-enum tRNS {
+pub enum tRNS {
     tRNS0(Trns0),
     tRNS2(Trns2),
     tRNS3(Trns3),
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Ztxt {
-    prefix:      Prefix,
-    keyword:     String,
-    comp_method: u8,
-    comp_text:   Vec<u8>,
-    postfix:     Postfix,
+pub struct Ztxt {
+    pub prefix:      Prefix,
+    pub keyword:     String,
+    pub comp_method: u8,
+    pub comp_text:   Vec<u8>,
+    pub postfix:     Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Srgb {
-    prefix:    Prefix,
-    rendering: u8,
-    postfix:   Postfix,
+pub struct Srgb {
+    pub prefix:    Prefix,
+    pub rendering: u8,
+    pub postfix:   Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Iccp {
-    prefix:       Prefix,
-    profile:      String,
-    comp_method:  u8,
-    comp_profile: Vec<u8>,
-    postfix:      Postfix,
+pub struct Iccp {
+    pub prefix:       Prefix,
+    pub profile:      String,
+    pub comp_method:  u8,
+    pub comp_profile: Vec<u8>,
+    pub postfix:      Postfix,
 }
 
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Itxt {
-    prefix:        Prefix,
-    keyword:       String,
-    comp_flag:     u8,
-    comp_method:   u8,
-    lang_tag:      String,
-    trans_keyword: String,
-    comp_text:     Vec<u8>,
-    postfix:       Postfix,
+pub struct Itxt {
+    pub prefix:        Prefix,
+    pub keyword:       String,
+    pub comp_flag:     u8,
+    pub comp_method:   u8,
+    pub lang_tag:      String,
+    pub trans_keyword: String,
+    pub comp_text:     Vec<u8>,
+    pub postfix:       Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Splt {
-    prefix:  Prefix,
-    name:    String,
-    depth:   u8,
-    plt:     Vec<sPLT>,
-    postfix: Postfix,
+pub struct Splt {
+    pub prefix:  Prefix,
+    pub name:    String,
+    pub depth:   u8,
+    pub plt:     Vec<sPLT>,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Splt8 {
-    rgb:   RGB,
-    alpha: u8,
-    freq:  u16,
+pub struct Splt8 {
+    pub rgb:   RGB,
+    pub alpha: u8,
+    pub freq:  u16,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Splt16 {
-    rgb:   RGB_16,
-    alpha: u16,
-    freq:  u16,
+pub struct Splt16 {
+    pub rgb:   RGB_16,
+    pub alpha: u16,
+    pub freq:  u16,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
 #[allow(non_camel_case_types)]
-enum sPLT {
+pub enum sPLT {
     sPLT8(Splt8),
     sPLT16(Splt16),
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Offs {
-    prefix:  Prefix,
-    x:       i32,
-    y:       i32,
-    unit:    i8,
-    postfix: Postfix,
+pub struct Offs {
+    pub prefix:  Prefix,
+    pub x:       i32,
+    pub y:       i32,
+    pub unit:    i8,
+    pub postfix: Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Pcal {
-    prefix:           Prefix,
-    name:             String,
-    org_zero:         i32,
-    org_max:          i32,
-    equation:         u8,
-    parameters_count: u8,
-    unit_name:        String,
-    parameters:       Vec<String>,
-    postfix:          Postfix,
+pub struct Pcal {
+    pub prefix:           Prefix,
+    pub name:             String,
+    pub org_zero:         i32,
+    pub org_max:          i32,
+    pub equation:         u8,
+    pub parameters_count: u8,
+    pub unit_name:        String,
+    pub parameters:       Vec<String>,
+    pub postfix:          Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Scal {
-    prefix:       Prefix,
-    unit:         u8,
-    pixel_width:  String,
-    pixel_height: String,
-    postfix:      Postfix,
+pub struct Scal {
+    pub prefix:       Prefix,
+    pub unit:         u8,
+    pub pixel_width:  String,
+    pub pixel_height: String,
+    pub postfix:      Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Gifg {
-    prefix:     Prefix,
-    disposal:   u8,
-    user_input: u8,
-    delay:      u16,
-    postfix:    Postfix,
+pub struct Gifg {
+    pub prefix:     Prefix,
+    pub disposal:   u8,
+    pub user_input: u8,
+    pub delay:      u16,
+    pub postfix:    Postfix,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 #[repr(C)]
-struct Gifx {
-    prefix:   Prefix,
-    app_id:   [u8; 8],
-    app_code: [u8; 3],
-    app_data: Vec<u8>,
-    postfix:  Postfix,
+pub struct Gifx {
+    pub prefix:   Prefix,
+    pub app_id:   [u8; 8],
+    pub app_code: [u8; 3],
+    pub app_data: Vec<u8>,
+    pub postfix:  Postfix,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 #[repr(C)]
-struct Ster {
-    prefix:   Prefix,
-    mode: u8,
-    postfix:  Postfix,
+pub struct Ster {
+    pub prefix:   Prefix,
+    pub mode: u8,
+    pub postfix:  Postfix,
 }
 
-#[derive(Debug)]
+/// Parsed PNG image. Critical chunks, PNG 1.2 ancillary chunks and the
+/// PNGEXT chunks are kept, unknown chunks are only recorded by name.
+#[derive(Serialize, Debug)]
 pub struct Png {
-    opt: Opt,
     //
     // Critical chunks
     //
-    ihdr: Ihdr,
-    plte: Option<Plte>,
-    idat: Vec<Idat>,
-    zlib: Zlib,
-    iend: Option<Iend>,
+    pub ihdr: Ihdr,
+    pub plte: Option<Plte>,
+    pub idat: Vec<Idat>,
+    pub zlib: Zlib,
+    pub iend: Option<Iend>,
     //
     // Ancillary chunks
     //
-    bkgd: Option<Bkgd>,
-    chrm: Option<Chrm>,
-    gama: Option<Gama>,
-    hist: Option<Hist>,
-    phys: Option<Phys>,
-    sbit: Option<Sbit>,
-    text: Vec<Text>,
-    time: Option<Time>,
-    trns: Option<Trns>,
-    ztxt: Vec<Ztxt>,
-    srgb: Option<Srgb>,
-    iccp: Option<Iccp>,
-    itxt: Vec<Itxt>,
-    splt: Vec<Splt>,
+    pub bkgd: Option<Bkgd>,
+    pub chrm: Option<Chrm>,
+    pub gama: Option<Gama>,
+    pub hist: Option<Hist>,
+    pub phys: Option<Phys>,
+    pub sbit: Option<Sbit>,
+    pub text: Vec<Text>,
+    pub time: Option<Time>,
+    pub trns: Option<Trns>,
+    pub ztxt: Vec<Ztxt>,
+    pub srgb: Option<Srgb>,
+    pub iccp: Option<Iccp>,
+    pub itxt: Vec<Itxt>,
+    pub splt: Vec<Splt>,
     //
     // PNGEXT 1.2 chunks https://pmt.sourceforge.io/specs/pngext-1.2.0-pdg-h20.html
     //
-    offs: Option<Offs>,
-    pcal: Option<Pcal>,
-    scal: Option<Scal>,
-    gifg: Vec<Gifg>,
-    gifx: Vec<Gifx>,
+    pub offs: Option<Offs>,
+    pub pcal: Option<Pcal>,
+    pub scal: Option<Scal>,
+    pub gifg: Vec<Gifg>,
+    pub gifx: Vec<Gifx>,
     // frac: Vec<Frac>,
     //
     // PNGEXT 1.3 chunks
     //
-    ster: Option<Ster>,
+    pub ster: Option<Ster>,
+
+    pub unknown_chunks: Vec<String>,
+}
+
+/// Bytes of a chunk field running from `start` up to `end`, the end of its chunk data.
+fn chunk_field<'a>(buf: &'a [u8], start: usize, end: usize, what: &str) -> Result<&'a [u8], Error> {
+    if start > end {
+        return Err(Error::from(Problem::msg("Field overruns its chunk").at(start as u64).within(what)));
+    }
+    bytes(buf, start as u64, (end - start) as u64, what)
 }
 
 impl super::FileFormat for Png {
     type Item = Self;
 
-    fn parse(opt: Opt, buf: &[u8]) -> Result<Self, Error> {
+    fn parse(buf: &[u8]) -> Result<Self, Error> {
 
         const CHUNK_SIZE: usize = 12;
         let mut data = Vec::new();
@@ -558,18 +567,20 @@ impl super::FileFormat for Png {
         //
         let mut ster = None;
 
+        let mut unknown_chunks = Vec::new();
+
         let ihdr: Ihdr = buf.pread_with(PNG_HEADER_SIZE, scroll::BE)
-            .map_err(|e| Problem::Msg(format!("Could not read IHDR: {}", e)))?;
+            .at(PNG_HEADER_SIZE as u64, "IHDR chunk")?;
 
         let mut index = PNG_HEADER_SIZE;
 
         while index < buf.len() {
 
             let size = buf.pread_with::<u32>(index, scroll::BE)
-                .map_err(|e| Problem::Msg(format!("Could not read chunk size: {}", e)))? as usize;
-            let id   = &buf[index + 4..index + 8];
+                .at(index as u64, "chunk size")? as usize;
+            let id   = bytes(buf, index as u64 + 4, 4, "chunk name")?;
             let s    = std::str::from_utf8(id).
-                map_err(|e| Problem::Msg(format!("Chunk name should be ASCII letters: {}", e)))?;
+                map_err(|e| Problem::msg(format!("Chunk name should be ASCII letters: {}", e)).at(index as u64 + 4))?;
 
             match s {
                 "IHDR" => {
@@ -580,12 +591,12 @@ impl super::FileFormat for Png {
                     let prefix = Prefix::new(buf, index)?;
                     let size = prefix.size as usize;
                     if size % 3 != 0 {
-                        return Err(Error::from(Problem::Msg(format!("PLTE size must be divisible by 3"))));
+                        return Err(Error::from(Problem::msg(format!("PLTE size must be divisible by 3")).at(index as u64)));
                     }
                     let mut rgb = Vec::new();
                     for i in 0..size / 3 {
                         rgb.push(buf.pread_with::<RGB>(index + 8 + i * 3, scroll::BE)
-                                 .map_err(|e| Problem::Msg(format!("Could not read PLTE pallete: {}" ,e)))?)
+                                 .at(index as u64, "PLTE pallete")?)
                     }
                     plte = Some(Plte {
                         prefix,
@@ -594,7 +605,7 @@ impl super::FileFormat for Png {
                     });
                 },
                 "IDAT" => {
-                    let mut inner_data = buf[index + 8..index + 8 + size].to_vec();
+                    let mut inner_data = chunk_field(buf, index + 8, index + size + 8, "IDAT data")?.to_vec();
                     data.append(&mut inner_data);
 
                     let dat = Idat {
@@ -608,25 +619,25 @@ impl super::FileFormat for Png {
                 },
                 "IEND" => {
                     iend = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read IEND chunk: {}", e)))?);
+                                .at(index as u64, "IEND chunk")?);
                 },
                 "bKGD" => {
                     let color_type = ihdr.color;
                     let color;
                     if color_type == 3 {
                         color = bKGD::bKGD3(buf.pread_with(index + 8, scroll::BE)
-                                            .map_err(|e| Problem::Msg(format!("Could not read bKGD chunk: {}", e)))?);
+                                            .at(index as u64, "bKGD chunk")?);
                     }
                     else if color_type == 0 || color_type == 4 {
                         color = bKGD::bKGD04(buf.pread_with(index + 8, scroll::BE)
-                                             .map_err(|e| Problem::Msg(format!("Could not read bKGD chunk: {}", e)))?);
+                                             .at(index as u64, "bKGD chunk")?);
                     }
                     else if color_type == 2 || color_type == 6 {
                         color = bKGD::bKGD26(buf.pread_with(index + 8, scroll::BE)
-                                             .map_err(|e| Problem::Msg(format!("Could not read bKGD chunk: {}", e)))?);
+                                             .at(index as u64, "bKGD chunk")?);
                     }
                     else {
-                        return Err(Error::from(Problem::Msg(format!("Invalid color type: {} for bKGD", color_type))));
+                        return Err(Error::from(Problem::msg(format!("Invalid color type: {} for bKGD", color_type)).at(index as u64)));
                     }
                     bkgd = Some(Bkgd {
                         prefix: Prefix::new(buf, index)?,
@@ -636,11 +647,11 @@ impl super::FileFormat for Png {
                 },
                 "cHRM" => {
                     chrm = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read cHRM chunk: {}", e)))?);
+                                .at(index as u64, "cHRM chunk")?);
                 },
                 "gAMA" => {
                     gama = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read gAMA chunk: {}", e)))?);
+                                .at(index as u64, "gAMA chunk")?);
                 },
                 "hIST" => {
                     if let Some(plte) = &plte {
@@ -649,7 +660,7 @@ impl super::FileFormat for Png {
                         let mut sum = 0;
                         for i in 0..hist_size {
                             entries.push(buf.pread_with(index + 8 + i * 2, scroll::BE)
-                                         .map_err(|e| Problem::Msg(format!("Could not read hIST entry: {}", e)))?);
+                                         .at(index as u64, "hIST entry")?);
                             sum += entries[i] as u64;
                         }
                         hist = Some(Hist {
@@ -660,12 +671,12 @@ impl super::FileFormat for Png {
                         });
                     }
                     else {
-                        return Err(Error::from(Problem::Msg(format!("hIST chunk needs PLTE chunk present to be valid"))));
+                        return Err(Error::from(Problem::msg(format!("hIST chunk needs PLTE chunk present to be valid")).at(index as u64)));
                     }
                 },
                 "pHYs" => {
                     phys = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read pHYs chunk: {}", e)))?);
+                                .at(index as u64, "pHYs chunk")?);
                 },
                 "sBIT" => {
                     let color_type = ihdr.color;
@@ -673,26 +684,26 @@ impl super::FileFormat for Png {
                     match color_type {
                         0 => {
                             inner_sbit = sBIT::sBIT0(buf.pread_with(index + 8, scroll::BE)
-                                                     .map_err(|e| Problem::Msg(format!("Could not read sBIT chunk: {}", e)))?);
+                                                     .at(index as u64, "sBIT chunk")?);
                         },
                         2 => {
                             inner_sbit = sBIT::sBIT2(buf.pread_with(index + 8, scroll::BE)
-                                                     .map_err(|e| Problem::Msg(format!("Could not read sBIT chunk: {}", e)))?);
+                                                     .at(index as u64, "sBIT chunk")?);
                         },
                         3 => {
                             inner_sbit = sBIT::sBIT3(buf.pread_with(index + 8, scroll::BE)
-                                                     .map_err(|e| Problem::Msg(format!("Could not read sBIT chunk: {}", e)))?);
+                                                     .at(index as u64, "sBIT chunk")?);
                         },
                         4 => {
                             inner_sbit = sBIT::sBIT4(buf.pread_with(index + 8, scroll::BE)
-                                                     .map_err(|e| Problem::Msg(format!("Could not read sBIT chunk: {}", e)))?);
+                                                     .at(index as u64, "sBIT chunk")?);
                         },
                         6 => {
                             inner_sbit = sBIT::sBIT6(buf.pread_with(index + 8, scroll::BE)
-                                                     .map_err(|e| Problem::Msg(format!("Could not read sBIT chunk: {}", e)))?);
+                                                     .at(index as u64, "sBIT chunk")?);
                         },
                         _ => {
-                            return Err(Error::from(Problem::Msg(format!("Invalid color type: {} for sBIT chunk", color_type))));
+                            return Err(Error::from(Problem::msg(format!("Invalid color type: {} for sBIT chunk", color_type)).at(index as u64)));
                         }
                     }
                     sbit = Some(Sbit {
@@ -703,10 +714,10 @@ impl super::FileFormat for Png {
                 },
                 "tEXt" => {
                     let keyword = buf.pread::<&str>(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read keyword field of tEXt chunk: {}", e)))?.to_string();
+                        .at(index as u64, "keyword field of tEXt chunk")?.to_string();
                     let inner_text = std::str::from_utf8(
-                        &buf[index + 9 + keyword.len()..index + size + 8])
-                        .map_err(|e| Problem::Msg(format!("Text field of tEXt chunk must be valid Latin-1: {}", e)))?.to_string();
+                        chunk_field(buf, index + 9 + keyword.len(), index + size + 8, "tEXt text")?)
+                        .map_err(|e| Problem::msg(format!("Text field of tEXt chunk must be valid Latin-1: {}", e)).at(index as u64))?.to_string();
 
                     let text_chunk = Text {
                         prefix: Prefix::new(buf, index)?,
@@ -720,7 +731,7 @@ impl super::FileFormat for Png {
                 },
                 "tIME" => {
                     time = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read tIME chunk: {}", e)))?);
+                                .at(index as u64, "tIME chunk")?);
                 },
                 "tRNS" => {
                     let prefix = Prefix::new(buf, index)?;
@@ -729,21 +740,18 @@ impl super::FileFormat for Png {
                     match color_type {
                         1 => {
                             data = tRNS::tRNS0(buf.pread_with(index + 8, scroll::BE)
-                                               .map_err(|e| Problem::Msg(format!("Could not read tRNS chunk: {}", e)))?);
+                                               .at(index as u64, "tRNS chunk")?);
                         },
                         2 => {
                             data = tRNS::tRNS2(buf.pread_with(index + 8, scroll::BE)
-                                               .map_err(|e| Problem::Msg(format!("Could not read tRNS chunk: {}", e)))?);
+                                               .at(index as u64, "tRNS chunk")?);
                         },
                         3 => {
-                            let mut alpha = Vec::with_capacity(prefix.size as usize);
-                            for i in 0..alpha.capacity() {
-                                alpha.push(buf[index + 8 + i]);
-                            }
+                            let alpha = chunk_field(buf, index + 8, index + size + 8, "tRNS alpha")?.to_vec();
                             data = tRNS::tRNS3(Trns3{ alpha });
                         },
                         _ => {
-                            return Err(Error::from(Problem::Msg(format!("Invalid color type: {} for tRNS", color_type))))
+                            return Err(Error::from(Problem::msg(format!("Invalid color type: {} for tRNS", color_type)).at(index as u64)))
                         },
                     }
                     trns = Some(Trns {
@@ -754,10 +762,10 @@ impl super::FileFormat for Png {
                 },
                 "zTXt" => {
                     let keyword = buf.pread::<&str>(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read keyword field of zTXt chunk: {}", e)))?.to_string();
-                    let comp_text = buf[index + 10 + keyword.len()..index + size + 8].to_vec();
+                        .at(index as u64, "keyword field of zTXt chunk")?.to_string();
+                    let comp_text = chunk_field(buf, index + 10 + keyword.len(), index + size + 8, "zTXt text")?.to_vec();
                     let comp_method: u8 = buf.pread(index + 9 + keyword.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read compression method field of zTXt chunk: {}", e)))?;
+                        .at(index as u64, "compression method field of zTXt chunk")?;
 
                     let ztxt_chunk = Ztxt {
                         prefix: Prefix::new(buf, index)?,
@@ -772,14 +780,14 @@ impl super::FileFormat for Png {
                 },
                 "sRGB" => {
                     srgb = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read sRGB chunk: {}", e)))?);
+                                .at(index as u64, "sRGB chunk")?);
                 },
                 "iCCP" => {
                     let profile = buf.pread::<&str>(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read profile field of iCCP chunk: {}", e)))?.to_string();
-                    let comp_profile = buf[index + 10 + profile.len()..index + size + 8].to_vec();
+                        .at(index as u64, "profile field of iCCP chunk")?.to_string();
+                    let comp_profile = chunk_field(buf, index + 10 + profile.len(), index + size + 8, "iCCP profile")?.to_vec();
                     let comp_method: u8 = buf.pread(index + 9 + profile.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read compression method field of iCCP chunk: {}", e)))?;
+                        .at(index as u64, "compression method field of iCCP chunk")?;
 
                     iccp = Some(Iccp {
                         prefix: Prefix::new(buf, index)?,
@@ -792,19 +800,19 @@ impl super::FileFormat for Png {
                 },
                 "iTXt" => {
                     let keyword     = buf.pread::<&str>(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read keyword field of iTXt chunk: {}", e)))?.to_string();
+                        .at(index as u64, "keyword field of iTXt chunk")?.to_string();
                     let comp_flag   = buf.pread(index + 9 + keyword.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read compression flag field of iTXt chunk: {}", e)))?;
+                        .at(index as u64, "compression flag field of iTXt chunk")?;
                     let comp_method = buf.pread(index + 10 + keyword.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read compression method field of iTXt chunk: {}", e)))?;
+                        .at(index as u64, "compression method field of iTXt chunk")?;
 
                     let lang_tag = buf.pread::<&str>(index + 11 + keyword.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read language tag field of iTXt chunk: {}", e)))?.to_string();
+                        .at(index as u64, "language tag field of iTXt chunk")?.to_string();
                     let trans_keyword = buf.pread::<&str>(index + 12 + keyword.len() + lang_tag.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read translated keyword field of iTXt chunk: {}", e)))?.to_string();
+                        .at(index as u64, "translated keyword field of iTXt chunk")?.to_string();
                     let comp_text =
-                        buf[index + 13 + keyword.len() + lang_tag.len() + trans_keyword.len()
-                            ..index + size + 8].to_vec();
+                        chunk_field(buf, index + 13 + keyword.len() + lang_tag.len() + trans_keyword.len(),
+                                    index + size + 8, "iTXt text")?.to_vec();
 
 
                     let itxt_chunk = Itxt {
@@ -824,39 +832,40 @@ impl super::FileFormat for Png {
                 "sPLT" => {
                     let prefix = Prefix::new(buf, index)?;
                     let name   = buf.pread::<&str>(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read name field of sPLT chunk: {}", e)))?.to_string();
+                        .at(index as u64, "name field of sPLT chunk")?.to_string();
                     let depth  = buf.pread(index + 9 + name.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read depth field of sPLT chunk: {}", e)))?;
+                        .at(index as u64, "depth field of sPLT chunk")?;
 
-                    let length = prefix.size as usize - name.len() - 2;
+                    let entries = chunk_field(buf, index + 10 + name.len(), index + size + 8, "sPLT entries")?;
+                    let length = entries.len();
                     let mut plt;
 
                     match depth {
 
                         8 => {
                             if length % 6 != 0 {
-                                return Err(Error::from(Problem::Msg(format!("sPLT remaining length not divisible by 6"))));
+                                return Err(Error::from(Problem::msg(format!("sPLT remaining length not divisible by 6")).at(index as u64)));
                             }
                             plt = Vec::with_capacity(length / 6);
-                            for _ in 0..length / 6 {
-                                plt.push(sPLT::sPLT8(buf.pread_with::<Splt8>
-                                                     (index + 10 + name.len(), scroll::BE)
-                                                     .map_err(|e| Problem::Msg(format!("Could not read sPLT entry: {}", e)))?));
+                            for i in 0..length / 6 {
+                                plt.push(sPLT::sPLT8(entries.pread_with::<Splt8>
+                                                     (i * 6, scroll::BE)
+                                                     .at(index as u64, "sPLT entry")?));
                             }
                         },
                         16 => {
                             if length % 10 != 0 {
-                                return Err(Error::from(Problem::Msg(format!("sPLT remaining length not divisible by 10"))));
+                                return Err(Error::from(Problem::msg(format!("sPLT remaining length not divisible by 10")).at(index as u64)));
                             }
                             plt = Vec::with_capacity(length / 10);
-                            for _ in 0..length / 6 {
-                                plt.push(sPLT::sPLT16(buf.pread_with::<Splt16>
-                                                      (index + 10 + name.len(), scroll::BE)
-                                                      .map_err(|e| Problem::Msg(format!("Could not read sPLT entry: {}", e)))?));
+                            for i in 0..length / 10 {
+                                plt.push(sPLT::sPLT16(entries.pread_with::<Splt16>
+                                                      (i * 10, scroll::BE)
+                                                      .at(index as u64, "sPLT entry")?));
                             }
                         },
                         _ => {
-                            return Err(Error::from(Problem::Msg(format!("Invalid bit depth: {} for sPLT", depth))));
+                            return Err(Error::from(Problem::msg(format!("Invalid bit depth: {} for sPLT", depth)).at(index as u64)));
                         },
 
                     }
@@ -874,34 +883,35 @@ impl super::FileFormat for Png {
                 },
                 "oFFs" => {
                     offs = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read oFFs chunk: {}", e)))?);
+                                .at(index as u64, "oFFs chunk")?);
                 },
                 "pCAL" => {
                     let prefix   = Prefix::new(buf, index)?;
                     let name     = buf.pread::<&str>(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read name field of pCAL chunk: {}", e)))?.to_string();
+                        .at(index as u64, "name field of pCAL chunk")?.to_string();
                     let org_zero = buf.pread_with(index + 9 + name.len(), scroll::BE)
-                        .map_err(|e| Problem::Msg(format!("Could not read org zero field of pCAL chunk: {}", e)))?;
+                        .at(index as u64, "org zero field of pCAL chunk")?;
                     let org_max  = buf.pread_with(index + 13 + name.len(), scroll::BE)
-                        .map_err(|e| Problem::Msg(format!("Could not read org max field of pCAL chunk: {}", e)))?;
+                        .at(index as u64, "org max field of pCAL chunk")?;
                     let equation = buf.pread_with(index + 17 + name.len(), scroll::BE)
-                        .map_err(|e| Problem::Msg(format!("Could not read equation field of pCAL chunk: {}", e)))?;
+                        .at(index as u64, "equation field of pCAL chunk")?;
                     let parameters_count = buf.pread_with::<u8>(index + 18 + name.len(), scroll::BE)
-                        .map_err(|e| Problem::Msg(format!("Could not read parameters count field of pCAL chunk: {}", e)))?;
+                        .at(index as u64, "parameters count field of pCAL chunk")?;
                     let unit_name = buf.pread::<&str>(index + 19 + name.len())
-                        .map_err(|e| Problem::Msg(format!("Could not read unit name field of pCAL chunk: {}", e)))?.to_string();
+                        .at(index as u64, "unit name field of pCAL chunk")?.to_string();
                     let mut parameters = Vec::with_capacity(parameters_count as usize);
 
                     let mut param_length = 0;
 
-                    for _ in 0..parameters_count - 1 {
-                        let parameter = buf.pread::<&str>(index + 20 + name.len() + unit_name.len())
-                            .map_err(|e| Problem::Msg(format!("Could no read parameter field of pCAL chunk: {}", e)))?.to_string();
+                    for _ in 0..parameters_count.saturating_sub(1) {
+                        let parameter = buf.pread::<&str>(index + 20 + name.len() + unit_name.len() + param_length)
+                            .at(index as u64, "parameter field of pCAL chunk")?.to_string();
                         param_length += parameter.len() + 1;
                         parameters.push(parameter);
                     }
-                    let parameter = std::str::from_utf8(&buf[index + 20 + name.len() + unit_name.len() + param_length..index + size + 8])
-                        .map_err(|e| Problem::Msg(format!("Could no read parameter field of pCAL chunk: {}", e)))?.to_string();
+                    let parameter = std::str::from_utf8(chunk_field(buf, index + 20 + name.len() + unit_name.len() + param_length,
+                                                                    index + size + 8, "pCAL parameter")?)
+                        .at(index as u64, "parameter field of pCAL chunk")?.to_string();
                     parameters.push(parameter);
 
                     pcal = Some(Pcal {
@@ -919,11 +929,11 @@ impl super::FileFormat for Png {
                 },
                 "sCAL" => {
                     let unit = buf.pread(index + 8)
-                        .map_err(|e| Problem::Msg(format!("Could not read unit field of sCAL chunk: {}", e)))?;
+                        .at(index as u64, "unit field of sCAL chunk")?;
                     let pixel_width = buf.pread::<&str>(index + 9)
-                        .map_err(|e| Problem::Msg(format!("Could not read pixel width field of sCAL chunk: {}", e)))?.to_string();
-                    let pixel_height = std::str::from_utf8(&buf[index + 10 + pixel_width.len()..index + size + 8])
-                        .map_err(|e| Problem::Msg(format!("Could not read pixel height field of sCAL chunk: {}", e)))?.to_string();
+                        .at(index as u64, "pixel width field of sCAL chunk")?.to_string();
+                    let pixel_height = std::str::from_utf8(chunk_field(buf, index + 10 + pixel_width.len(), index + size + 8, "sCAL pixel height")?)
+                        .at(index as u64, "pixel height field of sCAL chunk")?.to_string();
                     scal = Some(Scal {
                         prefix: Prefix::new(buf, index)?,
                         unit,
@@ -934,16 +944,16 @@ impl super::FileFormat for Png {
                 },
                 "gIFg" => {
                     let gifg_chunk = buf.pread_with(index, scroll::BE)
-                        .map_err(|e| Problem::Msg(format!("Could not read gIFg chunk: {}", e)))?;
+                        .at(index as u64, "gIFg chunk")?;
                     gifg.push(gifg_chunk);
                 },
                 "gIFx" => {
                     let prefix = Prefix::new(buf, index)?;
                     let mut app_id = [0; 8];
-                    app_id.copy_from_slice(&buf[index + 8..index + 16]);
+                    app_id.copy_from_slice(bytes(buf, index as u64 + 8, 8, "gIFx application id")?);
                     let mut app_code = [0; 3];
-                    app_code.copy_from_slice(&buf[index + 16..index + 19]);
-                    let app_data = buf[index + 19..index + size + 8].to_vec();
+                    app_code.copy_from_slice(bytes(buf, index as u64 + 16, 3, "gIFx application code")?);
+                    let app_data = chunk_field(buf, index + 19, index + size + 8, "gIFx application data")?.to_vec();
                     let gifx_chunk = Gifx {
                         prefix,
                         app_id,
@@ -955,11 +965,10 @@ impl super::FileFormat for Png {
                 },
                 "sTER" => {
                     ster = Some(buf.pread_with(index, scroll::BE)
-                                .map_err(|e| Problem::Msg(format!("Could not read sTER chunk: {}", e)))?);
+                                .at(index as u64, "sTER chunk")?);
                 }
                 _ => {
-                    eprintln!("Error: {} ",
-                              Color::Red.underline().paint(format!("Unsupported chunk: {}", s)));
+                    unknown_chunks.push(s.to_string());
                 },
             }
 
@@ -977,7 +986,7 @@ impl super::FileFormat for Png {
                 cmf: data.pread_with(0, scroll::BE)?,
                 flg: data.pread_with(1, scroll::BE)?,
                 deflate: data.pread_with(2, scroll::BE)?,
-                adler: data.pread_with(data.len() - 4, scroll::BE)?,
+                adler: data.pread_with(data.len().saturating_sub(4), scroll::BE)?,
             };
 
         }
@@ -991,14 +1000,12 @@ impl super::FileFormat for Png {
                     len: 0,
                     nlen: 0,
                 },
-                adler: data.pread_with(data.len() - 4, scroll::BE)?,
+                adler: data.pread_with(data.len().saturating_sub(4), scroll::BE)?,
             };
 
         }
 
         Ok(Png {
-            opt,
-
             ihdr,
             plte,
             idat,
@@ -1028,608 +1035,9 @@ impl super::FileFormat for Png {
             // frac,
 
             ster,
-        })
-
-    }
-
-    fn print(&self) -> Result<(), Error> {
-        use ansi_term::Color;
-        use prettytable::Table;
 
-        //
-        // PNG file
-        //
-        println!("PNG width: {}px height: {}px",
-                 self.ihdr.width,
-                 self.ihdr.height);
-        println!();
-
-        //
-        // IHDR
-        //
-        fmt_png_header("IHDR", &self.ihdr.prefix, &self.ihdr.postfix);
-        fmt_indentln(format!("Image width: {} height: {}", self.ihdr.width, self.ihdr.height));
-        fmt_indentln(format!("Bits per pixel: {}", self.ihdr.bpp));
-        fmt_indentln(format!("Color type: {}", self.ihdr.color));
-        fmt_indentln(format!("Compression method: {}", self.ihdr.compression));
-        fmt_indentln(format!("Filter method: {}", self.ihdr.filter));
-        fmt_indentln(format!("Interlace method: {}", self.ihdr.interlace));
-        fmt_indentln(format!("Checksum: {:#010X}", self.ihdr.postfix.checksum));
-        println!();
-
-        //
-        // PLTE
-        //
-        if let Some(plte) = &self.plte {
-            fmt_png_header("PLTE", &plte.prefix, &plte.postfix);
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .column_separator(' ')
-                .borders(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row![r->"Idx", rFr->"Red", rFg->"Green", rFb->"Blue"]);
-            for (i, rgb) in plte.rgb.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![r->i,
-                                   rFr->format!("{:#04X}", rgb.red),
-                                   rFg->format!("{:#04X}", rgb.green),
-                                   rFb->format!("{:#04X}", rgb.blue)]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // bKGD
-        //
-        if let Some(background) = &self.bkgd {
-            fmt_png_header("bKGD", &background.prefix, &background.postfix);
-            match &background.color {
-                bKGD::bKGD3(bkgd)  => {
-                    fmt_indentln(format!("Palette index: {}", bkgd.index));
-                },
-                bKGD::bKGD04(bkgd) => {
-                    fmt_indentln(format!("Gray: {:#06X}", bkgd.gray));
-                },
-                bKGD::bKGD26(bkgd) => {
-                    fmt_indentln(format!("Red:   {}",
-                                         Color::Red.paint(format!("{:#06X}",bkgd.red))));
-                    fmt_indentln(format!("Green: {}",
-                                         Color::Green.paint(format!("{:#06X}",bkgd.green))));
-                    fmt_indentln(format!("Blue:  {}",
-                                         Color::Blue.paint(format!("{:#06X}",bkgd.blue))));
-                },
-            }
-            println!();
-        }
-
-        //
-        // cHRM
-        //
-        if let Some(chrm) = &self.chrm {
-            fmt_png_header("cHRM", &chrm.prefix, &chrm.postfix);
-
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .column_separator(' ')
-                .borders(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row![r->"Color", r->"X", r->"Y", r->"Point x", r->"Point y"]);
-            table.add_row(row![Fwr=>"White",
-                               chrm.white_x, chrm.white_y,
-                               format!("{:.5}",chrm.white_x as f32 / 100000.0),
-                               format!("{:.5}",chrm.white_y as f32 / 100000.0)]);
-            table.add_row(row![Frr=>"Red",
-                               chrm.red_x, chrm.red_y,
-                               format!("{:.5}",chrm.red_x as f32 / 100000.0),
-                               format!("{:.5}",chrm.red_y as f32 / 100000.0)]);
-            table.add_row(row![Fgl=>"Green",
-                               chrm.green_x, chrm.green_y,
-                               format!("{:.5}",chrm.green_x as f32 / 100000.0),
-                               format!("{:.5}",chrm.green_y as f32 / 100000.0)]);
-            table.add_row(row![Fbr=>"Blue",
-                               chrm.blue_x, chrm.blue_y,
-                               format!("{:.5}",chrm.blue_x as f32 / 100000.0),
-                               format!("{:.5}",chrm.blue_y as f32 / 100000.0)]);
-            table.printstd();
-            println!();
-        }
-
-        //
-        // gAMA
-        //
-        if let Some(gama) = &self.gama {
-            fmt_png_header("gAMA", &gama.prefix, &gama.postfix);
-            fmt_indentln(format!("Gamma: {} ({})",
-                                 gama.gamma,
-                                 format!("{:.5}", gama.gamma as f32 / 100000.0)));
-            println!();
-        }
-
-        //
-        // hIST
-        //
-        if let Some(hist) = &self.hist {
-            fmt_png_header("hIST", &hist.prefix, &hist.postfix);
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .column_separator(' ')
-                .borders(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row![r->"Idx", rFb->"Frequency"]);
-            for (i, entry) in hist.entries.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![r->i,
-                                   rFb->format!("{:.8}", (*entry as f64 / hist.sum as f64))]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // pHYs
-        //
-        if let Some(phys) = &self.phys {
-            fmt_png_header("pHYs", &phys.prefix, &phys.postfix);
-            let unit = {
-                if phys.unit == 0 {
-                    "unknown unit"
-                }
-                else if phys.unit == 1{
-                    "meter"
-                }
-                else {
-                    panic!("pHYs: Invalid unit specifier");
-                }
-            };
-            fmt_indentln(format!("Pixels per {}, X axis: {}", unit, phys.ppu_x));
-            fmt_indentln(format!("Pixels per {}, Y axis: {}", unit, phys.ppu_y));
-            println!();
-        }
-
-        //
-        // sBIT
-        //
-        if let Some(sbit) = &self.sbit {
-            fmt_png_header("sBIT", &sbit.prefix, &sbit.postfix);
-            match &sbit.sbit {
-                sBIT::sBIT0(inner_sbit) => {
-                    fmt_indentln(format!("Significant bits: {}", inner_sbit.sig));
-                },
-                sBIT::sBIT2(inner_sbit) => {
-                    fmt_indentln(format!("{}",
-                        Color::Red.paint(
-                            format!("Significant bits red:   {}",inner_sbit.rgb.red))));
-                    fmt_indentln(format!("{}",
-                        Color::Green.paint(
-                            format!("Significant bits green: {}",inner_sbit.rgb.green))));
-                    fmt_indentln(format!("{}",
-                        Color::Blue.paint(
-                            format!("Significant bits blue:  {}",inner_sbit.rgb.blue))));
-                },
-                sBIT::sBIT3(inner_sbit) => {
-                    fmt_indentln(format!("{}",
-                        Color::Red.paint(
-                            format!("Significant bits red:   {}",inner_sbit.rgb.red))));
-                    fmt_indentln(format!("{}",
-                        Color::Green.paint(
-                            format!("Significant bits green: {}",inner_sbit.rgb.green))));
-                    fmt_indentln(format!("{}",
-                        Color::Blue.paint(
-                            format!("Significant bits blue:  {}",inner_sbit.rgb.blue))));
-                },
-                sBIT::sBIT4(inner_sbit) => {
-                    fmt_indentln(format!("Significant bits: {}", inner_sbit.sig));
-                    fmt_indentln(format!("{}",
-                        Color::White.paint(
-                            format!("Significant bits alpha  {}",inner_sbit.alpha))));
-                },
-                sBIT::sBIT6(inner_sbit) => {
-                    fmt_indentln(format!("{}",
-                        Color::Red.paint(
-                            format!("Significant bits red:   {}",inner_sbit.rgb.red))));
-                    fmt_indentln(format!("{}",
-                        Color::Green.paint(
-                            format!("Significant bits green: {}",inner_sbit.rgb.green))));
-                    fmt_indentln(format!("{}",
-                        Color::Blue.paint(
-                            format!("Significant bits blue:  {}",inner_sbit.rgb.blue))));
-                    fmt_indentln(format!("{}",
-                        Color::White.paint(
-                            format!("Significant bits alpha  {}",inner_sbit.alpha))));
-                },
-            }
-            println!();
-        }
-
-        //
-        // tEXt
-        //
-        if self.text.len() > 0 {
-            for (_i, chunk) in self.text.iter().enumerate() {
-                fmt_png_header("tEXt", &chunk.prefix, &chunk.postfix);
-                fmt_indentln(format!("{}: {}",
-                                     Color::Blue.paint(&chunk.keyword), chunk.text));
-            }
-            println!();
-        }
-
-        //
-        // tIME
-        //
-        if let Some(time) = &self.time {
-            fmt_png_header("tIME", &time.prefix, &time.postfix);
-            fmt_indentln(format!("Date: {}.{}.{} {}:{}:{}",
-                                 time.day,
-                                 time.month,
-                                 time.year,
-                                 time.hour,
-                                 time.minute,
-                                 time.second));
-            println!();
-        }
-        //
-        // tRNS
-        //
-        if let Some(trns) = &self.trns {
-            fmt_png_header("tRNS", &trns.prefix, &trns.postfix);
-            match &trns.data {
-                tRNS::tRNS0(data) => {
-                    fmt_indentln(format!("Gray: {:#06X}", data.gray));
-                },
-                tRNS::tRNS2(data) => {
-                    fmt_indentln(format!("Red:   {}",
-                        Color::Red.paint(format!("{:#06X}", data.rgb.red))));
-                    fmt_indentln(format!("Green: {}",
-                        Color::Green.paint(format!("{:#06X}", data.rgb.green))));
-                    fmt_indentln(format!("Blue:  {}",
-                        Color::Blue.paint(format!("{:#06X}", data.rgb.blue))));
-                },
-                tRNS::tRNS3(data) => {
-                    let mut trimmed = false;
-                    let mut table = Table::new();
-                    let format = prettytable::format::FormatBuilder::new()
-                        .column_separator(' ')
-                        .borders(' ')
-                        .padding(1, 1)
-                        .build();
-                    table.set_format(format);
-                    table.add_row(row![r->"Idx", rFw->"Transparency"]);
-                    for (i, entry) in data.alpha.iter().enumerate() {
-                        if i == self.opt.trim_lines {
-                            trimmed = true;
-                            break;
-                        }
-                        table.add_row(row![r->i,
-                                           rFw->format!("{:#04X}", entry)]);
-                    }
-                    table.printstd();
-                    if trimmed {
-                        fmt_indentln(format!("Output trimmed..."));
-                    }
-                },
-            }
-            println!();
-        }
-
-        //
-        // zTXt
-        //
-        if self.ztxt.len() > 0 {
-            for (_i, chunk) in self.ztxt.iter().enumerate() {
-                fmt_png_header("zTXt", &chunk.prefix, &chunk.postfix);
-                fmt_indentln(format!("Compression method: {}", chunk.comp_method));
-                fmt_indent(format!("{}: ", Color::Blue.paint(&chunk.keyword)));
-                for (i, b) in chunk.comp_text.iter().enumerate() {
-                    if i % 16 == 0 {
-                        println!("");
-                        fmt_indent(format!("{:02X} ", b));
-                    }
-                    else {
-                        print!("{:02X} ", b);
-                    }
-                }
-                println!();
-            }
-            println!();
-        }
-
-        //
-        // sRGB
-        //
-        if let Some(srgb) = &self.srgb {
-            fmt_png_header("sRGB", &srgb.prefix, &srgb.postfix);
-            fmt_indent(format!("Rendering intent: "));
-            match srgb.rendering {
-                0 => {
-                    println!("{} Perceptual", srgb.rendering);
-                }
-                1 => {
-                    println!("{} Relative colorimetric", srgb.rendering);
-                }
-                2 => {
-                    println!("{} Saturation", srgb.rendering);
-                }
-                3 => {
-                    println!("{} Absolute colorimetric", srgb.rendering);
-                }
-                _ => {
-                    panic!("Invalid rendering intent");
-                }
-
-            }
-            println!();
-        }
-        //
-        // iCCP
-        //
-        if let Some(iccp) = &self.iccp {
-            fmt_png_header("iCCP", &iccp.prefix, &iccp.postfix);
-            fmt_indentln(format!("Compression method: {}", iccp.comp_method));
-            fmt_indent(format!("{}: ", iccp.profile));
-            for (i, b) in iccp.comp_profile.iter().enumerate() {
-                if i % 16 == 0 {
-                    println!("");
-                    fmt_indent(format!("{:02X} ", b));
-                }
-                else {
-                    print!("{:02X} ", b);
-                }
-            }
-            println!();
-            println!();
-        }
-        //
-        // iTXt
-        //
-        if self.itxt.len() > 0 {
-            for (_i, chunk) in self.itxt.iter().enumerate() {
-                fmt_png_header("iTXt", &chunk.prefix, &chunk.postfix);
-                fmt_indentln(format!("Compression flag: {}", chunk.comp_flag));
-                fmt_indentln(format!("Lang: {}",
-                                     Color::Green.paint(&chunk.lang_tag)));
-                fmt_indentln(format!("Keyword: {}",
-                                     Color::Blue.paint(&chunk.keyword)));
-                fmt_indentln(format!("Translated keyword: {}",
-                                     Color::Purple.paint(&chunk.trans_keyword)));
-                if chunk.comp_flag == 1 {
-                    fmt_indentln(format!("Compression method: {}", chunk.comp_method));
-
-                    fmt_indent(format!("{}: ", Color::Blue.paint(&chunk.keyword)));
-                    for (i, b) in chunk.comp_text.iter().enumerate() {
-                        if i % 16 == 0 {
-                            println!("");
-                            fmt_indent(format!("{:02X} ", b));
-                        }
-                        else {
-                            print!("{:02X} ", b);
-                        }
-                    }
-                    println!();
-                }
-                else {
-                    fmt_indentln(format!("Text: {}",
-                                         std::str::from_utf8(&chunk.comp_text)?));
-                }
-            }
-            println!();
-        }
-        //
-        // sPLT
-        //
-        if self.splt.len() > 0 {
-            println!("{}({}) ",Color::White.underline().paint("sPLT"),
-                     self.splt.len(),);
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .column_separator(' ')
-                .borders(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row!["Idx", "Depth",
-                               Fr->"Red", Fg->"Green", Fb->"Blue",
-                               Fw->"Alpha", "Freq"]);
-            for (i, chunk) in self.splt.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                fmt_png_header("sPLT", &chunk.prefix, &chunk.postfix);
-                fmt_indentln(format!("Name: {}",
-                                     Color::Blue.paint(&chunk.name)));
-                for (j, palette) in chunk.plt.iter().enumerate() {
-                    if j == self.opt.trim_lines {
-                        trimmed = true;
-                        break;
-                    }
-                    match palette {
-                        sPLT::sPLT8(plt) => {
-                            table.add_row(row![r->j,
-                                               r->chunk.depth,
-                                               rFr->plt.rgb.red,
-                                               rFg->plt.rgb.green,
-                                               rFb->plt.rgb.blue,
-                                               rFw->plt.alpha,
-                                               r->plt.freq]);
-                        },
-                        sPLT::sPLT16(plt) => {
-                            table.add_row(row![r->j,
-                                               r->chunk.depth,
-                                               rFr->plt.rgb.red,
-                                               rFg->plt.rgb.green,
-                                               rFb->plt.rgb.blue,
-                                               rFw->plt.alpha,
-                                               r->plt.freq]);
-                        }
-                    }
-                }
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-        }
-
-        //
-        // oFFs
-        //
-        if let Some(offs) = &self.offs {
-            fmt_png_header("oFFs", &offs.prefix, &offs.postfix);
-            fmt_indentln(format!("X: {}", offs.x));
-            fmt_indentln(format!("Y: {}", offs.y));
-            fmt_indentln(format!("Unit specifier: {}", offs.unit));
-            println!();
-        }
-
-        //
-        // pCAL
-        //
-        if let Some(pcal) = &self.pcal {
-            fmt_png_header("pCAL", &pcal.prefix, &pcal.postfix);
-            fmt_indentln(format!("Name: {}", pcal.name));
-            fmt_indentln(format!("Original zero: {}", pcal.org_zero));
-            fmt_indentln(format!("Original max: {}", pcal.org_max));
-            fmt_indentln(format!("Equation type: {}", pcal.equation));
-            fmt_indentln(format!("Unit name: {}", pcal.unit_name));
-            fmt_indentln(format!("Number of parameters: {}", pcal.parameters_count));
-            for param in &pcal.parameters {
-                fmt_indentln(format!("{}", param));
-            }
-            println!();
-        }
-
-        //
-        // sCAL
-        //
-        if let Some(scal) = &self.scal {
-            fmt_png_header("sCAl", &scal.prefix, &scal.postfix);
-            fmt_indentln(format!("Unit specifier: {}", scal.unit));
-            fmt_indentln(format!("Pixel width: {}", scal.pixel_width));
-            fmt_indentln(format!("Pixel height: {}", scal.pixel_height));
-            println!();
-        }
-
-        //
-        // gIFg
-        //
-        if self.gifg.len() > 0 {
-            println!("{}({})",Color::White.underline().paint("gIFg"), self.gifg.len());
-            for chunk in &self.gifg {
-                fmt_png_header("gIFg", &chunk.prefix, &chunk.postfix);
-                fmt_indentln(format!("Disposal method: {}", chunk.disposal));
-                fmt_indentln(format!("User input flag: {}", chunk.user_input));
-                fmt_indentln(format!("Delay time: {}", chunk.delay));
-                println!();
-            }
-        }
-
-        //
-        // gIFx
-        //
-        if self.gifx.len() > 0 {
-            println!("{}({})",Color::White.underline().paint("gIFx"), self.gifg.len());
-            for chunk in &self.gifg {
-                fmt_png_header("gIFx", &chunk.prefix, &chunk.postfix);
-                fmt_indentln(format!("Application identifier: {}", chunk.disposal));
-                fmt_indentln(format!("Authentication code: {}", chunk.user_input));
-                fmt_indentln(format!("Application data: {}", chunk.delay));
-                println!();
-            }
-        }
-
-        //
-        // sTER
-        //
-        if let Some(ster) = &self.ster {
-            fmt_png_header("sTER", &ster.prefix, &ster.postfix);
-            match ster.mode {
-                0 => {
-                    fmt_indentln(format!("Mode: {}: {}", ster.mode, "cross-fuse layout"));
-                },
-                1 => {
-                    fmt_indentln(format!("Mode: {}: {}", ster.mode, "diverging-fuse layout"));
-                },
-                _ => {
-                    panic!("Invalid mode {} for sTER chunk", ster.mode);
-                },
-            }
-        }
-
-        //
-        // IDAT
-        //
-        {
-            println!("{}({}) {} {} {} {} {} {}",
-                Color::White.underline().paint("IDAT"),
-                self.idat.len(),
-                Color::Purple.paint(format!("Compression method: {}",self.zlib.cmf << 4 >> 4)),
-                Color::Cyan.paint(format!("Compression info: {}",self.zlib.cmf >> 4)),
-                Color::Green.paint(format!("Checksum: {:#07b}", self.zlib.flg << 3 >> 3)),
-                Color::Red.paint(format!("Dict: {:#03b}", self.zlib.flg << 2 >> 7)),
-                Color::Yellow.paint(format!("Compression level: {:#04b}", self.zlib.flg >> 6)),
-                Color::Fixed(221).paint(format!("Adler: {:#010X}", self.zlib.adler)));
-
-            let mut trimmed = false;
-            let mut table = Table::new();
-            let format = prettytable::format::FormatBuilder::new()
-                .column_separator(' ')
-                .borders(' ')
-                .padding(1, 1)
-                .build();
-            table.set_format(format);
-            table.add_row(row![l->"Idx", l->"Size", l->"Checksum"]);
-
-            for (i, idat) in self.idat.iter().enumerate() {
-                if i == self.opt.trim_lines {
-                    trimmed = true;
-                    break;
-                }
-                table.add_row(row![r->i,
-                                   rFy->idat.prefix.size,
-                                   rFg->format!("{:#010X}", idat.postfix.checksum)]);
-            }
-            table.printstd();
-            if trimmed {
-                fmt_indentln(format!("Output trimmed..."));
-            }
-            println!();
-
-        }
-
-        //
-        // IEND
-        //
-        if let Some(iend) = &self.iend {
-            fmt_png_header("IEND", &iend.prefix, &iend.postfix);
-        }
-
-        Ok(())
+            unknown_chunks,
+        })
 
     }
 
@@ -1,5 +1,4 @@
 #![allow(non_camel_case_types)]
-use crate::Opt;
 use failure::{Error};
 use scroll::{self, Pread};
 
@@ -17,159 +16,149 @@ const SIGNATURE_VALUE: u32     = 0x05054b50;
 const ZIP64_CENTRAL_VALUE: u32 = 0x06064b50;
 const ZIP64_END_VALUE: u32     = 0x07064b50;
 
-#[derive(Debug, Pread)]
-struct Local_file_header {
-    signature:     u32,
-    version:       u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct Local_file_header {
+    pub signature:     u32,
+    pub version:       u16,
     /// Bit 0: If set, indicates that the file is encrypted.
-    flag:          u16,
-    compr_method:  u16,
-    last_mod_time: u16,
-    last_mod_date: u16,
-    crc32:         u32,
+    pub flag:          u16,
+    pub compr_method:  u16,
+    pub last_mod_time: u16,
+    pub last_mod_date: u16,
+    pub crc32:         u32,
     /// If bit 3 of the general purpose bit flag is set,
     /// these fields are set to zero in the local header and the
     /// correct values are put in the data descriptor and in the central directory.
-    compr_sz:      u32,
-    uncompr_sz:    u32,
-    name_sz:       u16,
-    extra_sz:      u16,
+    pub compr_sz:      u32,
+    pub uncompr_sz:    u32,
+    pub name_sz:       u16,
+    pub extra_sz:      u16,
 }
 
-#[derive(Debug)]
-struct Local_file {
-    header: Local_file_header,
-    name:   String,
-    extra:  Option<Archive_extra_data>,
+#[derive(Serialize, Debug)]
+pub struct Local_file {
+    pub header: Local_file_header,
+    pub name:   String,
+    pub extra:  Option<Archive_extra_data>,
     // enc_header: (),
-    data_desc: Option<Data_descriptior>,
+    pub data_desc: Option<Data_descriptior>,
 }
 
-#[derive(Debug)]
+#[derive(Serialize, Debug)]
 /// This descriptor MUST exist if bit 3 of the general purpose bit flag is set.
-struct Data_descriptior {
-    crc32:      u32,
-    compr_sz:   u32,
-    uncompr_sz: u32,
+pub struct Data_descriptior {
+    pub crc32:      u32,
+    pub compr_sz:   u32,
+    pub uncompr_sz: u32,
 }
 
-#[derive(Debug)]
-struct Archive_extra_data {
-    signature: u32,
-    length:    u32,
-    data:      Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct Archive_extra_data {
+    pub signature: u32,
+    pub length:    u32,
+    pub data:      Vec<u8>,
 }
 
-#[derive(Debug, Pread)]
-struct Central_dir_header {
-    signature:        u32,
-    version_made:     u16,
-    version_needed:   u16,
-    flag:             u16,
-    compr_method:     u16,
-    last_mod_time:    u16,
-    last_mod_date:    u16,
-    crc32:            u32,
-    compr_sz:         u32,
-    uncompr_sz:       u32,
-    name_sz:          u16,
-    extra_sz:         u16,
-    comment_sz:       u16,
-    disk_num_start:   u16,
-    internal_attr:    u16,
-    external_attr:    u32,
-    local_header_off: u32,
+#[derive(Serialize, Debug, Pread)]
+pub struct Central_dir_header {
+    pub signature:        u32,
+    pub version_made:     u16,
+    pub version_needed:   u16,
+    pub flag:             u16,
+    pub compr_method:     u16,
+    pub last_mod_time:    u16,
+    pub last_mod_date:    u16,
+    pub crc32:            u32,
+    pub compr_sz:         u32,
+    pub uncompr_sz:       u32,
+    pub name_sz:          u16,
+    pub extra_sz:         u16,
+    pub comment_sz:       u16,
+    pub disk_num_start:   u16,
+    pub internal_attr:    u16,
+    pub external_attr:    u32,
+    pub local_header_off: u32,
 }
 
-#[derive(Debug)]
-struct Central_dir {
-    header:  Central_dir_header,
-    name:    String,
-    extra:   Vec<u8>,
-    comment: Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct Central_dir {
+    pub header:  Central_dir_header,
+    pub name:    String,
+    pub extra:   Vec<u8>,
+    pub comment: Vec<u8>,
 }
 
-#[derive(Debug)]
-struct Digital_signature {
-    signature:      u32,
-    data_sz:        u16,
-    signature_data: Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct Digital_signature {
+    pub signature:      u32,
+    pub data_sz:        u16,
+    pub signature_data: Vec<u8>,
 }
 
-#[derive(Debug, Pread)]
-struct End_central_dir_header {
-    signature:         u32,
-    n_this_disk:       u16,
-    n_start_disk:      u16,
-    n_this_entries:    u16,
-    n_central_entries: u16,
-    central_sz:        u32,
-    offset:            u32,
-    zip_comment_sz:    u16,
+#[derive(Serialize, Debug, Pread)]
+pub struct End_central_dir_header {
+    pub signature:         u32,
+    pub n_this_disk:       u16,
+    pub n_start_disk:      u16,
+    pub n_this_entries:    u16,
+    pub n_central_entries: u16,
+    pub central_sz:        u32,
+    pub offset:            u32,
+    pub zip_comment_sz:    u16,
 }
 
-#[derive(Debug)]
-struct End_central_dir {
-    header:  End_central_dir_header,
-    comment: Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct End_central_dir {
+    pub header:  End_central_dir_header,
+    pub comment: Vec<u8>,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 /// Zip64 end of central dir
-struct Zip64_End_central_dir_header {
-    signature:         u32,
-    size:              u64,
-    version_made:      u16,
-    version_needed:    u16,
-    n_this_disk:       u32,
-    n_start_disk:      u32,
-    n_this_entries:    u64,
-    n_central_entries: u64,
-    central_dir:       u64,
-    central_dir_sz:    u64,
-    start_disk_num:    u64,
+pub struct Zip64_End_central_dir_header {
+    pub signature:         u32,
+    pub size:              u64,
+    pub version_made:      u16,
+    pub version_needed:    u16,
+    pub n_this_disk:       u32,
+    pub n_start_disk:      u32,
+    pub n_this_entries:    u64,
+    pub n_central_entries: u64,
+    pub central_dir:       u64,
+    pub central_dir_sz:    u64,
+    pub start_disk_num:    u64,
 }
 
-#[derive(Debug)]
-struct Zip64_End_central_dir {
-    header: Zip64_End_central_dir_header,
-    data:   Vec<u8>,
+#[derive(Serialize, Debug)]
+pub struct Zip64_End_central_dir {
+    pub header: Zip64_End_central_dir_header,
+    pub data:   Vec<u8>,
 }
 
-#[derive(Debug, Pread)]
+#[derive(Serialize, Debug, Pread)]
 /// zip64 end of central dir locator
-struct Zip64_End_central_locator {
-    signature:       u32,
-    start_disk:      u32,
-    end_central_dir: u64,
-    n_disks:         u32,
+pub struct Zip64_End_central_locator {
+    pub signature:       u32,
+    pub start_disk:      u32,
+    pub end_central_dir: u64,
+    pub n_disks:         u32,
 }
 
 // If one of the fields in the end of central directory record is too small to hold required data,
 // the field SHOULD be set to -1 (0xFFFF or 0xFFFFFFFF) and the ZIP64 format record SHOULD be created.
-#[derive(Debug)]
+/// ZIP archive. Only detected for now, nothing is parsed yet.
+#[derive(Serialize, Debug)]
 pub struct Zip {
-    opt: Opt,
 }
 
 impl super::FileFormat for Zip {
     type Item = Self;
 
-    fn parse(opt: Opt, _buf: &[u8]) -> Result<Self, Error> {
+    fn parse(_buf: &[u8]) -> Result<Self, Error> {
 
         Ok(Zip {
-            opt,
         })
 
     }
 
-    fn print(&self) -> Result<(), Error> {
-
-        println!("ZIP");
-        println!("{:#X?}", self);
-
-        Ok(())
-    }
-
-
 }
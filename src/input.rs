@@ -0,0 +1,116 @@
+//! Bytes of the files to look at.
+//!
+//! Parsers want the whole file as one slice. Regular files are memory-mapped
+//! where the platform allows it, so a multi-gigabyte core dump or disk image
+//! is paged in only as far as parsing reads it, instead of being copied into
+//! memory up front. Everything else, stdin included, is read into a buffer.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::ops::Deref;
+
+use bininfo::Problem;
+
+/// Path that stands for stdin.
+pub const STDIN: &str = "-";
+
+/// Contents of one input file.
+pub enum Input {
+    #[cfg(unix)]
+    Mapped(mmap::Mmap),
+    Buffer(Vec<u8>),
+}
+
+impl Input {
+    /// Maps or reads the file at `path`, or stdin if it is [`STDIN`].
+    pub fn open(path: &str) -> Result<Input, Problem> {
+        if path == STDIN {
+            let mut buffer = Vec::new();
+            io::stdin().lock().read_to_end(&mut buffer)
+                .map_err(|e| Problem::msg(format!("Cannot read stdin: {}", e)))?;
+            return Ok(Input::Buffer(buffer));
+        }
+
+        let mut fd = File::open(path)
+            .map_err(|e| Problem::msg(format!("Cannot open file {:?}: {}", path, e)))?;
+
+        #[cfg(unix)]
+        {
+            if let Ok(meta) = fd.metadata() {
+                // Pipes, devices and files under /proc report no size or can't be mapped
+                if meta.is_file() && meta.len() > 0 && meta.len() <= usize::MAX as u64 {
+                    if let Ok(map) = mmap::Mmap::map(&fd, meta.len() as usize) {
+                        return Ok(Input::Mapped(map));
+                    }
+                }
+            }
+        }
+
+        let mut buffer = Vec::new();
+        fd.read_to_end(&mut buffer)
+            .map_err(|e| Problem::msg(format!("Cannot read file {:?}: {}", path, e)))?;
+        Ok(Input::Buffer(buffer))
+    }
+}
+
+impl Deref for Input {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(unix)]
+            Input::Mapped(map) => map,
+            Input::Buffer(buffer) => buffer,
+        }
+    }
+}
+
+#[cfg(unix)]
+mod mmap {
+    use std::fs::File;
+    use std::io;
+    use std::ops::Deref;
+    use std::os::unix::io::AsRawFd;
+
+    /// Read-only private mapping of a whole file.
+    ///
+    /// Like every file mapping, it reflects changes other processes make to
+    /// the file, and reading past the end of a file truncated meanwhile
+    /// raises SIGBUS.
+    pub struct Mmap {
+        ptr: *mut libc::c_void,
+        len: usize,
+    }
+
+    impl Mmap {
+        /// Maps the first `len` bytes of `file`, which must not be 0.
+        pub fn map(file: &File, len: usize) -> io::Result<Mmap> {
+            // Safety: a fresh mapping aliases no Rust memory, and failure is checked
+            let ptr = unsafe {
+                libc::mmap(std::ptr::null_mut(), len, libc::PROT_READ, libc::MAP_PRIVATE, file.as_raw_fd(), 0)
+            };
+            if ptr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(Mmap { ptr, len })
+        }
+    }
+
+    impl Deref for Mmap {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            // Safety: the mapping is readable and `len` bytes long until dropped
+            unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+        }
+    }
+
+    impl Drop for Mmap {
+        fn drop(&mut self) {
+            // Safety: `ptr` and `len` are those of a mapping nothing borrows any more
+            unsafe {
+                libc::munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Parsers for various binary file formats.
+//!
+//! Every supported format lives in its own module under [`formats`] and
+//! implements [`FileFormat`], which turns a byte buffer into a public model
+//! type (`formats::elf::Elf`, `formats::png::Png`, ...). [`Binary::parse`]
+//! detects the format from the file magic and dispatches to the right parser.
+//!
+//! ```no_run
+//! use bininfo::Binary;
+//!
+//! let buf = std::fs::read("/bin/ls").unwrap();
+//! if let Binary::Elf(elf) = Binary::parse(&buf).unwrap() {
+//!     println!("{} section headers", elf.section_headers.len());
+//! }
+//! ```
+extern crate cpp_demangle;
+extern crate failure;
+extern crate miniz_oxide;
+extern crate rustc_demangle;
+extern crate ruzstd;
+#[macro_use]
+extern crate scroll_derive;
+extern crate scroll;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate strum_macros;
+
+pub mod demangle;
+pub mod error;
+pub mod magic;
+pub mod binary;
+pub mod formats;
+
+pub use crate::binary::Binary;
+pub use crate::error::{Problem, ResultExt};
+pub use crate::formats::{FileFormat, Recovery};
@@ -1,13 +1,12 @@
 use crate::formats::*;
 use crate::Problem;
+use std::cmp::Reverse;
 
 use failure::{
     Error,
 };
 
-const MAGIC_SIZE: usize = 16;
-
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Format {
     // Image formats
     Png,
@@ -19,79 +18,241 @@ pub enum Format {
     Elf,
     JavaClass,
     MachO,
+    MachOFat,
     Lua,
     // Archive formats
     Zip,
+    Ar,
     // Document formats
     Pdf,
     // Other
     Unknown,
 }
 
-pub fn parse<T: std::io::Read + std::io::Seek>(fd: &mut T) -> Result<Format, Error> {
+/// Bytes of a file [`parse`] reads. The detectors look past the magic, as far
+/// as the PE signature at e_lfanew, which is well within this in practice.
+pub const DETECT_SIZE: u64 = 64 * 1024;
 
-    use std::io::SeekFrom;
+/// Format of the file read from `fd`, from its first [`DETECT_SIZE`] bytes.
+pub fn parse<T: std::io::Read + std::io::Seek>(fd: &mut T) -> Result<Format, Error> {
 
-    const BYTES_READ: usize = 16;
+    use std::io::{Read, SeekFrom};
 
-    let mut bytes = [0u8; BYTES_READ];
+    let mut bytes = Vec::new();
     fd.seek(SeekFrom::Start(0))
-        .map_err(|_| Problem::Msg(format!("Could not seek file")))?;
-    fd.read_exact(&mut bytes)
-        .map_err(|e| Problem::Msg(format!("File should be atleast {} bytes long: {}", 16 , e)))?;
+        .map_err(|_| Problem::msg("Could not seek file".to_string()))?;
+    fd.take(DETECT_SIZE).read_to_end(&mut bytes)
+        .map_err(|e| Problem::msg(format!("Could not read file: {}", e)))?;
     fd.seek(SeekFrom::Start(0))
-        .map_err(|_| Problem::Msg(format!("Could not seek file")))?;
+        .map_err(|_| Problem::msg("Could not seek file".to_string()))?;
+
+    Ok(detect(&bytes))
+
+}
+
+/// Most likely format of the file starting with `buf`, Unknown when no
+/// format fits, as for files shorter than any magic. Unlike [`parse`] this
+/// needs neither a reader nor a copy of the file.
+pub fn detect(buf: &[u8]) -> Format {
+    candidates(buf).first().map_or(Format::Unknown, |candidate| candidate.format)
+}
+
+//
+// DETECTORS
+//
+
+/// Magic bytes matched, but nothing else was checked or the checks failed.
+pub const SCORE_MAGIC: u8 = 50;
+/// Magic bytes matched and the header around them makes sense.
+pub const SCORE_VALID: u8 = 100;
+/// Magic bytes so short or common that they are likely a coincidence.
+pub const SCORE_WEAK: u8 = 20;
+
+/// Recognizes one format from the start of a file.
+pub struct Detector {
+    pub format: Format,
+    /// Name accepted by `--force-format`
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Bytes needed to tell the format apart, shorter files are not of it
+    pub min_size: usize,
+    /// Confidence from 0 (not this format) to [`SCORE_VALID`]
+    pub score: fn(&[u8]) -> u8,
+}
+
+/// Format a file could be, with how sure its detector is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate {
+    pub format: Format,
+    pub score: u8,
+}
+
+/// Every known format, in the order ties between scores are settled.
+pub const DETECTORS: &[Detector] = &[
+    Detector { format: Format::Png, name: "png", description: "PNG image", min_size: png::PNG_HEADER_SIZE, score: score_png },
+    Detector { format: Format::Bmp, name: "bmp", description: "BMP image", min_size: bmp::BMP_MAGIC_SIZE, score: score_bmp },
+    Detector { format: Format::Gif, name: "gif", description: "GIF image", min_size: gif::GIF_MAGIC_SIZE, score: score_gif },
+    Detector { format: Format::Jpg, name: "jpg", description: "JPEG image", min_size: jpg::JPG_MAGIC_SIZE, score: score_jpg },
+
+    Detector { format: Format::Elf, name: "elf", description: "ELF executable, object, library or core dump", min_size: elf::ELF_MAGIC_SIZE, score: score_elf },
+    Detector { format: Format::Pe, name: "pe", description: "PE executable or DLL", min_size: pe::PE_MAGIC_SIZE, score: score_pe },
+    Detector { format: Format::MachO, name: "macho", description: "Mach-O executable, object or library", min_size: macho::MACHO_MAGIC_SIZE, score: score_macho },
+    Detector { format: Format::MachOFat, name: "macho-fat", description: "Mach-O universal binary (not parsed)", min_size: javaclass::CLASS_MAGIC_SIZE + 4, score: score_macho_fat },
+    Detector { format: Format::JavaClass, name: "javaclass", description: "Java class file", min_size: javaclass::CLASS_MAGIC_SIZE, score: score_java_class },
+    Detector { format: Format::Lua, name: "lua", description: "Lua 5.1 bytecode", min_size: lua::LUA_MAGIC_SIZE, score: score_lua },
+
+    Detector { format: Format::Zip, name: "zip", description: "ZIP archive", min_size: zip::ZIP_MAGIC_SIZE, score: score_zip },
+    Detector { format: Format::Ar, name: "ar", description: "ar archive, static library or Debian package", min_size: ar::AR_MAGIC_SIZE, score: score_ar },
+
+    Detector { format: Format::Pdf, name: "pdf", description: "PDF document", min_size: pdf::PDF_MAGIC_SIZE, score: score_pdf },
+];
+
+/// Detector of the format called `name`, as listed by `--list-formats`.
+pub fn by_name(name: &str) -> Option<&'static Detector> {
+    DETECTORS.iter().find(|detector| detector.name == name)
+}
+
+/// Detector of `format`.
+pub fn detector(format: Format) -> Option<&'static Detector> {
+    DETECTORS.iter().find(|detector| detector.format == format)
+}
+
+/// Formats `buf` could be, most likely first.
+pub fn candidates(buf: &[u8]) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = DETECTORS.iter()
+        .filter(|detector| buf.len() >= detector.min_size)
+        .map(|detector| Candidate { format: detector.format, score: (detector.score)(buf) })
+        .filter(|candidate| candidate.score > 0)
+        .collect();
+    // Stable, so ties keep the registry order
+    candidates.sort_by_key(|candidate| Reverse(candidate.score));
+    candidates
+}
+
+fn starts_with_any(buf: &[u8], magics: &[&[u8]]) -> bool {
+    magics.iter().any(|magic| buf.starts_with(magic))
+}
+
+fn u16_be(buf: &[u8], offset: usize) -> Option<u16> {
+    buf.get(offset..offset + 2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn u32_le(buf: &[u8], offset: usize) -> Option<u32> {
+    buf.get(offset..offset + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn u32_be(buf: &[u8], offset: usize) -> Option<u32> {
+    buf.get(offset..offset + 4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn score_png(buf: &[u8]) -> u8 {
+    if !buf.starts_with(png::PNG_HEADER) {
+        return 0;
+    }
+    // The first chunk is always IHDR
+    if buf.get(12..16) == Some(b"IHDR") { SCORE_VALID } else { SCORE_MAGIC }
+}
 
-    check_magic(&mut bytes)
+fn score_bmp(buf: &[u8]) -> u8 {
+    if !buf.starts_with(bmp::BMP_MAGIC) {
+        return 0;
+    }
+    // Size of the DIB header, one of the known versions
+    match u32_le(buf, 14) {
+        Some(12) | Some(16) | Some(40) | Some(52) | Some(56) | Some(64) | Some(108) | Some(124) => SCORE_VALID,
+        _ => SCORE_WEAK,
+    }
+}
 
+fn score_gif(buf: &[u8]) -> u8 {
+    if starts_with_any(buf, &[gif::GIF87A_MAGIC, gif::GIF89A_MAGIC]) { SCORE_VALID } else { 0 }
 }
 
-fn check_magic(magic: &[u8; MAGIC_SIZE]) -> Result<Format, Error> {
+fn score_jpg(buf: &[u8]) -> u8 {
+    if starts_with_any(buf, &[jpg::JPG_MAGIC, jpg::JPG_MAGIC_2]) { SCORE_MAGIC } else { 0 }
+}
 
-    if &magic[0..png::PNG_HEADER_SIZE] == png::PNG_HEADER {
-        Ok(Format::Png)
+fn score_elf(buf: &[u8]) -> u8 {
+    if !buf.starts_with(elf::ELF_MAGIC) {
+        return 0;
     }
-    else if &magic[0..bmp::BMP_MAGIC_SIZE] == bmp::BMP_MAGIC {
-        Ok(Format::Bmp)
+    // EI_CLASS, EI_DATA and EI_VERSION
+    match (buf.get(4), buf.get(5), buf.get(6)) {
+        (Some(1..=2), Some(1..=2), Some(1)) => SCORE_VALID,
+        _ => SCORE_MAGIC,
     }
-    else if &magic[0..elf::ELF_MAGIC_SIZE] == elf::ELF_MAGIC {
-        Ok(Format::Elf)
+}
+
+fn score_pe(buf: &[u8]) -> u8 {
+    if !buf.starts_with(pe::PE_MAGIC) {
+        return 0;
     }
-    else if &magic[0..gif::GIF_MAGIC_SIZE] == gif::GIF87A_MAGIC
-         || &magic[0..gif::GIF_MAGIC_SIZE] == gif::GIF89A_MAGIC {
-             Ok(Format::Gif)
-        }
-    else if &magic[0..pdf::PDF_MAGIC_SIZE] == pdf::PDF_MAGIC {
-        Ok(Format::Pdf)
+    // A plain DOS executable has no PE signature at e_lfanew
+    let signature = u32_le(buf, 0x3C).and_then(|offset| buf.get(offset as usize..(offset as usize).checked_add(4)?));
+    if signature == Some(b"PE\0\0") { SCORE_VALID } else { SCORE_WEAK }
+}
+
+fn score_macho(buf: &[u8]) -> u8 {
+    let big_endian = starts_with_any(buf, &[macho::MACHO_MAGIC_32, macho::MACHO_MAGIC_64]);
+    if !big_endian && !starts_with_any(buf, &[macho::MACHO_MAGIC_32_R, macho::MACHO_MAGIC_64_R]) {
+        return 0;
+    }
+    // sizeofcmds has to fit in the file
+    let read = if big_endian { u32_be } else { u32_le };
+    match read(buf, 20) {
+        Some(size) if (size as usize) < buf.len() => SCORE_VALID,
+        _ => SCORE_MAGIC,
+    }
+}
+
+/// Universal binaries share their magic with Java classes. Where a class has
+/// its version, they have a small number of architectures.
+fn score_macho_fat(buf: &[u8]) -> u8 {
+    if !buf.starts_with(javaclass::CLASS_MAGIC) {
+        return 0;
+    }
+    match u32_be(buf, 4) {
+        Some(1..=32) => SCORE_VALID,
+        _ => 0,
+    }
+}
+
+fn score_java_class(buf: &[u8]) -> u8 {
+    if !buf.starts_with(javaclass::CLASS_MAGIC) {
+        return 0;
     }
-    else if &magic[0..jpg::JPG_MAGIC_SIZE] == jpg::JPG_MAGIC
-        || &magic[0..jpg::JPG_MAGIC_SIZE] == jpg::JPG_MAGIC_2 {
-            Ok(Format::Jpg)
-        }
-    else if &magic[0..pe::PE_MAGIC_SIZE] == pe::PE_MAGIC {
-        Ok(Format::Pe)
+    // Major versions start at 45 with JDK 1.0.2
+    match u16_be(buf, 6) {
+        Some(45..=255) => SCORE_VALID,
+        _ => SCORE_WEAK,
     }
-    else if &magic[0..javaclass::CLASS_MAGIC_SIZE] == javaclass::CLASS_MAGIC {
-        Ok(Format::JavaClass)
+}
+
+fn score_lua(buf: &[u8]) -> u8 {
+    if !buf.starts_with(lua::LUA_MAGIC) {
+        return 0;
     }
-    else if &magic[0..macho::MACHO_MAGIC_SIZE] == macho::MACHO_MAGIC_32
-        || &magic[0..macho::MACHO_MAGIC_SIZE] == macho::MACHO_MAGIC_64
-        || &magic[0..macho::MACHO_MAGIC_SIZE] == macho::MACHO_MAGIC_32_R
-        || &magic[0..macho::MACHO_MAGIC_SIZE] == macho::MACHO_MAGIC_64_R {
-            Ok(Format::MachO)
-        }
-    else if &magic[0..lua::LUA_MAGIC_SIZE] == lua::LUA_MAGIC {
-        Ok(Format::Lua)
+    if buf.get(4) == Some(&0x51) { SCORE_VALID } else { SCORE_MAGIC }
+}
+
+fn score_zip(buf: &[u8]) -> u8 {
+    if starts_with_any(buf, &[zip::ZIP_MAGIC, zip::ZIP_MAGIC_EMPTY, zip::ZIP_MAGIC_SPANNED]) { SCORE_VALID } else { 0 }
+}
+
+fn score_ar(buf: &[u8]) -> u8 {
+    if !starts_with_any(buf, &[ar::AR_MAGIC, ar::AR_THIN_MAGIC]) {
+        return 0;
     }
-    else if &magic[0..zip::ZIP_MAGIC_SIZE] == zip::ZIP_MAGIC
-        || &magic[0..zip::ZIP_MAGIC_SIZE] == zip::ZIP_MAGIC_EMPTY
-        || &magic[0..zip::ZIP_MAGIC_SIZE] == zip::ZIP_MAGIC_SPANNED {
-            Ok(Format::Zip)
-        }
-    else {
-        Ok(Format::Unknown)
+    // The first member header ends with its magic, unless the archive is empty
+    match buf.get(ar::AR_MAGIC_SIZE + 58..ar::AR_MAGIC_SIZE + 60) {
+        Some(fmag) if fmag == ar::AR_FMAG => SCORE_VALID,
+        None if buf.len() == ar::AR_MAGIC_SIZE => SCORE_VALID,
+        _ => SCORE_MAGIC,
     }
+}
 
+fn score_pdf(buf: &[u8]) -> u8 {
+    if buf.starts_with(pdf::PDF_MAGIC) { SCORE_VALID } else { 0 }
 }
 
 #[cfg(test)]
@@ -116,6 +277,40 @@ mod tests {
         let magic = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                          0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,];
         assert_eq!(parse(&mut Cursor::new(&magic))?, Format::Unknown);
+        assert_eq!(parse(&mut Cursor::new(&magic[..0]))?, Format::Unknown);
+        assert_eq!(detect(&magic), Format::Unknown);
+        assert_eq!(detect(&magic[..8]), Format::Unknown);
+        assert_eq!(detect(&[]), Format::Unknown);
+
+        // Only the start of longer files is read
+        let mut elf = vec![0x7F, 0x45, 0x4C, 0x46, 0x02, 0x01, 0x01];
+        elf.resize(DETECT_SIZE as usize * 2, 0);
+        let mut cursor = Cursor::new(&elf);
+        assert_eq!(parse(&mut cursor)?, Format::Elf);
+        assert_eq!(cursor.position(), 0);
+
+        Ok(())
+
+    }
+
+    #[test]
+    fn scores_settle_shared_magics() -> Result<(), Error> {
+
+        let mut class = vec![0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x00, 0x00, 0x34];
+        class.resize(16, 0);
+        assert_eq!(detect(&class), Format::JavaClass);
+        assert_eq!(candidates(&class).len(), 1);
+
+        let mut fat = vec![0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x00, 0x00, 0x02];
+        fat.resize(16, 0);
+        assert_eq!(detect(&fat), Format::MachOFat);
+        assert_eq!(candidates(&fat), [Candidate { format: Format::MachOFat, score: SCORE_VALID },
+                                      Candidate { format: Format::JavaClass, score: SCORE_WEAK }]);
+
+        // MZ alone is a DOS executable, still offered as PE
+        let mut dos = b"MZ".to_vec();
+        dos.resize(0x40, 0);
+        assert_eq!(candidates(&dos), [Candidate { format: Format::Pe, score: SCORE_WEAK }]);
 
         Ok(())
 
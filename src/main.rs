@@ -1,34 +1,34 @@
 extern crate ansi_term;
-#[macro_use]
+extern crate bininfo;
 extern crate failure;
-#[macro_use]
+#[cfg(unix)]
+extern crate libc;
 extern crate prettytable;
-#[macro_use]
-extern crate scroll_derive;
 extern crate scroll;
-extern crate structopt;
+extern crate serde;
 #[macro_use]
-extern crate strum_macros;
+extern crate serde_derive;
+extern crate serde_json;
+extern crate structopt;
 extern crate textwrap;
 
-mod magic;
-mod binary;
+#[macro_use]
+mod render;
 mod format;
-mod formats;
+mod diagnostic;
+mod input;
+mod paths;
 
-use crate::binary::Binary;
-use crate::formats::FileFormat;
+use bininfo::{Binary, Problem, Recovery};
+use bininfo::magic::{self, Detector};
+use crate::format::Describe;
+use crate::input::Input;
+use crate::render::{Renderer, Report};
 
 use failure::Error;
 
 use structopt::StructOpt;
 
-#[derive(Debug, Fail)]
-pub enum Problem {
-    #[fail(display = "{}", _0)]
-    Msg (String)
-}
-
 #[derive(StructOpt, Debug)]
 #[structopt(name = "bininfo")]
 pub struct Opt {
@@ -41,88 +41,301 @@ pub struct Opt {
     #[structopt(short = "w", long = "wrap", default_value = "120", help = "number of chars before wrap")]
     wrap_chars: usize,
 
-    /// File to print info about
-    #[structopt(help = "file path")]
-    file: String
+    /// Output style
+    #[structopt(short = "o", long = "output", default_value = "text",
+                raw(possible_values = "&[\"text\", \"plain\", \"json\", \"markdown\", \"html\"]"),
+                help = "output style")]
+    output: Output,
 
-}
+    /// Print headers
+    #[structopt(long = "headers", help = "print headers")]
+    headers: bool,
 
-use std::fs::File;
-use std::io::Read;
+    /// Print sections or segments
+    #[structopt(long = "sections", help = "print sections")]
+    sections: bool,
 
-fn main() {
+    /// Print symbol tables
+    #[structopt(long = "symbols", help = "print symbols")]
+    symbols: bool,
 
-    let opt = Opt::from_args();
+    /// Print relocations
+    #[structopt(long = "relocs", help = "print relocations")]
+    relocs: bool,
+
+    /// Print imported libraries and symbols
+    #[structopt(long = "imports", help = "print imports")]
+    imports: bool,
+
+    /// Print exported symbols
+    #[structopt(long = "exports", help = "print exports")]
+    exports: bool,
+
+    /// Print compilation units from debug information
+    #[structopt(long = "debug-info", help = "print debug information")]
+    debug_info: bool,
+
+    /// Print the source line an address was compiled from
+    #[structopt(long = "line", parse(try_from_str = "parse_address"),
+                help = "print the source file and line of an address, from debug information")]
+    line: Option<u64>,
+
+    /// Print C++ and Rust symbol names demangled
+    #[structopt(long = "demangle", help = "demangle C++ and Rust symbol names")]
+    demangle: bool,
+
+    /// Parse the members of archives too
+    #[structopt(long = "members", help = "parse and print every member of ar archives")]
+    members: bool,
+
+    /// Print what can be parsed from damaged files, with warnings
+    #[structopt(long = "lenient", help = "recover from damaged parts of the file, listing them as warnings")]
+    lenient: bool,
+
+    /// Look inside directories
+    #[structopt(short = "r", long = "recursive", help = "print info about every file in directories")]
+    recursive: bool,
+
+    /// Print one line per file
+    #[structopt(long = "brief", help = "print one summary line per file")]
+    brief: bool,
 
-    match run(opt) {
-        Ok(()) => (),
-        Err(e) => {
-            eprintln!("{}", e);
-            std::process::exit(1);
+    /// Parse as this format instead of detecting it
+    #[structopt(long = "force-format", help = "parse as this format, see --list-formats")]
+    force_format: Option<String>,
+
+    /// List the formats bininfo detects
+    #[structopt(long = "list-formats", help = "list the formats bininfo detects")]
+    list_formats: bool,
+
+    /// Files, directories or glob patterns to print info about, `-` for stdin
+    #[structopt(help = "file paths, - for stdin", raw(required_unless = r#""list_formats""#))]
+    files: Vec<String>,
+
+}
+
+/// Part of a file that can be asked for on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+    Headers,
+    Sections,
+    Symbols,
+    Relocs,
+    Imports,
+    Exports,
+    DebugInfo,
+}
+
+impl Opt {
+    /// Whether `part` was asked for with its flag.
+    pub fn selected(&self, part: Part) -> bool {
+        match part {
+            Part::Headers  => self.headers,
+            Part::Sections => self.sections,
+            Part::Symbols  => self.symbols,
+            Part::Relocs   => self.relocs,
+            Part::Imports  => self.imports,
+            Part::Exports  => self.exports,
+            Part::DebugInfo => self.debug_info,
         }
     }
 
+    /// Whether `part` should be shown. Without any part flags everything is.
+    pub fn show(&self, part: Part) -> bool {
+        let any = self.headers || self.sections || self.symbols
+            || self.relocs || self.imports || self.exports
+            || self.debug_info || self.line.is_some();
+
+        !any || self.selected(part)
+    }
 }
 
-fn run(opt: Opt) -> Result<(), Error> {
+#[derive(Debug, PartialEq)]
+enum Output {
+    Text,
+    Plain,
+    Json,
+    Markdown,
+    Html,
+}
 
-    let file_path = &opt.file;
+impl std::str::FromStr for Output {
+    type Err = Problem;
 
-    let mut fd = File::open(file_path)
-        .map_err(|e| Problem::Msg(format!("Cannot open file {:?}: {}", file_path, e)))?;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text"     => Ok(Output::Text),
+            "plain"    => Ok(Output::Plain),
+            "json"     => Ok(Output::Json),
+            "markdown" => Ok(Output::Markdown),
+            "html"     => Ok(Output::Html),
+            _          => Err(Problem::msg(format!("Unknown output style {:?}", s))),
+        }
+    }
+}
 
+impl Output {
+    fn renderer(&self) -> Box<dyn Renderer> {
+        use crate::render::*;
 
-    let mut buffer = Vec::new();
-    fd.read_to_end(&mut buffer)
-        .map_err(|e| Problem::Msg(format!("Cannot read file {:?}: {}", file_path, e)))?;
+        match self {
+            Output::Text     => Box::new(TextRenderer { color: true }),
+            Output::Plain    => Box::new(TextRenderer { color: false }),
+            Output::Json     => Box::new(JsonRenderer),
+            Output::Markdown => Box::new(MarkdownRenderer),
+            Output::Html     => Box::new(HtmlRenderer),
+        }
+    }
+}
 
-    let bin = Binary::parse(opt, &buffer)?;
+/// Address given in hexadecimal with a `0x` prefix, or in decimal.
+fn parse_address(s: &str) -> Result<u64, Problem> {
+    let parsed = match s.get(..2) {
+        Some("0x") | Some("0X") => u64::from_str_radix(&s[2..], 16),
+        _ => s.parse(),
+    };
+    parsed.map_err(|_| Problem::msg(format!("Invalid address {:?}", s)))
+}
 
-    match bin {
-        Binary::Bmp(bmp) => {
-            bmp.print()?;
-        },
-        Binary::Png(png) => {
-            png.print()?;
-        },
-        Binary::Gif(gif) => {
-            gif.print()?;
-        },
-        Binary::Jpg(jpg) => {
-            jpg.print()?;
-        },
+use std::io::Write;
 
-        Binary::Pe(pe) => {
-            pe.print()?;
-        },
-        Binary::Elf(elf) => {
-            elf.print()?;
-        },
-        Binary::JavaClass(java_class) => {
-            java_class.print()?;
-        },
-        Binary::MachO(macho) => {
-            macho.print()?;
-        }
-        Binary::Lua(lua) => {
-            lua.print()?;
+fn main() {
+
+    let opt = Opt::from_args();
+
+    if opt.list_formats {
+        for detector in magic::DETECTORS {
+            println!("{:<12}{}", detector.name, detector.description);
         }
+        return;
+    }
+
+    if !run(&opt) {
+        std::process::exit(1);
+    }
+
+}
 
-        Binary::Pdf(pdf) => {
-            pdf.print()?;
+/// Prints info about every file asked for, reporting errors as they come.
+/// Returns whether all files could be read.
+fn run(opt: &Opt) -> bool {
+
+    let forced = match opt.force_format.as_ref().map(|name| (name, magic::by_name(name))) {
+        Some((name, None)) => {
+            eprintln!("Unknown format {:?}, see --list-formats", name);
+            return false;
         },
+        Some((_, detector)) => detector,
+        None => None,
+    };
+
+    let entries = paths::expand(&opt.files, opt.recursive);
+    let files: Vec<String> = entries.iter().flatten().map(|path| path.display().to_string()).collect();
+    let names: Vec<&str> = files.iter().map(String::as_str).collect();
+    // With a single file, errors need not say which one they are about
+    let several = entries.len() > 1;
+
+    let renderer = opt.output.renderer();
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    let mut ok = true;
+
+    if let Err(e) = renderer.begin(&mut out, &names) {
+        eprintln!("{}", e);
+        return false;
+    }
 
-        Binary::Zip(zip) => {
-            zip.print()?;
+    let mut written = 0;
+    for entry in entries {
+        let path = match entry {
+            Ok(path) => path,
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+                continue;
+            },
+        };
+        let file = path.display().to_string();
+
+        match describe_file(opt, forced, &*renderer, &mut out, &file, written, files.len()) {
+            Ok(()) => written += 1,
+            Err(e) if several => {
+                eprintln!("{}: {}", file, e);
+                ok = false;
+            },
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+            },
         }
+    }
 
-        Binary::Unknown  => {
-            use ansi_term::Color;
+    if let Err(e) = renderer.end(&mut out, &names) {
+        eprintln!("{}", e);
+        return false;
+    }
 
-            eprintln!("{}", Color::Black.on(Color::Red).paint("Unknown/unsupported file format"));
-            eprintln!("Check for newest version: {}", "https://github.com/Forlos/bininfo");
-        },
+    ok
+}
+
+fn describe_file(opt: &Opt, forced: Option<&Detector>, renderer: &dyn Renderer, out: &mut dyn Write, file_path: &str, index: usize, count: usize) -> Result<(), Error> {
+
+    let buffer = Input::open(file_path)?;
+
+    // Brief errors stay on one line too
+    let locate = |e| if opt.brief { e } else { diagnostic::locate(e, &buffer) };
+
+    // Other formats that fit are worth knowing about when detection picked one
+    let candidates = if forced.is_none() { magic::candidates(&buffer) } else { Vec::new() };
+
+    let mut recovery = if opt.lenient { Recovery::lenient() } else { Recovery::strict() };
+    let mut bin = match forced {
+        Some(detector) => Binary::parse_as(&buffer, detector.format, &mut recovery),
+        None => Binary::parse_with(&buffer, &mut recovery),
+    }.map_err(|e| suggest_candidates(locate(e), &candidates, opt.brief))?;
+    if let (true, Binary::Ar(ar)) = (opt.members, &mut bin) {
+        ar.parse_members(&buffer, &mut recovery)
+            .map_err(locate)?;
+    }
+
+    let mut document = bin.describe(opt)
+        .map_err(locate)?;
+    let ambiguous = candidates.len() > 1;
+    if ambiguous {
+        document.section(format::candidates(&candidates));
+    }
+    if !recovery.warnings.is_empty() {
+        document.section(format::warnings(&recovery.warnings));
+    }
+    let report = Report {
+        file: file_path,
+        binary: &bin,
+        document: &document,
+        warnings: &recovery.warnings,
+        candidates: if ambiguous { &candidates } else { &[] },
+        index,
+        count,
+    };
+
+    if opt.brief {
+        renderer.brief(out, &report)
+    }
+    else {
+        renderer.render(out, &report)
+    }
+}
+
+/// Points at the formats detection passed over when the one it picked fails to parse.
+fn suggest_candidates(error: Error, candidates: &[magic::Candidate], brief: bool) -> Error {
+    let others: Vec<&str> = candidates.iter()
+        .skip(1)
+        .filter_map(|candidate| magic::detector(candidate.format))
+        .map(|detector| detector.name)
+        .collect();
+    if others.is_empty() {
+        return error;
     }
 
-    Ok(())
+    let separator = if brief { "; " } else { "\n" };
+    failure::err_msg(format!("{}{}could also be {}, see --force-format", error, separator, others.join(", ")))
 }
@@ -0,0 +1,214 @@
+//! Turns the paths given on the command line into the files to look at.
+//!
+//! Shells expand globs before bininfo sees them, but quoted patterns and
+//! shells that don't (cmd.exe) still reach us, so `*`, `?` and `[...]` are
+//! expanded here too. Directories are walked with `--recursive`.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use bininfo::Problem;
+
+/// Files named by `args`, in command line order, or why an argument names
+/// none. Directories and globs are expanded in name order.
+pub fn expand(args: &[String], recursive: bool) -> Vec<Result<PathBuf, Problem>> {
+    let mut entries = Vec::new();
+
+    for arg in args {
+        // A file whose name looks like a pattern is still just that file
+        if is_pattern(arg) && !Path::new(arg).exists() {
+            let matches = glob(arg);
+            if matches.is_empty() {
+                entries.push(Err(Problem::msg(format!("No files match {:?}", arg))));
+            }
+            for path in matches {
+                walk(path, recursive, &mut entries);
+            }
+        }
+        else {
+            walk(PathBuf::from(arg), recursive, &mut entries);
+        }
+    }
+
+    entries
+}
+
+fn walk(path: PathBuf, recursive: bool, entries: &mut Vec<Result<PathBuf, Problem>>) {
+    if !path.is_dir() {
+        entries.push(Ok(path));
+        return;
+    }
+    if !recursive {
+        entries.push(Err(Problem::msg(format!("{:?} is a directory, use --recursive to look inside", path))));
+        return;
+    }
+
+    match sorted_dir(&path) {
+        Ok(children) => {
+            for child in children {
+                // Symlinked directories could loop back onto themselves
+                let is_link = fs::symlink_metadata(&child).map(|meta| meta.file_type().is_symlink()).unwrap_or(false);
+                if is_link && child.is_dir() {
+                    continue;
+                }
+                walk(child, recursive, entries);
+            }
+        },
+        Err(err) => entries.push(Err(err)),
+    }
+}
+
+fn sorted_dir(dir: &Path) -> Result<Vec<PathBuf>, Problem> {
+    let mut children = fs::read_dir(dir)
+        .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>())
+        .map_err(|e| Problem::msg(format!("Cannot read directory {:?}: {}", dir, e)))?;
+    children.sort();
+    Ok(children)
+}
+
+fn is_pattern(arg: &str) -> bool {
+    arg.contains(['*', '?', '['])
+}
+
+/// Existing paths matching `pattern`, which may hold wildcards in any component.
+fn glob(pattern: &str) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::new()];
+
+    for component in Path::new(pattern).components() {
+        let part = match component {
+            Component::Normal(part) => part.to_string_lossy(),
+            other => {
+                for path in &mut paths {
+                    path.push(other.as_os_str());
+                }
+                continue;
+            },
+        };
+
+        if !is_pattern(&part) {
+            for path in &mut paths {
+                path.push(part.as_ref());
+            }
+            paths.retain(|path| path.exists());
+            continue;
+        }
+
+        let mut matches = Vec::new();
+        for path in &paths {
+            let dir = if path.as_os_str().is_empty() { Path::new(".") } else { path.as_path() };
+            for child in sorted_dir(dir).unwrap_or_default() {
+                let name = child.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+                // Like shells, wildcards skip hidden files unless the pattern starts with a dot
+                if name.starts_with('.') && !part.starts_with('.') {
+                    continue;
+                }
+                if matches_pattern(part.as_bytes(), name.as_bytes()) {
+                    matches.push(path.join(name));
+                }
+            }
+        }
+        paths = matches;
+    }
+
+    paths
+}
+
+/// Whether `name` matches `pattern`, made of `*`, `?`, `[...]` classes and literal bytes.
+/// Everything but `*` matches one byte, so on a mismatch only the last `*`
+/// needs to take one more byte, keeping this linear in most cases and
+/// quadratic at worst.
+fn matches_pattern(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    // Pattern index after the last `*`, and the name index it matched up to
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        // Length of the pattern element at `p` when it matches the byte at `n`
+        let matched = match pattern.get(p) {
+            Some(b'*') => {
+                p += 1;
+                star = Some((p, n));
+                continue;
+            },
+            Some(b'?') => Some(1),
+            Some(b'[') => match class_end(&pattern[p..]) {
+                Some(end) => Some(end + 1).filter(|_| in_class(&pattern[p + 1..p + end], name[n])),
+                // Unclosed class, a literal bracket
+                None => Some(1).filter(|_| name[n] == b'['),
+            },
+            Some(&c) => Some(1).filter(|_| name[n] == c),
+            None => None,
+        };
+        match (matched, star) {
+            (Some(len), _) => {
+                p += len;
+                n += 1;
+            },
+            (None, Some((star_p, star_n))) => {
+                p = star_p;
+                n = star_n + 1;
+                star = Some((star_p, n));
+            },
+            (None, None) => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Index of the `]` closing the class that `pattern` starts with.
+fn class_end(pattern: &[u8]) -> Option<usize> {
+    // A `]` right after the opening bracket (or its negation) is part of the class
+    let first = match pattern.get(1) {
+        Some(b'!') | Some(b'^') => 3,
+        _ => 2,
+    };
+    pattern.iter().skip(first).position(|&c| c == b']').map(|pos| pos + first)
+}
+
+fn in_class(class: &[u8], c: u8) -> bool {
+    let (negated, class) = match class.first() {
+        Some(b'!') | Some(b'^') => (true, &class[1..]),
+        _ => (false, class),
+    };
+
+    let mut found = false;
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == b'-' {
+            found |= class[i] <= c && c <= class[i + 2];
+            i += 3;
+        }
+        else {
+            found |= class[i] == c;
+            i += 1;
+        }
+    }
+
+    found != negated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_pattern;
+
+    #[test]
+    fn wildcards_and_classes() {
+        assert!(matches_pattern(b"*.so", b"libc.so"));
+        assert!(!matches_pattern(b"*.so", b"libc.so.6"));
+        assert!(matches_pattern(b"*.so*", b"libc.so.6"));
+        assert!(matches_pattern(b"lib?.a", b"libm.a"));
+        assert!(matches_pattern(b"obj[0-9][0-9].o", b"obj32.o"));
+        assert!(!matches_pattern(b"obj[!3]*.o", b"obj32.o"));
+        assert!(matches_pattern(b"[]x]", b"]"));
+        assert!(matches_pattern(b"a*b*c", b"aXbYbZc"));
+        assert!(matches_pattern(b"[ab", b"[ab"));
+    }
+
+    #[test]
+    fn stars_do_not_backtrack_exponentially() {
+        let name = [b'a'; 200];
+        assert!(!matches_pattern(b"*a*a*a*a*a*a*a*a*a*a*a*a*b", &name));
+        assert!(matches_pattern(b"*a*a*a*a*a*a*a*a*a*a*a*a*", &name));
+    }
+}
@@ -0,0 +1,202 @@
+use ansi_term::{Color, Style};
+use failure::Error;
+
+use std::io::Write;
+
+use super::{Align, Node, Renderer, Report, Section, Table, Text, warning_count};
+
+/// Standalone HTML page keeping the terminal colors.
+pub struct HtmlRenderer;
+
+const STYLESHEET: &str = "
+body { background: #1d1f21; color: #c5c8c6; font-family: monospace; }
+section { margin-left: 1em; }
+table { border-collapse: collapse; }
+th, td { padding: 0 0.6em; vertical-align: top; white-space: pre-wrap; }
+th { text-align: left; }
+.right { text-align: right; }
+.key { color: #ffffff; }
+.trimmed { font-style: italic; }
+";
+
+impl Renderer for HtmlRenderer {
+
+    fn begin(&self, out: &mut dyn Write, files: &[&str]) -> Result<(), Error> {
+
+        let title = match files {
+            [file] => escape(file),
+            _ => format!("bininfo: {} files", files.len()),
+        };
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html>")?;
+        writeln!(out, "<head>")?;
+        writeln!(out, "<meta charset=\"utf-8\">")?;
+        writeln!(out, "<title>{}</title>", title)?;
+        writeln!(out, "<style>{}</style>", STYLESHEET)?;
+        writeln!(out, "</head>")?;
+        writeln!(out, "<body>")?;
+
+        Ok(())
+    }
+
+    fn render(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+
+        let doc = report.document;
+        // Several files are each titled with their path, one level up
+        let level = if report.count > 1 {
+            writeln!(out, "<h1>{}</h1>", escape(report.file))?;
+            writeln!(out, "<h2>{}</h2>", text_html(&doc.title))?;
+            3
+        }
+        else {
+            writeln!(out, "<h1>{}</h1>", text_html(&doc.title))?;
+            2
+        };
+        for node in &doc.children {
+            node_html(out, node, level)?;
+        }
+
+        Ok(())
+    }
+
+    fn brief(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+        writeln!(out, "<div>{}: {}{}</div>", escape(report.file), text_html(&report.document.title), escape(&warning_count(report)))?;
+        Ok(())
+    }
+
+    fn end(&self, out: &mut dyn Write, _files: &[&str]) -> Result<(), Error> {
+
+        writeln!(out, "</body>")?;
+        writeln!(out, "</html>")?;
+
+        Ok(())
+    }
+
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// CSS color of a terminal color, assuming the xterm palette.
+fn css_color(color: Color) -> String {
+    const BASIC: [&str; 16] = [
+        "#000000", "#cc6666", "#b5bd68", "#f0c674", "#81a2be", "#b294bb", "#8abeb7", "#ffffff",
+        "#666666", "#ff3334", "#9ec400", "#e7c547", "#7aa6da", "#b77ee0", "#54ced6", "#ffffff",
+    ];
+
+    match color {
+        Color::Black     => BASIC[0].to_string(),
+        Color::Red       => BASIC[1].to_string(),
+        Color::Green     => BASIC[2].to_string(),
+        Color::Yellow    => BASIC[3].to_string(),
+        Color::Blue      => BASIC[4].to_string(),
+        Color::Purple    => BASIC[5].to_string(),
+        Color::Cyan      => BASIC[6].to_string(),
+        Color::White     => BASIC[7].to_string(),
+        Color::RGB(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Color::Fixed(n) if n < 16 => BASIC[n as usize].to_string(),
+        Color::Fixed(n) if n < 232 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let n = n - 16;
+            format!("#{:02x}{:02x}{:02x}", level(n / 36), level(n / 6 % 6), level(n % 6))
+        },
+        Color::Fixed(n) => {
+            let grey = 8 + (n - 232) * 10;
+            format!("#{:02x}{:02x}{:02x}", grey, grey, grey)
+        },
+    }
+}
+
+fn css(style: &Style) -> String {
+    let mut css = String::new();
+    if let Some(color) = style.foreground {
+        css += &format!("color:{};", css_color(color));
+    }
+    if let Some(color) = style.background {
+        css += &format!("background:{};", css_color(color));
+    }
+    if style.is_bold {
+        css += "font-weight:bold;";
+    }
+    if style.is_underline {
+        css += "text-decoration:underline;";
+    }
+    css
+}
+
+fn text_html(text: &Text) -> String {
+    text.0.iter().map(|span| {
+        let css = css(&span.style);
+        if css.is_empty() {
+            escape(&span.text)
+        }
+        else {
+            format!("<span style=\"{}\">{}</span>", css, escape(&span.text))
+        }
+    }).collect()
+}
+
+fn node_html(out: &mut dyn Write, node: &Node, level: usize) -> Result<(), Error> {
+
+    match node {
+        Node::Section(section) => section_html(out, section, level)?,
+        Node::Field(key, value) => {
+            writeln!(out, "<div><span class=\"key\">{}</span>: {}</div>", escape(key), text_html(value))?;
+        },
+        Node::Line(line) => writeln!(out, "<div>{}</div>", text_html(line))?,
+        Node::Table(table) => table_html(out, table)?,
+        Node::Hex(bytes) => {
+            writeln!(out, "<pre>")?;
+            for chunk in bytes.chunks(16) {
+                let line: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+                writeln!(out, "{}", line.join(" "))?;
+            }
+            writeln!(out, "</pre>")?;
+        },
+        Node::Trimmed => writeln!(out, "<div class=\"trimmed\">Output trimmed...</div>")?,
+    }
+
+    Ok(())
+}
+
+fn section_html(out: &mut dyn Write, section: &Section, level: usize) -> Result<(), Error> {
+
+    let level = level.min(6);
+    writeln!(out, "<section>")?;
+    match section.count {
+        Some(count) => writeln!(out, "<h{lvl}>{}({})</h{lvl}>", text_html(&section.title), count, lvl = level)?,
+        None        => writeln!(out, "<h{lvl}>{}</h{lvl}>", text_html(&section.title), lvl = level)?,
+    }
+    for node in &section.children {
+        node_html(out, node, level + 1)?;
+    }
+    writeln!(out, "</section>")?;
+
+    Ok(())
+}
+
+fn table_html(out: &mut dyn Write, table: &Table) -> Result<(), Error> {
+
+    let class = |align: Align| if align == Align::Right { " class=\"right\"" } else { "" };
+
+    writeln!(out, "<table>")?;
+    write!(out, "<tr>")?;
+    for column in &table.columns {
+        write!(out, "<th{}>{}</th>", class(column.align), escape(&column.name))?;
+    }
+    writeln!(out, "</tr>")?;
+    for row in &table.rows {
+        write!(out, "<tr>")?;
+        for (cell, column) in row.iter().zip(&table.columns) {
+            write!(out, "<td{}>{}</td>", class(column.align), text_html(cell))?;
+        }
+        writeln!(out, "</tr>")?;
+    }
+    writeln!(out, "</table>")?;
+
+    Ok(())
+}
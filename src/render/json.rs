@@ -0,0 +1,118 @@
+use bininfo::{Binary, Problem};
+use bininfo::magic;
+use failure::Error;
+
+use std::io::Write;
+
+use super::{Renderer, Report};
+
+/// Version of the JSON document layout, bumped on incompatible changes.
+const JSON_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Serialize)]
+struct JsonDocument<'a> {
+    schema_version: u32,
+    bininfo_version: &'static str,
+    file: &'a str,
+    #[serde(flatten)]
+    binary: &'a Binary,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<JsonWarning>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    candidates: Vec<JsonCandidate>,
+}
+
+#[derive(Serialize)]
+struct JsonCandidate {
+    format: &'static str,
+    score: u8,
+}
+
+/// One line summary of a file, for `--brief`.
+#[derive(Serialize)]
+struct JsonBrief<'a> {
+    file: &'a str,
+    summary: String,
+    warnings: usize,
+}
+
+#[derive(Serialize)]
+struct JsonWarning {
+    offset: Option<u64>,
+    context: Vec<String>,
+    message: String,
+}
+
+impl<'a> From<&'a Problem> for JsonWarning {
+    fn from(problem: &'a Problem) -> Self {
+        JsonWarning {
+            offset: problem.offset,
+            context: problem.context.clone(),
+            message: problem.cause.to_string(),
+        }
+    }
+}
+
+/// Serialized parse result, for scripts. Ignores the document tree and
+/// dumps the library model instead, so no information is lost to trimming.
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+
+    fn begin(&self, out: &mut dyn Write, files: &[&str]) -> Result<(), Error> {
+        if files.len() != 1 {
+            writeln!(out, "[")?;
+        }
+        Ok(())
+    }
+
+    fn render(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+
+        let doc = JsonDocument {
+            schema_version: JSON_SCHEMA_VERSION,
+            bininfo_version: env!("CARGO_PKG_VERSION"),
+            file: report.file,
+            binary: report.binary,
+            warnings: report.warnings.iter().map(JsonWarning::from).collect(),
+            candidates: report.candidates.iter()
+                .filter_map(|candidate| Some(JsonCandidate { format: magic::detector(candidate.format)?.name, score: candidate.score }))
+                .collect(),
+        };
+        // Several files make an array of documents
+        if report.count > 1 && report.index > 0 {
+            writeln!(out, ",")?;
+        }
+        serde_json::to_writer_pretty(&mut *out, &doc)?;
+        if report.count == 1 {
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+
+    fn brief(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+
+        let brief = JsonBrief {
+            file: report.file,
+            summary: report.document.title.plain(),
+            warnings: report.warnings.len(),
+        };
+        if report.count > 1 && report.index > 0 {
+            writeln!(out, ",")?;
+        }
+        serde_json::to_writer(&mut *out, &brief)?;
+        if report.count == 1 {
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+
+    fn end(&self, out: &mut dyn Write, files: &[&str]) -> Result<(), Error> {
+        if files.len() != 1 {
+            writeln!(out, "\n]")?;
+        }
+        Ok(())
+    }
+
+}
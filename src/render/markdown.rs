@@ -0,0 +1,109 @@
+use failure::Error;
+
+use std::io::Write;
+
+use super::{Node, Renderer, Report, Section, Table, Text, warning_count};
+
+/// GitHub flavored Markdown. Colors are dropped.
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+
+    fn render(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+
+        let doc = report.document;
+        writeln!(out, "# {}", escape(&doc.title))?;
+        writeln!(out)?;
+        writeln!(out, "`{}`", report.file)?;
+        writeln!(out)?;
+        for node in &doc.children {
+            node_md(out, node, 2)?;
+        }
+
+        Ok(())
+    }
+
+    fn brief(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+        writeln!(out, "- `{}`: {}{}", report.file, escape(&report.document.title), warning_count(report))?;
+        Ok(())
+    }
+
+}
+
+fn escape(text: &Text) -> String {
+    let mut escaped = String::new();
+    for c in text.plain().chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            '\n' => escaped.push_str("<br>"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn node_md(out: &mut dyn Write, node: &Node, level: usize) -> Result<(), Error> {
+
+    match node {
+        Node::Section(section) => section_md(out, section, level)?,
+        Node::Field(key, value) => writeln!(out, "- **{}**: {}", key, escape(value))?,
+        Node::Line(line) => writeln!(out, "{}  ", escape(line))?,
+        Node::Table(table) => table_md(out, table)?,
+        Node::Hex(bytes) => {
+            writeln!(out)?;
+            writeln!(out, "```")?;
+            for chunk in bytes.chunks(16) {
+                let line: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+                writeln!(out, "{}", line.join(" "))?;
+            }
+            writeln!(out, "```")?;
+            writeln!(out)?;
+        },
+        Node::Trimmed => {
+            writeln!(out)?;
+            writeln!(out, "*Output trimmed...*")?;
+            writeln!(out)?;
+        },
+    }
+
+    Ok(())
+}
+
+fn section_md(out: &mut dyn Write, section: &Section, level: usize) -> Result<(), Error> {
+
+    // Markdown has no headings past level 6
+    let hashes = "#".repeat(level.min(6));
+    match section.count {
+        Some(count) => writeln!(out, "{} {} ({})", hashes, escape(&section.title), count)?,
+        None        => writeln!(out, "{} {}", hashes, escape(&section.title))?,
+    }
+    writeln!(out)?;
+    for node in &section.children {
+        node_md(out, node, level + 1)?;
+    }
+    writeln!(out)?;
+
+    Ok(())
+}
+
+fn table_md(out: &mut dyn Write, table: &Table) -> Result<(), Error> {
+    use super::Align;
+
+    writeln!(out)?;
+    let names: Vec<&str> = table.columns.iter().map(|c| c.name.as_str()).collect();
+    writeln!(out, "| {} |", names.join(" | "))?;
+    let aligns: Vec<&str> = table.columns.iter()
+        .map(|c| if c.align == Align::Right { "---:" } else { "---" })
+        .collect();
+    writeln!(out, "|{}|", aligns.join("|"))?;
+    for row in &table.rows {
+        let cells: Vec<String> = row.iter().map(escape).collect();
+        writeln!(out, "| {} |", cells.join(" | "))?;
+    }
+    writeln!(out)?;
+
+    Ok(())
+}
@@ -0,0 +1,309 @@
+//! Output styles.
+//!
+//! Formats describe a parsed file as a [`Document`], a tree of sections,
+//! key-value fields, tables and hex dumps. A [`Renderer`] walks that tree and
+//! writes it out in its own style, so adding a style never touches the
+//! format modules.
+
+mod text;
+mod json;
+mod markdown;
+mod html;
+
+pub use self::text::TextRenderer;
+pub use self::json::JsonRenderer;
+pub use self::markdown::MarkdownRenderer;
+pub use self::html::HtmlRenderer;
+
+use ansi_term::Style;
+use bininfo::{Binary, Problem};
+use bininfo::magic::Candidate;
+use failure::Error;
+
+use std::io::Write;
+
+/// Everything a renderer may need to write out one file.
+pub struct Report<'a> {
+    pub file:     &'a str,
+    pub binary:   &'a Binary,
+    pub document: &'a Document,
+    /// Problems a lenient parse recovered from
+    pub warnings: &'a [Problem],
+    /// Formats the file could be, when there is more than one
+    pub candidates: &'a [Candidate],
+    /// Number of reports written before this one
+    pub index:    usize,
+    /// Number of files given to [`Renderer::begin`]
+    pub count:    usize,
+}
+
+/// Writes [`Report`]s in one output style: `begin` with the files about to
+/// be reported, `render` for each of them and `end`.
+pub trait Renderer {
+    fn begin(&self, _out: &mut dyn Write, _files: &[&str]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn render(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error>;
+
+    fn end(&self, _out: &mut dyn Write, _files: &[&str]) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Writes a one line summary instead of the whole report, for `--brief`.
+    fn brief(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+        writeln!(out, "{}: {}{}", report.file, report.document.title.plain(), warning_count(report))?;
+        Ok(())
+    }
+}
+
+/// Note on the number of warnings of a `--brief` line, if any.
+pub fn warning_count(report: &Report) -> String {
+    match report.warnings.len() {
+        0 => String::new(),
+        1 => " (1 warning)".to_string(),
+        n => format!(" ({} warnings)", n),
+    }
+}
+
+/// Piece of text sharing one style.
+#[derive(Debug, Clone)]
+pub struct Span {
+    pub text:  String,
+    pub style: Style,
+}
+
+impl Span {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Span { text: text.into(), style: Style::default() }
+    }
+}
+
+impl<'a> From<&'a str> for Span {
+    fn from(text: &'a str) -> Self {
+        Span::new(text)
+    }
+}
+
+impl<'a> From<&'a String> for Span {
+    fn from(text: &'a String) -> Self {
+        Span::new(text.as_str())
+    }
+}
+
+impl From<String> for Span {
+    fn from(text: String) -> Self {
+        Span::new(text)
+    }
+}
+
+macro_rules! span_from_display {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Span {
+                fn from(value: $t) -> Self {
+                    Span::new(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+span_from_display!(u8, u16, u32, u64, usize, i8, i16, i32, i64, bool);
+
+/// Styled `text`, the tree counterpart of `Color::Red.paint(...)`.
+pub fn paint<S: Into<Style>, T: Into<String>>(style: S, text: T) -> Span {
+    Span { text: text.into(), style: style.into() }
+}
+
+/// Line of text made of differently styled spans.
+#[derive(Debug, Clone, Default)]
+pub struct Text(pub Vec<Span>);
+
+impl Text {
+    pub fn push<S: Into<Span>>(&mut self, span: S) -> &mut Self {
+        self.0.push(span.into());
+        self
+    }
+
+    /// Text without any styling.
+    pub fn plain(&self) -> String {
+        self.0.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    pub fn append<T: Into<Text>>(&mut self, text: T) -> &mut Self {
+        self.0.extend(text.into().0);
+        self
+    }
+
+    /// Give `style` to every span that has none of its own.
+    pub fn styled<S: Into<Style>>(mut self, style: S) -> Self {
+        let style = style.into();
+        for span in self.0.iter_mut().filter(|s| s.style == Style::default()) {
+            span.style = style;
+        }
+        self
+    }
+
+    /// Break lines longer than `width` at spaces, keeping span styles.
+    pub fn wrap(&self, width: usize) -> Text {
+        let mut wrapped = Text::default();
+        let mut column = 0;
+
+        for span in &self.0 {
+            let mut text = String::new();
+            for (i, line) in span.text.split('\n').enumerate() {
+                if i > 0 {
+                    text.push('\n');
+                    column = 0;
+                }
+                for (j, word) in line.split(' ').enumerate() {
+                    if j > 0 {
+                        if column + 1 + word.chars().count() > width && column > 0 {
+                            text.push('\n');
+                            column = 0;
+                        }
+                        else {
+                            text.push(' ');
+                            column += 1;
+                        }
+                    }
+                    text.push_str(word);
+                    column += word.chars().count();
+                }
+            }
+            wrapped.0.push(Span { text, style: span.style });
+        }
+
+        wrapped
+    }
+}
+
+impl<T: Into<Span>> From<T> for Text {
+    fn from(span: T) -> Self {
+        Text(vec![span.into()])
+    }
+}
+
+/// Builds a [`Text`] out of spans and other texts.
+macro_rules! text {
+    ($($part:expr),* $(,)*) => {
+        {
+            let mut text = crate::render::Text::default();
+            $( text.append($part); )*
+            text
+        }
+    };
+}
+
+/// Builds a table row out of anything convertible into a [`Text`].
+macro_rules! cells {
+    ($($cell:expr),* $(,)*) => {
+        vec![$( crate::render::Text::from($cell) ),*]
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Column {
+    pub name:  String,
+    pub align: Align,
+}
+
+#[derive(Debug)]
+pub struct Table {
+    pub columns: Vec<Column>,
+    pub rows:    Vec<Vec<Text>>,
+}
+
+impl Table {
+    /// Table with left aligned columns named `names`.
+    pub fn new(names: &[&str]) -> Self {
+        Table {
+            columns: names.iter().map(|n| Column { name: n.to_string(), align: Align::Left }).collect(),
+            rows:    Vec::new(),
+        }
+    }
+
+    /// Right align columns at `idxs`.
+    pub fn align_right(mut self, idxs: &[usize]) -> Self {
+        for idx in idxs {
+            self.columns[*idx].align = Align::Right;
+        }
+        self
+    }
+
+    pub fn row(&mut self, cells: Vec<Text>) {
+        self.rows.push(cells);
+    }
+}
+
+#[derive(Debug)]
+pub enum Node {
+    Section(Section),
+    /// `key: value` pair.
+    Field(String, Text),
+    Line(Text),
+    Table(Table),
+    /// Bytes shown as a hex dump.
+    Hex(Vec<u8>),
+    /// Marks that the preceding list was cut after `--trim` entries.
+    Trimmed,
+}
+
+/// Titled group of nodes. The root section of a file is its [`Document`].
+#[derive(Debug, Default)]
+pub struct Section {
+    pub title:    Text,
+    pub count:    Option<usize>,
+    pub children: Vec<Node>,
+}
+
+/// Description of a whole file, titled with a one line summary.
+pub type Document = Section;
+
+impl Section {
+    pub fn new<T: Into<Text>>(title: T) -> Self {
+        Section { title: title.into(), count: None, children: Vec::new() }
+    }
+
+    /// Section listing `count` entries, shown as `Title(count)`.
+    pub fn with_count<T: Into<Text>>(title: T, count: usize) -> Self {
+        Section { title: title.into(), count: Some(count), children: Vec::new() }
+    }
+
+    pub fn field<V: Into<Text>>(&mut self, key: &str, value: V) -> &mut Self {
+        self.children.push(Node::Field(key.to_string(), value.into()));
+        self
+    }
+
+    pub fn line<V: Into<Text>>(&mut self, line: V) -> &mut Self {
+        self.children.push(Node::Line(line.into()));
+        self
+    }
+
+    pub fn table(&mut self, table: Table) -> &mut Self {
+        self.children.push(Node::Table(table));
+        self
+    }
+
+    pub fn section(&mut self, section: Section) -> &mut Self {
+        self.children.push(Node::Section(section));
+        self
+    }
+
+    pub fn hex(&mut self, bytes: &[u8]) -> &mut Self {
+        self.children.push(Node::Hex(bytes.to_vec()));
+        self
+    }
+
+    pub fn trimmed(&mut self) -> &mut Self {
+        self.children.push(Node::Trimmed);
+        self
+    }
+}
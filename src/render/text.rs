@@ -0,0 +1,134 @@
+use ansi_term::{Color, Style};
+use failure::Error;
+use prettytable::{Cell, Row};
+
+use std::io::Write;
+
+use super::{Align, Node, Renderer, Report, Section, Table, Text, warning_count};
+
+/// Terminal output, with or without ANSI colors.
+pub struct TextRenderer {
+    pub color: bool,
+}
+
+impl Renderer for TextRenderer {
+
+    fn render(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+
+        let doc = report.document;
+        if report.count > 1 {
+            if report.index > 0 {
+                writeln!(out)?;
+            }
+            let header = format!("==> {} <==", report.file);
+            writeln!(out, "{}", if self.color { Style::new().bold().paint(header).to_string() } else { header })?;
+        }
+        writeln!(out, "{}", self.text(&doc.title))?;
+        writeln!(out)?;
+        for node in &doc.children {
+            self.node(out, node, 0)?;
+        }
+
+        Ok(())
+    }
+
+    fn brief(&self, out: &mut dyn Write, report: &Report) -> Result<(), Error> {
+        writeln!(out, "{}: {}{}", report.file, self.text(&report.document.title), warning_count(report))?;
+        Ok(())
+    }
+
+}
+
+impl TextRenderer {
+
+    fn text(&self, text: &Text) -> String {
+        if self.color {
+            text.0.iter().map(|s| s.style.paint(s.text.as_str()).to_string()).collect()
+        }
+        else {
+            text.plain()
+        }
+    }
+
+    fn node(&self, out: &mut dyn Write, node: &Node, indent: usize) -> Result<(), Error> {
+
+        match node {
+            Node::Section(section) => self.section(out, section, indent)?,
+            Node::Field(key, value) => {
+                writeln!(out, "{:>idt$}{}: {}", "", key, self.text(value), idt = indent)?;
+            },
+            Node::Line(line) => {
+                writeln!(out, "{:>idt$}{}", "", self.text(line), idt = indent)?;
+            },
+            Node::Table(table) => self.table(out, table, indent)?,
+            Node::Hex(bytes) => {
+                for chunk in bytes.chunks(16) {
+                    let line: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+                    writeln!(out, "{:>idt$}{}", "", line.join(" "), idt = indent)?;
+                }
+            },
+            Node::Trimmed => {
+                writeln!(out, "{:>idt$}Output trimmed...", "", idt = indent)?;
+            },
+        }
+
+        Ok(())
+    }
+
+    fn section(&self, out: &mut dyn Write, section: &Section, indent: usize) -> Result<(), Error> {
+
+        // Top level titles are underlined, nested ones keep their own style
+        let mut title = if indent == 0 && self.color {
+            section.title.0.iter().map(|s| {
+                let mut style = s.style;
+                style.is_underline = true;
+                if style.foreground.is_none() {
+                    style.foreground = Some(Color::White);
+                }
+                style.paint(s.text.as_str()).to_string()
+            }).collect()
+        }
+        else {
+            self.text(&section.title)
+        };
+        if let Some(count) = section.count {
+            title += &format!("({})", count);
+        }
+
+        writeln!(out, "{:>idt$}{}", "", title, idt = indent)?;
+        for node in &section.children {
+            self.node(out, node, indent + 2)?;
+        }
+        writeln!(out)?;
+
+        Ok(())
+    }
+
+    fn table(&self, out: &mut dyn Write, table: &Table, indent: usize) -> Result<(), Error> {
+
+        let mut pt = prettytable::Table::new();
+        let format = prettytable::format::FormatBuilder::new()
+            .column_separator(' ')
+            .borders(' ')
+            .padding(1, 1)
+            .build();
+        pt.set_format(format);
+
+        let spec = |align: Align| if align == Align::Right { "r" } else { "l" };
+        pt.add_row(Row::new(table.columns.iter()
+                            .map(|c| Cell::new(&c.name).style_spec(spec(c.align)))
+                            .collect()));
+        for row in &table.rows {
+            pt.add_row(Row::new(row.iter().zip(&table.columns)
+                                .map(|(cell, c)| Cell::new(&self.text(cell)).style_spec(spec(c.align)))
+                                .collect()));
+        }
+
+        for line in pt.to_string().lines() {
+            writeln!(out, "{:>idt$}{}", "", line, idt = indent.saturating_sub(2))?;
+        }
+
+        Ok(())
+    }
+
+}
@@ -0,0 +1,106 @@
+//! ar archives, on files under tests/samples.
+//!
+//! `libgnu.a` holds `a.o`, defining `alpha`, and
+//! `a_very_long_member_name_object.o`, defining
+//! `a_rather_long_function_name_here` and `beta`, both built with `gcc -c`
+//! and archived with `ar rcs`. `libbsd.a` holds the same objects archived
+//! with `llvm-ar --format=bsd rcs`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use bininfo::{Binary, Recovery};
+use bininfo::formats::ar::{Ar, ArVariant, SymbolIndex};
+use bininfo::magic::{self, Format};
+
+fn sample_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/samples").join(name)
+}
+
+fn sample(name: &str) -> (Vec<u8>, Ar) {
+    let buf = fs::read(sample_path(name)).unwrap();
+    match Binary::parse(&buf).unwrap() {
+        Binary::Ar(ar) => (buf, ar),
+        _ => panic!("should parse as ar"),
+    }
+}
+
+#[test]
+fn gnu_and_bsd_archives_name_members_alike() {
+    for (name, variant, index) in [("libgnu.a", ArVariant::Gnu, SymbolIndex::Gnu), ("libbsd.a", ArVariant::Bsd, SymbolIndex::Bsd)] {
+        let (_, ar) = sample(name);
+
+        assert_eq!((ar.variant, ar.symbol_index, ar.thin), (variant, Some(index), false));
+        let members: Vec<&str> = ar.members.iter().map(|member| member.name.as_str()).collect();
+        assert_eq!(members, ["a.o", "a_very_long_member_name_object.o"]);
+        let symbols: Vec<(&str, Option<usize>)> = ar.symbols.iter().map(|symbol| (symbol.name.as_str(), symbol.member)).collect();
+        assert_eq!(symbols, [("alpha", Some(0)), ("a_rather_long_function_name_here", Some(1)), ("beta", Some(1))]);
+        assert!(!ar.is_deb());
+    }
+}
+
+#[test]
+fn members_parse_as_their_own_format() {
+    let (buf, mut ar) = sample("libbsd.a");
+    assert_eq!(magic::detect(ar.member_data(&buf, &ar.members[1]).unwrap()), Format::Elf);
+
+    ar.parse_members(&buf, &mut Recovery::strict()).unwrap();
+    match &ar.members[1].binary {
+        Some(Binary::Elf(elf)) => assert_eq!(elf.symtab.len(), 5),
+        _ => panic!("member should parse as ELF"),
+    }
+}
+
+#[test]
+fn archives_and_members_shorter_than_a_magic_are_read() {
+    match Binary::parse(b"!<arch>\n").unwrap() {
+        Binary::Ar(ar) => assert!(ar.members.is_empty() && ar.symbols.is_empty()),
+        _ => panic!("an empty archive should parse as ar"),
+    }
+
+    let mut buf = b"!<arch>\n".to_vec();
+    buf.extend_from_slice(format!("{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", "hi.txt/", 0, 0, 0, 644, 3).as_bytes());
+    buf.extend_from_slice(b"hi\n\n");
+    let mut ar = match Binary::parse(&buf).unwrap() {
+        Binary::Ar(ar) => ar,
+        _ => panic!("should parse as ar"),
+    };
+    ar.parse_members(&buf, &mut Recovery::strict()).unwrap();
+    assert_eq!(ar.members[0].name, "hi.txt");
+    assert!(matches!(ar.members[0].binary, Some(Binary::Unknown)));
+}
+
+#[test]
+fn members_of_thin_archives_are_left_unparsed_with_a_warning() {
+    // Headers only, the member data is in hi.txt
+    let mut buf = b"!<thin>\n".to_vec();
+    buf.extend_from_slice(format!("{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", "hi.txt/", 0, 0, 0, 644, 3).as_bytes());
+    let mut ar = match Binary::parse(&buf).unwrap() {
+        Binary::Ar(ar) => ar,
+        _ => panic!("should parse as ar"),
+    };
+    assert!(ar.thin);
+    assert!(ar.parse_members(&buf, &mut Recovery::strict()).is_err());
+
+    let mut rec = Recovery::lenient();
+    ar.parse_members(&buf, &mut rec).unwrap();
+    assert_eq!(rec.warnings.len(), 1);
+    assert!(rec.warnings[0].to_string().contains("thin archive"));
+    assert_eq!(ar.members[0].name, "hi.txt");
+    assert!(ar.members[0].binary.is_none());
+}
+
+#[test]
+fn members_flag_prints_every_member() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(["--output", "plain", "--members", "--symbols"])
+        .arg(sample_path("libgnu.a"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout.starts_with("ar archive GNU\n"));
+    assert!(stdout.lines().any(|line| line.starts_with("a_very_long_member_name_object.o: ELF REL")));
+}
@@ -0,0 +1,46 @@
+//! Files that used to crash bininfo. Each of them must now either parse or
+//! fail with an error, both through the library and through every output
+//! style of the binary.
+
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+fn crashers() -> Vec<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/crashers");
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .expect("crashers directory")
+        .map(|entry| entry.expect("crashers entry").path())
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty());
+    paths
+}
+
+#[test]
+fn parsers_do_not_panic() {
+    for path in crashers() {
+        let buf = fs::read(&path).unwrap();
+        let parsed = panic::catch_unwind(|| { let _ = bininfo::Binary::parse(&buf); });
+        assert!(parsed.is_ok(), "{} panicked", path.display());
+    }
+}
+
+#[test]
+fn binary_does_not_crash() {
+    for path in crashers() {
+        for output in ["text", "json", "markdown", "html"] {
+            let status = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+                .args(["--output", output, "--trim", "5"])
+                .arg(&path)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .unwrap();
+            // 0 when the file parsed, 1 when bininfo reported an error.
+            assert!(status.code() == Some(0) || status.code() == Some(1),
+                    "{} with --output {}: {}", path.display(), output, status);
+        }
+    }
+}
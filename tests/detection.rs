@@ -0,0 +1,54 @@
+//! Format detection, and overriding it.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn crasher(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/crashers").join(name)
+}
+
+fn bininfo(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn list_formats_needs_no_files() {
+    let output = bininfo(&["--list-formats"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout.lines().any(|l| l.starts_with("macho-fat ")));
+    assert!(stdout.lines().any(|l| l.starts_with("javaclass ")));
+}
+
+#[test]
+fn force_format_skips_detection() {
+    let file = crasher("elf-symtab-zero-entsize");
+    let output = bininfo(&["--force-format", "pe", file.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Could not read PE signature"));
+
+    let output = bininfo(&["--force-format", "nope", file.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown format \"nope\""));
+}
+
+#[test]
+fn ambiguous_files_name_the_other_candidates() {
+    // Two fat architectures, or a Java class of major version 2
+    let mut buf = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 2];
+    buf.resize(64, 0);
+    let path = std::env::temp_dir().join(format!("bininfo-ambiguous-{}", std::process::id()));
+    fs::write(&path, &buf).unwrap();
+    let output = bininfo(&["--brief", path.to_str().unwrap()]);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stderr),
+               "Mach-O universal binaries are not supported; could also be javaclass, see --force-format\n");
+}
@@ -0,0 +1,37 @@
+//! Parse errors say where in the file they happened.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use bininfo::{Binary, Problem};
+
+fn crasher(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/crashers").join(name)
+}
+
+#[test]
+fn problem_records_offset_and_structure() {
+    let buf = fs::read(crasher("elf-symtab-zero-entsize")).unwrap();
+    let err = Binary::parse(&buf).err().expect("parse should fail");
+    let problem = err.downcast_ref::<Problem>().expect("error should be a Problem");
+
+    assert_eq!(problem.offset, Some(0xEC));
+    assert_eq!(problem.context, ["ELF symbol table", "section .symtab"]);
+}
+
+#[test]
+fn binary_prints_hexdump_around_offset() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .arg(crasher("elf-symtab-zero-entsize"))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let lines: Vec<&str> = stderr.lines().collect();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(lines[0], "Could not read ELF symbol table in section .symtab at 0xEC: Entry size is 0");
+
+    let line = lines.iter().position(|l| l.starts_with("000000e0  ")).expect("line holding the offset");
+    assert_eq!(lines[line + 1].trim_end(), format!("{}^^", " ".repeat(8 + 2 + 12 * 3 + 1)));
+}
@@ -0,0 +1,268 @@
+//! ELF specifics, on files under tests/samples.
+//!
+//! `hello-sstripped` is a small C program built with `gcc -Os -s` calling
+//! strncpy, strlen and printf, then cut down to its program headers and
+//! segments like sstrip does. `core-segv` is the core dump of a program
+//! run as `./crash arg1 arg2` that writes through a null pointer, cut after
+//! its notes. `hello-g.o` is the object of the same program built with
+//! `gcc -g -O1 -c`, whose DWARF 5 strings and addresses need relocating.
+//! `hello-zstd.o` is that object after `objcopy --compress-debug-sections=zstd`.
+//! `mips64el.o` is a small function calling another through a global, built
+//! with `llc -march=mips64el -relocation-model=pic`. `hello-signed.ko` is
+//! a stand-in for a kernel module built with `gcc -O2 -c`, with `.modinfo`,
+//! `__versions` and `.gnu.linkonce.this_module` sections, then signed like
+//! sign-file does with `openssl cms -sign -binary -noattr -nocerts -md sha256`.
+//! `greet` is a program exporting its `greet` function, built with
+//! `gcc -Os -s -rdynamic -fcf-protection=full -Wl,-z,ibt,-z,shstk`, whose
+//! use of `stdout` copies it in through a copy relocation.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use bininfo::Binary;
+use bininfo::formats::elf::{DT_FINI_ARRAY, DT_INIT_ARRAY, ELF_NOTE_OS_LINUX, EM_AARCH64, Elf, Elf_reloc_entries, GNU_PROPERTY_X86_ISA_1_NEEDED};
+use bininfo::formats::elf::compress::ELFCOMPRESS_ZSTD;
+use bininfo::formats::elf::coredump::*;
+use bininfo::formats::elf::dwarf::*;
+use bininfo::formats::elf::layout::RegionKind;
+use bininfo::formats::elf::module::PKEY_ID_PKCS7;
+use bininfo::formats::elf::note::NoteDesc;
+use scroll::Pread;
+
+fn string_at(strtab: &[u8], offset: u32) -> &str {
+    strtab.pread::<&str>(offset as usize).unwrap()
+}
+
+fn sample_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/samples").join(name)
+}
+
+fn sample_bytes(name: &str) -> Vec<u8> {
+    fs::read(sample_path(name)).unwrap()
+}
+
+fn parse(buf: &[u8]) -> Elf {
+    match Binary::parse(buf).unwrap() {
+        Binary::Elf(elf) => elf,
+        _ => panic!("should parse as ELF"),
+    }
+}
+
+fn sample(name: &str) -> Elf {
+    parse(&sample_bytes(name))
+}
+
+#[test]
+fn stripped_files_are_read_through_the_dynamic_segment() {
+    let elf = sample("hello-sstripped");
+
+    assert!(elf.section_headers.is_empty());
+    assert_eq!(elf.dynsym.len(), 9);
+    let table = |name: &str| elf.relocations.iter().find(|relocs| relocs.name == name).unwrap();
+    assert_eq!(table("DT_RELA").len(), 8);
+    assert_eq!(table("DT_JMPREL").len(), 3);
+    assert_eq!(elf.notes.len(), 3);
+    assert!(matches!(elf.note_desc(&elf.notes[2]), NoteDesc::AbiTag { os: ELF_NOTE_OS_LINUX, version: [3, 2, 0] }));
+    match elf.note_desc(&elf.notes[0]) {
+        NoteDesc::Properties(properties) => assert_eq!(properties[0].pr_type, GNU_PROPERTY_X86_ISA_1_NEEDED),
+        other => panic!("decoded as {:?}", other),
+    }
+
+    let arrays: Vec<(u64, usize)> = elf.init_arrays.iter().map(|array| (array.tag, array.functions.len())).collect();
+    assert_eq!(arrays, [(DT_INIT_ARRAY, 1), (DT_FINI_ARRAY, 1)]);
+    assert!(elf.init_arrays.iter().all(|array| array.functions.iter().all(|&function| function != 0)));
+
+    let gnu_hash = elf.gnu_hash.as_ref().expect("DT_GNU_HASH table");
+    assert_eq!(gnu_hash.symbol_count(), 9);
+    assert!(elf.unreachable_symbols().is_empty());
+
+    let printf = elf.dynsym.iter().position(|sym| string_at(&elf.dynstr, sym.st_name) == "printf").unwrap();
+    let version = elf.symbol_version(printf).unwrap();
+    assert_eq!(string_at(&elf.dynstr, version.name), "GLIBC_2.2.5");
+    assert!(!version.hidden && !version.defined);
+}
+
+#[test]
+fn copied_symbols_bind_to_the_version_of_their_library() {
+    let elf = sample("greet");
+
+    // Defined in .bss by the copy relocation, but versioned in .gnu.version_r
+    let stdout = elf.dynsym.iter().position(|sym| string_at(&elf.dynstr, sym.st_name) == "stdout").unwrap();
+    assert_ne!(elf.dynsym[stdout].st_shndx, 0);
+    let version = elf.symbol_version(stdout).unwrap();
+    assert_eq!(string_at(&elf.dynstr, version.name), "GLIBC_2.2.5");
+    assert!(!version.defined);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(["--output", "plain", "--symbols"])
+        .arg(sample_path("greet"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(" stdout@GLIBC_2.2.5 "));
+    assert!(!stdout.contains("@@"));
+}
+
+#[test]
+fn symbols_missing_from_the_hash_table_do_not_fail_parsing() {
+    let mut buf = sample_bytes("greet");
+    assert!(sample("greet").unreachable_symbols().is_empty());
+
+    // Renaming the exported function in .dynstr moves it off its hash chain
+    let name = buf.windows(7).position(|window| window == b"\0greet\0").unwrap();
+    buf[name + 5] = b'u';
+    let elf = parse(&buf);
+
+    let unreachable = elf.unreachable_symbols();
+    assert_eq!(unreachable.len(), 1);
+    assert_eq!(string_at(&elf.dynstr, elf.dynsym[unreachable[0].index].st_name), "greeu");
+}
+
+#[test]
+fn cet_properties_are_only_read_on_x86() {
+    let mut buf = sample_bytes("greet");
+    let hardening = parse(&buf).hardening();
+    assert!(hardening.ibt && hardening.shstk);
+
+    // The same property number means something else on other machines
+    buf[18..20].copy_from_slice(&EM_AARCH64.to_le_bytes());
+    let hardening = parse(&buf).hardening();
+    assert!(!hardening.ibt && !hardening.shstk);
+}
+
+#[test]
+fn core_dumps_tell_what_crashed() {
+    let elf = sample("core-segv");
+    let core = elf.core.expect("core dump notes");
+
+    let process = core.process.unwrap();
+    assert_eq!(process.pr_fname, "crash");
+    assert_eq!(process.pr_psargs, "./crash arg1 arg2");
+
+    let signal = core.signal.unwrap();
+    assert_eq!(signal.si_signo, SIGSEGV);
+    assert_eq!(signal.si_addr, Some(0));
+
+    assert_eq!(core.threads.len(), 1);
+    assert_eq!(core.threads[0].pr_pid, process.pr_pid);
+    assert_eq!(core.threads[0].registers.len(), register_names(elf.header.e_machine).unwrap().len());
+
+    assert_eq!(core.page_size, 0x1000);
+    assert_eq!(core.files.len(), 15);
+    assert!(core.files[0].name.ends_with("/crash"));
+    assert!(core.auxv.iter().any(|entry| entry.a_type == AT_PAGESZ && entry.a_val == 0x1000));
+}
+
+#[test]
+fn debug_information_maps_addresses_to_lines() {
+    let elf = sample("hello-g.o");
+    let dwarf = elf.dwarf.expect("DWARF debug information");
+
+    assert_eq!(dwarf.units.len(), 1);
+    let unit = &dwarf.units[0];
+    assert_eq!(unit.version, 5);
+    assert_eq!(unit.name.as_ref().unwrap(), "hello.c");
+    assert!(unit.producer.as_ref().unwrap().starts_with("GNU C17"));
+    assert_eq!(unit.language, Some(DW_LANG_C11));
+    assert!(unit.files.iter().any(|file| file == "/usr/include/stdio.h"));
+
+    // strncpy call of the program
+    let location = dwarf.find_line(0x10).unwrap();
+    assert!(location.file.unwrap().ends_with("/hello.c"));
+    assert_eq!(location.line, 5);
+}
+
+#[test]
+fn compressed_debug_sections_read_like_plain_ones() {
+    let plain = sample("hello-g.o");
+    let elf = sample("hello-zstd.o");
+    let info = elf.section_headers.iter().position(|head| string_at(&elf.sh_strtab, head.sh_name) == ".debug_info").unwrap();
+
+    let compression = elf.compression(info).expect(".debug_info compressed");
+    assert_eq!(compression.ch_type, ELFCOMPRESS_ZSTD);
+    assert_eq!(compression.ch_size, plain.section_headers[info].sh_size);
+    assert!(elf.section_headers[info].sh_size < compression.ch_size);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/samples/hello-zstd.o");
+    assert_eq!(elf.section_data(&fs::read(path).unwrap(), info).unwrap().len() as u64, compression.ch_size);
+
+    let (plain, dwarf) = (plain.dwarf.unwrap(), elf.dwarf.expect("DWARF debug information"));
+    assert_eq!(dwarf.units.len(), plain.units.len());
+    assert_eq!(dwarf.units[0].name, plain.units[0].name);
+    assert_eq!(dwarf.find_line(0x10).unwrap().line, 5);
+}
+
+#[test]
+fn object_relocations_refer_to_the_symbol_table() {
+    let elf = sample("hello-g.o");
+    let text = elf.relocations.iter().find(|relocs| relocs.name == ".rela.text").unwrap();
+
+    assert!(text.uses_symtab(&elf.section_headers));
+    assert_eq!(string_at(&elf.sh_strtab, elf.section_headers[text.sh_info as usize].sh_name), ".text");
+
+    let names: Vec<&str> = match &text.entries {
+        Elf_reloc_entries::Rela(relas) => relas.iter()
+            .map(|rela| string_at(&elf.symstr, elf.symtab[elf.r_sym(rela.r_info)].st_name))
+            .filter(|name| !name.is_empty())
+            .collect(),
+        _ => panic!("x86-64 relocations have addends"),
+    };
+    assert_eq!(names, ["strncpy", "strlen", ".LC0", "printf"]);
+}
+
+#[test]
+fn mips64_relocations_pack_three_types() {
+    let elf = sample("mips64el.o");
+    let text = elf.relocations.iter().find(|relocs| relocs.name == ".rela.text").unwrap();
+    let relocs: Vec<(String, &str)> = match &text.entries {
+        Elf_reloc_entries::Rela(relas) => relas.iter()
+            .map(|rela| (elf.r_type_name(rela.r_info), string_at(&elf.symstr, elf.symtab[elf.r_sym(rela.r_info)].st_name)))
+            .collect(),
+        _ => panic!("should hold RELA relocations"),
+    };
+    let relocs: Vec<(&str, &str)> = relocs.iter().map(|(typ, name)| (typ.as_str(), *name)).collect();
+    assert_eq!(relocs, [("MIPS_GPREL16/MIPS_SUB/MIPS_HI16", "main"), ("MIPS_GPREL16/MIPS_SUB/MIPS_LO16", "main"),
+                        ("MIPS_GOT_DISP", "g"), ("MIPS_CALL16", "f"), ("MIPS_JALR", "f")]);
+}
+
+#[test]
+fn layout_accounts_for_every_byte() {
+    for name in ["hello-g.o", "hello-sstripped"] {
+        let elf = sample(name);
+        let layout = elf.layout();
+
+        let mut end = 0;
+        for region in &layout {
+            assert_eq!(region.offset, end, "{} has a hole or overlap at {:#x}", name, end);
+            assert!(region.overlaps.is_none());
+            end = region.offset + region.size;
+        }
+        assert_eq!(end, elf.file_size);
+    }
+
+    let object = sample("hello-g.o").layout();
+    assert_eq!(object.last().unwrap().kind, RegionKind::SectionHeaders);
+    let stripped = sample("hello-sstripped").layout();
+    assert_eq!(stripped.last().unwrap().kind, RegionKind::SegmentData);
+}
+
+#[test]
+fn kernel_modules_show_their_metadata_and_signer() {
+    let elf = sample("hello-signed.ko");
+    let module = elf.module.as_ref().expect("kernel module");
+
+    assert_eq!(module.modinfo("license").collect::<Vec<_>>(), ["GPL"]);
+    assert_eq!(module.modinfo("alias").count(), 2);
+    assert!(module.modinfo("vermagic").next().unwrap().starts_with("6.1.0-18-amd64 SMP"));
+    let versions: Vec<(u32, &str)> = module.versions.iter().map(|version| (version.crc, version.name.as_str())).collect();
+    assert_eq!(versions, [(0xbdfb6dbb, "module_layout"), (0x92997ed8, "_printk")]);
+    assert_eq!((module.name.as_deref(), module.init.as_deref(), module.exit.as_deref()), (Some("hello"), Some("hello_init"), Some("hello_exit")));
+
+    let signature = module.signature.as_ref().expect("module signature");
+    assert_eq!(signature.id_type, PKEY_ID_PKCS7);
+    assert_eq!(signature.offset + signature.sig_len as u64 + 12 + 28, elf.file_size);
+    let signer = signature.pkcs7.as_ref().expect("decoded PKCS#7 signer");
+    assert_eq!(signer.issuer.as_deref(), Some("O=bininfo, CN=Build time autogenerated kernel key, emailAddress=test@example.com"));
+    assert_eq!((signer.digest.as_str(), signer.algorithm.as_str(), signer.size), ("sha256", "rsaEncryption", 256));
+}
@@ -0,0 +1,68 @@
+//! Several files, directories and globs in one run.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn crashers() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/crashers")
+}
+
+fn bininfo(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn brief_prints_a_line_per_file_in_directories() {
+    let dir = crashers();
+    let output = bininfo(&["--brief", "--lenient", "--output", "plain", "--recursive", dir.to_str().unwrap()]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Files that can't be recovered are reported on stderr, one line each
+    let files = fs::read_dir(&dir).unwrap().count();
+    assert_eq!(stdout.lines().count() + stderr.lines().count(), files);
+    assert_eq!(output.status.code(), Some(1));
+
+    let line = format!("{}: ELF REL 386 little-endian @ 0x0 (1 warning)", dir.join("elf-symtab-zero-entsize").display());
+    assert!(stdout.lines().any(|l| l == line));
+}
+
+#[test]
+fn directories_need_recursive() {
+    let dir = crashers();
+    let output = bininfo(&["--brief", dir.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("is a directory, use --recursive"));
+}
+
+#[test]
+fn globs_expand_to_an_array_of_json_documents() {
+    let pattern = crashers().join("elf-symtab-*");
+    let output = bininfo(&["--output", "json", "--lenient", pattern.to_str().unwrap()]);
+    let docs: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(docs.as_array().map(Vec::len), Some(2));
+}
+
+#[test]
+fn dash_reads_stdin() {
+    let buf = fs::read(crashers().join("elf-symtab-zero-entsize")).unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(["--brief", "--lenient", "--output", "plain", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(&buf).unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "-: ELF REL 386 little-endian @ 0x0 (1 warning)\n");
+}
@@ -0,0 +1,114 @@
+//! JSON output, on files under tests/samples. See tests/elf.rs for how they
+//! were built.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde_json::Value;
+
+fn json(name: &str) -> Value {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/samples").join(name);
+    let output = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(["--output", "json"])
+        .arg(path)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+fn named<'a>(entries: &'a Value, name: &str) -> &'a Value {
+    entries.as_array().unwrap().iter().find(|entry| entry["name"] == name).unwrap()
+}
+
+#[test]
+fn elf_names_are_resolved_instead_of_dumping_string_tables() {
+    let doc = json("hello-g.o");
+    let elf = &doc["data"];
+
+    assert_eq!(doc["format"], "elf");
+    for table in ["sh_strtab", "symstr", "dynstr"] {
+        assert!(elf.get(table).is_none(), "{} should not be serialized", table);
+    }
+    assert_eq!(named(&elf["section_headers"], ".debug_info")["sh_type"], 1);
+    assert_eq!(named(&elf["symtab"], "main")["st_size"].as_u64().map(|size| size > 0), Some(true));
+}
+
+#[test]
+fn elf_dynamic_names_and_versions_are_resolved() {
+    let doc = json("greet");
+    let elf = &doc["data"];
+
+    assert_eq!(named(&elf["dynsym"], "greet")["st_info"], 0x12);
+    let needed: Vec<&Value> = elf["dynamic"].as_array().unwrap().iter().filter(|entry| entry["d_tag"] == 1).collect();
+    assert_eq!(needed.len(), 1);
+    assert_eq!(needed[0]["string"], "libc.so.6");
+
+    let libc = &elf["verneed"][0];
+    assert_eq!(libc["file"], "libc.so.6");
+    assert_eq!(named(&libc["aux"], "GLIBC_2.2.5")["vna_flags"], 0);
+}
+
+#[test]
+fn elf_hardening_is_given_for_linked_files() {
+    let hardening = &json("greet")["data"]["hardening"];
+    assert_eq!(hardening["relro"], "Partial");
+    assert_eq!(hardening["pie"], "Pie");
+    assert_eq!(hardening["nx"], true);
+    assert_eq!((&hardening["ibt"], &hardening["shstk"]), (&Value::Bool(true), &Value::Bool(true)));
+    assert_eq!(hardening["fortified"].as_array().map(Vec::len), Some(0));
+
+    assert!(json("hello-g.o")["data"]["hardening"].is_null());
+}
+
+#[test]
+fn elf_layout_and_segment_sections_are_given() {
+    let doc = json("greet");
+    let elf = &doc["data"];
+
+    let segments = elf["segment_sections"].as_array().unwrap();
+    assert_eq!(segments.len(), elf["program_headers"].as_array().unwrap().len());
+    let interp = elf["program_headers"].as_array().unwrap().iter().position(|header| header["p_type"] == 3).unwrap();
+    let sections: Vec<&Value> = segments[interp].as_array().unwrap().iter()
+        .map(|index| &elf["section_headers"][index.as_u64().unwrap() as usize]["name"])
+        .collect();
+    assert_eq!(sections, [".interp"]);
+
+    // Regions cover the file end to end
+    let mut end = 0;
+    for region in elf["layout"].as_array().unwrap() {
+        let offset = region["offset"].as_u64().unwrap();
+        if region["overlaps"].is_null() {
+            assert_eq!(offset, end);
+        }
+        end = end.max(offset + region["size"].as_u64().unwrap());
+    }
+    assert_eq!(Some(end), elf["file_size"].as_u64());
+    assert_eq!(elf["layout"][2]["kind"]["Section"], 1);
+}
+
+#[test]
+fn elf_notes_are_decoded() {
+    let doc = json("greet");
+    let notes = doc["data"]["notes"].as_array().unwrap();
+
+    let abi_tag = notes.iter().find(|note| note["n_type"] == 1).unwrap();
+    assert_eq!(abi_tag["decoded"]["AbiTag"]["version"], serde_json::json!([3, 2, 0]));
+    // GNU_PROPERTY_X86_FEATURE_1_AND with IBT and SHSTK
+    let properties = notes.iter().find(|note| note["n_type"] == 5).unwrap()["decoded"]["Properties"].as_array().unwrap();
+    let feature = properties.iter().find(|property| property["pr_type"] == 0xC0000002u32).unwrap();
+    assert_eq!(feature["data"], serde_json::json!([3, 0, 0, 0]));
+}
+
+#[test]
+fn elf_relocations_are_listed_by_table_since_schema_2() {
+    let doc = json("greet");
+    let elf = &doc["data"];
+
+    assert_eq!(doc["schema_version"], 2);
+    for table in ["reldyn", "relplt", "reladyn", "relaplt"] {
+        assert!(elf.get(table).is_none(), "{} was replaced by relocations", table);
+    }
+    let tables: Vec<&Value> = elf["relocations"].as_array().unwrap().iter().map(|relocs| &relocs["name"]).collect();
+    assert_eq!(tables, [".rela.dyn", ".rela.plt"]);
+}
@@ -0,0 +1,42 @@
+//! Lenient parsing keeps what it can read from damaged files.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use bininfo::{Binary, Recovery};
+
+fn crasher(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/crashers").join(name)
+}
+
+#[test]
+fn lenient_parse_keeps_the_rest_of_the_file() {
+    let buf = fs::read(crasher("elf-symtab-zero-entsize")).unwrap();
+    let mut recovery = Recovery::lenient();
+    let elf = match Binary::parse_with(&buf, &mut recovery).unwrap() {
+        Binary::Elf(elf) => elf,
+        _ => panic!("should parse as ELF"),
+    };
+
+    assert!(elf.symtab.is_empty());
+    assert!(!elf.section_headers.is_empty());
+    assert_eq!(recovery.warnings.len(), 1);
+    assert_eq!(recovery.warnings[0].offset, Some(0xEC));
+    assert_eq!(recovery.warnings[0].context, ["ELF symbol table", "section .symtab"]);
+}
+
+#[test]
+fn binary_lists_warnings_at_the_end() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bininfo"))
+        .args(["--lenient", "--output", "plain"])
+        .arg(crasher("elf-reloc-symbol-out-of-range"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let warnings = stdout.find("Warnings(1)").expect("warnings section");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout.contains("<invalid>"));
+    assert!(stdout[warnings..].contains("Could not read section .rela.plt: 1 relocations with invalid symbol index, first is 256"));
+}